@@ -14,392 +14,394 @@ CREATE TABLE zones (
                 hot_zone BOOLEAN NOT NULL DEFAULT FALSE,
                 mission BOOLEAN NOT NULL DEFAULT FALSE,
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-                verified BOOLEAN NOT NULL DEFAULT FALSE
+                verified BOOLEAN NOT NULL DEFAULT FALSE,
+                deleted_at DATETIME,
+                expansion_id INTEGER REFERENCES expansions (id)
             );
-INSERT INTO zones VALUES(1,'Ak''Anon','[[0,0]]','Classic','Faydwer','Indoor','["Steamfont Mountains"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(2,'Befallen','[[7,25]]','Classic','Antonica','Indoor','["Commonlands"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(3,'Blackburrow','[[4,15]]','Classic','Antonica','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(4,'Castle Mistmoore','[[20,45]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(5,'Clan Runnyeye','[[7,30]]','Classic','','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(6,'Commonlands','[[1,20],[35,35]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(7,'Crushbone','[[5,20]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(8,'Dagnor''s Cauldron','[[10,25]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(9,'East Karana','[[10,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(10,'Erud''s Crossing','[[5,15]]','Classic','Odus','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(11,'Erudin','[[0,0]]','Classic','Odus','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(12,'Erudin Palace','[[0,0]]','Classic','Odus','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(13,'Estate of Unrest','[[10,35]]','Classic','Faydwer','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(14,'Everfrost Peaks','[[1,25]]','Classic','Antonica','Outdoor','["Halas","Blackburrow","Permafrost Keep","Plane of Knowledge"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(15,'Felwithe','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(16,'Freeport','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(17,'Freeport Sewers','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(18,'Gorge of King Xorbb','[[8,25]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(19,'Greater Faydark','[[1,12]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(20,'Grobb','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(21,'Halas','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(22,'High Keep','[[20,40]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(23,'Highpass Hold','[[9,22]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(24,'Infected Paw','[[61,80]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(25,'Innothule Swamp','[[1,10],[25,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(26,'Kaladim','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(27,'Kedge Keep','[[32,50]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(28,'Kithicor Forest','[[1,13],[35,50]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(29,'Lake Rathetear','[[15,35]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(30,'Lavastorm Mountains','[[10,19],[25,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(31,'Lesser Faydark','[[10,30],[40,50]]','Classic','','Outdoor','["Greater Faydark","Steamfont Mountains","Mistmoore Castle"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(32,'Lower Guk','[[25,50]]','Classic','Antonica','dungeon','["Upper Guk"]','','',0,1,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(33,'Misty Thicket','[[1,12]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(34,'Nagafen''s Lair','[[40,55]]','Classic','','Dungeon','["Lavastorm Mountains","Solusek''s Eye"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(35,'Najena','[[15,35]]','Classic','','Dungeon','["Lavastorm Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(36,'Nektulos Forest','[[1,20],[25,30]]','Classic','','Outdoor','["Lavastorm Mountains","Commonlands","Neriak"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(37,'Neriak','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(38,'North Karana','[[10,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(39,'North Ro','[[5,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(40,'Ocean of Tears','[[9,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(41,'Oggok','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(42,'Paineel','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(43,'Permafrost Keep','[[15,50]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(44,'Plane of Fear','[[46,65]]','Classic','','outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(45,'Plane of Hate','[[46,65]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(46,'Plane of Sky','[[46,65]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(47,'Qeynos','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(48,'Qeynos Catacombs','[[0,0]]','Classic','','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(49,'Qeynos Hills','[[2,10]]','Classic','','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(50,'The Rathe Mountains','[[1,35]]','Classic','Antonica','outdoor',replace(replace('["Lake Rathetear",\r\n"Plane of Knowledge",\r\n"The Feerrott"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(51,'Rivervale','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(52,'Solusek''s Eye','[[20,40]]','Classic','','dungeon','["Nagafen''s Lair","Lavastorm Mountains"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(53,'South Karana','[[15,35]]','Classic','','Outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(54,'South Ro','[[5,20]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(55,'Steamfont Mountains','[[1,18],[30,35]]','Classic','Faydwer','Outdoor','["Lesser Faydark","Ak''Anon","Dragonscale Hills","Loping Plains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(56,'Surefall Glade','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(57,'Temple of Cazic-Thule','[[52,65]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(58,'Temple of Solusek Ro','[[0,0]]','Classic','','City','["Lavastorm Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(59,'The Feerrott','[[1,30],[34,40]]','Classic','','Outdoors','["Plane of Fear","Oggok","Innothule Swamp","Rathe Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(60,'Toxxulia Forest','[[1,10]]','Classic','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(61,'Upper Guk','[[4,30]]','Classic','','Dungeon','["Lower Guk","Innothule Swamp"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(62,'West Karana','[[4,20]]','Classic','','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(63,'Burning Woods','[[35,55]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(64,'Chardok','[[40,65]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(65,'City of Mist','[[35,55]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(66,'Crypt of Dalnir','[[25,35]]','Kunark','Kunark','dungeon','["Warsliks Woods"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(67,'Dreadlands','[[35,50]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(68,'East Cabilis','[[0,0]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(69,'Firiona Vie','[[20,35]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(70,'Frontier Mountains','[[22,45]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(71,'Howling Stones','[[40,60]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(72,'Kaesora','[[30,40]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(73,'Karnor''s Castle','[[40,55]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(74,'Kurn''s Tower','[[8,30]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(75,'Lake of Ill Omen','[[1,30]]','Kunark','Kunark','Outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(76,'Mines of Nurga','[[40,55]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(77,'Old Sebilis','[[40,60]]','Kunark','Kunark','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(78,'Skyfire Mountains','[[40,60]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(79,'Swamp of No Hope','[[1,25]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(80,'Temple of Droga','[[48,63]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(81,'The Emerald Jungle','[[30,45]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(82,'Field of Bone','[[1,20]]','Kunark','Kunark','Outdoor','["East Cabilis","Emerald Jungle","Kaesora","Kurn''s Tower","Plane of Knowledge","Swamp of No Hope","Warsliks Woods"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(83,'The Overthere','[[15,40],[50,55]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(84,'Trakanon''s Teeth','[[30,50]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(85,'Veeshan''s Peak','[[60,75]]','Kunark','Kunark','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(86,'Veksar','[[40,60]]','Kunark','Kunark','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(87,'Warsliks Wood','[[1,30]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(88,'West Cabilis','[[0,0]]','Kunark','Kunark','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(89,'Cobalt Scar','[[35,55]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(90,'Crystal Caverns','[[25,45]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(91,'Dragon Necropolis','[[45,60]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(92,'Eastern Wastes','[[25,45]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(93,'Iceclad Ocean','[[28,45]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(94,'Icewell Keep','[[35,60]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(95,'Kael Drakkal','[[30,60]]','Velious','Velious','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(96,'Plane of Growth','[[46,60]]','Velious','Outer Planes','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(97,'Plane of Mischief','[[52,65]]','Velious','Outer Planes','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(98,'Siren''s Grotto','[[50,65]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(99,'Skyshrine','[[35,60]]','Velious','Velious','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(100,'Sleeper''s Tomb','[[60,75]]','Velious','Velious','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(101,'Stonebrunt Mountains','[[15,40]]','Velious','Odus','Outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(102,'Temple of Veeshan','[[60,75]]','Velious','Velious','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(103,'The Warrens','[[5,20]]','Velious','Odus','Dungeon','["Paineel","Stonebrunt Mountains"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(104,'Thurgadin','[[30,45]]','Velious','Velious','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(105,'Tower of Frozen Shadow','[[30,55]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(106,'Velketor''s Labyrinth','[[45,65]]','Velious','Velious','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(107,'Wakening Land','[[40,55]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(108,'Western Wastes','[[50,65]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(109,'Shadow Haven','[[0,0]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(110,'The Nexus','[[0,0]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(111,'Echo Caverns','[[20,45]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(112,'Acrylia Caverns','[[40,60]]','Shadows of Luclin','Luclin','Indoor','["Grimling Forest"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(113,'Shar Vahl','[[0,0]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(114,'Paludal Caverns','[[5,25]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(115,'Fungus Grove','[[40,55]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(116,'Vex Thal','[[60,75]]','Shadows of Luclin','Luclin','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(117,'Sanctus Seru','[[40,60]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(118,'Katta Castellum','[[30,60]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(119,'Netherbian Lair','[[15,35]]','Shadows of Luclin','Luclin','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(120,'Ssraeshza Temple','[[60,75]]','Shadows of Luclin','Luclin','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(121,'Grieg''s End','[[45,60]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(122,'The Deep','[[50,60]]','Shadows of Luclin','Luclin','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(123,'Shadeweaver''s Thicket','[[1,25]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(124,'Hollowshade Moor','[[10,45]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(125,'Grimling Forest','[[25,50]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(126,'Marus Seru','[[15,45]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(127,'Mons Letalis','[[35,50]]','Shadows of Luclin','Luclin','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(128,'Twilight Sea','[[25,50]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(129,'The Grey','[[40,55]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(130,'Tenebrous Mountains','[[35,50]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(131,'Maiden''s Eye','[[45,60]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(132,'Dawnshroud Peaks','[[24,45]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(133,'Scarlet Desert','[[30,50]]','Shadows of Luclin','Luclin','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(134,'Umbral Plains','[[50,65]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(135,'Akheva Ruins','[[45,65]]','Shadows of Luclin','Luclin','Indoor','["Maiden''s Eye"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(136,'Doomfire, the Burning Lands (Plane of Fire)','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(137,'Drunder, Fortress of Zek (Plane of Tactics)','[[60,65]]','Planes of Power','Power','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(138,'Eryslai, the Kingdom of Wind (Plane of Air)','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(139,'Halls of Honor','[[60,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(140,'Lair of Terris Thule','[[55,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(141,'Plane of Disease','[[46,60]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(142,'Plane of Innovation','[[46,63]]','Planes of Power','Power','outdoor','["Plane of Time A", "Plane of Tranquility"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(143,'Plane of Justice','[[46,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(144,'Plane of Knowledge','[[0,0]]','Planes of Power','Power','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(145,'Plane of Nightmare','[[46,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(146,'Plane of Storms','[[55,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(147,'Plane of Time A','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(148,'Plane of Time B','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(149,'Plane of Torment','[[55,65]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(150,'Plane of Tranquility','[[0,0]]','Planes of Power','Power','Outdoor City/Plane','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(151,'Plane of Valor','[[55,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(152,'Plane of War','[[100,105]]','Call of the Forsaken','Power','Outdoor','["Plane of Tranquility"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(153,'Ragrax, Stronghold of the Twelve','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(154,'Reef of Coirnav (Plane of Water)','[[65,75]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(155,'Ruins of Lxanvom (Crypt of Decay)','[[60,65]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(156,'Solusek Ro''s Tower','[[65,75]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(157,'Temple of Marr','[[60,70]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(158,'Torden, the Bastion of Thunder','[[57,70]]','Planes of Power','Power','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(159,'Gulf of Gunthak','[[35,50]]','The Legacy of Ykesha','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(160,'Dulak''s Harbor','[[40,55]]','The Legacy of Ykesha','Antonica','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(161,'Torgiran Mines','[[45,60]]','The Legacy of Ykesha','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(162,'Crypt of Nadox','[[50,65]]','The Legacy of Ykesha','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(163,'Hate''s Fury, The Scorned Maiden','[[55,65]]','The Legacy of Ykesha','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(169,'Abysmal Sea','[[0,0]]','Gates of Discord','Taelosia','Outdoor','["Natimbi, The Broken Shores","Nedaria''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(170,'Nedaria''s Landing','[[15,35]]','Gates of Discord','Antonica','Outdoors','["Guild Lobby","Abysmal Sea","Jaggedpine Forest","Natimbi, The Broken Shores","The Forgotton Halls"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(171,'Natimbi, The Broken Shores','[[43,60]]','Gates of Discord','Taelosia','Outdoors','["Abysmal Sea","Natimbi, The Broken Shores","Nedaria''s Landing","Qinimi, Court of Nihilia","Qvic, Prayer Grounds of Calling"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(172,'Qinimi, Court of Nihilia','[[50,75]]','Gates of Discord','Taelosia','Outdoors','["Natimbi, The Broken Shores","Ferubi, Forgotten Temple of Taelosia","Riwwi, Coliseum of Games","Barindu, Hanging Gardens"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(173,'Riwwi, Coliseum of Games','[[60,70]]','Gates of Discord','Taelosia','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(174,'Barindu, Hanging Gardens','[[55,65]]','Gates of Discord','Taelosia','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(176,'Tipt, Treacherous Crags','[[65,75]]','Gates of Discord','Taelosia','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(177,'Ferubi, Forgotten Temple of Taelosia','[[60,75]]','Gates of Discord','Taelosia','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(178,'Kod''Taz, Broken Trial Grounds','[[65,75]]','Gates of Discord','Taelosia','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(179,'Yxtta, Pulpit of Exiles','[[65,75]]','Gates of Discord','Taelosia','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(181,'Qvic, Prayer Grounds of Calling','[[65,75]]','Gates of Discord','Taelosia','outdoor',replace(replace('["Inktu`Ta, the Unmasked Chapel",\r\n"Kod''Taz, Broken Trial Grounds",\r\n"Natimbi, the Broken Shores",\r\n"Txevu, Lair of the Elite",\r\n"Yxtta, Pulpit of Exiles"]\r\n','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(196,'Dranik''s Scar','[[30,60]]','Omens of War','Kuua','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(197,'Harbinger''s Spire','[[55,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(198,'The Bloodfields','[[55,75]]','Omens of War','Kuua','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(199,'Ruined City of Dranik','[[65,75]]','Omens of War','Kuua','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(200,'Nobles'' Causeway','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(201,'Wall of Slaughter','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(202,'Muramite Proving Grounds','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(203,'Plane of Hate: Innoruuk''s Realm','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(204,'Riftseeker''s Sanctum','[[65,80]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(228,'Guild Hall','[[0,0]]','Dragons of Norrath','Antonica','Indoor',replace(replace('["Arcstone, Isle of Spirits",\r\n"Argath, Bastion of Illdaera",\r\n"Barindu, Hanging Gardens",\r\n"Brell''s Rest",\r\n"Cobalt Scar",\r\n"Commonlands",\r\n"Dragonscale Hills",\r\n"Dreadlands",\r\n"Ethernere Tainted West Karana West Karana",\r\n"Feerrott, the Dream",\r\n"Goru`kar Mesa",\r\n"Greater Faydark",\r\n"Guild Lobby",\r\n"Iceclad Ocean",\r\n"Katta Castrum",\r\n"Katta Castrum: Deluge",\r\n"Lavastorm Mountains",\r\n"Lceanium",\r\n"North Karana",\r\n"Plane of Hate",\r\n"Plane of Sky",\r\n"Plane of Time",\r\n"Shard''s Landing",\r\n"Stonebrunt Mountains",\r\n"Toxxulia Forest",\r\n"Twilight Sea",\r\n"Undershore",\r\n"Wall of Slaughter",\r\n"The Eastern Wastes",\r\n"Stratos: Zephyr''s Flight",\r\n"Cobalt Scar",\r\n"The Overthere",\r\n"Shar Vahl, Divided",\r\n"Maiden''s Eye",\r\n"Laurion''s Inn"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(229,'Guild Lobby','[[0,0]]','Dragons of Norrath','Antonica','City','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(230,'Lavaspinner''s Lair','[[45,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(231,'Stillmoon Temple','[[55,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(232,'The Accursed Nest','[[65,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(233,'The Ascent','[[60,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(234,'The Broodlands','[[40,60]]','Dragons of Norrath','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(235,'Thundercrest Isles','[[60,75]]','Dragons of Norrath','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(236,'Tirranun''s Delve','[[60,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(285,'Corathus Creep','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(286,'Dreadspire Keep','[[70,80]]','Depths of Darkhollow','Antonica','Indoor','["Stoneroot Falls", "The Hive"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(292,'Ruins of Illsalin','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(293,'Shadow Spine','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(294,'Stoneroot Falls','[[65,75]]','Depths of Darkhollow','Antonica','Outdoor','["Dreadspire Keep","The Hive","Undershore"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(295,'The Hive','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(297,'Undershore','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(343,'Arcstone, Isle of Spirits','[[60,75]]','Prophecy of Ro','Antonica','Outdoor',replace(replace('["East Freeport",\r\n"Guild Hall",\r\n"Relic, the Artifact City",\r\n"West Freeport"]','\r',char(13)),'\n',char(10)),'','',0,1,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(344,'Relic, the Artifact City','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(345,'Ruins of Takish-Hiz','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(346,'Sverag, Stronghold of Rage','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(347,'The Devastation','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(348,'The Elddar Forest','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(349,'Theater of Blood','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(359,'Freeport Sewers','[[5,25]]','Prophecy of Ro','Antonica','dungeon',replace(replace('["East Freeport",\r\n"North Ro",\r\n"West Freeport"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(382,'Crescent Reach','[[1,20]]','The Serpent''s Spine','Antonica','outdoor','["Blightfire Moors","Bixie Warfront"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(383,'Highpass (revamp)','[[15,25]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(384,'Blightfire Moors','[[20,40]]','The Serpent''s Spine','Antonica','outdoor','["Crescent Reach","Goru''kar Mesa","Stone Hive"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(385,'Stone Hive','[[35,45]]','The Serpent''s Spine','Antonica','outdoor','["Blightfire Moors","Goru''kar Mesa"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(386,'Goru''kar Mesa','[[40,60]]','The Serpent''s Spine','Antonica','Outdoors','["Blightfire Moors","Blackfeather Roost","Guild Hall","Sunderock Springs","The Steppes"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(387,'Blackfeather Roost','[[55,65]]','The Serpent''s Spine','Antonica','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(388,'The Steppes','[[60,70]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(389,'Sunderock Springs','[[63,73]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(390,'Vergalid Mines','[[60,75]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(391,'Direwind Cliffs','[[70,75]]','The Serpent''s Spine','Antonica','Outdoors','["Ashengate, Reliquary of the Scale", "Sunderock Springs"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(392,'Icefall Glacier','[[70,75]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(393,'Ashengate, Reliquary of the Scale','[[75,75]]','The Serpent''s Spine','Antonica','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(394,'Valdeholm','[[72,75]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(395,'Frostcrypt, Throne of the Shade King','[[75,75]]','The Serpent''s Spine','Antonica','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(396,'Barren Coast','[[50,75]]','The Buried Sea','Odus','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(406,'Jewel of Atiiki','[[58,80]]','The Buried Sea','Odus','Outdoors','["Katta Castrum"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(412,'Katta Castrum','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(425,'Silyssar, New Chelsith','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(431,'Solteris, the Throne of Ro','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(437,'Thalassius, the Coral Keep','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(443,'The Buried Sea','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(449,'Zhisza, the Shissar Sanctuary','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(455,'Bloodmoon Keep','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(456,'Crypt of Shade','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(457,'Crystallos, Lair of the Awakened','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(459,'Dragonscale Hills','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(460,'Fortress Mechanotus','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(461,'Gyrospire Beza','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(462,'Gyrospire Zeka','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(463,'Hills of Shade','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(464,'Loping Plains','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(465,'Meldrath''s Majestic Mansion','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(466,'S.H.I.P Workshop','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(467,'The Steam Factory','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(502,'Bloody Kithicor','[[75,85]]','Seeds of Destruction','The Void','outdoor','["Old Commonlands", "The Void"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(503,'Citadel of the Worldslayer','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(504,'City of Dranik','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(505,'Field of Scale','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(506,'Kaesora Hatchery','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(507,'Kaesora Library','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(508,'Korafax, Home of the Riders','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(509,'Korascian Warrens','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(510,'Oceangreen Hills','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(511,'Oceangreen Village','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(512,'Old Blackburrow','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(513,'Old Bloodfields','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(514,'Old Commonlands','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(515,'Old Kurn''s Tower','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(516,'Rathe Council Chambers','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(517,'Temple of Bertoxxulous','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(518,'The Precipice of War','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(519,'Toskirakk','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(548,'Brell''s Rest','[[80,85]]','Underfoot','Norrath','outdoor','["Cooling Chamber","The Foundation"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(549,'Cooling Chamber','[[80,85]]','Underfoot','Norrath','indoor','["Brell''s Rest","Volska''s Husk"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(550,'Pellucid Grotto','[[85,85]]','Underfoot','Norrath','indoor','["Brell''s Rest","Cooling Chamber","Fungal Forest","The Underquarry"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(551,'Fungal Forest','[[85,90]]','Underfoot','Norrath','indoor','["Arthicrex","Pellucid Grotto"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(552,'The Underquarry','[[80,85]]','Underfoot','Norrath','underground','["Brell''s Rest","The Foundation"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(553,'The Foundation','[[82,87]]','Underfoot','Norrath','underground','["Brell''s Rest","The Underquarry","Convorteum"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(554,'Volska''s Husk','[[83,88]]','Underfoot','Norrath','indoor','["Convorteum","Arthicrex"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(555,'Arthicrex','[[84,89]]','Underfoot','Norrath','dungeon','["Volska''s Husk","The Grounds"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(556,'Brell''s Temple','[[86,91]]','Underfoot','Norrath','indoor','["The Foundation","Convorteum"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(557,'Convorteum','[[87,92]]','Underfoot','Norrath','raid','["The Foundation","Brell''s Temple"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(558,'Lichen Creep','[[87,92]]','Underfoot','Norrath','raid','["Arthicrex","Corathus Creep"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(559,'Al''Kabor''s Nightmare','[[88,95]]','House of Thule','Antonica','Outdoor','["House of Thule, Upper Floors"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(560,'Erudin Burning','[[83,90]]','House of Thule','Antonica','Outdoor','["House of Thule, Lower Floors"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(561,'Fear Itself','[[88,92]]','House of Thule','Antonica','Outdoor','["House of Thule, Upper Floors"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(562,'Feerrott, the Dream','[[80,90]]','House of Thule','Antonica','Outdoor','["Guild Hall", "House of Thule, Lower Floors", "Plane of Knowledge", "The Feerrott"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(563,'House of Thule, Lower Floors','[[83,88]]','House of Thule','Antonica','Outdoor','["Erudin Burning", "Feerrott, the Dream", "House of Thule, Upper Floors", "The Grounds", "The Library"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(564,'House of Thule, Upper Floors','[[85,92]]','House of Thule','Antonica','Indoor','["Al''Kabor''s Nightmare", "Fear Itself", "House of Thule, Lower Floors", "Miragul''s Nightmare", "Morell''s Castle"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(565,'Miragul''s Nightmare','[[83,95]]','House of Thule','Antonica','Outdoor','["House of Thule, Upper Floors"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(566,'Morell''s Castle','[[89,96]]','House of Thule','Antonica','Indoor','["House of Thule, Upper Floors","Sanctum Somnium"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(567,'Sanctum Somnium','[[85,96]]','House of Thule','Antonica','Indoor','["Morell''s Castle"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(568,'Sunrise Hills','[[0,0]]','House of Thule','Antonica','Outdoor','["Guild Lobby"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(569,'The Grounds','[[83,94]]','House of Thule','Antonica','Outdoor','["House of Thule, Lower Floors", "The Well"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(570,'The Library','[[84,92]]','House of Thule','Antonica','Indoor','["House of Thule, Lower Floors"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(571,'The Well','[[83,93]]','House of Thule','Antonica','Indoor','["The Grounds"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(604,'Argath, Bastion of Illdaera','[[90,92]]','Veil of Alaris','Alaris','Outdoor','["Valley of Lunanyn"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(605,'Valley of Lunanyn','[[91,93]]','Veil of Alaris','Alaris','outdoor','["Argath, Bastion of Illdaera","Sarith, City of Tides"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(606,'Sarith, City of Tides','[[92,94]]','Veil of Alaris','Alaris','outdoor','["Valley of Lunanyn","Rubak Oseka, Temple of the Sea","The Resplendent Temple"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(607,'Rubak Oseka, Temple of the Sea','[[92,96]]','Veil of Alaris','Alaris','Indoor','["Sarith, City of Tides"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(608,'The Resplendent Temple','[[93,95]]','Veil of Alaris','Alaris','outdoor','["Sarith, City of Tides","Beasts'' Domain"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(609,'Beasts'' Domain','[[94,96]]','Veil of Alaris','Alaris','outdoor','["The Resplendent Temple","Pillars of Alra"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(610,'Pillars of Alra','[[95,97]]','Veil of Alaris','Alaris','outdoor','["Beasts'' Domain","City of Bronze"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(611,'City of Bronze','[[95,98]]','Veil of Alaris','Alaris','outdoor','["Pillars of Alra","Sepulcher of Order"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(612,'Windsong Sanctuary','[[96,98]]','Veil of Alaris','Alaris','outdoor','["Sepulcher of Order"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(613,'Sepulcher of Order','[[94,101]]','Veil of Alaris','Alaris','Indoor','["Erillion, City of Bronze", "Sepulcher East", "Sepulcher West", "Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(614,'Shard''s Landing','[[95,100]]','Rain of Fear','Norrath','outdoor','["Xorbb''s Cursed Caverns","Valley of King Xorbb","Breeding Grounds"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(615,'Breeding Grounds','[[97,105]]','Rain of Fear','Norrath','Indoor','["Dragon Necropolis", "Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(616,'Evantil, the Vile Oak','[[95,100]]','Rain of Fear','Norrath','dungeon','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(617,'Grelleth''s Palace, the Chateau of Filth','[[95,100]]','Rain of Fear','Norrath','dungeon','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(618,'Heart of Fear','[[98,100]]','Rain of Fear','Norrath','dungeon','["Chelsith Reborn","Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(619,'Chelsith Reborn','[[95,100]]','Rain of Fear','Odus','dungeon','["Shard''s Landing","Heart of Fear"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(620,'The Plane of Shadow','[[95,100]]','Rain of Fear','Planes','raid','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(621,'Xorbb''s Cursed Caverns','[[96,100]]','Rain of Fear','Norrath','dungeon','["Shard''s Landing"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(622,'Kael Drakkel: The King''s Madness','[[96,100]]','Rain of Fear','Velious','dungeon','["East Wastes: Zeixshi-Kar''s Awakening"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(623,'The Crystal Caverns: Fragment of Fear','[[95,100]]','Rain of Fear','Velious','dungeon','["East Wastes, Zeixshi-Kar''s Awakening"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(624,'Ethernere Tainted West Karana','[[95,100]]','Call of the Forsaken','Antonica','outdoor','["Neriak Fourth Gate","Dead Hills"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(625,'Neriak - Fourth Gate','[[95,100]]','Call of the Forsaken','Faydwer','dungeon','["Ethernere Tainted West Karana"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(626,'Dead Hills','[[95,100]]','Call of the Forsaken','Antonica','outdoor','["Ethernere Tainted West Karana","Bixie Warfront"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(627,'Bixie Warfront','[[96,100]]','Call of the Forsaken','Faydwer','outdoor','["Dead Hills","Ethernere Tainted West Karana"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(628,'Tower of Rot','[[100,100]]','Call of the Forsaken','Planes','dungeon','["Argin-Hiz"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(629,'Argin-Hiz','[[100,104]]','Call of the Forsaken','Planes','Outdoor','["Ethernere Tainted West Karana"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(630,'Tempest Temple','[[100,105]]','The Darkened Sea','Buried Sea','outdoor','["Brother Island","Caverns of Endless Song","Thuliasaur Island"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(631,'Brother Island','[[100,105]]','The Darkened Sea','Buried Sea','outdoor','["Tempest Temple"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(632,'Caverns of Endless Song','[[100,105]]','The Darkened Sea','Buried Sea','dungeon','["Tempest Temple"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(633,'Thuliasaur Island','[[100,105]]','The Darkened Sea','Buried Sea','outdoor','["Tempest Temple","Degmar, the Lost Castle"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(634,'Degmar, the Lost Castle','[[100,105]]','The Darkened Sea','Buried Sea','dungeon','["Thuliasaur Island","Arx Mentis"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(635,'Arx Mentis','[[103,105]]','The Darkened Sea','Buried Sea','raid','["Degmar, the Lost Castle"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(636,'Plane of Health','[[105,110]]','The Broken Mirror','Planes','outdoor','["Crypt of Decay","Plane of Fear: Revisited","Plane of Hate: Revisited"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(637,'Crypt of Decay','[[105,110]]','The Broken Mirror','Planes','dungeon','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(638,'Plane of Fear: Revisited','[[105,110]]','The Broken Mirror','Planes','raid','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(639,'Plane of Hate: Revisited','[[105,110]]','The Broken Mirror','Planes','raid','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(640,'Demiplane of Life','[[105,110]]','The Broken Mirror','Planes','raid','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(641,'Demiplane of Decay','[[105,110]]','The Broken Mirror','Planes','raid','["Crypt of Decay"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(642,'Lceanium','[[100,105]]','Empires of Kunark','Kunark','outdoor','["Scorched Woods","Chardok"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(643,'Scorched Woods','[[100,105]]','Empires of Kunark','Kunark','outdoor','["Lceanium","Frontier Mountains"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(644,'Frontier Mountains','[[103,105]]','Empires of Kunark','Kunark','outdoor','["Scorched Woods","Kor-Sha Laboratory","Droga"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(645,'Chardok','[[103,105]]','Empires of Kunark','Kunark','dungeon','["Lceanium"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(646,'Kor-Sha Laboratory','[[104,105]]','Empires of Kunark','Kunark','dungeon','["Frontier Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(647,'Droga','[[104,105]]','Empires of Kunark','Kunark','dungeon','["Frontier Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(648,'The Overthere (RoS)','[[105,110]]','Ring of Scale','Kunark','outdoor',replace(replace('["Frontier Mountains (EoK)",\r\n"Howling Stones (RoS)",\r\n"Plane of Knowledge",\r\n"Skyfire Mountains (RoS)"]','\r',char(13)),'\n',char(10)),'','',0,1,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(649,'Skyfire Mountains (RoS)','[[106,110]]','Ring of Scale','Kunark','outdoor',replace(replace('["Gorowyn",\r\n"Scorched Woods",\r\n"The Overthere (RoS)",\r\n"Veeshan''s Peak (RoS)"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(650,'Howling Stones [RoS]','[[107,110]]','Ring of Scale','Kunark','dungeon','["The Overthere (RoS)","Sathir''s Tomb"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(651,'Gorowyn','[[105,110]]','Ring of Scale','Kunark','city','["Skyfire Mountains (RoS)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(652,'Veeshan''s Peak','[[110,110]]','Ring of Scale','Kunark','raid','["Skyfire Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(653,'Stratos: Zephyr''s Flight','[[108,110]]','The Burning Lands','Elemental Planes','outdoor','["Esianti: Palace of the Winds","Mearatas: The Stone Demesne"]','','',0,1,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(654,'Esianti: Palace of the Winds','[[109,110]]','The Burning Lands','Elemental Planes','Outdoor','["Stratos: Zephyr''s Flight","Aalishai: Palace of Embers"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(655,'Aalishai: Palace of Embers','[[108,114]]','The Burning Lands','Elemental Planes','Outdoor','["Esianti: Palace of the Winds","Empyr: Realms of Ash"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(656,'Empyr: Realms of Ash','[[109,110]]','The Burning Lands','Elemental Planes','outdoor','["Aalishai: Palace of Embers","Mearatas: The Stone Demesne"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(657,'Mearatas: The Stone Demesne','[[110,110]]','The Burning Lands','Elemental Planes','raid','["Stratos: Zephyr''s Flight","Empyr: Realms of Ash"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(658,'The Eastern Wastes (ToV)','[[111,120]]','Torment of Velious','Velious','Outdoor','["Crystal Caverns (ToV)","Kael Drakkel (ToV)","Ry`Gorr Mines (ToV)","Sleeper''s Tomb (CoV)","The Great Divide (ToV)","The Tower of Frozen Shadow (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(659,'Ry`Gorr Mines (ToV)','[[112,121]]','Torment of Velious','Velious','Indoor','["Crystal Caverns (ToV)", "The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(660,'Crystal Caverns (ToV)','[[111,120]]','Torment of Velious','Velious','Indoor','["Ry`Gorr Mines (ToV)","The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(661,'The Great Divide (ToV)','[[113,120]]','Torment of Velious','Velious','Outdoor','["Plane of Knowledge","The Eastern Wastes (ToV)","Velketor''s Labyrinth (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(662,'The Tower of Frozen Shadow (ToV)','[[113,117]]','Torment of Velious','Velious','Indoors','["The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(663,'Velketor''s Labyrinth (ToV)','[[113,121]]','Torment of Velious','Velious','Indoors','["The Great Divide (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(664,'Kael Drakkel (ToV)','[[113,118]]','Torment of Velious','Velious','Indoor','["Cobalt Scar (CoV)", "The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(665,'Cobalt Scar (CoV)','[[115,116]]','Claws of Veeshan','Velious','Outdoor','["Kael Drakkel (CoV)","Skyshrine (CoV)","The Western Wastes (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(666,'Western Wastes (CoV)','[[115,120]]','Claws of Veeshan','Velious','Outdoors','["Cobalt Scar (CoV)","Dragon Necropolis (CoV)","Temple of Veeshan (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(667,'Dragon Necropolis (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoors','["Western Wastes (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(668,'Skyshrine (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoor','["Cobalt Scar (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(669,'Temple of Veeshan (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoor','["Western Wastes (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(670,'Sleeper''s Tomb (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoor','["The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(671,'Maiden''s Eye (TOL)','[[114,121]]','Terror of Luclin','Luclin','Outdoors','["Ka Vethan","Umbral Plains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(672,'Umbral Plains (TOL)','[[115,120]]','Terror of Luclin','Luclin','Outdoors','["Maiden''s Eye","Vex Thal"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(673,'Ka Vethan','[[115,120]]','Terror of Luclin','Luclin','Indoors','["Maiden''s Eye","Shadow Valley"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(674,'Vex Thal (TOL)','[[115,120]]','Terror of Luclin','Luclin','Outdoors','["Umbral Plains"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(675,'Shadow Valley','[[117,120]]','Terror of Luclin','Luclin','Outdoors','["Basilica of Adumbration","Ka Vethan"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(676,'Basilica of Adumbration','[[117,120]]','Terror of Luclin','Luclin','Indoors','["Shadow Valley"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(677,'Bloodfalls','[[117,121]]','Terror of Luclin','Luclin','Outdoors','["Castle Mistmoore"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(678,'Firefall Pass','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Umbral Plains","Shadeweaver''s Tangle"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(679,'Shadeweaver''s Tangle','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Firefall Pass","Shar Vahl, Divided"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(680,'Shar Vahl, Divided','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Shadeweaver''s Tangle","Ruins of Shadow Haven"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(681,'Ruins of Shadow Haven','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Shar Vahl, Divided","Deepshade"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(682,'Deepshade','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Ruins of Shadow Haven","Darklight Caverns"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(683,'Darklight Caverns','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Deepshade"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(684,'Paludal Depths','[[115,120]]','Night of Shadows','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(685,'Laurion Inn','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Ankexfen Keep","Moors of Nokk","Pal''Lomen","The Hero''s Forge","The Plane of Tranquility","Timorous Falls","Unkempt Woods"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(686,'Timorous Falls','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(687,'Ankexfen Keep','[[120,125]]','Laurion''s Song','Antonica','Outdoor','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(688,'Moors of Nokk','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(689,'Unkempt Woods','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(690,'The Hero''s Forge','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(691,'Pal''Lomen','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0);
-INSERT INTO zones VALUES(694,'Hodstock Hills','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Theater of Eternity", "East Freeport", "Nektulos Forest"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(695,'The Theater of Eternity','[[120,125]]','The Outer Brood','Antonica','Outdoor','["Hodstock Hills","The Harbinger''s Cradle","Aureate Covert"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(696,'Aureate Covert','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Theater of Eternity","The Harbinger''s Cradle","The Gilded Spire"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(697,'The Harbinger''s Cradle','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Theater of Eternity","Aureate Covert","The Chambers of Puissance"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(698,'The Chambers of Puissance','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Harbinger''s Cradle"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(699,'The Gilded Spire','[[120,125]]','The Outer Brood','Antonica','Outdoor','["Aureate Covert"]','','',0,0,0,'2025-07-21 23:58:30',1);
-INSERT INTO zones VALUES(704,'Great Divide','[[30,50]]','Velious','Velious','outdoor',replace(replace('["Eastern Wastes",\r\n"Icewell Keep",\r\n"Plane of Knowledge",\r\n"Plane of Mischief",\r\n"The Nexus",\r\n"Thurgadin",\r\n"Velketor''s Labyrinth"]','\r',char(13)),'\n',char(10)),'','',0,1,0,'2025-07-22 01:53:15',1);
-INSERT INTO zones VALUES(705,'The Mines of Gloomingdeep','[[1,15]]','The Serpent''s Spine','Antonica','Indoor','["Crescent Reach", "Plane of Knowledge"]','','',0,0,0,'2025-08-12 04:56:20',1);
-INSERT INTO zones VALUES(706,'Chapterhouse of the Fallen','[[100,105]]','Rain of Fear','Rain of Fear','Indoor','["Commonlands","Shard''s Landing"]','','',0,0,0,'2025-08-12 04:56:20',1);
-INSERT INTO zones VALUES(707,'Sathir''s Tomb','[[106,114]]','Ring of Scale','Kunark','Indoor','["Howling Stones [RoS]"]','','',0,0,0,'2025-08-12 04:56:20',1);
-INSERT INTO zones VALUES(708,'Ruins of Old Paineel (The Hole)','[[40,65]]','Classic','Odus','Indoor','["Brell''s Rest","Paineel"]','','',0,0,0,'2025-08-12 04:56:20',1);
-INSERT INTO zones VALUES(709,'Caverns of Exile (Solusek C)','[[50, 65]]','Ykesha','Antonica','Indoor','["Nagafen''s Lair","Solusek''s Eye (Solusek A)"]','','',0,0,0,'2025-08-12 04:56:20',1);
-INSERT INTO zones VALUES(710,'Katta Castrum: Deluge','[[100,105]]','The Darkened Sea','Odus','Outdoor','["Tempest Temple", "Guild Hall"]','','',0,0,0,'2025-08-12 21:35:15',1);
+INSERT INTO zones VALUES(1,'Ak''Anon','[[0,0]]','Classic','Faydwer','Indoor','["Steamfont Mountains"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,1);
+INSERT INTO zones VALUES(2,'Befallen','[[7,25]]','Classic','Antonica','Indoor','["Commonlands"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,1);
+INSERT INTO zones VALUES(3,'Blackburrow','[[4,15]]','Classic','Antonica','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(4,'Castle Mistmoore','[[20,45]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(5,'Clan Runnyeye','[[7,30]]','Classic','','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(6,'Commonlands','[[1,20],[35,35]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(7,'Crushbone','[[5,20]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(8,'Dagnor''s Cauldron','[[10,25]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(9,'East Karana','[[10,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(10,'Erud''s Crossing','[[5,15]]','Classic','Odus','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(11,'Erudin','[[0,0]]','Classic','Odus','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(12,'Erudin Palace','[[0,0]]','Classic','Odus','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(13,'Estate of Unrest','[[10,35]]','Classic','Faydwer','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(14,'Everfrost Peaks','[[1,25]]','Classic','Antonica','Outdoor','["Halas","Blackburrow","Permafrost Keep","Plane of Knowledge"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,1);
+INSERT INTO zones VALUES(15,'Felwithe','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(16,'Freeport','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(17,'Freeport Sewers','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(18,'Gorge of King Xorbb','[[8,25]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(19,'Greater Faydark','[[1,12]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(20,'Grobb','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(21,'Halas','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(22,'High Keep','[[20,40]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(23,'Highpass Hold','[[9,22]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(24,'Infected Paw','[[61,80]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(25,'Innothule Swamp','[[1,10],[25,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(26,'Kaladim','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(27,'Kedge Keep','[[32,50]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(28,'Kithicor Forest','[[1,13],[35,50]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(29,'Lake Rathetear','[[15,35]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(30,'Lavastorm Mountains','[[10,19],[25,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(31,'Lesser Faydark','[[10,30],[40,50]]','Classic','','Outdoor','["Greater Faydark","Steamfont Mountains","Mistmoore Castle"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(32,'Lower Guk','[[25,50]]','Classic','Antonica','dungeon','["Upper Guk"]','','',0,1,0,'2025-07-21 23:58:30',1,NULL,1);
+INSERT INTO zones VALUES(33,'Misty Thicket','[[1,12]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(34,'Nagafen''s Lair','[[40,55]]','Classic','','Dungeon','["Lavastorm Mountains","Solusek''s Eye"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(35,'Najena','[[15,35]]','Classic','','Dungeon','["Lavastorm Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(36,'Nektulos Forest','[[1,20],[25,30]]','Classic','','Outdoor','["Lavastorm Mountains","Commonlands","Neriak"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(37,'Neriak','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(38,'North Karana','[[10,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(39,'North Ro','[[5,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(40,'Ocean of Tears','[[9,30]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(41,'Oggok','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(42,'Paineel','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(43,'Permafrost Keep','[[15,50]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(44,'Plane of Fear','[[46,65]]','Classic','','outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(45,'Plane of Hate','[[46,65]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,1);
+INSERT INTO zones VALUES(46,'Plane of Sky','[[46,65]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,1);
+INSERT INTO zones VALUES(47,'Qeynos','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(48,'Qeynos Catacombs','[[0,0]]','Classic','','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(49,'Qeynos Hills','[[2,10]]','Classic','','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(50,'The Rathe Mountains','[[1,35]]','Classic','Antonica','outdoor',replace(replace('["Lake Rathetear",\r\n"Plane of Knowledge",\r\n"The Feerrott"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(51,'Rivervale','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(52,'Solusek''s Eye','[[20,40]]','Classic','','dungeon','["Nagafen''s Lair","Lavastorm Mountains"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(53,'South Karana','[[15,35]]','Classic','','Outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(54,'South Ro','[[5,20]]','Classic','','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(55,'Steamfont Mountains','[[1,18],[30,35]]','Classic','Faydwer','Outdoor','["Lesser Faydark","Ak''Anon","Dragonscale Hills","Loping Plains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(56,'Surefall Glade','[[0,0]]','Classic','','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(57,'Temple of Cazic-Thule','[[52,65]]','Classic','','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(58,'Temple of Solusek Ro','[[0,0]]','Classic','','City','["Lavastorm Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(59,'The Feerrott','[[1,30],[34,40]]','Classic','','Outdoors','["Plane of Fear","Oggok","Innothule Swamp","Rathe Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(60,'Toxxulia Forest','[[1,10]]','Classic','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(61,'Upper Guk','[[4,30]]','Classic','','Dungeon','["Lower Guk","Innothule Swamp"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(62,'West Karana','[[4,20]]','Classic','','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,1);
+INSERT INTO zones VALUES(63,'Burning Woods','[[35,55]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(64,'Chardok','[[40,65]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(65,'City of Mist','[[35,55]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(66,'Crypt of Dalnir','[[25,35]]','Kunark','Kunark','dungeon','["Warsliks Woods"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(67,'Dreadlands','[[35,50]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(68,'East Cabilis','[[0,0]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(69,'Firiona Vie','[[20,35]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(70,'Frontier Mountains','[[22,45]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(71,'Howling Stones','[[40,60]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(72,'Kaesora','[[30,40]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(73,'Karnor''s Castle','[[40,55]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(74,'Kurn''s Tower','[[8,30]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(75,'Lake of Ill Omen','[[1,30]]','Kunark','Kunark','Outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(76,'Mines of Nurga','[[40,55]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(77,'Old Sebilis','[[40,60]]','Kunark','Kunark','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(78,'Skyfire Mountains','[[40,60]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(79,'Swamp of No Hope','[[1,25]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(80,'Temple of Droga','[[48,63]]','Kunark','Kunark','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(81,'The Emerald Jungle','[[30,45]]','Kunark','Kunark','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(82,'Field of Bone','[[1,20]]','Kunark','Kunark','Outdoor','["East Cabilis","Emerald Jungle","Kaesora","Kurn''s Tower","Plane of Knowledge","Swamp of No Hope","Warsliks Woods"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,2);
+INSERT INTO zones VALUES(83,'The Overthere','[[15,40],[50,55]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(84,'Trakanon''s Teeth','[[30,50]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(85,'Veeshan''s Peak','[[60,75]]','Kunark','Kunark','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(86,'Veksar','[[40,60]]','Kunark','Kunark','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(87,'Warsliks Wood','[[1,30]]','Kunark','Kunark','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(88,'West Cabilis','[[0,0]]','Kunark','Kunark','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,2);
+INSERT INTO zones VALUES(89,'Cobalt Scar','[[35,55]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(90,'Crystal Caverns','[[25,45]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(91,'Dragon Necropolis','[[45,60]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(92,'Eastern Wastes','[[25,45]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(93,'Iceclad Ocean','[[28,45]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(94,'Icewell Keep','[[35,60]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(95,'Kael Drakkal','[[30,60]]','Velious','Velious','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(96,'Plane of Growth','[[46,60]]','Velious','Outer Planes','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(97,'Plane of Mischief','[[52,65]]','Velious','Outer Planes','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(98,'Siren''s Grotto','[[50,65]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(99,'Skyshrine','[[35,60]]','Velious','Velious','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(100,'Sleeper''s Tomb','[[60,75]]','Velious','Velious','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(101,'Stonebrunt Mountains','[[15,40]]','Velious','Odus','Outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(102,'Temple of Veeshan','[[60,75]]','Velious','Velious','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(103,'The Warrens','[[5,20]]','Velious','Odus','Dungeon','["Paineel","Stonebrunt Mountains"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,3);
+INSERT INTO zones VALUES(104,'Thurgadin','[[30,45]]','Velious','Velious','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(105,'Tower of Frozen Shadow','[[30,55]]','Velious','Velious','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(106,'Velketor''s Labyrinth','[[45,65]]','Velious','Velious','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(107,'Wakening Land','[[40,55]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(108,'Western Wastes','[[50,65]]','Velious','Velious','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,3);
+INSERT INTO zones VALUES(109,'Shadow Haven','[[0,0]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(110,'The Nexus','[[0,0]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(111,'Echo Caverns','[[20,45]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(112,'Acrylia Caverns','[[40,60]]','Shadows of Luclin','Luclin','Indoor','["Grimling Forest"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,4);
+INSERT INTO zones VALUES(113,'Shar Vahl','[[0,0]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(114,'Paludal Caverns','[[5,25]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(115,'Fungus Grove','[[40,55]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(116,'Vex Thal','[[60,75]]','Shadows of Luclin','Luclin','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(117,'Sanctus Seru','[[40,60]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(118,'Katta Castellum','[[30,60]]','Shadows of Luclin','Luclin','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(119,'Netherbian Lair','[[15,35]]','Shadows of Luclin','Luclin','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(120,'Ssraeshza Temple','[[60,75]]','Shadows of Luclin','Luclin','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(121,'Grieg''s End','[[45,60]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(122,'The Deep','[[50,60]]','Shadows of Luclin','Luclin','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(123,'Shadeweaver''s Thicket','[[1,25]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(124,'Hollowshade Moor','[[10,45]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(125,'Grimling Forest','[[25,50]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(126,'Marus Seru','[[15,45]]','Shadows of Luclin','Luclin','Dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(127,'Mons Letalis','[[35,50]]','Shadows of Luclin','Luclin','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(128,'Twilight Sea','[[25,50]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(129,'The Grey','[[40,55]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(130,'Tenebrous Mountains','[[35,50]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(131,'Maiden''s Eye','[[45,60]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(132,'Dawnshroud Peaks','[[24,45]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(133,'Scarlet Desert','[[30,50]]','Shadows of Luclin','Luclin','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(134,'Umbral Plains','[[50,65]]','Shadows of Luclin','Luclin','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,4);
+INSERT INTO zones VALUES(135,'Akheva Ruins','[[45,65]]','Shadows of Luclin','Luclin','Indoor','["Maiden''s Eye"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,4);
+INSERT INTO zones VALUES(136,'Doomfire, the Burning Lands (Plane of Fire)','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(137,'Drunder, Fortress of Zek (Plane of Tactics)','[[60,65]]','Planes of Power','Power','dungeon','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(138,'Eryslai, the Kingdom of Wind (Plane of Air)','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(139,'Halls of Honor','[[60,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(140,'Lair of Terris Thule','[[55,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(141,'Plane of Disease','[[46,60]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(142,'Plane of Innovation','[[46,63]]','Planes of Power','Power','outdoor','["Plane of Time A", "Plane of Tranquility"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(143,'Plane of Justice','[[46,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(144,'Plane of Knowledge','[[0,0]]','Planes of Power','Power','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(145,'Plane of Nightmare','[[46,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(146,'Plane of Storms','[[55,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(147,'Plane of Time A','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(148,'Plane of Time B','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(149,'Plane of Torment','[[55,65]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(150,'Plane of Tranquility','[[0,0]]','Planes of Power','Power','Outdoor City/Plane','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(151,'Plane of Valor','[[55,65]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(152,'Plane of War','[[100,105]]','Call of the Forsaken','Power','Outdoor','["Plane of Tranquility"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,21);
+INSERT INTO zones VALUES(153,'Ragrax, Stronghold of the Twelve','[[65,75]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(154,'Reef of Coirnav (Plane of Water)','[[65,75]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(155,'Ruins of Lxanvom (Crypt of Decay)','[[60,65]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(156,'Solusek Ro''s Tower','[[65,75]]','Planes of Power','Power','Indoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(157,'Temple of Marr','[[60,70]]','Planes of Power','Power','Outdoor','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(158,'Torden, the Bastion of Thunder','[[57,70]]','Planes of Power','Power','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,5);
+INSERT INTO zones VALUES(159,'Gulf of Gunthak','[[35,50]]','The Legacy of Ykesha','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,6);
+INSERT INTO zones VALUES(160,'Dulak''s Harbor','[[40,55]]','The Legacy of Ykesha','Antonica','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,6);
+INSERT INTO zones VALUES(161,'Torgiran Mines','[[45,60]]','The Legacy of Ykesha','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,6);
+INSERT INTO zones VALUES(162,'Crypt of Nadox','[[50,65]]','The Legacy of Ykesha','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,6);
+INSERT INTO zones VALUES(163,'Hate''s Fury, The Scorned Maiden','[[55,65]]','The Legacy of Ykesha','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,6);
+INSERT INTO zones VALUES(169,'Abysmal Sea','[[0,0]]','Gates of Discord','Taelosia','Outdoor','["Natimbi, The Broken Shores","Nedaria''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,8);
+INSERT INTO zones VALUES(170,'Nedaria''s Landing','[[15,35]]','Gates of Discord','Antonica','Outdoors','["Guild Lobby","Abysmal Sea","Natimbi, The Broken Shores"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(171,'Natimbi, The Broken Shores','[[43,60]]','Gates of Discord','Taelosia','Outdoors','["Abysmal Sea","Natimbi, The Broken Shores","Nedaria''s Landing","Qinimi, Court of Nihilia","Qvic, Prayer Grounds of Calling"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(172,'Qinimi, Court of Nihilia','[[50,75]]','Gates of Discord','Taelosia','Outdoors','["Natimbi, The Broken Shores","Ferubi, Forgotten Temple of Taelosia","Riwwi, Coliseum of Games","Barindu, Hanging Gardens"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(173,'Riwwi, Coliseum of Games','[[60,70]]','Gates of Discord','Taelosia','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(174,'Barindu, Hanging Gardens','[[55,65]]','Gates of Discord','Taelosia','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(176,'Tipt, Treacherous Crags','[[65,75]]','Gates of Discord','Taelosia','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(177,'Ferubi, Forgotten Temple of Taelosia','[[60,75]]','Gates of Discord','Taelosia','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(178,'Kod''Taz, Broken Trial Grounds','[[65,75]]','Gates of Discord','Taelosia','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(179,'Yxtta, Pulpit of Exiles','[[65,75]]','Gates of Discord','Taelosia','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,8);
+INSERT INTO zones VALUES(181,'Qvic, Prayer Grounds of Calling','[[65,75]]','Gates of Discord','Taelosia','outdoor',replace(replace('["Inku''Ta, The Unmasked Chapel",\r\n"Kod''Taz, Broken Trial Grounds",\r\n"Natimbi, the Broken Shores",\r\n"Txevu, Lair of the Elite",\r\n"Yxtta, Pulpit of Exiles"]\r\n','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1,NULL,8);
+INSERT INTO zones VALUES(196,'Dranik''s Scar','[[30,60]]','Omens of War','Kuua','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(197,'Harbinger''s Spire','[[55,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(198,'The Bloodfields','[[55,75]]','Omens of War','Kuua','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(199,'Ruined City of Dranik','[[65,75]]','Omens of War','Kuua','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(200,'Nobles'' Causeway','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(201,'Wall of Slaughter','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(202,'Muramite Proving Grounds','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(203,'Plane of Hate: Innoruuk''s Realm','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(204,'Riftseeker''s Sanctum','[[65,80]]','Omens of War','Kuua','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,9);
+INSERT INTO zones VALUES(228,'Guild Hall','[[0,0]]','Dragons of Norrath','Antonica','Indoor',replace(replace('["Arcstone, Isle of Spirits",\r\n"Argath, Bastion of Illdaera",\r\n"Barindu, Hanging Gardens",\r\n"Brell''s Rest",\r\n"Cobalt Scar",\r\n"Commonlands",\r\n"Dragonscale Hills",\r\n"Dreadlands",\r\n"Ethernere Tainted West Karana",\r\n"Feerrott, the Dream",\r\n"Goru''kar Mesa",\r\n"Greater Faydark",\r\n"Guild Lobby",\r\n"Iceclad Ocean",\r\n"Katta Castrum",\r\n"Katta Castrum: Deluge",\r\n"Lavastorm Mountains",\r\n"Lceanium",\r\n"North Karana",\r\n"Plane of Hate",\r\n"Plane of Sky",\r\n"Plane of Time A",\r\n"Shard''s Landing",\r\n"Stonebrunt Mountains",\r\n"Toxxulia Forest",\r\n"Twilight Sea",\r\n"Undershore",\r\n"Wall of Slaughter",\r\n"The Eastern Wastes",\r\n"Stratos: Zephyr''s Flight",\r\n"Cobalt Scar",\r\n"The Overthere",\r\n"Shar Vahl, Divided",\r\n"Maiden''s Eye",\r\n"Laurion Inn"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1,NULL,10);
+INSERT INTO zones VALUES(229,'Guild Lobby','[[0,0]]','Dragons of Norrath','Antonica','City','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(230,'Lavaspinner''s Lair','[[45,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(231,'Stillmoon Temple','[[55,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(232,'The Accursed Nest','[[65,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(233,'The Ascent','[[60,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(234,'The Broodlands','[[40,60]]','Dragons of Norrath','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(235,'Thundercrest Isles','[[60,75]]','Dragons of Norrath','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(236,'Tirranun''s Delve','[[60,75]]','Dragons of Norrath','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,10);
+INSERT INTO zones VALUES(285,'Corathus Creep','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,11);
+INSERT INTO zones VALUES(286,'Dreadspire Keep','[[70,80]]','Depths of Darkhollow','Antonica','Indoor','["Stoneroot Falls", "The Hive"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,11);
+INSERT INTO zones VALUES(292,'Ruins of Illsalin','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,11);
+INSERT INTO zones VALUES(293,'Shadow Spine','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,11);
+INSERT INTO zones VALUES(294,'Stoneroot Falls','[[65,75]]','Depths of Darkhollow','Antonica','Outdoor','["Dreadspire Keep","The Hive","Undershore"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,11);
+INSERT INTO zones VALUES(295,'The Hive','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,11);
+INSERT INTO zones VALUES(297,'Undershore','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,11);
+INSERT INTO zones VALUES(343,'Arcstone, Isle of Spirits','[[60,75]]','Prophecy of Ro','Antonica','Outdoor',replace(replace('["East Freeport",\r\n"Guild Hall",\r\n"Relic, the Artifact City",\r\n"West Freeport"]','\r',char(13)),'\n',char(10)),'','',0,1,0,'2025-07-21 23:58:30',1,NULL,12);
+INSERT INTO zones VALUES(344,'Relic, the Artifact City','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,12);
+INSERT INTO zones VALUES(345,'Ruins of Takish-Hiz','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,12);
+INSERT INTO zones VALUES(346,'Sverag, Stronghold of Rage','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,12);
+INSERT INTO zones VALUES(347,'The Devastation','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,12);
+INSERT INTO zones VALUES(348,'The Elddar Forest','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,12);
+INSERT INTO zones VALUES(349,'Theater of Blood','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,12);
+INSERT INTO zones VALUES(359,'Freeport Sewers (PoR)','[[5,25]]','Prophecy of Ro','Antonica','dungeon',replace(replace('["East Freeport",\r\n"North Ro",\r\n"West Freeport"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1,NULL,12);
+INSERT INTO zones VALUES(382,'Crescent Reach','[[1,20]]','The Serpent''s Spine','Antonica','outdoor','["Blightfire Moors","Bixie Warfront"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,13);
+INSERT INTO zones VALUES(383,'Highpass (revamp)','[[15,25]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(384,'Blightfire Moors','[[20,40]]','The Serpent''s Spine','Antonica','outdoor','["Crescent Reach","Goru''kar Mesa","Stone Hive"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(385,'Stone Hive','[[35,45]]','The Serpent''s Spine','Antonica','outdoor','["Blightfire Moors","Goru''kar Mesa"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(386,'Goru''kar Mesa','[[40,60]]','The Serpent''s Spine','Antonica','Outdoors','["Blightfire Moors","Blackfeather Roost","Guild Hall","Sunderock Springs","The Steppes"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(387,'Blackfeather Roost','[[55,65]]','The Serpent''s Spine','Antonica','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(388,'The Steppes','[[60,70]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(389,'Sunderock Springs','[[63,73]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(390,'Vergalid Mines','[[60,75]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(391,'Direwind Cliffs','[[70,75]]','The Serpent''s Spine','Antonica','Outdoors','["Ashengate, Reliquary of the Scale", "Sunderock Springs"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,13);
+INSERT INTO zones VALUES(392,'Icefall Glacier','[[70,75]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(393,'Ashengate, Reliquary of the Scale','[[75,75]]','The Serpent''s Spine','Antonica','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(394,'Valdeholm','[[72,75]]','The Serpent''s Spine','Antonica','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(395,'Frostcrypt, Throne of the Shade King','[[75,75]]','The Serpent''s Spine','Antonica','Raid','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,13);
+INSERT INTO zones VALUES(396,'Barren Coast','[[50,75]]','The Buried Sea','Odus','outdoor','[]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(406,'Jewel of Atiiki','[[58,80]]','The Buried Sea','Odus','Outdoors','["Katta Castrum"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,14);
+INSERT INTO zones VALUES(412,'Katta Castrum','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(425,'Silyssar, New Chelsith','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(431,'Solteris, the Throne of Ro','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(437,'Thalassius, the Coral Keep','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(443,'The Buried Sea','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(449,'Zhisza, the Shissar Sanctuary','[[50,75]]','The Buried Sea','Odus','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,14);
+INSERT INTO zones VALUES(455,'Bloodmoon Keep','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(456,'Crypt of Shade','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(457,'Crystallos, Lair of the Awakened','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(459,'Dragonscale Hills','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(460,'Fortress Mechanotus','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(461,'Gyrospire Beza','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(462,'Gyrospire Zeka','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(463,'Hills of Shade','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(464,'Loping Plains','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(465,'Meldrath''s Majestic Mansion','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(466,'S.H.I.P Workshop','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(467,'The Steam Factory','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,15);
+INSERT INTO zones VALUES(502,'Bloody Kithicor','[[75,85]]','Seeds of Destruction','The Void','outdoor','["Old Commonlands"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,16);
+INSERT INTO zones VALUES(503,'Citadel of the Worldslayer','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(504,'City of Dranik','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(505,'Field of Scale','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(506,'Kaesora Hatchery','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(507,'Kaesora Library','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(508,'Korafax, Home of the Riders','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(509,'Korascian Warrens','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(510,'Oceangreen Hills','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(511,'Oceangreen Village','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(512,'Old Blackburrow','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(513,'Old Bloodfields','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(514,'Old Commonlands','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(515,'Old Kurn''s Tower','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(516,'Rathe Council Chambers','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(517,'Temple of Bertoxxulous','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(518,'The Precipice of War','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(519,'Toskirakk','[[75,85]]','Seeds of Destruction','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,16);
+INSERT INTO zones VALUES(548,'Brell''s Rest','[[80,85]]','Underfoot','Norrath','outdoor','["Cooling Chamber","The Foundation"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(549,'Cooling Chamber','[[80,85]]','Underfoot','Norrath','indoor','["Brell''s Rest","Volska''s Husk"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(550,'Pellucid Grotto','[[85,85]]','Underfoot','Norrath','indoor','["Brell''s Rest","Cooling Chamber","Fungal Forest","The Underquarry"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(551,'Fungal Forest','[[85,90]]','Underfoot','Norrath','indoor','["Arthicrex","Pellucid Grotto"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(552,'The Underquarry','[[80,85]]','Underfoot','Norrath','underground','["Brell''s Rest","The Foundation"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(553,'The Foundation','[[82,87]]','Underfoot','Norrath','underground','["Brell''s Rest","The Underquarry","Convorteum"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(554,'Volska''s Husk','[[83,88]]','Underfoot','Norrath','indoor','["Convorteum","Arthicrex"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(555,'Arthicrex','[[84,89]]','Underfoot','Norrath','dungeon','["Volska''s Husk","The Grounds"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(556,'Brell''s Temple','[[86,91]]','Underfoot','Norrath','indoor','["The Foundation","Convorteum"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(557,'Convorteum','[[87,92]]','Underfoot','Norrath','raid','["The Foundation","Brell''s Temple"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(558,'Lichen Creep','[[87,92]]','Underfoot','Norrath','raid','["Arthicrex","Corathus Creep"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,17);
+INSERT INTO zones VALUES(559,'Al''Kabor''s Nightmare','[[88,95]]','House of Thule','Antonica','Outdoor','["House of Thule, Upper Floors"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(560,'Erudin Burning','[[83,90]]','House of Thule','Antonica','Outdoor','["House of Thule, Lower Floors"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(561,'Fear Itself','[[88,92]]','House of Thule','Antonica','Outdoor','["House of Thule, Upper Floors"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(562,'Feerrott, the Dream','[[80,90]]','House of Thule','Antonica','Outdoor','["Guild Hall", "House of Thule, Lower Floors", "Plane of Knowledge", "The Feerrott"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(563,'House of Thule, Lower Floors','[[83,88]]','House of Thule','Antonica','Outdoor','["Erudin Burning", "Feerrott, the Dream", "House of Thule, Upper Floors", "The Grounds", "The Library"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(564,'House of Thule, Upper Floors','[[85,92]]','House of Thule','Antonica','Indoor','["Al''Kabor''s Nightmare", "Fear Itself", "House of Thule, Lower Floors", "Miragul''s Nightmare", "Morell''s Castle"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(565,'Miragul''s Nightmare','[[83,95]]','House of Thule','Antonica','Outdoor','["House of Thule, Upper Floors"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(566,'Morell''s Castle','[[89,96]]','House of Thule','Antonica','Indoor','["House of Thule, Upper Floors","Sanctum Somnium"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(567,'Sanctum Somnium','[[85,96]]','House of Thule','Antonica','Indoor','["Morell''s Castle"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(568,'Sunrise Hills','[[0,0]]','House of Thule','Antonica','Outdoor','["Guild Lobby"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(569,'The Grounds','[[83,94]]','House of Thule','Antonica','Outdoor','["House of Thule, Lower Floors", "The Well"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(570,'The Library','[[84,92]]','House of Thule','Antonica','Indoor','["House of Thule, Lower Floors"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(571,'The Well','[[83,93]]','House of Thule','Antonica','Indoor','["The Grounds"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,18);
+INSERT INTO zones VALUES(604,'Argath, Bastion of Illdaera','[[90,92]]','Veil of Alaris','Alaris','Outdoor','["Valley of Lunanyn"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(605,'Valley of Lunanyn','[[91,93]]','Veil of Alaris','Alaris','outdoor','["Argath, Bastion of Illdaera","Sarith, City of Tides"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(606,'Sarith, City of Tides','[[92,94]]','Veil of Alaris','Alaris','outdoor','["Valley of Lunanyn","Rubak Oseka, Temple of the Sea","The Resplendent Temple"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(607,'Rubak Oseka, Temple of the Sea','[[92,96]]','Veil of Alaris','Alaris','Indoor','["Sarith, City of Tides"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,19);
+INSERT INTO zones VALUES(608,'The Resplendent Temple','[[93,95]]','Veil of Alaris','Alaris','outdoor','["Sarith, City of Tides","Beasts'' Domain"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(609,'Beasts'' Domain','[[94,96]]','Veil of Alaris','Alaris','outdoor','["The Resplendent Temple","Pillars of Alra"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(610,'Pillars of Alra','[[95,97]]','Veil of Alaris','Alaris','outdoor','["Beasts'' Domain","City of Bronze"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(611,'City of Bronze','[[95,98]]','Veil of Alaris','Alaris','outdoor','["Pillars of Alra","Sepulcher of Order"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(612,'Windsong Sanctuary','[[96,98]]','Veil of Alaris','Alaris','outdoor','["Sepulcher of Order"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,19);
+INSERT INTO zones VALUES(613,'Sepulcher of Order','[[94,101]]','Veil of Alaris','Alaris','Indoor','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,19);
+INSERT INTO zones VALUES(614,'Shard''s Landing','[[95,100]]','Rain of Fear','Norrath','outdoor','["Xorbb''s Cursed Caverns","Breeding Grounds"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(615,'Breeding Grounds','[[97,105]]','Rain of Fear','Norrath','Indoor','["Dragon Necropolis", "Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,20);
+INSERT INTO zones VALUES(616,'Evantil, the Vile Oak','[[95,100]]','Rain of Fear','Norrath','dungeon','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(617,'Grelleth''s Palace, the Chateau of Filth','[[95,100]]','Rain of Fear','Norrath','dungeon','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(618,'Heart of Fear','[[98,100]]','Rain of Fear','Norrath','dungeon','["Chelsith Reborn","Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(619,'Chelsith Reborn','[[95,100]]','Rain of Fear','Odus','dungeon','["Shard''s Landing","Heart of Fear"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(620,'The Plane of Shadow','[[95,100]]','Rain of Fear','Planes','raid','["Shard''s Landing"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(621,'Xorbb''s Cursed Caverns','[[96,100]]','Rain of Fear','Norrath','dungeon','["Shard''s Landing"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,20);
+INSERT INTO zones VALUES(622,'Kael Drakkel: The King''s Madness','[[96,100]]','Rain of Fear','Velious','dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,20);
+INSERT INTO zones VALUES(623,'The Crystal Caverns: Fragment of Fear','[[95,100]]','Rain of Fear','Velious','dungeon','[]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,20);
+INSERT INTO zones VALUES(624,'Ethernere Tainted West Karana','[[95,100]]','Call of the Forsaken','Antonica','outdoor','["Neriak - Fourth Gate","Dead Hills"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,21);
+INSERT INTO zones VALUES(625,'Neriak - Fourth Gate','[[95,100]]','Call of the Forsaken','Faydwer','dungeon','["Ethernere Tainted West Karana"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,21);
+INSERT INTO zones VALUES(626,'Dead Hills','[[95,100]]','Call of the Forsaken','Antonica','outdoor','["Ethernere Tainted West Karana","Bixie Warfront"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,21);
+INSERT INTO zones VALUES(627,'Bixie Warfront','[[96,100]]','Call of the Forsaken','Faydwer','outdoor','["Dead Hills","Ethernere Tainted West Karana"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,21);
+INSERT INTO zones VALUES(628,'Tower of Rot','[[100,100]]','Call of the Forsaken','Planes','dungeon','["Argin-Hiz"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,21);
+INSERT INTO zones VALUES(629,'Argin-Hiz','[[100,104]]','Call of the Forsaken','Planes','Outdoor','["Ethernere Tainted West Karana"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,21);
+INSERT INTO zones VALUES(630,'Tempest Temple','[[100,105]]','The Darkened Sea','Buried Sea','outdoor','["Brother Island","Caverns of Endless Song","Thuliasaur Island"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,22);
+INSERT INTO zones VALUES(631,'Brother Island','[[100,105]]','The Darkened Sea','Buried Sea','outdoor','["Tempest Temple"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,22);
+INSERT INTO zones VALUES(632,'Caverns of Endless Song','[[100,105]]','The Darkened Sea','Buried Sea','dungeon','["Tempest Temple"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,22);
+INSERT INTO zones VALUES(633,'Thuliasaur Island','[[100,105]]','The Darkened Sea','Buried Sea','outdoor','["Tempest Temple","Degmar, the Lost Castle"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,22);
+INSERT INTO zones VALUES(634,'Degmar, the Lost Castle','[[100,105]]','The Darkened Sea','Buried Sea','dungeon','["Thuliasaur Island","Arx Mentis"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,22);
+INSERT INTO zones VALUES(635,'Arx Mentis','[[103,105]]','The Darkened Sea','Buried Sea','raid','["Degmar, the Lost Castle"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,22);
+INSERT INTO zones VALUES(636,'Plane of Health','[[105,110]]','The Broken Mirror','Planes','outdoor','["Crypt of Decay","Plane of Fear: Revisited","Plane of Hate: Revisited"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,23);
+INSERT INTO zones VALUES(637,'Crypt of Decay','[[105,110]]','The Broken Mirror','Planes','dungeon','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,23);
+INSERT INTO zones VALUES(638,'Plane of Fear: Revisited','[[105,110]]','The Broken Mirror','Planes','raid','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,23);
+INSERT INTO zones VALUES(639,'Plane of Hate: Revisited','[[105,110]]','The Broken Mirror','Planes','raid','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,23);
+INSERT INTO zones VALUES(640,'Demiplane of Life','[[105,110]]','The Broken Mirror','Planes','raid','["Plane of Health"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,23);
+INSERT INTO zones VALUES(641,'Demiplane of Decay','[[105,110]]','The Broken Mirror','Planes','raid','["Crypt of Decay"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,23);
+INSERT INTO zones VALUES(642,'Lceanium','[[100,105]]','Empires of Kunark','Kunark','outdoor','["Scorched Woods","Chardok (EoK)"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,24);
+INSERT INTO zones VALUES(643,'Scorched Woods','[[100,105]]','Empires of Kunark','Kunark','outdoor','["Lceanium","Frontier Mountains (EoK)"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,24);
+INSERT INTO zones VALUES(644,'Frontier Mountains (EoK)','[[103,105]]','Empires of Kunark','Kunark','outdoor','["Scorched Woods","Kor-Sha Laboratory","Droga"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,24);
+INSERT INTO zones VALUES(645,'Chardok (EoK)','[[103,105]]','Empires of Kunark','Kunark','dungeon','["Lceanium"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,24);
+INSERT INTO zones VALUES(646,'Kor-Sha Laboratory','[[104,105]]','Empires of Kunark','Kunark','dungeon','["Frontier Mountains (EoK)"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,24);
+INSERT INTO zones VALUES(647,'Droga','[[104,105]]','Empires of Kunark','Kunark','dungeon','["Frontier Mountains (EoK)"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,24);
+INSERT INTO zones VALUES(648,'The Overthere (RoS)','[[105,110]]','Ring of Scale','Kunark','outdoor',replace(replace('["Frontier Mountains (EoK)",\r\n"Howling Stones (RoS)",\r\n"Plane of Knowledge",\r\n"Skyfire Mountains (RoS)"]','\r',char(13)),'\n',char(10)),'','',0,1,0,'2025-07-21 23:58:30',1,NULL,25);
+INSERT INTO zones VALUES(649,'Skyfire Mountains (RoS)','[[106,110]]','Ring of Scale','Kunark','outdoor',replace(replace('["Gorowyn",\r\n"Scorched Woods",\r\n"The Overthere (RoS)",\r\n"Veeshan''s Peak (RoS)"]','\r',char(13)),'\n',char(10)),'','',0,0,0,'2025-07-21 23:58:30',1,NULL,25);
+INSERT INTO zones VALUES(650,'Howling Stones (RoS)','[[107,110]]','Ring of Scale','Kunark','dungeon','["The Overthere (RoS)","Sathir''s Tomb"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,25);
+INSERT INTO zones VALUES(651,'Gorowyn','[[105,110]]','Ring of Scale','Kunark','city','["Skyfire Mountains (RoS)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,25);
+INSERT INTO zones VALUES(652,'Veeshan''s Peak (RoS)','[[110,110]]','Ring of Scale','Kunark','raid','["Skyfire Mountains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,25);
+INSERT INTO zones VALUES(653,'Stratos: Zephyr''s Flight','[[108,110]]','The Burning Lands','Elemental Planes','outdoor','["Esianti: Palace of the Winds","Mearatas: The Stone Demesne"]','','',0,1,0,'2025-07-21 23:58:30',0,NULL,26);
+INSERT INTO zones VALUES(654,'Esianti: Palace of the Winds','[[109,110]]','The Burning Lands','Elemental Planes','Outdoor','["Stratos: Zephyr''s Flight","Aalishai: Palace of Embers"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,26);
+INSERT INTO zones VALUES(655,'Aalishai: Palace of Embers','[[108,114]]','The Burning Lands','Elemental Planes','Outdoor','["Esianti: Palace of the Winds","Empyr: Realms of Ash"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,26);
+INSERT INTO zones VALUES(656,'Empyr: Realms of Ash','[[109,110]]','The Burning Lands','Elemental Planes','outdoor','["Aalishai: Palace of Embers","Mearatas: The Stone Demesne"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,26);
+INSERT INTO zones VALUES(657,'Mearatas: The Stone Demesne','[[110,110]]','The Burning Lands','Elemental Planes','raid','["Stratos: Zephyr''s Flight","Empyr: Realms of Ash"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,26);
+INSERT INTO zones VALUES(658,'The Eastern Wastes (ToV)','[[111,120]]','Torment of Velious','Velious','Outdoor','["Crystal Caverns (ToV)","Kael Drakkel (ToV)","Ry`Gorr Mines (ToV)","Sleeper''s Tomb (CoV)","The Great Divide (ToV)","The Tower of Frozen Shadow (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(659,'Ry`Gorr Mines (ToV)','[[112,121]]','Torment of Velious','Velious','Indoor','["Crystal Caverns (ToV)", "The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(660,'Crystal Caverns (ToV)','[[111,120]]','Torment of Velious','Velious','Indoor','["Ry`Gorr Mines (ToV)","The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(661,'The Great Divide (ToV)','[[113,120]]','Torment of Velious','Velious','Outdoor','["Plane of Knowledge","The Eastern Wastes (ToV)","Velketor''s Labyrinth (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(662,'The Tower of Frozen Shadow (ToV)','[[113,117]]','Torment of Velious','Velious','Indoors','["The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(663,'Velketor''s Labyrinth (ToV)','[[113,121]]','Torment of Velious','Velious','Indoors','["The Great Divide (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(664,'Kael Drakkel (ToV)','[[113,118]]','Torment of Velious','Velious','Indoor','["Cobalt Scar (CoV)", "The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,27);
+INSERT INTO zones VALUES(665,'Cobalt Scar (CoV)','[[115,116]]','Claws of Veeshan','Velious','Outdoor','["Kael Drakkel (ToV)","Skyshrine (CoV)","Western Wastes (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,28);
+INSERT INTO zones VALUES(666,'Western Wastes (CoV)','[[115,120]]','Claws of Veeshan','Velious','Outdoors','["Cobalt Scar (CoV)","Dragon Necropolis (CoV)","Temple of Veeshan (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,28);
+INSERT INTO zones VALUES(667,'Dragon Necropolis (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoors','["Western Wastes (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,28);
+INSERT INTO zones VALUES(668,'Skyshrine (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoor','["Cobalt Scar (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,28);
+INSERT INTO zones VALUES(669,'Temple of Veeshan (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoor','["Western Wastes (CoV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,28);
+INSERT INTO zones VALUES(670,'Sleeper''s Tomb (CoV)','[[115,121]]','Claws of Veeshan','Velious','Indoor','["The Eastern Wastes (ToV)"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,28);
+INSERT INTO zones VALUES(671,'Maiden''s Eye (TOL)','[[114,121]]','Terror of Luclin','Luclin','Outdoors','["Ka Vethan","Umbral Plains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(672,'Umbral Plains (TOL)','[[115,120]]','Terror of Luclin','Luclin','Outdoors','["Maiden''s Eye","Vex Thal"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(673,'Ka Vethan','[[115,120]]','Terror of Luclin','Luclin','Indoors','["Maiden''s Eye","Shadow Valley"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(674,'Vex Thal (TOL)','[[115,120]]','Terror of Luclin','Luclin','Outdoors','["Umbral Plains"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(675,'Shadow Valley','[[117,120]]','Terror of Luclin','Luclin','Outdoors','["Basilica of Adumbration","Ka Vethan"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(676,'Basilica of Adumbration','[[117,120]]','Terror of Luclin','Luclin','Indoors','["Shadow Valley"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(677,'Bloodfalls','[[117,121]]','Terror of Luclin','Luclin','Outdoors','["Castle Mistmoore"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,29);
+INSERT INTO zones VALUES(678,'Firefall Pass','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Umbral Plains","Shadeweaver''s Tangle"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(679,'Shadeweaver''s Tangle','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Firefall Pass","Shar Vahl, Divided"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(680,'Shar Vahl, Divided','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Shadeweaver''s Tangle","Ruins of Shadow Haven"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(681,'Ruins of Shadow Haven','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Shar Vahl, Divided","Deepshade"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(682,'Deepshade','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Ruins of Shadow Haven","Darklight Caverns"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(683,'Darklight Caverns','[[115,120]]','Night of Shadows','Luclin','Outdoors','["Deepshade"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(684,'Paludal Depths','[[115,120]]','Night of Shadows','Luclin','Outdoors','[]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,30);
+INSERT INTO zones VALUES(685,'Laurion Inn','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Ankexfen Keep","Moors of Nokk","Pal''Lomen","The Hero''s Forge","Plane of Tranquility","Timorous Falls","Unkempt Woods"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,31);
+INSERT INTO zones VALUES(686,'Timorous Falls','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,31);
+INSERT INTO zones VALUES(687,'Ankexfen Keep','[[120,125]]','Laurion''s Song','Antonica','Outdoor','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,31);
+INSERT INTO zones VALUES(688,'Moors of Nokk','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,31);
+INSERT INTO zones VALUES(689,'Unkempt Woods','[[120,125]]','Laurion''s Song','Realm of Heroes','Outdoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,31);
+INSERT INTO zones VALUES(690,'The Hero''s Forge','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,31);
+INSERT INTO zones VALUES(691,'Pal''Lomen','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',0,0,0,'2025-07-21 23:58:30',0,NULL,31);
+INSERT INTO zones VALUES(694,'Hodstock Hills','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Theater of Eternity", "East Freeport", "Nektulos Forest"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,32);
+INSERT INTO zones VALUES(695,'The Theater of Eternity','[[120,125]]','The Outer Brood','Antonica','Outdoor','["Hodstock Hills","The Harbinger''s Cradle","Aureate Covert"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,32);
+INSERT INTO zones VALUES(696,'Aureate Covert','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Theater of Eternity","The Harbinger''s Cradle","The Gilded Spire"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,32);
+INSERT INTO zones VALUES(697,'The Harbinger''s Cradle','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Theater of Eternity","Aureate Covert","The Chambers of Puissance"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,32);
+INSERT INTO zones VALUES(698,'The Chambers of Puissance','[[120,125]]','The Outer Brood','Antonica','Outdoor','["The Harbinger''s Cradle"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,32);
+INSERT INTO zones VALUES(699,'The Gilded Spire','[[120,125]]','The Outer Brood','Antonica','Outdoor','["Aureate Covert"]','','',0,0,0,'2025-07-21 23:58:30',1,NULL,32);
+INSERT INTO zones VALUES(704,'Great Divide','[[30,50]]','Velious','Velious','outdoor',replace(replace('["Eastern Wastes",\r\n"Icewell Keep",\r\n"Plane of Knowledge",\r\n"Plane of Mischief",\r\n"The Nexus",\r\n"Thurgadin",\r\n"Velketor''s Labyrinth"]','\r',char(13)),'\n',char(10)),'','',0,1,0,'2025-07-22 01:53:15',1,NULL,3);
+INSERT INTO zones VALUES(705,'The Mines of Gloomingdeep','[[1,15]]','The Serpent''s Spine','Antonica','Indoor','["Crescent Reach", "Plane of Knowledge"]','','',0,0,0,'2025-08-12 04:56:20',1,NULL,13);
+INSERT INTO zones VALUES(706,'Chapterhouse of the Fallen','[[100,105]]','Rain of Fear','Norrath','Indoor','["Commonlands","Shard''s Landing"]','','',0,0,0,'2025-08-12 04:56:20',1,NULL,20);
+INSERT INTO zones VALUES(707,'Sathir''s Tomb','[[106,114]]','Ring of Scale','Kunark','Indoor','["Howling Stones (RoS)"]','','',0,0,0,'2025-08-12 04:56:20',1,NULL,25);
+INSERT INTO zones VALUES(708,'Ruins of Old Paineel (The Hole)','[[40,65]]','Classic','Odus','Indoor','["Brell''s Rest","Paineel"]','','',0,0,0,'2025-08-12 04:56:20',1,NULL,1);
+INSERT INTO zones VALUES(709,'Caverns of Exile (Solusek C)','[[50, 65]]','The Legacy of Ykesha','Antonica','Indoor','["Nagafen''s Lair","Solusek''s Eye"]','','',0,0,0,'2025-08-12 04:56:20',1,NULL,6);
+INSERT INTO zones VALUES(710,'Katta Castrum: Deluge','[[100,105]]','The Darkened Sea','Odus','Outdoor','["Tempest Temple", "Guild Hall"]','','',0,0,0,'2025-08-12 21:35:15',1,NULL,22);
 CREATE TABLE zone_ratings (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 zone_id INTEGER NOT NULL,
@@ -489,24 +491,25 @@ CREATE TABLE links (
                 category TEXT NOT NULL,
                 description TEXT,
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                deleted_at DATETIME
             );
-INSERT INTO links VALUES(3,'hammackj','http://www.youtube.com/@hammackj','Content Creators','Everquest Let''s Plays and Guides','2025-07-23 01:46:29','2025-07-23 02:13:15');
-INSERT INTO links VALUES(4,'Everquest','https://everquest.com','General','Main site for Everquest.','2025-07-23 01:58:49','2025-07-23 02:13:38');
-INSERT INTO links VALUES(5,'Everquest Server Status','https://www.daybreakgames.com/status#eq','General','Server status for Everquest Servers.','2025-07-23 02:15:04','2025-07-23 02:15:04');
-INSERT INTO links VALUES(6,'EQ Resource','https://eqresource.com/','General','','2025-07-23 02:15:33','2025-07-23 02:15:33');
-INSERT INTO links VALUES(7,'Bard','https://discord.gg/zTzYQbu76Z','Class Discords','Bard Discord','2025-07-23 02:19:14','2025-07-23 02:19:14');
-INSERT INTO links VALUES(8,'Allakhazam','https://everquest.allakhazam.com/','General','The OG Everquest wiki...','2025-07-23 02:29:47','2025-07-23 02:29:47');
-INSERT INTO links VALUES(9,'Almars Guides','https://almarsguides.com/EQ','General','Great Guide Site','2025-07-23 02:30:19','2025-07-23 02:30:19');
-INSERT INTO links VALUES(10,'EQ Progression','https://www.eqprogression.com/','General','TLP Specific Guides and Information','2025-07-23 02:30:49','2025-07-23 02:30:49');
-INSERT INTO links VALUES(11,'EQ Traders','http://eqtraders.com/','General','The Best Tradeskill Site for Everquest.','2025-07-23 02:31:21','2025-07-23 02:31:21');
-INSERT INTO links VALUES(12,'Ion Blaze','https://www.youtube.com/@IonBlaze1','Content Creators','','2025-07-23 02:38:04','2025-07-23 02:38:04');
-INSERT INTO links VALUES(13,'Twelvestrings','https://www.youtube.com/@Twelvestrings','Content Creators','','2025-07-23 02:39:29','2025-07-23 02:39:29');
-INSERT INTO links VALUES(14,'Shaman','https://discord.gg/zTzYQbu76Z','Class Discords','Shaman Discord','2025-07-23 02:40:20','2025-07-23 02:40:39');
-INSERT INTO links VALUES(15,'Shadowknight','https://discord.gg/nPvJqUq7tD','Class Discords','Shadowknight Discord','2025-07-23 02:41:09','2025-07-23 02:41:09');
-INSERT INTO links VALUES(16,'Beastlord','https://discord.gg/42ZHX3nc','Class Discords','Beastlord Discord','2025-07-23 02:41:50','2025-07-23 02:41:50');
-INSERT INTO links VALUES(17,'Raid Loot','https://www.raidloot.com','General','A site with all items and raid loot in the game.','2025-07-23 12:15:39','2025-07-23 12:15:39');
-INSERT INTO links VALUES(18,'EQ Items','https://eqitems.com/','General','A site with tier lists of items at each level for all classes.','2025-07-23 12:16:45','2025-07-23 12:16:45');
+INSERT INTO links VALUES(3,'hammackj','http://www.youtube.com/@hammackj','Content Creators','Everquest Let''s Plays and Guides','2025-07-23 01:46:29','2025-07-23 02:13:15',NULL);
+INSERT INTO links VALUES(4,'Everquest','https://everquest.com','General','Main site for Everquest.','2025-07-23 01:58:49','2025-07-23 02:13:38',NULL);
+INSERT INTO links VALUES(5,'Everquest Server Status','https://www.daybreakgames.com/status#eq','General','Server status for Everquest Servers.','2025-07-23 02:15:04','2025-07-23 02:15:04',NULL);
+INSERT INTO links VALUES(6,'EQ Resource','https://eqresource.com/','General','','2025-07-23 02:15:33','2025-07-23 02:15:33',NULL);
+INSERT INTO links VALUES(7,'Bard','https://discord.gg/zTzYQbu76Z','Class Discords','Bard Discord','2025-07-23 02:19:14','2025-07-23 02:19:14',NULL);
+INSERT INTO links VALUES(8,'Allakhazam','https://everquest.allakhazam.com/','General','The OG Everquest wiki...','2025-07-23 02:29:47','2025-07-23 02:29:47',NULL);
+INSERT INTO links VALUES(9,'Almars Guides','https://almarsguides.com/EQ','General','Great Guide Site','2025-07-23 02:30:19','2025-07-23 02:30:19',NULL);
+INSERT INTO links VALUES(10,'EQ Progression','https://www.eqprogression.com/','General','TLP Specific Guides and Information','2025-07-23 02:30:49','2025-07-23 02:30:49',NULL);
+INSERT INTO links VALUES(11,'EQ Traders','http://eqtraders.com/','General','The Best Tradeskill Site for Everquest.','2025-07-23 02:31:21','2025-07-23 02:31:21',NULL);
+INSERT INTO links VALUES(12,'Ion Blaze','https://www.youtube.com/@IonBlaze1','Content Creators','','2025-07-23 02:38:04','2025-07-23 02:38:04',NULL);
+INSERT INTO links VALUES(13,'Twelvestrings','https://www.youtube.com/@Twelvestrings','Content Creators','','2025-07-23 02:39:29','2025-07-23 02:39:29',NULL);
+INSERT INTO links VALUES(14,'Shaman','https://discord.gg/zTzYQbu76Z','Class Discords','Shaman Discord','2025-07-23 02:40:20','2025-07-23 02:40:39',NULL);
+INSERT INTO links VALUES(15,'Shadowknight','https://discord.gg/nPvJqUq7tD','Class Discords','Shadowknight Discord','2025-07-23 02:41:09','2025-07-23 02:41:09',NULL);
+INSERT INTO links VALUES(16,'Beastlord','https://discord.gg/42ZHX3nc','Class Discords','Beastlord Discord','2025-07-23 02:41:50','2025-07-23 02:41:50',NULL);
+INSERT INTO links VALUES(17,'Raid Loot','https://www.raidloot.com','General','A site with all items and raid loot in the game.','2025-07-23 12:15:39','2025-07-23 12:15:39',NULL);
+INSERT INTO links VALUES(18,'EQ Items','https://eqitems.com/','General','A site with tier lists of items at each level for all classes.','2025-07-23 12:16:45','2025-07-23 12:16:45',NULL);
 CREATE TABLE instances (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 name TEXT NOT NULL,
@@ -521,334 +524,343 @@ CREATE TABLE instances (
                 hot_zone BOOLEAN NOT NULL DEFAULT FALSE,
                 mission BOOLEAN NOT NULL DEFAULT FALSE,
                 verified BOOLEAN NOT NULL DEFAULT FALSE,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                deleted_at DATETIME,
+                min_players INTEGER,
+                max_players INTEGER,
+                lockout_minutes INTEGER,
+                request_npc TEXT NOT NULL DEFAULT '',
+                request_zone TEXT NOT NULL DEFAULT '',
+                task_type TEXT NOT NULL DEFAULT '',
+                classification TEXT NOT NULL DEFAULT '',
+                expansion_id INTEGER REFERENCES expansions (id)
             );
-INSERT INTO instances VALUES(1,'The High Priest','[[120,125]]','The Outer Brood','Antonica','indoor','["Aureate Covert"]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(2,'The Control Room','[[120,125]]','The Outer Brood','Antonica','indoor','["Aureate Covert"]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(3,'The Leviathan''s Heart','[[120,125]]','The Outer Brood','Antonica','indoor','["The Chamber of Puissance"]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(4,'Brood Architect Hazuri','[[120,125]]','The Outer Brood','Antonica','indoor','["The Theater of Eternity"]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(5,'Academy of Arcane Sciences','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(6,'Final Fugue','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',5,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(7,'Heroes Are Forged','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',1,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(8,'Erudin Burning: The Pit of All Fears','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(9,'Al''Kabor''s Nightmare: Catastrophic Power','[[85,90]]','House of Thule','Faydwer','instanced','["House of Thule, Upper Floors"]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(10,'Feerrott, the Dream: Ritual of Terror','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(11,'The Well: Terror in the Dark','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(12,'Al''Kabor''s Nightmare: At World''s End','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(13,'Erudin Burning: The Torches of Truth','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(14,'Erudin Burning: The Illuminating Truth','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(15,'Fear Itself: Cazic''s Final Stand','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(16,'Fear Itself: An Offering to Thule','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(17,'Sanctum Somnium: Raid Instance','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(18,'Feerrott, the Dream: A Tearable Fear','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(19,'House of Thule: Tick Tock','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(20,'House of Thule Upper: Redemption','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(21,'House of Thule Upper: Guardian of the House','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(22,'Feerrott, the Dream: Fall of the Tae Ew','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(23,'House of Thule: Welcome to My Nightmare','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(24,'The Well: No Freedom in Death','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(25,'The Library: A Moment of Clarity','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(26,'The Library: The Librarian''s Nightmare','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(27,'The Library: The Stuff of Dreams','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(28,'The Grounds: Mashing Pumpkins','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(29,'Miragul''s Phylactery','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(30,'Miragul''s Nightmare: Miragul''s Vendetta','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(31,'Morell''s Castle: The Awakening','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(32,'Morell''s Castle: Hippity Hoppity Kill','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(33,'Morell''s Castle: Gouzah Safari','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(34,'Shadowed Grove: Baga''s Stand','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(35,'Sanctum Somnium: Morell''s Challenge','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(36,'Sanctum Somnium: Morell Ascendant','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(37,'The Grounds: The Roots of Evil','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(38,'The Grounds: The Gardener','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(39,'The Well: What Lies Below','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(40,'Toskirakk: Spies, Lies, and Prison Cells','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(41,'Toskirakk: Hiding in Plain Sight','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(42,'Toskirakk: Cruel Overseers','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(43,'Temple of Bertoxxulous: The Bubonians are Coming!','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(44,'Temple of Bertoxxulous: Remove the Head','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(45,'Temple of Bertoxxulous: A Corrupted Arch Priest','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(46,'Old Kurn''s Tower: Defeat the Dragonbane Phalanx','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(47,'Old Kurn''s Tower: Audience with the Warmaster','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(48,'Old Commonlands: Safe Passage','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(49,'Old Commonlands: Bitter Victuals','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(50,'Old Bloodfields: Holding the Fort','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(51,'Asylum of Anguish','[[70,80]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(52,'Blacksail Folly: A Spy Among Them','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(53,'Blacksail Folly: Buried Treasure','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(54,'Blacksail Folly: Reduce His Numbers','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(55,'Blacksail Folly: Running Rum','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(56,'Blacksail Folly: Sharpeye''s Demise','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(57,'Bloodmoon Keep: Bronzebeard''s Fate','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(58,'Bloodmoon Keep: Crazok Moonfang','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(59,'Bloodmoon Keep: Moonfang','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(60,'Bloodmoon Keep: Ralkor''s Crystals','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(61,'Bloodmoon Keep: The Bloodmoon','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(62,'Bloodmoon Keep: The Broodmother','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(63,'Bloodmoon Keep: The Dark Crystals','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(64,'Bloodmoon Keep: The Departed','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(65,'Bloodmoon Keep: The Fanged Moon','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(66,'Bloodmoon Keep: Walking Into Spider Webs','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(67,'Bloody Kithicor: Safe Passage','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(68,'Catacombs of Dranik Beza','[[55,75]]','Omens of War','Kuua','instanced','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(69,'Catacombs of Dranik Caza','[[55,75]]','Omens of War','Kuua','instanced','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(70,'Catacombs of Dranik Azia','[[55,75]]','Omens of War','Kuua','instanced','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(71,'Catacombs of Dranik: Redemption','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(72,'City Hall: Drachnid Spies','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(73,'Corathus Lair: The Mines','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(74,'Corathus Lair: The Rescue','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(75,'Coven of the Skinwalkers: Rescue Cicero','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(76,'Coven of the Skinwalkers: Spies Like Them','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(77,'Deadbone Reef: Pirate Stew','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(78,'Deadbone Reef: Shipping Lanes','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(79,'Deadbone Reef: The Drowned Dead','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(80,'Deathknell, Tower of Dissonance','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(81,'Deepest Guk','[[20,75]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(82,'Dranik''s Hollows A (Watering Hole)','[[45,70]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(83,'Dranik''s Hollows B (Fire Pit)','[[45,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(84,'Dranik''s Hollows C (Murkglider Hive)','[[45,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(85,'Dulak''s Harbor: The Grozmok Stone','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(86,'Eastern Wastes: Cage Kerafyrm','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(87,'Everfrost Peaks: Battle for Permafrost','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(88,'Freeport Arena','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(89,'Freeport Militia House','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(90,'Freeport Sewers: Ratfink Mystery','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(91,'Freeport Sewers: The Ooze News','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(92,'Great Divide: The Defense of Thurgadin','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(93,'Great Divide: The Founding of Thurgadin','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(94,'Gyrospire Beza: End the Return','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(95,'Gyrospire Beza: How Does Your Garden Grow','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(96,'Gyrospire Beza: It''s All in Your Head','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(97,'Gyrospire Beza: Sand in the Gears','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(98,'Gyrospire Beza: Scout Gyrospire Beza','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(99,'Gyrospire Zeka: Cut Off the Head','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(100,'Gyrospire Zeka: Did We Forget the Cheese','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(101,'Gyrospire Zeka: Intelligence Gathering','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(102,'Gyrospire Zeka: To Climb a Spire','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(103,'Gyrospire Zeka: You Might Poke an Eye Out','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(104,'Hall of Truth','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(105,'Ikkinz: Chambers of Destruction','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(106,'Ikkinz: Trial of Glorification','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(107,'Ikkinz: Trial of Righteousness','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(108,'Ikkinz: Trial of Singular Might','[[65,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(109,'Ikkinz: Trial of Transcendence','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(110,'Ikkinz: Trial of Tri-Fates','[[65,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(111,'Ikkinz: Trial of Twin Struggles','[[65,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(112,'Inku''Ta, The Unmasked Chapel','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(113,'Jardel''s Hook: Jardel''s Hidden Cove','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(114,'Jewel of Atiiki: Fate of the Combine','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(115,'Jewel of Atiiki: Gorillas in the Garden','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(116,'Jewel of Atiiki: Knowledge for Power','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(117,'Jewel of Atiiki: Stone Tongue of Ateleka','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(118,'Jewel of Atiiki: The Great Invasion','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(119,'Kael Drakkel: A Daring Raid','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(120,'Kaesora Hatchery: An Impenetrable Shield','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(121,'Kaesora Hatchery: Hatching a Plan','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(122,'Kaesora Hatchery: The Brood of an Emperor','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(123,'Kaesora Library: The Price of Knowledge','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(124,'Korafax: Inside Information','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(125,'Korafax: Kill the Shearspawn','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(126,'Korafax: Meeting Adjourned','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(127,'Korafax: Stardouser','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(128,'Korascian Warrens: Murdunk''s Final Stand','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(129,'Korascian Warrens: The Crystal Bloom','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(130,'Lair of the Korlach: Discovering the Secret','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(131,'Lair of the Korlach: Into the Leviathan''s Lair','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(132,'Lair of the Korlach: Learning the Power','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(133,'Lair of the Korlach: Raid Instance','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(134,'Lair of the Korlach: Taking Control','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(135,'Lair of the Korlach: The Search for Coral','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(136,'Lavaspinner''s Lair: A Halfling''s Greed','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(137,'Lavaspinner''s Lair: Forbin''s Elixer','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(138,'Lavaspinner''s Lair: Infested','[[65,80]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(139,'Lavaspinner''s Lair: Lavaspinner Hunting','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(140,'Lavaspinner''s Lair: Lavaspinner''s Locals','[[45,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(141,'Lavaspinner''s Lair: The Drake Menace','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(142,'Lavaspinner''s Lair: Volkara''s Bite','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(143,'Living Larder: Find Fiddlebrap 3, The Hive','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(144,'Living Larder: The Lost Gnomes','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(145,'Living Larder: The Search for Clues','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(146,'Mistmoore Catacombs','[[20,75]]','Lost Dungeons of Norrath','Faydwer','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(147,'Miragul''s Menagerie','[[20,75]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(148,'Maiden''s Grave: If You Can''t Beat Em','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(149,'Maiden''s Grave: Mer''s Lost Love','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(150,'Maiden''s Grave: Never Trust a Siren','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(151,'Maiden''s Grave: Piecing Things Together','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(152,'Maiden''s Grave: The Grim News','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(153,'Monkey Rock: A Volatile Disease','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(154,'Monkey Rock: An Experiment Gone Wrong','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(155,'Monkey Rock: Cleansing the Island','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(156,'Monkey Rock: Long Abandoned','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(157,'Monkey Rock: The Wold Island','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(158,'Nagafen''s Lair: Defending the Lair','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(159,'Oceangreen Village: A Dread Challenger','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(160,'Oceangreen Village: Music For a Weary Soul','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(161,'Oceangreen Village: Stop the Contamination','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(162,'Old Blackburrow: Beat the Blackburrow Boss','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(163,'Old Blackburrow: Disrupting the Ritual','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(164,'Old Blackburrow: Freedom!','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(165,'Prince''s Manor: The Rage of Kelliad','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(166,'Prince''s Manor: The Search for Ilsuras','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(167,'Proving Grounds: The Mastery of Adaptation','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(168,'Proving Grounds: The Mastery of Corruption','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(169,'Rujarkin Hills','[[20,70]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(170,'S.H.I.P Workshop: Administrative Assassination','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(171,'S.H.I.P Workshop: Deception and Interception','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(172,'Proving Grounds: The Mastery of Destruction','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(173,'Proving Grounds: The Mastery of Efficiency','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(174,'Proving Grounds: The Mastery of Endurance','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(175,'Proving Grounds: The Mastery of Fear','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(176,'Proving Grounds: The Mastery of Foresight','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(177,'Proving Grounds: The Mastery of Hate','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(178,'Proving Grounds: The Mastery of Ingenuity','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(179,'Proving Grounds: The Mastery of Specialization','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(180,'Razorthorn: Samples of Corruption','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(181,'Sewers of Nihilia, the Crematory','[[60,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(182,'Proving Grounds: The Mastery of Subversion','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(183,'Proving Grounds: The Mastery of Weaponry','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(184,'Razorthorn, Tower of Sullon Zek','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(185,'Razorthorn: Hero''s Challenge','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(186,'Redfeather Isle: Mystery of Aviak Island','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(187,'Redfeather Isle: The Final Lyric','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(188,'Rivervale: Zombie Invasion','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(189,'Ruins of Takish-Hiz: Message to the Past','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(190,'Ruins of Takish-Hiz: The Burning Prince','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(191,'S.H.I.P Workshop: Kill Ungracious','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(192,'S.H.I.P Workshop: Mechalossus','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(193,'S.H.I.P Workshop: S.H.I.P Sabotage','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(194,'S.H.I.P Workshop: Tactical Teleportation','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(195,'Sewers of Dranik A','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(196,'Sewers of Dranik B','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(197,'Sewers of Dranik C','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(198,'Sewers of Nihilia, Lair of Trapped Ones','[[60,65]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(199,'Sewers of Nihilia, Pool of Sludge','[[60,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(200,'Sewers of Nihilia, Purifying Plant','[[60,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(201,'Silyssar: Immortal Coils','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(202,'Silyssar: Slay the Serpent','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(203,'Silyssar: Stop the Ritual','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(204,'Silyssar: The Source of Shissar Power','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(205,'Silyssar: Where There''s a Will','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(206,'Skylance: Daosheen the Firstborn','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(207,'Skylance: The Laboratory','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(208,'Skylance: The Library','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(209,'Skylance: The Oubliette','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(210,'Snarlstone Dens: A Plea for Help','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(211,'Snarlstone Dens: Bloodeye','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(212,'Snarlstone Dens: Confronting a Traitor','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(213,'Snarlstone Dens: Shadowmane Invasion','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(214,'Sporali Caverns: Antraygus, the Sporali King','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(215,'Sporali Caverns: Cavern Botany','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(216,'Sporali Caverns: Defending the Grove','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(217,'Sporali Caverns: Sporali Mind Meld','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(218,'Sporali Caverns: Way of the Freemind','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(219,'Stillmoon Temple: Animated Statue Plans','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(220,'Stillmoon Temple: Best Laid Plans','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(221,'Stillmoon Temple: Diseased Pumas','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(222,'Stillmoon Temple: Guardian of the Sands','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(223,'Stillmoon Temple: Scales of Justice','[[65,70]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(224,'Stillmoon Temple: Sickness of the Spirit','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(225,'Takish-Hiz','[[20,75]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(226,'Stillmoon Temple: Tea for Thy Master','[[65,90]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(227,'Stoneroot Falls: A Rogue''s Trust','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(228,'Stoneroot Falls: Building the Disguise','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(229,'Stoneroot Falls: Scouting the City','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(230,'Suncrest Isle: Altar Call','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(231,'Suncrest Isle: Pay a Visit','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(232,'Suncrest Isle: Repaying the Tidewater Goblins','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(233,'Suncrest Isle: Sunstone Savages','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(234,'Suncrest Isle: The Secret of Suncrest Ridge','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(235,'Tacvi, Seat of the Slaver','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(236,'Temple of the Korlach: Last of the Legion','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(237,'Temple of the Korlach: Praetorian Guard','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(238,'Temple of the Korlach: Shiliskin Subterfuge','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(239,'Temple of the Korlach: The Council of Nine','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(240,'Temple of the Korlach: The Korlach','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(241,'Thalassius: Coral Diving','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(242,'Thalassius: Evidence of Unity','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(243,'Thalassius: Locating the Statue','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(244,'Thalassius: Sea Serpents','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(245,'Thalassius: The Hydromancer','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(246,'The Accursed Nest: Circle of Drakes','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(247,'The Accursed Nest: Clues','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(248,'The Accursed Nest: Dragon''s Egg','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(249,'The Accursed Nest: In the Shadows','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(250,'The Accursed Nest: Lair of the Blackwing','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(251,'The Accursed Nest: Lost Comrades','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(252,'The Accursed Nest: Rival Party','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(253,'The Accursed Nest: Spider''s Eye','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(254,'The Accursed Nest: The Curse of Ju''rek','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(255,'The Accursed Nest: The Rampaging Monolith','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(256,'The Accursed Nest: Web of Lies','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(257,'The Ascent: Death Comes Swiftly','[[60,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(258,'The Ascent: Drake Eggs','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(259,'The Ascent: Kessdona''s Perch','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(260,'The Ascent: Reflections of Silver','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(261,'The Ascent: Signal Fires','[[65,80]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(262,'The Ascent: Storm Dragon Scales','[[60,70]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(263,'The Ascent: Sudden Tremors','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(264,'The Hive: Freeing an Elder','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(265,'The Hive: Hides You Seek','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(266,'The Hive: Infiltrating the Hive','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(267,'The Hive: Sabotage!','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(268,'The Hive: The Accursed Book','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(269,'The Hive: The Lost Notebook','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(270,'The Hive: The Orb of Subversion','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(271,'The Mechamatic Guardian: Disposing of Things','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(272,'The Seething Wall','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(273,'The Mechamatic Guardian: Engine Room','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(274,'The Mechamatic Guardian: Infiltrating the Guardian','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(275,'The Mechamatic Guardian: Mechamatic Memory','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(276,'The Mechamatic Guardian: Security, Yeah Right','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(277,'The Mechamatic Guardian: Solo Tasks','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(278,'The Mechamatic Guardian: Tinmizer''s Wunderwerks','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(279,'The Mechamatic Guardian: Tracking the Steamworks','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(280,'The Nargilor Pits: Deserting the Ranks','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(281,'The Nargilor Pits: Emperor Draygun','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(282,'The Nargilor Pits: Preemptive Strike','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(283,'The Nargilor Pits: The Last Migration','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(284,'The Open Sea: Hired Hands','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(285,'The Open Sea: Pirating Ways','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(286,'The Open Sea: Protect My Ship','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(287,'Theater of the Tranquil','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(288,'The Open Sea: Stop Kenox!','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(289,'The Open Sea: We Need More Crew','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(290,'The Root of Ro: Lair of Suchun','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(291,'The Root of Ro: The Key to the Past','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(292,'Thundercrest Isles: A Simple Task','[[60,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(293,'Thundercrest Isles: An End to the Storms','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(294,'Thundercrest Isles: Behind Closed Doors','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(295,'Thundercrest Isles: History of the Isle','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(296,'Thundercrest Isles: Holy Hour','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(297,'Thundercrest Isles: House of the Autumn Rose','[[60,70]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(298,'Thundercrest Isles: Lair Unguarded','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(299,'Thundercrest Isles: Scions of Thundercrest','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(300,'Thundercrest Isles: Splitting the Storm','[[60,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(301,'Thundercrest Isles: The Creator','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(302,'Thundercrest Isles: The Gilded Scroll','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(303,'Thundercrest Isles: Throes of Contagion','[[60,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(304,'Tirranun''s Delve: Children of Gimblax','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(305,'Txevu, Lair of the Elite','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(306,'Tirranun''s Delve: Have Note Will Travel','[[60,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(307,'Tirranun''s Delve: Storming the Goblin Temple','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(308,'Tunare''s Shrine: Investigating the Elddar','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(309,'Tunare''s Shrine: Questioning the Priest','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(310,'Tunare''s Shrine: The Corruption of Ro','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(311,'Undershore: Trailing Longshadow','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(312,'Uqua, The Ocean God Chantry','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(313,'Vixed, The Crumbling Caverns','[[65,75]]','Gates of Discord','Taelosia','mission','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(314,'Zhisza: Confront Emperor Vaakiszh','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(315,'Zhisza: Counter-Attack','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(316,'Zhisza: Leave No Stone Unturned','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(317,'Zhisza: The Domes Are Cracking','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(318,'Zhisza: The Snakes are Hiding Something','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30');
-INSERT INTO instances VALUES(319,'Deepscar''s Den','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,0,'2025-08-13 12:56:11');
-INSERT INTO instances VALUES(320,'Dreadspire Keep: Demi-Plane of Blood','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:28:24');
-INSERT INTO instances VALUES(321,'Lodge of the Fang','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:33:43');
-INSERT INTO instances VALUES(322,'Caverns of the Lost','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:33:49');
-INSERT INTO instances VALUES(323,'Lair of the Creep Beast','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:34:15');
-INSERT INTO instances VALUES(324,'Queen Sendaii''s Lair','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:34:46');
-INSERT INTO instances VALUES(325,'Illsalin Marketplace','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:35:24');
-INSERT INTO instances VALUES(326,'The Liberation of Runnyeye','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,0,'2025-08-13 13:35:58');
+INSERT INTO instances VALUES(1,'The High Priest','[[120,125]]','The Outer Brood','Antonica','indoor','["Aureate Covert"]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',32);
+INSERT INTO instances VALUES(2,'The Control Room','[[120,125]]','The Outer Brood','Antonica','indoor','["Aureate Covert"]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',32);
+INSERT INTO instances VALUES(3,'The Leviathan''s Heart','[[120,125]]','The Outer Brood','Antonica','indoor','["The Chambers of Puissance"]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',32);
+INSERT INTO instances VALUES(4,'Brood Architect Hazuri','[[120,125]]','The Outer Brood','Antonica','indoor','["The Theater of Eternity"]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',32);
+INSERT INTO instances VALUES(5,'Academy of Arcane Sciences','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(6,'Final Fugue','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',5,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',31);
+INSERT INTO instances VALUES(7,'Heroes Are Forged','[[120,125]]','Laurion''s Song','Realm of Heroes','Indoors','["Laurion Inn"]','','',1,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',31);
+INSERT INTO instances VALUES(8,'Erudin Burning: The Pit of All Fears','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(9,'Al''Kabor''s Nightmare: Catastrophic Power','[[85,90]]','House of Thule','Faydwer','instanced','["House of Thule, Upper Floors"]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(10,'Feerrott, the Dream: Ritual of Terror','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(11,'The Well: Terror in the Dark','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(12,'Al''Kabor''s Nightmare: At World''s End','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(13,'Erudin Burning: The Torches of Truth','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(14,'Erudin Burning: The Illuminating Truth','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(15,'Fear Itself: Cazic''s Final Stand','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(16,'Fear Itself: An Offering to Thule','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(17,'Sanctum Somnium: Raid Instance','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(18,'Feerrott, the Dream: A Tearable Fear','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(19,'House of Thule: Tick Tock','[[85,90]]','House of Thule','Faydwer','raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(20,'House of Thule Upper: Redemption','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(21,'House of Thule Upper: Guardian of the House','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(22,'Feerrott, the Dream: Fall of the Tae Ew','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(23,'House of Thule: Welcome to My Nightmare','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(24,'The Well: No Freedom in Death','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(25,'The Library: A Moment of Clarity','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(26,'The Library: The Librarian''s Nightmare','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(27,'The Library: The Stuff of Dreams','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(28,'The Grounds: Mashing Pumpkins','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(29,'Miragul''s Phylactery','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(30,'Miragul''s Nightmare: Miragul''s Vendetta','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(31,'Morell''s Castle: The Awakening','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(32,'Morell''s Castle: Hippity Hoppity Kill','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(33,'Morell''s Castle: Gouzah Safari','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(34,'Shadowed Grove: Baga''s Stand','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(35,'Sanctum Somnium: Morell''s Challenge','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(36,'Sanctum Somnium: Morell Ascendant','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(37,'The Grounds: The Roots of Evil','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(38,'The Grounds: The Gardener','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(39,'The Well: What Lies Below','[[85,90]]','House of Thule','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',18);
+INSERT INTO instances VALUES(40,'Toskirakk: Spies, Lies, and Prison Cells','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(41,'Toskirakk: Hiding in Plain Sight','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(42,'Toskirakk: Cruel Overseers','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(43,'Temple of Bertoxxulous: The Bubonians are Coming!','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(44,'Temple of Bertoxxulous: Remove the Head','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(45,'Temple of Bertoxxulous: A Corrupted Arch Priest','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(46,'Old Kurn''s Tower: Defeat the Dragonbane Phalanx','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(47,'Old Kurn''s Tower: Audience with the Warmaster','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(48,'Old Commonlands: Safe Passage','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(49,'Old Commonlands: Bitter Victuals','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(50,'Old Bloodfields: Holding the Fort','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(51,'Asylum of Anguish','[[70,80]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(52,'Blacksail Folly: A Spy Among Them','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(53,'Blacksail Folly: Buried Treasure','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(54,'Blacksail Folly: Reduce His Numbers','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(55,'Blacksail Folly: Running Rum','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(56,'Blacksail Folly: Sharpeye''s Demise','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(57,'Bloodmoon Keep: Bronzebeard''s Fate','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(58,'Bloodmoon Keep: Crazok Moonfang','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(59,'Bloodmoon Keep: Moonfang','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(60,'Bloodmoon Keep: Ralkor''s Crystals','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(61,'Bloodmoon Keep: The Bloodmoon','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(62,'Bloodmoon Keep: The Broodmother','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(63,'Bloodmoon Keep: The Dark Crystals','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(64,'Bloodmoon Keep: The Departed','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(65,'Bloodmoon Keep: The Fanged Moon','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(66,'Bloodmoon Keep: Walking Into Spider Webs','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(67,'Bloody Kithicor: Safe Passage','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(68,'Catacombs of Dranik Beza','[[55,75]]','Omens of War','Kuua','instanced','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(69,'Catacombs of Dranik Caza','[[55,75]]','Omens of War','Kuua','instanced','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(70,'Catacombs of Dranik Azia','[[55,75]]','Omens of War','Kuua','instanced','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(71,'Catacombs of Dranik: Redemption','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(72,'City Hall: Drachnid Spies','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(73,'Corathus Lair: The Mines','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(74,'Corathus Lair: The Rescue','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(75,'Coven of the Skinwalkers: Rescue Cicero','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(76,'Coven of the Skinwalkers: Spies Like Them','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(77,'Deadbone Reef: Pirate Stew','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(78,'Deadbone Reef: Shipping Lanes','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(79,'Deadbone Reef: The Drowned Dead','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(80,'Deathknell, Tower of Dissonance','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(81,'Deepest Guk','[[20,75]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',7);
+INSERT INTO instances VALUES(82,'Dranik''s Hollows A (Watering Hole)','[[45,70]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(83,'Dranik''s Hollows B (Fire Pit)','[[45,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(84,'Dranik''s Hollows C (Murkglider Hive)','[[45,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(85,'Dulak''s Harbor: The Grozmok Stone','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(86,'Eastern Wastes: Cage Kerafyrm','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(87,'Everfrost Peaks: Battle for Permafrost','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(88,'Freeport Arena','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(89,'Freeport Militia House','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(90,'Freeport Sewers: Ratfink Mystery','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(91,'Freeport Sewers: The Ooze News','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(92,'Great Divide: The Defense of Thurgadin','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(93,'Great Divide: The Founding of Thurgadin','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(94,'Gyrospire Beza: End the Return','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(95,'Gyrospire Beza: How Does Your Garden Grow','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(96,'Gyrospire Beza: It''s All in Your Head','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(97,'Gyrospire Beza: Sand in the Gears','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(98,'Gyrospire Beza: Scout Gyrospire Beza','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(99,'Gyrospire Zeka: Cut Off the Head','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(100,'Gyrospire Zeka: Did We Forget the Cheese','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(101,'Gyrospire Zeka: Intelligence Gathering','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(102,'Gyrospire Zeka: To Climb a Spire','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(103,'Gyrospire Zeka: You Might Poke an Eye Out','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(104,'Hall of Truth','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(105,'Ikkinz: Chambers of Destruction','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(106,'Ikkinz: Trial of Glorification','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(107,'Ikkinz: Trial of Righteousness','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(108,'Ikkinz: Trial of Singular Might','[[65,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(109,'Ikkinz: Trial of Transcendence','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(110,'Ikkinz: Trial of Tri-Fates','[[65,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(111,'Ikkinz: Trial of Twin Struggles','[[65,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(112,'Inku''Ta, The Unmasked Chapel','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(113,'Jardel''s Hook: Jardel''s Hidden Cove','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(114,'Jewel of Atiiki: Fate of the Combine','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(115,'Jewel of Atiiki: Gorillas in the Garden','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(116,'Jewel of Atiiki: Knowledge for Power','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(117,'Jewel of Atiiki: Stone Tongue of Ateleka','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(118,'Jewel of Atiiki: The Great Invasion','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(119,'Kael Drakkel: A Daring Raid','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(120,'Kaesora Hatchery: An Impenetrable Shield','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(121,'Kaesora Hatchery: Hatching a Plan','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(122,'Kaesora Hatchery: The Brood of an Emperor','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(123,'Kaesora Library: The Price of Knowledge','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(124,'Korafax: Inside Information','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(125,'Korafax: Kill the Shearspawn','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(126,'Korafax: Meeting Adjourned','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(127,'Korafax: Stardouser','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(128,'Korascian Warrens: Murdunk''s Final Stand','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(129,'Korascian Warrens: The Crystal Bloom','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(130,'Lair of the Korlach: Discovering the Secret','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(131,'Lair of the Korlach: Into the Leviathan''s Lair','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(132,'Lair of the Korlach: Learning the Power','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(133,'Lair of the Korlach: Raid Instance','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(134,'Lair of the Korlach: Taking Control','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(135,'Lair of the Korlach: The Search for Coral','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(136,'Lavaspinner''s Lair: A Halfling''s Greed','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(137,'Lavaspinner''s Lair: Forbin''s Elixer','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(138,'Lavaspinner''s Lair: Infested','[[65,80]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(139,'Lavaspinner''s Lair: Lavaspinner Hunting','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(140,'Lavaspinner''s Lair: Lavaspinner''s Locals','[[45,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(141,'Lavaspinner''s Lair: The Drake Menace','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(142,'Lavaspinner''s Lair: Volkara''s Bite','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(143,'Living Larder: Find Fiddlebrap 3, The Hive','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(144,'Living Larder: The Lost Gnomes','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(145,'Living Larder: The Search for Clues','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(146,'Mistmoore Catacombs','[[20,75]]','Lost Dungeons of Norrath','Faydwer','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',7);
+INSERT INTO instances VALUES(147,'Miragul''s Menagerie','[[20,75]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',7);
+INSERT INTO instances VALUES(148,'Maiden''s Grave: If You Can''t Beat Em','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(149,'Maiden''s Grave: Mer''s Lost Love','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(150,'Maiden''s Grave: Never Trust a Siren','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(151,'Maiden''s Grave: Piecing Things Together','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(152,'Maiden''s Grave: The Grim News','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(153,'Monkey Rock: A Volatile Disease','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(154,'Monkey Rock: An Experiment Gone Wrong','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(155,'Monkey Rock: Cleansing the Island','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(156,'Monkey Rock: Long Abandoned','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(157,'Monkey Rock: The Wold Island','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(158,'Nagafen''s Lair: Defending the Lair','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(159,'Oceangreen Village: A Dread Challenger','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(160,'Oceangreen Village: Music For a Weary Soul','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(161,'Oceangreen Village: Stop the Contamination','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(162,'Old Blackburrow: Beat the Blackburrow Boss','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(163,'Old Blackburrow: Disrupting the Ritual','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(164,'Old Blackburrow: Freedom!','[[75,85]]','Seeds of Destruction','Luclin','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',16);
+INSERT INTO instances VALUES(165,'Prince''s Manor: The Rage of Kelliad','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(166,'Prince''s Manor: The Search for Ilsuras','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(167,'Proving Grounds: The Mastery of Adaptation','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(168,'Proving Grounds: The Mastery of Corruption','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(169,'Rujarkin Hills','[[20,70]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',7);
+INSERT INTO instances VALUES(170,'S.H.I.P Workshop: Administrative Assassination','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(171,'S.H.I.P Workshop: Deception and Interception','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(172,'Proving Grounds: The Mastery of Destruction','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(173,'Proving Grounds: The Mastery of Efficiency','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(174,'Proving Grounds: The Mastery of Endurance','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(175,'Proving Grounds: The Mastery of Fear','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(176,'Proving Grounds: The Mastery of Foresight','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(177,'Proving Grounds: The Mastery of Hate','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(178,'Proving Grounds: The Mastery of Ingenuity','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(179,'Proving Grounds: The Mastery of Specialization','[[70,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(180,'Razorthorn: Samples of Corruption','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(181,'Sewers of Nihilia, the Crematory','[[60,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(182,'Proving Grounds: The Mastery of Subversion','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(183,'Proving Grounds: The Mastery of Weaponry','[[65,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(184,'Razorthorn, Tower of Sullon Zek','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(185,'Razorthorn: Hero''s Challenge','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(186,'Redfeather Isle: Mystery of Aviak Island','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(187,'Redfeather Isle: The Final Lyric','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(188,'Rivervale: Zombie Invasion','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(189,'Ruins of Takish-Hiz: Message to the Past','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(190,'Ruins of Takish-Hiz: The Burning Prince','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(191,'S.H.I.P Workshop: Kill Ungracious','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(192,'S.H.I.P Workshop: Mechalossus','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(193,'S.H.I.P Workshop: S.H.I.P Sabotage','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(194,'S.H.I.P Workshop: Tactical Teleportation','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(195,'Sewers of Dranik A','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(196,'Sewers of Dranik B','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(197,'Sewers of Dranik C','[[60,75]]','Omens of War','Kuua','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',9);
+INSERT INTO instances VALUES(198,'Sewers of Nihilia, Lair of Trapped Ones','[[60,65]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(199,'Sewers of Nihilia, Pool of Sludge','[[60,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(200,'Sewers of Nihilia, Purifying Plant','[[60,75]]','Gates of Discord','Taelosia','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(201,'Silyssar: Immortal Coils','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(202,'Silyssar: Slay the Serpent','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(203,'Silyssar: Stop the Ritual','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(204,'Silyssar: The Source of Shissar Power','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(205,'Silyssar: Where There''s a Will','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(206,'Skylance: Daosheen the Firstborn','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(207,'Skylance: The Laboratory','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(208,'Skylance: The Library','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(209,'Skylance: The Oubliette','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(210,'Snarlstone Dens: A Plea for Help','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(211,'Snarlstone Dens: Bloodeye','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(212,'Snarlstone Dens: Confronting a Traitor','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(213,'Snarlstone Dens: Shadowmane Invasion','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(214,'Sporali Caverns: Antraygus, the Sporali King','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(215,'Sporali Caverns: Cavern Botany','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(216,'Sporali Caverns: Defending the Grove','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(217,'Sporali Caverns: Sporali Mind Meld','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(218,'Sporali Caverns: Way of the Freemind','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(219,'Stillmoon Temple: Animated Statue Plans','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(220,'Stillmoon Temple: Best Laid Plans','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(221,'Stillmoon Temple: Diseased Pumas','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(222,'Stillmoon Temple: Guardian of the Sands','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(223,'Stillmoon Temple: Scales of Justice','[[65,70]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(224,'Stillmoon Temple: Sickness of the Spirit','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(225,'Takish-Hiz','[[20,75]]','Lost Dungeons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',7);
+INSERT INTO instances VALUES(226,'Stillmoon Temple: Tea for Thy Master','[[65,90]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(227,'Stoneroot Falls: A Rogue''s Trust','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(228,'Stoneroot Falls: Building the Disguise','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(229,'Stoneroot Falls: Scouting the City','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(230,'Suncrest Isle: Altar Call','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(231,'Suncrest Isle: Pay a Visit','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(232,'Suncrest Isle: Repaying the Tidewater Goblins','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(233,'Suncrest Isle: Sunstone Savages','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(234,'Suncrest Isle: The Secret of Suncrest Ridge','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(235,'Tacvi, Seat of the Slaver','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(236,'Temple of the Korlach: Last of the Legion','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(237,'Temple of the Korlach: Praetorian Guard','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(238,'Temple of the Korlach: Shiliskin Subterfuge','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(239,'Temple of the Korlach: The Council of Nine','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(240,'Temple of the Korlach: The Korlach','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(241,'Thalassius: Coral Diving','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(242,'Thalassius: Evidence of Unity','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(243,'Thalassius: Locating the Statue','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(244,'Thalassius: Sea Serpents','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(245,'Thalassius: The Hydromancer','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(246,'The Accursed Nest: Circle of Drakes','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(247,'The Accursed Nest: Clues','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(248,'The Accursed Nest: Dragon''s Egg','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(249,'The Accursed Nest: In the Shadows','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(250,'The Accursed Nest: Lair of the Blackwing','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(251,'The Accursed Nest: Lost Comrades','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(252,'The Accursed Nest: Rival Party','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(253,'The Accursed Nest: Spider''s Eye','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(254,'The Accursed Nest: The Curse of Ju''rek','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(255,'The Accursed Nest: The Rampaging Monolith','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(256,'The Accursed Nest: Web of Lies','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(257,'The Ascent: Death Comes Swiftly','[[60,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(258,'The Ascent: Drake Eggs','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(259,'The Ascent: Kessdona''s Perch','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(260,'The Ascent: Reflections of Silver','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(261,'The Ascent: Signal Fires','[[65,80]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(262,'The Ascent: Storm Dragon Scales','[[60,70]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(263,'The Ascent: Sudden Tremors','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(264,'The Hive: Freeing an Elder','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(265,'The Hive: Hides You Seek','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(266,'The Hive: Infiltrating the Hive','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(267,'The Hive: Sabotage!','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(268,'The Hive: The Accursed Book','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(269,'The Hive: The Lost Notebook','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(270,'The Hive: The Orb of Subversion','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(271,'The Mechamatic Guardian: Disposing of Things','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(272,'The Seething Wall','[[50,70]]','Prophecy of Ro','Antonica','Outdoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(273,'The Mechamatic Guardian: Engine Room','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(274,'The Mechamatic Guardian: Infiltrating the Guardian','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(275,'The Mechamatic Guardian: Mechamatic Memory','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(276,'The Mechamatic Guardian: Security, Yeah Right','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(277,'The Mechamatic Guardian: Solo Tasks','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(278,'The Mechamatic Guardian: Tinmizer''s Wunderwerks','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(279,'The Mechamatic Guardian: Tracking the Steamworks','[[70,80]]','Secrets of Faydwer','Faydwer','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',15);
+INSERT INTO instances VALUES(280,'The Nargilor Pits: Deserting the Ranks','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(281,'The Nargilor Pits: Emperor Draygun','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(282,'The Nargilor Pits: Preemptive Strike','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(283,'The Nargilor Pits: The Last Migration','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(284,'The Open Sea: Hired Hands','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(285,'The Open Sea: Pirating Ways','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(286,'The Open Sea: Protect My Ship','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(287,'Theater of the Tranquil','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(288,'The Open Sea: Stop Kenox!','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(289,'The Open Sea: We Need More Crew','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(290,'The Root of Ro: Lair of Suchun','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(291,'The Root of Ro: The Key to the Past','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(292,'Thundercrest Isles: A Simple Task','[[60,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(293,'Thundercrest Isles: An End to the Storms','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(294,'Thundercrest Isles: Behind Closed Doors','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(295,'Thundercrest Isles: History of the Isle','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(296,'Thundercrest Isles: Holy Hour','[[65,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(297,'Thundercrest Isles: House of the Autumn Rose','[[60,70]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(298,'Thundercrest Isles: Lair Unguarded','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(299,'Thundercrest Isles: Scions of Thundercrest','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(300,'Thundercrest Isles: Splitting the Storm','[[60,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(301,'Thundercrest Isles: The Creator','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(302,'Thundercrest Isles: The Gilded Scroll','[[70,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(303,'Thundercrest Isles: Throes of Contagion','[[60,85]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(304,'Tirranun''s Delve: Children of Gimblax','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(305,'Txevu, Lair of the Elite','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(306,'Tirranun''s Delve: Have Note Will Travel','[[60,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(307,'Tirranun''s Delve: Storming the Goblin Temple','[[65,75]]','Dragons of Norrath','Antonica','Mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',10);
+INSERT INTO instances VALUES(308,'Tunare''s Shrine: Investigating the Elddar','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(309,'Tunare''s Shrine: Questioning the Priest','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(310,'Tunare''s Shrine: The Corruption of Ro','[[50,70]]','Prophecy of Ro','Antonica','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',12);
+INSERT INTO instances VALUES(311,'Undershore: Trailing Longshadow','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',11);
+INSERT INTO instances VALUES(312,'Uqua, The Ocean God Chantry','[[65,75]]','Gates of Discord','Taelosia','Raid','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(313,'Vixed, The Crumbling Caverns','[[65,75]]','Gates of Discord','Taelosia','mission','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',8);
+INSERT INTO instances VALUES(314,'Zhisza: Confront Emperor Vaakiszh','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(315,'Zhisza: Counter-Attack','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(316,'Zhisza: Leave No Stone Unturned','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(317,'Zhisza: The Domes Are Cracking','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(318,'Zhisza: The Snakes are Hiding Something','[[50,75]]','The Buried Sea','Odus','Indoors','[]','','',0,0,1,0,'2025-07-21 23:58:30',NULL,NULL,NULL,NULL,'','','mission','',14);
+INSERT INTO instances VALUES(319,'Deepscar''s Den','[[70,80]]','Secrets of Faydwer','Faydwer','Outdoors','[]','','',0,0,0,0,'2025-08-13 12:56:11',NULL,NULL,NULL,NULL,'','','','',15);
+INSERT INTO instances VALUES(320,'Dreadspire Keep: Demi-Plane of Blood','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:28:24',NULL,NULL,NULL,NULL,'','','','',11);
+INSERT INTO instances VALUES(321,'Lodge of the Fang','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:33:43',NULL,NULL,NULL,NULL,'','','','',11);
+INSERT INTO instances VALUES(322,'Caverns of the Lost','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:33:49',NULL,NULL,NULL,NULL,'','','','',11);
+INSERT INTO instances VALUES(323,'Lair of the Creep Beast','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:34:15',NULL,NULL,NULL,NULL,'','','','',11);
+INSERT INTO instances VALUES(324,'Queen Sendaii''s Lair','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:34:46',NULL,NULL,NULL,NULL,'','','','',11);
+INSERT INTO instances VALUES(325,'Illsalin Marketplace','[[45,70]]','Depths of Darkhollow','Antonica','Indoors','[]','','',0,0,0,0,'2025-08-13 13:35:24',NULL,NULL,NULL,NULL,'','','','',11);
+INSERT INTO instances VALUES(326,'The Liberation of Runnyeye','[[45,70]]','Depths of Darkhollow','Antonica','Outdoors','[]','','',0,0,0,0,'2025-08-13 13:35:58',NULL,NULL,NULL,NULL,'','','','',11);
 CREATE TABLE instance_notes (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 instance_id INTEGER NOT NULL,
@@ -1095,6 +1107,122 @@ INSERT INTO zone_flags VALUES(216,286,2,'2025-08-13 13:29:58');
 INSERT INTO zone_flags VALUES(217,294,2,'2025-08-13 13:33:12');
 INSERT INTO zone_flags VALUES(218,629,2,'2025-08-13 13:39:19');
 INSERT INTO zone_flags VALUES(219,152,2,'2025-08-13 14:04:01');
+CREATE TABLE expansions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                code TEXT NOT NULL UNIQUE,
+                name TEXT NOT NULL UNIQUE,
+                release_date TEXT NOT NULL,
+                release_order INTEGER NOT NULL UNIQUE,
+                level_cap INTEGER NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+INSERT INTO expansions VALUES(1,'Classic','Classic','1999-03-16',1,50,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(2,'RoK','Kunark','2000-04-24',2,60,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(3,'SoV','Velious','2000-12-05',3,60,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(4,'SoL','Shadows of Luclin','2001-12-04',4,60,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(5,'PoP','Planes of Power','2002-10-29',5,65,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(6,'LoY','The Legacy of Ykesha','2003-02-25',6,65,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(7,'LDoN','Lost Dungeons of Norrath','2003-09-09',7,65,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(8,'GoD','Gates of Discord','2004-02-10',8,65,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(9,'OoW','Omens of War','2004-09-14',9,70,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(10,'DoN','Dragons of Norrath','2005-02-15',10,70,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(11,'DoD','Depths of Darkhollow','2005-09-13',11,70,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(12,'PoR','Prophecy of Ro','2006-02-21',12,70,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(13,'TSS','The Serpent''s Spine','2006-09-19',13,75,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(14,'TBS','The Buried Sea','2007-02-13',14,75,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(15,'SoF','Secrets of Faydwer','2007-11-13',15,80,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(16,'SoD','Seeds of Destruction','2008-10-21',16,85,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(17,'UF','Underfoot','2009-12-15',17,85,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(18,'HoT','House of Thule','2010-10-12',18,90,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(19,'VoA','Veil of Alaris','2011-11-15',19,95,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(20,'RoF','Rain of Fear','2012-11-28',20,100,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(21,'CotF','Call of the Forsaken','2013-10-08',21,100,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(22,'TDS','The Darkened Sea','2014-10-28',22,105,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(23,'TBM','The Broken Mirror','2015-11-18',23,105,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(24,'EoK','Empires of Kunark','2016-11-16',24,105,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(25,'RoS','Ring of Scale','2017-11-28',25,110,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(26,'TBL','The Burning Lands','2018-12-11',26,110,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(27,'ToV','Torment of Velious','2019-12-18',27,115,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(28,'CoV','Claws of Veeshan','2020-12-08',28,115,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(29,'ToL','Terror of Luclin','2021-12-07',29,120,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(30,'NoS','Night of Shadows','2022-12-06',30,120,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(31,'LS','Laurion''s Song','2023-12-05',31,125,'2025-08-14 00:00:00');
+INSERT INTO expansions VALUES(32,'ToB','The Outer Brood','2024-12-10',32,125,'2025-08-14 00:00:00');
+CREATE TABLE rulesets (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                display_name TEXT NOT NULL,
+                max_expansion TEXT NOT NULL,
+                level_cap INTEGER,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+INSERT INTO rulesets VALUES(1,'mangler','Mangler','SoV',NULL,'2025-08-14 00:00:00');
+CREATE TABLE zone_aliases (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    zone_id INTEGER NOT NULL,
+                    alias TEXT NOT NULL COLLATE NOCASE UNIQUE,
+                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                    FOREIGN KEY (zone_id) REFERENCES zones (id) ON DELETE CASCADE
+                );
+INSERT INTO zone_aliases VALUES(1,34,'solb','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(2,34,'Sol B','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(3,52,'sola','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(4,52,'Sol A','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(5,709,'solc','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(6,709,'Sol C','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(7,95,'kael','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(8,95,'Kael Drakkel','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(9,92,'eastwastes','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(10,92,'The Eastern Wastes','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(11,4,'mistmoore','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(12,4,'Mistmoore Castle','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(13,50,'rathemtn','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(14,50,'Rathe Mountains','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(15,87,'warslikswood','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(16,87,'Warsliks Woods','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(17,81,'emeraldjungle','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(18,81,'Emerald Jungle','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(19,16,'East Freeport','2025-08-20 00:00:00');
+INSERT INTO zone_aliases VALUES(20,16,'West Freeport','2025-08-20 00:00:00');
+CREATE TABLE instance_aliases (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    instance_id INTEGER NOT NULL,
+                    alias TEXT NOT NULL COLLATE NOCASE UNIQUE,
+                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                    FOREIGN KEY (instance_id) REFERENCES instances (id) ON DELETE CASCADE
+                );
+CREATE TABLE audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entity_type TEXT NOT NULL,
+                entity_id INTEGER NOT NULL,
+                action TEXT NOT NULL CHECK (action IN ('create', 'update', 'delete')),
+                actor TEXT NOT NULL,
+                before_json TEXT,
+                after_json TEXT,
+                changes_json TEXT NOT NULL DEFAULT '[]',
+                batch_id INTEGER,
+                revert_of INTEGER,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            );
+CREATE TABLE instance_ratings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id INTEGER NOT NULL,
+                user_ip TEXT NOT NULL,
+                rating INTEGER NOT NULL CHECK (rating >= 1 AND rating <= 5),
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (instance_id) REFERENCES instances (id) ON DELETE CASCADE,
+                UNIQUE(instance_id, user_ip)
+            );
+CREATE TABLE instance_flags (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id INTEGER NOT NULL,
+                flag_type_id INTEGER NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (instance_id) REFERENCES instances (id) ON DELETE CASCADE,
+                FOREIGN KEY (flag_type_id) REFERENCES flag_types (id) ON DELETE CASCADE,
+                UNIQUE(instance_id, flag_type_id)
+            );
 CREATE TABLE migrations (
             id INTEGER PRIMARY KEY AUTOINCREMENT,
             name TEXT NOT NULL UNIQUE,
@@ -1111,6 +1239,9 @@ INSERT INTO sqlite_sequence VALUES('instances',326);
 INSERT INTO sqlite_sequence VALUES('instance_notes',3);
 INSERT INTO sqlite_sequence VALUES('flag_types',17);
 INSERT INTO sqlite_sequence VALUES('zone_flags',219);
+INSERT INTO sqlite_sequence VALUES('expansions',32);
+INSERT INTO sqlite_sequence VALUES('rulesets',1);
+INSERT INTO sqlite_sequence VALUES('zone_aliases',20);
 INSERT INTO sqlite_sequence VALUES('migrations',4);
 CREATE INDEX idx_zones_expansion ON zones(expansion);
 CREATE INDEX idx_zones_zone_type ON zones(zone_type);
@@ -1139,4 +1270,18 @@ CREATE INDEX idx_instance_notes_created_at ON instance_notes(created_at);
 CREATE INDEX idx_zone_flags_zone_id ON zone_flags(zone_id);
 CREATE INDEX idx_zone_flags_flag_type_id ON zone_flags(flag_type_id);
 CREATE INDEX idx_zone_flags_created_at ON zone_flags(created_at);
+CREATE INDEX idx_zone_aliases_zone_id ON zone_aliases(zone_id);
+CREATE INDEX idx_instance_aliases_instance_id ON instance_aliases(instance_id);
+CREATE INDEX idx_audit_log_entity ON audit_log(entity_type, entity_id);
+CREATE INDEX idx_audit_log_created_at ON audit_log(created_at);
+CREATE INDEX idx_zones_deleted_at ON zones(deleted_at);
+CREATE INDEX idx_instances_deleted_at ON instances(deleted_at);
+CREATE INDEX idx_links_deleted_at ON links(deleted_at);
+CREATE INDEX idx_instance_ratings_instance_id ON instance_ratings(instance_id);
+CREATE INDEX idx_instance_flags_instance_id ON instance_flags(instance_id);
+CREATE INDEX idx_instance_flags_flag_type_id ON instance_flags(flag_type_id);
+CREATE INDEX idx_audit_log_batch_id ON audit_log(batch_id);
+CREATE INDEX idx_audit_log_revert_of ON audit_log(revert_of);
+CREATE INDEX idx_instances_task_type ON instances(task_type);
+CREATE INDEX idx_instances_classification ON instances(classification);
 COMMIT;
@@ -16,6 +16,7 @@ export default defineConfig({
         "/random_race": "http://localhost:3000",
         "/random_class": "http://localhost:3000",
         "/version": "http://localhost:3000",
+        "/csrf-token": "http://localhost:3000",
         "/zones": "http://localhost:3000",
       },
     },
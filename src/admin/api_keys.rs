@@ -0,0 +1,250 @@
+// API key management
+// This file lists API keys with their usage and lets owners issue and revoke them
+
+#[cfg(feature = "admin")]
+use axum::{
+    extract::{Form, Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+};
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::admin::types::ApiKeyForm;
+#[cfg(feature = "admin")]
+use crate::api_keys::{self, Scope};
+#[cfg(feature = "admin")]
+use crate::error::AppError;
+#[cfg(feature = "admin")]
+use crate::security::{escape_html, escape_html_attribute};
+
+#[cfg(feature = "admin")]
+fn api_keys_page(keys: &[api_keys::ApiKey], message: &str) -> Html<String> {
+    let rows: String = keys
+        .iter()
+        .map(|key| {
+            let status = match &key.revoked_at {
+                Some(revoked_at) => format!("Revoked {}", escape_html(revoked_at)),
+                None => format!(
+                    r#"<form method="post" action="/admin/api-keys/{id}/revoke" class="inline-form" data-confirm="Revoke {name}? Clients using it stop working at once.">
+                    <button type="submit" class="btn btn-danger btn-small">Revoke</button>
+                </form>"#,
+                    id = key.id,
+                    name = escape_html_attribute(&key.name)
+                ),
+            };
+            format!(
+                r#"
+            <tr>
+                <td>{name}</td>
+                <td><code>{prefix}…</code></td>
+                <td>{scopes}</td>
+                <td>{rate_limit}/min</td>
+                <td>{quota_used} / {quota}</td>
+                <td>{requests}</td>
+                <td>{last_used}</td>
+                <td>{created}</td>
+                <td>{status}</td>
+            </tr>"#,
+                name = escape_html(&key.name),
+                prefix = escape_html(&key.key_prefix),
+                scopes = key
+                    .scopes
+                    .iter()
+                    .map(|scope| scope.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                rate_limit = key.rate_limit_per_minute,
+                quota_used = key.quota_used,
+                quota = if key.daily_quota == 0 {
+                    "unlimited".to_string()
+                } else {
+                    key.daily_quota.to_string()
+                },
+                requests = key.request_count,
+                last_used = escape_html(key.last_used_at.as_deref().unwrap_or("never")),
+                created = escape_html(key.created_at.as_deref().unwrap_or("")),
+                status = status,
+            )
+        })
+        .collect();
+
+    let nonce = crate::security::csp_nonce();
+    Html(format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>API Keys - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .form-section {{ background: #f8f9fa; padding: 20px; margin-bottom: 20px; border-radius: 5px; }}
+        .form-group {{ margin-bottom: 15px; display: inline-block; margin-right: 15px; }}
+        .message {{ background: #d4edda; border: 1px solid #c3e6cb; color: #155724; padding: 10px; border-radius: 4px; margin-bottom: 20px; }}
+        .new-key {{ font-size: 1.1em; word-break: break-all; }}
+        label {{ display: block; margin-bottom: 5px; font-weight: bold; }}
+        label.checkbox {{ display: inline; font-weight: normal; margin-right: 10px; }}
+        input[type=text], input[type=number] {{ padding: 8px; border: 1px solid #ddd; border-radius: 4px; }}
+        .btn {{ background: #007bff; color: white; padding: 8px 15px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        .btn-danger {{ background: #dc3545; }}
+        .btn-danger:hover {{ background: #c82333; }}
+        .btn-small {{ padding: 4px 8px; font-size: 0.8em; }}
+        table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; }}
+        th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
+        .inline-form {{ display: inline; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/audit">Audit Log</a>
+        <a href="/admin/users">Admin Users</a>
+        <a href="/admin/api-keys">API Keys</a>
+    </div>
+
+    <h1>API Keys</h1>
+    {message}
+    <div class="form-section">
+        <h2>Issue Key</h2>
+        <form method="post" action="/admin/api-keys">
+            <div class="form-group">
+                <label for="name">Name:</label>
+                <input type="text" id="name" name="name" maxlength="{max_name}" placeholder="Discord bot" required />
+            </div>
+            <div class="form-group">
+                <label for="rate_limit_per_minute">Requests per minute:</label>
+                <input type="number" id="rate_limit_per_minute" name="rate_limit_per_minute" min="1" value="{default_rate_limit}" required />
+            </div>
+            <div class="form-group">
+                <label for="daily_quota">Requests per day (0 = unlimited):</label>
+                <input type="number" id="daily_quota" name="daily_quota" min="0" value="{default_quota}" required />
+            </div>
+            <div class="form-group">
+                <label>Scopes:</label>
+                <label class="checkbox"><input type="checkbox" name="scope_read" checked /> read (public API)</label>
+                <label class="checkbox"><input type="checkbox" name="scope_rate" /> rate (submit ratings)</label>
+                <label class="checkbox"><input type="checkbox" name="scope_admin_read" /> admin-read (view admin pages)</label>
+            </div>
+            <div class="form-group">
+                <button type="submit" class="btn">Issue Key</button>
+            </div>
+        </form>
+        <p>Clients send the key as <code>Authorization: Bearer &lt;key&gt;</code>. Only a hash is kept, so a lost key has to be revoked and replaced.</p>
+    </div>
+
+    <table>
+        <tr><th>Name</th><th>Key</th><th>Scopes</th><th>Limit</th><th>Today</th><th>Total requests</th><th>Last used</th><th>Created</th><th></th></tr>
+        {rows}
+    </table>
+</body>
+</html>
+        "#,
+        message = message,
+        max_name = api_keys::MAX_NAME_LENGTH,
+        default_rate_limit = api_keys::DEFAULT_RATE_LIMIT_PER_MINUTE,
+        default_quota = api_keys::DEFAULT_DAILY_QUOTA,
+        rows = if rows.is_empty() {
+            r#"<tr><td colspan="9">No API keys yet</td></tr>"#.to_string()
+        } else {
+            rows
+        },
+    ))
+}
+
+#[cfg(feature = "admin")]
+async fn render(state: &AppState, message: &str) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+    let keys = api_keys::list_api_keys(pool.as_ref()).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list API keys");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(api_keys_page(&keys, message))
+}
+
+#[cfg(feature = "admin")]
+fn error_page(message: &str) -> Response {
+    Html(format!(
+        r#"<h1>Error</h1><p>{}</p><a href="/admin/api-keys">Go back</a>"#,
+        escape_html(message)
+    ))
+    .into_response()
+}
+
+#[cfg(feature = "admin")]
+pub async fn list_api_keys_admin(
+    State(state): State<AppState>,
+) -> Result<Html<String>, StatusCode> {
+    render(&state, "").await
+}
+
+/// Issue a key and show it once; it cannot be looked up again
+#[cfg(feature = "admin")]
+pub async fn create_api_key_admin(
+    State(state): State<AppState>,
+    Form(form): Form<ApiKeyForm>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let scopes: Vec<Scope> = [
+        (form.scope_read.is_some(), Scope::Read),
+        (form.scope_rate.is_some(), Scope::Rate),
+        (form.scope_admin_read.is_some(), Scope::AdminRead),
+    ]
+    .into_iter()
+    .filter_map(|(ticked, scope)| ticked.then_some(scope))
+    .collect();
+
+    let (id, key) = match api_keys::create_api_key(
+        pool.as_ref(),
+        form.name.trim(),
+        &scopes,
+        form.rate_limit_per_minute,
+        form.daily_quota,
+    )
+    .await
+    {
+        Ok(created) => created,
+        Err(AppError::Validation(message)) => return Ok(error_page(&message)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to create API key");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    crate::logging::log_admin_action("create", "api_key", Some(id), None);
+
+    let message = format!(
+        r#"<div class="message">Issued <strong>{}</strong>. Copy the key now; it will not be shown again:<br><code class="new-key">{}</code></div>"#,
+        escape_html(form.name.trim()),
+        escape_html(&key)
+    );
+    Ok(render(&state, &message).await?.into_response())
+}
+
+#[cfg(feature = "admin")]
+pub async fn revoke_api_key_admin(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    match api_keys::revoke_api_key(pool.as_ref(), id).await {
+        Ok(()) => {
+            crate::logging::log_admin_action("revoke", "api_key", Some(id), None);
+            Ok(Redirect::to("/admin/api-keys").into_response())
+        }
+        Err(AppError::Validation(message)) => Ok(error_page(&message)),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to revoke API key");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
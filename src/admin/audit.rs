@@ -0,0 +1,518 @@
+// Audit log admin functionality
+// This file lets an admin browse recorded data changes by entity and date,
+// view a single revision and revert it
+
+#[cfg(feature = "admin")]
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+};
+#[cfg(feature = "admin")]
+use serde::Deserialize;
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::audit::{
+    AuditEntity, AuditEntry, AuditFilter, count_entries, find_revert, get_batch, get_entry,
+    list_entries,
+};
+#[cfg(feature = "admin")]
+use crate::error::AppError;
+#[cfg(feature = "admin")]
+use crate::security::{escape_html, escape_html_attribute};
+
+#[cfg(feature = "admin")]
+const PAGE_SIZE: i64 = 50;
+#[cfg(feature = "admin")]
+const MAX_VALUE_LENGTH: usize = 120;
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditLogQuery {
+    pub entity_type: Option<String>,
+    pub entity_id: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub page: Option<i64>,
+}
+
+#[cfg(feature = "admin")]
+fn format_value(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::Null => return "<em>null</em>".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    if text.chars().count() > MAX_VALUE_LENGTH {
+        let truncated: String = text.chars().take(MAX_VALUE_LENGTH).collect();
+        format!("{}…", escape_html(&truncated))
+    } else {
+        escape_html(&text)
+    }
+}
+
+#[cfg(feature = "admin")]
+fn entity_link(entry: &AuditEntry) -> String {
+    let label = format!("{} #{}", entry.entity_type, entry.entity_id);
+    let edit_url = match entry.entity_type.as_str() {
+        "zone" => Some(format!("/admin/zones/{}", entry.entity_id)),
+        "instance" => Some(format!("/admin/instances/{}", entry.entity_id)),
+        "link" => Some(format!("/admin/links/{}", entry.entity_id)),
+        _ => None,
+    };
+
+    let history = format!(
+        r#"<a href="/admin/audit?entity_type={}&entity_id={}" title="History">{}</a>"#,
+        entry.entity_type, entry.entity_id, label
+    );
+
+    match edit_url {
+        Some(url) if entry.action != "delete" => {
+            format!(r#"{} <a href="{}" class="small">edit</a>"#, history, url)
+        }
+        _ => history,
+    }
+}
+
+#[cfg(feature = "admin")]
+fn render_changes(entry: &AuditEntry) -> String {
+    if entry.changes.is_empty() {
+        return "<em>no field changes</em>".to_string();
+    }
+
+    let items: String = entry
+        .changes
+        .iter()
+        .map(|change| match entry.action.as_str() {
+            "create" => format!(
+                "<li><code>{}</code>: {}</li>",
+                escape_html(&change.field),
+                format_value(&change.after)
+            ),
+            "delete" => format!(
+                "<li><code>{}</code>: {}</li>",
+                escape_html(&change.field),
+                format_value(&change.before)
+            ),
+            _ => format!(
+                r#"<li><code>{}</code>: <span class="before">{}</span> → <span class="after">{}</span></li>"#,
+                escape_html(&change.field),
+                format_value(&change.before),
+                format_value(&change.after)
+            ),
+        })
+        .collect();
+
+    // Whole-row images for creates and deletes are long; fold them away
+    if entry.action == "update" {
+        format!("<ul>{}</ul>", items)
+    } else {
+        format!(
+            "<details><summary>{} fields</summary><ul>{}</ul></details>",
+            entry.changes.len(),
+            items
+        )
+    }
+}
+
+#[cfg(feature = "admin")]
+pub async fn list_audit_log(
+    Query(params): Query<AuditLogQuery>,
+    State(state): State<AppState>,
+) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let entity_type = params
+        .entity_type
+        .filter(|t| AuditEntity::parse(t).is_some());
+    // Empty form fields arrive as "", so parse the id by hand
+    let entity_id = params
+        .entity_id
+        .as_deref()
+        .and_then(|id| id.trim().parse::<i64>().ok());
+    let filter = AuditFilter {
+        entity_type,
+        entity_id,
+        from: params.from.filter(|d| !d.trim().is_empty()),
+        to: params.to.filter(|d| !d.trim().is_empty()),
+    };
+    let page = params.page.unwrap_or(1).max(1);
+
+    let total = count_entries(pool.as_ref(), &filter).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to count audit log entries");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let entries = list_entries(pool.as_ref(), &filter, PAGE_SIZE, (page - 1) * PAGE_SIZE)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to list audit log entries");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let total_pages = ((total + PAGE_SIZE - 1) / PAGE_SIZE).max(1);
+
+    let entity_options: String = AuditEntity::ALL
+        .iter()
+        .map(|entity| {
+            let name = entity.as_str();
+            format!(
+                r#"<option value="{}"{}>{}</option>"#,
+                name,
+                if filter.entity_type.as_deref() == Some(name) {
+                    " selected"
+                } else {
+                    ""
+                },
+                name
+            )
+        })
+        .collect();
+
+    let rows: String = entries
+        .iter()
+        .map(|entry| {
+            format!(
+                r#"<tr>
+                <td><a href="/admin/audit/{}">{}</a></td>
+                <td>{}</td>
+                <td>{}</td>
+                <td><span class="action action-{}">{}</span></td>
+                <td>{}</td>
+                <td>{}</td>
+            </tr>"#,
+                entry.id,
+                entry.id,
+                escape_html(&entry.created_at),
+                escape_html(&entry.actor),
+                entry.action,
+                entry.action,
+                entity_link(entry),
+                render_changes(entry)
+            )
+        })
+        .collect();
+
+    let page_link = |page: i64| {
+        format!(
+            "/admin/audit?entity_type={}&entity_id={}&from={}&to={}&page={}",
+            urlencoding::encode(filter.entity_type.as_deref().unwrap_or("")),
+            filter
+                .entity_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            urlencoding::encode(filter.from.as_deref().unwrap_or("")),
+            urlencoding::encode(filter.to.as_deref().unwrap_or("")),
+            page
+        )
+    };
+    let pagination = format!(
+        "{} Page {} of {} ({} entries) {}",
+        if page > 1 {
+            format!(r#"<a href="{}">← Newer</a>"#, page_link(page - 1))
+        } else {
+            String::new()
+        },
+        page,
+        total_pages,
+        total,
+        if page < total_pages {
+            format!(r#"<a href="{}">Older →</a>"#, page_link(page + 1))
+        } else {
+            String::new()
+        }
+    );
+
+    let nonce = crate::security::csp_nonce();
+    Ok(Html(format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Audit Log - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 1400px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .form-section {{ background: #f8f9fa; padding: 20px; margin-bottom: 20px; border-radius: 5px; }}
+        .form-section label {{ margin-right: 5px; font-weight: bold; }}
+        .form-section input, .form-section select {{ margin-right: 15px; padding: 6px; }}
+        .btn {{ background: #007bff; color: white; padding: 8px 15px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; vertical-align: top; }}
+        th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
+        td ul {{ margin: 0; padding-left: 18px; }}
+        .before {{ color: #b02a37; text-decoration: line-through; }}
+        .after {{ color: #146c43; }}
+        .action {{ padding: 2px 8px; border-radius: 10px; font-size: 0.85em; color: white; }}
+        .action-create {{ background: #198754; }}
+        .action-update {{ background: #0d6efd; }}
+        .action-delete {{ background: #dc3545; }}
+        .small {{ font-size: 0.85em; }}
+        .pagination {{ margin: 15px 0; }}
+        .pagination a {{ margin: 0 10px; }}
+        #entity_id {{ width: 90px; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/ratings">Manage Ratings</a>
+        <a href="/admin/links">Manage Links</a>
+        <a href="/admin/backups">Backups</a>
+        <a href="/admin/audit">Audit Log</a>
+    </div>
+
+    <h1>Audit Log</h1>
+    <div class="form-section">
+        <form method="get" action="/admin/audit">
+            <label for="entity_type">Entity:</label>
+            <select id="entity_type" name="entity_type">
+                <option value="">All</option>
+                {}
+            </select>
+            <label for="entity_id">ID:</label>
+            <input type="number" id="entity_id" name="entity_id" value="{}">
+            <label for="from">From:</label>
+            <input type="date" id="from" name="from" value="{}">
+            <label for="to">To:</label>
+            <input type="date" id="to" name="to" value="{}">
+            <button type="submit" class="btn">Filter</button>
+            <a href="/admin/audit">Clear</a>
+        </form>
+    </div>
+
+    <div class="pagination">{}</div>
+    <table>
+        <thead>
+            <tr><th>#</th><th>When (UTC)</th><th>Actor</th><th>Action</th><th>Entity</th><th>Changes</th></tr>
+        </thead>
+        <tbody>
+            {}
+        </tbody>
+    </table>
+    <div class="pagination">{}</div>
+</body>
+</html>
+        "#,
+        entity_options,
+        filter
+            .entity_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        escape_html_attribute(filter.from.as_deref().unwrap_or("")),
+        escape_html_attribute(filter.to.as_deref().unwrap_or("")),
+        pagination,
+        if rows.is_empty() {
+            r#"<tr><td colspan="6">No matching changes</td></tr>"#.to_string()
+        } else {
+            rows
+        },
+        pagination,
+    )))
+}
+
+#[cfg(feature = "admin")]
+fn render_revision_fields(entry: &AuditEntry) -> String {
+    let empty = serde_json::Map::new();
+    let before = entry
+        .before
+        .as_ref()
+        .and_then(|v| v.as_object())
+        .unwrap_or(&empty);
+    let after = entry
+        .after
+        .as_ref()
+        .and_then(|v| v.as_object())
+        .unwrap_or(&empty);
+
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .map(|field| {
+            let changed = entry.changes.iter().any(|change| &change.field == field);
+            let cell = |image: &serde_json::Map<String, serde_json::Value>| {
+                image.get(field).map(format_value).unwrap_or_default()
+            };
+            format!(
+                r#"<tr{}><td><code>{}</code></td><td>{}</td><td>{}</td></tr>"#,
+                if changed { r#" class="changed""# } else { "" },
+                escape_html(field),
+                cell(before),
+                cell(after)
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "admin")]
+pub async fn view_audit_entry(
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+    let internal = |e: sqlx::Error| {
+        tracing::error!(error = %e, "Failed to load audit entry");
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+
+    let entry = get_entry(pool.as_ref(), id)
+        .await
+        .map_err(internal)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    let root = entry.batch_root();
+
+    let mut conn = pool.acquire().await.map_err(internal)?;
+    let batch = get_batch(&mut conn, root).await.map_err(internal)?;
+    let reverted_by = find_revert(&mut conn, root).await.map_err(internal)?;
+    drop(conn);
+
+    let batch_section = if batch.len() > 1 {
+        let items: String = batch
+            .iter()
+            .map(|member| {
+                let label = format!(
+                    "{} {} #{}",
+                    member.action, member.entity_type, member.entity_id
+                );
+                if member.id == entry.id {
+                    format!("<li><strong>{}</strong></li>", escape_html(&label))
+                } else {
+                    format!(
+                        r#"<li><a href="/admin/audit/{}">{}</a></li>"#,
+                        member.id,
+                        escape_html(&label)
+                    )
+                }
+            })
+            .collect();
+        format!(
+            "<h2>Part of one change with {} entries</h2><ul>{}</ul>",
+            batch.len(),
+            items
+        )
+    } else {
+        String::new()
+    };
+
+    let revert_section = match (reverted_by, entry.revert_of) {
+        (Some(revert), _) => format!(
+            r#"<p>This change was reverted by <a href="/admin/audit/{}">entry #{}</a>.</p>"#,
+            revert, revert
+        ),
+        (None, revert_of) => format!(
+            r#"{}<form method="post" action="/admin/audit/{}/revert" data-confirm="Revert this change{}?">
+            <button type="submit" class="btn btn-danger">Revert</button>
+        </form>"#,
+            revert_of
+                .map(|original| format!(
+                    r#"<p>This entry reverted <a href="/admin/audit/{}">entry #{}</a>; reverting it reapplies that change.</p>"#,
+                    original, original
+                ))
+                .unwrap_or_default(),
+            entry.id,
+            if batch.len() > 1 {
+                " and every entry listed above"
+            } else {
+                ""
+            }
+        ),
+    };
+
+    let nonce = crate::security::csp_nonce();
+    Ok(Html(format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Audit Entry #{} - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 1400px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .btn {{ background: #007bff; color: white; padding: 8px 15px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn-danger {{ background: #dc3545; }}
+        .btn-danger:hover {{ background: #b02a37; }}
+        table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; vertical-align: top; }}
+        th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
+        tr.changed td {{ background: #fff3cd; }}
+        .action {{ padding: 2px 8px; border-radius: 10px; font-size: 0.85em; color: white; }}
+        .action-create {{ background: #198754; }}
+        .action-update {{ background: #0d6efd; }}
+        .action-delete {{ background: #dc3545; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/ratings">Manage Ratings</a>
+        <a href="/admin/links">Manage Links</a>
+        <a href="/admin/backups">Backups</a>
+        <a href="/admin/audit">Audit Log</a>
+    </div>
+
+    <h1>Audit Entry #{}</h1>
+    <p><span class="action action-{}">{}</span> {} by {} at {} UTC</p>
+    {}
+    <h2>Revision</h2>
+    <table>
+        <thead>
+            <tr><th>Field</th><th>Before</th><th>After</th></tr>
+        </thead>
+        <tbody>
+            {}
+        </tbody>
+    </table>
+    {}
+</body>
+</html>
+        "#,
+        entry.id,
+        entry.id,
+        entry.action,
+        entry.action,
+        entity_link(&entry),
+        escape_html(&entry.actor),
+        escape_html(&entry.created_at),
+        batch_section,
+        render_revision_fields(&entry),
+        revert_section,
+    )))
+}
+
+#[cfg(feature = "admin")]
+pub async fn revert_audit_entry(
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    match crate::revisions::revert_entry(pool.as_ref(), id).await {
+        Ok(revert_id) => {
+            let _ = crate::checkpoint_wal(pool.as_ref()).await;
+            Ok(Redirect::to(&format!("/admin/audit/{}", revert_id)).into_response())
+        }
+        Err(AppError::Validation(message)) => Ok(Html(format!(
+            r#"<h1>Error</h1><p>{}</p><a href="/admin/audit/{}">Go back</a>"#,
+            escape_html(&message),
+            id
+        ))
+        .into_response()),
+        Err(e) => {
+            tracing::error!(error = %e, audit_id = id, "Failed to revert audit entry");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -0,0 +1,537 @@
+// Admin sign-in
+// This file holds the login and logout pages and the middleware that keeps every
+// other /admin route, and the JSON API's changes, behind a session cookie
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::admin::types::{LoginForm, LoginQuery};
+#[cfg(feature = "admin")]
+use crate::api_keys::{ApiKey, Scope};
+#[cfg(feature = "admin")]
+use crate::auth::{self, AdminUser, Role};
+#[cfg(feature = "admin")]
+use crate::client_ip::ClientIp;
+#[cfg(feature = "admin")]
+use crate::csrf;
+#[cfg(feature = "admin")]
+use crate::security::{escape_html, escape_html_attribute};
+#[cfg(feature = "admin")]
+use axum::{
+    body::Body,
+    extract::{Form, Query, State},
+    http::{HeaderMap, Method, StatusCode, header},
+    response::{AppendHeaders, Html, IntoResponse, Redirect, Response},
+};
+
+#[cfg(feature = "admin")]
+pub const SESSION_COOKIE: &str = "eqrng_admin_session";
+
+/// The session token from the request's cookies, if any
+#[cfg(feature = "admin")]
+fn session_token(headers: &HeaderMap) -> Option<String> {
+    crate::security::read_cookie(headers, SESSION_COOKIE)
+}
+
+/// `Set-Cookie` value for a session token; an empty token with no lifetime clears it.
+/// It covers the whole site because the JSON API's changes need a session too.
+/// Outside development the cookie is only ever sent over HTTPS.
+#[cfg(feature = "admin")]
+fn session_cookie(token: &str, max_age_seconds: u64, secure: bool) -> String {
+    session_cookie_at("/", token, max_age_seconds, secure)
+}
+
+/// `Set-Cookie` value clearing a session cookie scoped to /admin, as earlier releases
+/// set it, which would otherwise be sent ahead of the current one on admin pages
+#[cfg(feature = "admin")]
+fn clear_admin_path_session_cookie(secure: bool) -> String {
+    session_cookie_at("/admin", "", 0, secure)
+}
+
+#[cfg(feature = "admin")]
+fn session_cookie_at(path: &str, token: &str, max_age_seconds: u64, secure: bool) -> String {
+    format!(
+        "{}={}; Path={}; Max-Age={}; HttpOnly; SameSite=Strict{}",
+        SESSION_COOKIE,
+        token,
+        path,
+        max_age_seconds,
+        if secure { "; Secure" } else { "" }
+    )
+}
+
+/// Where to send the admin after signing in. Only admin pages on this site are
+/// allowed, so the login form cannot be used to bounce someone elsewhere.
+#[cfg(feature = "admin")]
+fn safe_next(next: Option<&str>) -> &str {
+    match next {
+        Some(next)
+            if (next == "/admin" || next.starts_with("/admin/") || next.starts_with("/admin?"))
+                && !next.contains("//")
+                && !next.contains('\\') =>
+        {
+            next
+        }
+        _ => "/admin",
+    }
+}
+
+/// Keeps admin routes behind a session. Signed-out page views are sent to the login
+/// page; anything else gets a 401. Audit entries name the signed-in admin.
+#[cfg(feature = "admin")]
+pub async fn require_admin(
+    State(state): State<AppState>,
+    mut request: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> Response {
+    let pool = &state.zone_state.pool;
+
+    let user = match session_token(request.headers()) {
+        Some(token) => match auth::session_user(pool.as_ref(), &token).await {
+            Ok(user) => user,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to look up admin session");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        },
+        None => None,
+    };
+
+    // An API key with the admin-read scope browses like a viewer; the key middleware
+    // has already checked the scope and only lets it through for page views
+    let user = user.or_else(|| {
+        request
+            .extensions()
+            .get::<ApiKey>()
+            .filter(|key| key.allows(Scope::AdminRead))
+            .map(|key| AdminUser {
+                id: 0,
+                username: format!("key:{}", key.name),
+                role: Role::Viewer,
+            })
+    });
+
+    let Some(user) = user else {
+        if request.method() == Method::GET {
+            let target = request
+                .uri()
+                .path_and_query()
+                .map(|pq| pq.as_str())
+                .unwrap_or("/admin");
+            return Redirect::to(&format!(
+                "/admin/login?next={}",
+                urlencoding::encode(target)
+            ))
+            .into_response();
+        }
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    let client = ClientIp::from_extensions(request.extensions())
+        .map(|client| client.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let actor = format!("{}@{}", user.username, client);
+    request.extensions_mut().insert(user);
+
+    crate::audit::with_actor(actor, next.run(request)).await
+}
+
+/// The least role that may make this request. Reading is open to every admin;
+/// each kind of change needs the role that owns it.
+#[cfg(feature = "admin")]
+fn required_role(method: &Method, path: &str) -> Role {
+    if path == "/admin/users"
+        || path.starts_with("/admin/users/")
+        || path == "/admin/api-keys"
+        || path.starts_with("/admin/api-keys/")
+    {
+        return Role::Owner;
+    }
+    if !super::is_admin_mutation(method, path) {
+        return Role::Viewer;
+    }
+
+    if path == "/admin/dump-database" || path == "/admin/migrate" || path == "/admin/backups" {
+        Role::Owner
+    } else if path.starts_with("/admin/ratings/")
+        || path.starts_with("/api/ratings/")
+        || (path.starts_with("/admin/trash/") && path.ends_with("/purge"))
+        || (path.starts_with("/admin/audit/") && path.ends_with("/revert"))
+    {
+        Role::Moderator
+    } else {
+        Role::Editor
+    }
+}
+
+/// Turns away signed-in admins whose role does not cover the request. Runs inside
+/// `require_admin`, which attaches the user.
+#[cfg(feature = "admin")]
+pub async fn check_permission(
+    request: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> Response {
+    let required = required_role(request.method(), request.uri().path());
+    let Some(role) = request
+        .extensions()
+        .get::<AdminUser>()
+        .map(|user| user.role)
+    else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    if !role.allows(required) {
+        tracing::warn!(
+            role = role.as_str(),
+            required = required.as_str(),
+            method = %request.method(),
+            path = request.uri().path(),
+            "Admin request refused for role"
+        );
+        return (
+            StatusCode::FORBIDDEN,
+            Html(format!(
+                r#"<h1>Error</h1><p>This needs the {} role; you are signed in as a {}.</p><a href="/admin">Go back</a>"#,
+                escape_html(required.label()),
+                escape_html(&role.label().to_lowercase())
+            )),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Add a hidden CSRF token field to every POST form on a page
+#[cfg(feature = "admin")]
+fn add_form_tokens(html: &str, token: &str) -> String {
+    let field = format!(
+        r#"<input type="hidden" name="{}" value="{}">"#,
+        csrf::CSRF_FIELD,
+        token
+    );
+    let mut page = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<form") {
+        let Some(end) = rest[start..].find('>').map(|end| start + end + 1) else {
+            break;
+        };
+        page.push_str(&rest[..end]);
+        if rest[start..end]
+            .to_ascii_lowercase()
+            .contains(r#"method="post""#)
+        {
+            page.push_str(&field);
+        }
+        rest = &rest[end..];
+    }
+    page.push_str(rest);
+    page
+}
+
+/// Confirmation prompts, delete buttons and auto-submitting selects on admin pages.
+/// The CSP blocks inline `on*=` handlers, so pages mark elements with `data-confirm`,
+/// `data-submit-form` (the id of a form to submit) and `data-autosubmit` instead.
+#[cfg(feature = "admin")]
+const ADMIN_SCRIPT: &str = r#"
+    document.addEventListener('click', function (event) {
+        const target = event.target.closest('[data-confirm], [data-submit-form]');
+        if (!target || target.tagName === 'FORM') return;
+        if (target.dataset.confirm && !confirm(target.dataset.confirm)) {
+            event.preventDefault();
+            return;
+        }
+        if (target.dataset.submitForm) {
+            event.preventDefault();
+            document.getElementById(target.dataset.submitForm).submit();
+        }
+    });
+    document.addEventListener('submit', function (event) {
+        const message = event.target.dataset.confirm;
+        if (message && !confirm(message)) event.preventDefault();
+    });
+    document.addEventListener('change', function (event) {
+        if (event.target.matches('[data-autosubmit]')) event.target.form.submit();
+    });
+"#;
+
+/// Add the admin page script to the head of a page, if it has one
+#[cfg(feature = "admin")]
+fn add_admin_script(html: &str, nonce: &str) -> String {
+    match html.find("</head>") {
+        Some(end) => format!(
+            r#"{}<script nonce="{}">{}</script>{}"#,
+            &html[..end],
+            nonce,
+            ADMIN_SCRIPT,
+            &html[end..]
+        ),
+        None => html.to_string(),
+    }
+}
+
+/// Gives the browser a CSRF cookie if it has none and puts the matching token in
+/// every POST form on admin pages, so plain HTML forms pass the CSRF check. Pages
+/// also get the admin script that stands in for inline event handlers.
+#[cfg(feature = "admin")]
+pub async fn embed_csrf_tokens(
+    State(state): State<AppState>,
+    request: axum::http::Request<Body>,
+    next: axum::middleware::Next,
+) -> Response {
+    let existing = csrf::request_cookie(request.headers());
+    let cookie = existing.clone().unwrap_or_else(csrf::new_cookie_value);
+    let response = next.run(request).await;
+
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/html"));
+    let mut response = if is_html {
+        let (mut parts, body) = response.into_parts();
+        let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to read admin page");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+        let page = add_form_tokens(
+            &String::from_utf8_lossy(&bytes),
+            &csrf::token_for(&state.config, &cookie),
+        );
+        let page = add_admin_script(&page, &crate::security::csp_nonce());
+        parts.headers.remove(header::CONTENT_LENGTH);
+        Response::from_parts(parts, Body::from(page))
+    } else {
+        response
+    };
+
+    if existing.is_none()
+        && let Ok(value) = csrf::cookie_header(&cookie, !state.config.is_development()).parse()
+    {
+        response.headers_mut().append(header::SET_COOKIE, value);
+    }
+    response
+}
+
+#[cfg(feature = "admin")]
+fn login_page(next: &str, error: Option<&str>) -> String {
+    let nonce = crate::security::csp_nonce();
+    format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Sign In - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 400px; margin: 80px auto; padding: 20px; }}
+        .form-group {{ margin-bottom: 15px; }}
+        label {{ display: block; margin-bottom: 5px; font-weight: bold; }}
+        input {{ width: 100%; padding: 8px; border: 1px solid #ddd; border-radius: 4px; box-sizing: border-box; }}
+        .btn {{ background: #007bff; color: white; padding: 10px 20px; border: none; border-radius: 4px; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        .error {{ background: #f8d7da; color: #721c24; padding: 10px; border-radius: 4px; margin-bottom: 15px; }}
+    </style>
+</head>
+<body>
+    <h1>EQ RNG Admin</h1>
+    {error}
+    <form method="post" action="/admin/login">
+        <input type="hidden" name="next" value="{next}">
+        <div class="form-group">
+            <label for="username">Username</label>
+            <input type="text" id="username" name="username" autocomplete="username" required autofocus>
+        </div>
+        <div class="form-group">
+            <label for="password">Password</label>
+            <input type="password" id="password" name="password" autocomplete="current-password" required>
+        </div>
+        <button type="submit" class="btn">Sign In</button>
+    </form>
+</body>
+</html>
+        "#,
+        error = error
+            .map(|message| format!(r#"<div class="error">{}</div>"#, message))
+            .unwrap_or_default(),
+        next = escape_html_attribute(next),
+    )
+}
+
+#[cfg(feature = "admin")]
+pub async fn login_form(Query(query): Query<LoginQuery>) -> Html<String> {
+    Html(login_page(safe_next(query.next.as_deref()), None))
+}
+
+#[cfg(feature = "admin")]
+pub async fn login(
+    State(state): State<AppState>,
+    client: ClientIp,
+    Form(form): Form<LoginForm>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+    let next = safe_next(form.next.as_deref());
+
+    let user = auth::authenticate(pool.as_ref(), &form.username, &form.password)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to check admin credentials");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let Some(user) = user else {
+        tracing::warn!(username = %form.username, client = %client, "Failed admin sign-in");
+        return Ok((
+            StatusCode::UNAUTHORIZED,
+            Html(login_page(next, Some("Invalid username or password"))),
+        )
+            .into_response());
+    };
+
+    let ttl_minutes = state.config.auth.session_ttl_minutes;
+    let token = auth::start_session(pool.as_ref(), user.id, ttl_minutes)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Failed to start admin session");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    tracing::info!(username = %user.username, client = %client, "Admin signed in");
+
+    let secure = !state.config.is_development();
+    Ok((
+        AppendHeaders([
+            (header::SET_COOKIE, clear_admin_path_session_cookie(secure)),
+            (
+                header::SET_COOKIE,
+                session_cookie(&token, ttl_minutes * 60, secure),
+            ),
+        ]),
+        Redirect::to(next),
+    )
+        .into_response())
+}
+
+#[cfg(feature = "admin")]
+pub async fn logout(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let pool = &state.zone_state.pool;
+
+    if let Some(token) = session_token(&headers)
+        && let Err(e) = auth::end_session(pool.as_ref(), &token).await
+    {
+        tracing::error!(error = %e, "Failed to end admin session");
+    }
+
+    let secure = !state.config.is_development();
+    (
+        AppendHeaders([
+            (header::SET_COOKIE, clear_admin_path_session_cookie(secure)),
+            (header::SET_COOKIE, session_cookie("", 0, secure)),
+        ]),
+        Redirect::to("/admin/login"),
+    )
+        .into_response()
+}
+
+#[cfg(all(test, feature = "admin"))]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn reads_the_session_cookie_among_others() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::COOKIE,
+            HeaderValue::from_static("theme=dark; eqrng_admin_session=abc123; other=1"),
+        );
+        assert_eq!(session_token(&headers).as_deref(), Some("abc123"));
+
+        headers.insert(
+            header::COOKIE,
+            HeaderValue::from_static("eqrng_admin_session="),
+        );
+        assert_eq!(session_token(&headers), None);
+    }
+
+    #[test]
+    fn routes_need_the_role_that_owns_them() {
+        assert_eq!(required_role(&Method::GET, "/admin/zones"), Role::Viewer);
+        assert_eq!(required_role(&Method::GET, "/admin/audit/4"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/admin/zones/4"), Role::Editor);
+        assert_eq!(
+            required_role(&Method::POST, "/admin/zones/4/flags/2/delete"),
+            Role::Editor
+        );
+        assert_eq!(
+            required_role(&Method::POST, "/admin/ratings/instance/9/delete"),
+            Role::Moderator
+        );
+        assert_eq!(
+            required_role(&Method::DELETE, "/api/ratings/9"),
+            Role::Moderator
+        );
+        assert_eq!(required_role(&Method::POST, "/api/links"), Role::Editor);
+        assert_eq!(required_role(&Method::DELETE, "/api/links/3"), Role::Editor);
+        assert_eq!(
+            required_role(&Method::POST, "/admin/trash/zone/4/restore"),
+            Role::Editor
+        );
+        assert_eq!(
+            required_role(&Method::POST, "/admin/trash/zone/4/purge"),
+            Role::Moderator
+        );
+        assert_eq!(
+            required_role(&Method::POST, "/admin/audit/4/revert"),
+            Role::Moderator
+        );
+        assert_eq!(required_role(&Method::GET, "/admin/backups"), Role::Viewer);
+        assert_eq!(required_role(&Method::POST, "/admin/backups"), Role::Owner);
+        assert_eq!(required_role(&Method::POST, "/admin/migrate"), Role::Owner);
+        assert_eq!(required_role(&Method::GET, "/admin/users"), Role::Owner);
+        assert_eq!(required_role(&Method::GET, "/admin/api-keys"), Role::Owner);
+    }
+
+    #[test]
+    fn post_forms_get_the_token_field() {
+        let page = r#"<form method="get" action="/admin/zones"></form>
+<form method="post" action="/admin/zones/4"><input name="name"></form>
+<form id="delete-form-4" method="post" action="/admin/zones/4/delete" class="hidden"></form>"#;
+        let page = add_form_tokens(page, "abc");
+
+        assert_eq!(page.matches(r#"name="csrf_token" value="abc""#).count(), 2);
+        assert!(page.contains(r#"<form method="get" action="/admin/zones"></form>"#));
+        assert!(page.contains(
+            r#"action="/admin/zones/4"><input type="hidden" name="csrf_token" value="abc"><input name="name">"#
+        ));
+    }
+
+    #[test]
+    fn pages_with_a_head_get_the_admin_script() {
+        let page = add_admin_script(
+            "<html><head><title>x</title></head><body></body></html>",
+            "n1",
+        );
+        assert!(page.contains(r#"</title><script nonce="n1">"#));
+        assert!(page.contains("</script></head><body>"));
+        assert!(page.contains("data-confirm"));
+
+        assert_eq!(add_admin_script("<h1>Error</h1>", "n1"), "<h1>Error</h1>");
+    }
+
+    #[test]
+    fn only_admin_pages_are_valid_login_targets() {
+        assert_eq!(
+            safe_next(Some("/admin/zones?page=2")),
+            "/admin/zones?page=2"
+        );
+        assert_eq!(safe_next(Some("/admin")), "/admin");
+        assert_eq!(safe_next(Some("https://evil.example/admin")), "/admin");
+        assert_eq!(safe_next(Some("//evil.example/admin")), "/admin");
+        assert_eq!(safe_next(Some("/admin//evil.example")), "/admin");
+        assert_eq!(safe_next(Some("/administrator")), "/admin");
+        assert_eq!(safe_next(None), "/admin");
+    }
+}
@@ -0,0 +1,148 @@
+// Backup-related admin functionality
+// This file lists database backups and lets an admin take one on demand
+
+#[cfg(feature = "admin")]
+use axum::{extract::State, http::StatusCode, response::Html};
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::backup::{list_backups, run_backup};
+
+#[cfg(feature = "admin")]
+fn backups_page(state: &AppState, message: &str) -> Result<Html<String>, StatusCode> {
+    let backup_dir = &state.config.database.backup_dir;
+    let backups = list_backups(backup_dir).map_err(|e| {
+        tracing::error!(error = %e, "Failed to list database backups");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let rows: String = backups
+        .iter()
+        .map(|backup| {
+            format!(
+                r#"<tr>
+                <td><code>{}</code></td>
+                <td>{}</td>
+                <td>{:.1} KB</td>
+                <td><code class="hash">{}</code></td>
+            </tr>"#,
+                backup.file_name,
+                backup.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                backup.size_bytes as f64 / 1024.0,
+                backup.blake3.as_deref().unwrap_or("not in manifest"),
+            )
+        })
+        .collect();
+
+    let backup_config = &state.config.backup;
+    let schedule = if backup_config.enabled && backup_config.interval_minutes > 0 {
+        format!("every {} minutes", backup_config.interval_minutes)
+    } else {
+        "disabled".to_string()
+    };
+
+    let nonce = crate::security::csp_nonce();
+    Ok(Html(format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Database Backups - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .form-section {{ background: #f8f9fa; padding: 20px; margin-bottom: 20px; border-radius: 5px; }}
+        .message {{ background: #d4edda; border: 1px solid #c3e6cb; color: #155724; padding: 10px; border-radius: 4px; margin-bottom: 20px; }}
+        .btn {{ background: #007bff; color: white; padding: 8px 15px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; }}
+        th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
+        .hash {{ font-size: 0.75em; word-break: break-all; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/ratings">Manage Ratings</a>
+        <a href="/admin/links">Manage Links</a>
+        <a href="/admin/backups">Backups</a>
+        <a href="/admin/audit">Audit Log</a>
+    </div>
+
+    <h1>Database Backups</h1>
+    {}
+    <div class="form-section">
+        <p><strong>Directory:</strong> <code>{}</code></p>
+        <p><strong>Schedule:</strong> {}</p>
+        <p><strong>Retention:</strong> keep {} newest, delete after {} days (0 = unlimited)</p>
+        <form method="post" action="/admin/backups">
+            <button type="submit" class="btn">💾 Back Up Now</button>
+        </form>
+    </div>
+
+    <table>
+        <thead>
+            <tr><th>File</th><th>Created</th><th>Size</th><th>BLAKE3</th></tr>
+        </thead>
+        <tbody>
+            {}
+        </tbody>
+    </table>
+</body>
+</html>
+        "#,
+        message,
+        backup_dir,
+        schedule,
+        backup_config.keep_count,
+        backup_config.max_age_days,
+        if rows.is_empty() {
+            r#"<tr><td colspan="4">No backups yet</td></tr>"#.to_string()
+        } else {
+            rows
+        },
+    )))
+}
+
+#[cfg(feature = "admin")]
+pub async fn list_backups_admin(State(state): State<AppState>) -> Result<Html<String>, StatusCode> {
+    backups_page(&state, "")
+}
+
+#[cfg(feature = "admin")]
+pub async fn create_backup_admin(
+    State(state): State<AppState>,
+) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let run = run_backup(
+        pool.as_ref(),
+        &state.config.database.backup_dir,
+        &state.config.backup,
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!(error = %e, "Manual database backup failed");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    crate::logging::log_admin_action("create", "backup", None, None);
+
+    let message = format!(
+        r#"<div class="message">Created <code>{}</code>{}</div>"#,
+        run.backup.file_name,
+        if run.removed.is_empty() {
+            String::new()
+        } else {
+            format!("; removed {} expired backup(s)", run.removed.len())
+        }
+    );
+
+    backups_page(&state, &message)
+}
@@ -0,0 +1,227 @@
+// Zone/instance conversion admin functionality
+// This file previews what converting a zone to an instance (or back) carries over
+// and runs the conversion once the admin confirms
+
+#[cfg(feature = "admin")]
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+};
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::conversion::{ConversionReport, convert_location, plan_conversion};
+#[cfg(feature = "admin")]
+use crate::error::AppError;
+#[cfg(feature = "admin")]
+use crate::locations::LocationKind;
+#[cfg(feature = "admin")]
+use crate::security::escape_html;
+
+#[cfg(feature = "admin")]
+fn title(kind: LocationKind) -> &'static str {
+    match kind {
+        LocationKind::Zone => "Zone",
+        LocationKind::Instance => "Instance",
+    }
+}
+
+#[cfg(feature = "admin")]
+fn list_path(kind: LocationKind) -> &'static str {
+    match kind {
+        LocationKind::Zone => "/admin/zones",
+        LocationKind::Instance => "/admin/instances",
+    }
+}
+
+#[cfg(feature = "admin")]
+fn render_report(report: &ConversionReport) -> String {
+    let source = format!("{}/{}", list_path(report.from), report.source_id);
+    let action = format!("{}/convert", source);
+
+    let conflicts = if report.conflicts.is_empty() {
+        String::new()
+    } else {
+        let items: String = report
+            .conflicts
+            .iter()
+            .map(|conflict| format!("<li>{}</li>", escape_html(conflict)))
+            .collect();
+        format!(
+            r#"<div class="conflicts">
+        <strong>This conversion cannot go ahead:</strong>
+        <ul>{}</ul>
+        <p>Rename or remove the conflicting {} first, then try again.</p>
+    </div>"#,
+            items,
+            report.to.as_str()
+        )
+    };
+
+    let dropped = if report.dropped.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "<p>Zones have no place for these instance details, so they are left behind (the audit log keeps a copy): {}</p>",
+            escape_html(&report.dropped.join(", "))
+        )
+    };
+
+    let nonce = crate::security::csp_nonce();
+    format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Convert {from_title} - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 900px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .btn {{ background: #007bff; color: white; padding: 8px 16px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        .btn:disabled {{ background: #6c757d; cursor: not-allowed; }}
+        .btn-secondary {{ background: #6c757d; }}
+        table {{ border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px 16px; border: 1px solid #ddd; text-align: left; }}
+        th {{ background: #f8f9fa; }}
+        .conflicts {{ background: #f8d7da; color: #721c24; padding: 15px; border-radius: 5px; margin-bottom: 20px; }}
+        .inline-form {{ display: inline; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/audit">Audit Log</a>
+        <a href="/admin/trash">Trash</a>
+    </div>
+
+    <h1>Convert "{name}" to {to_article}</h1>
+    <p>The {from} is replaced by a new {to} with the same details. Everything attached to it moves across:</p>
+    <table>
+        <tr><th>Notes</th><td>{notes}</td></tr>
+        <tr><th>Flags</th><td>{flags}</td></tr>
+        <tr><th>Ratings</th><td>{ratings}</td></tr>
+        <tr><th>Aliases</th><td>{aliases}</td></tr>
+    </table>
+    {dropped}
+    <p>The conversion is recorded as one change in the audit log and can be reverted from there.</p>
+    {conflicts}
+    <form method="post" action="{action}" class="inline-form">
+        <button type="submit" class="btn"{disabled}>Convert to {to_title}</button>
+    </form>
+    <a href="{source}" class="btn btn-secondary">Cancel</a>
+</body>
+</html>
+        "#,
+        from_title = title(report.from),
+        to_title = title(report.to),
+        to_article = match report.to {
+            LocationKind::Zone => "a Zone",
+            LocationKind::Instance => "an Instance",
+        },
+        name = escape_html(&report.name),
+        from = report.from.as_str(),
+        to = report.to.as_str(),
+        notes = report.notes,
+        flags = report.flags,
+        ratings = report.ratings,
+        aliases = report.aliases,
+        conflicts = conflicts,
+        dropped = dropped,
+        action = action,
+        disabled = if report.conflicts.is_empty() {
+            ""
+        } else {
+            " disabled"
+        },
+        source = source,
+    )
+}
+
+#[cfg(feature = "admin")]
+async fn conversion_form(
+    state: AppState,
+    from: LocationKind,
+    id: i64,
+) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    match plan_conversion(pool.as_ref(), from, id).await {
+        Ok(report) => Ok(Html(render_report(&report))),
+        Err(AppError::ZoneNotFound(_)) | Err(AppError::InstanceNotFound(_)) => {
+            Err(StatusCode::NOT_FOUND)
+        }
+        Err(e) => {
+            tracing::error!(error = %e, kind = from.as_str(), id, "Failed to plan conversion");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[cfg(feature = "admin")]
+async fn run_conversion(
+    state: AppState,
+    from: LocationKind,
+    id: i64,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    match convert_location(pool.as_ref(), from, id).await {
+        Ok(new_id) => {
+            let _ = crate::checkpoint_wal(pool.as_ref()).await;
+            Ok(Redirect::to(&format!("{}/{}", list_path(from.other()), new_id)).into_response())
+        }
+        Err(AppError::Validation(message)) => Ok(Html(format!(
+            r#"<h1>Error</h1><p>{}</p><a href="{}/{}/convert">Go back</a>"#,
+            escape_html(&message),
+            list_path(from),
+            id
+        ))
+        .into_response()),
+        Err(AppError::ZoneNotFound(_)) | Err(AppError::InstanceNotFound(_)) => {
+            Err(StatusCode::NOT_FOUND)
+        }
+        Err(e) => {
+            tracing::error!(error = %e, kind = from.as_str(), id, "Failed to convert location");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[cfg(feature = "admin")]
+pub async fn convert_zone_form(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Html<String>, StatusCode> {
+    conversion_form(state, LocationKind::Zone, id).await
+}
+
+#[cfg(feature = "admin")]
+pub async fn convert_zone(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Response, StatusCode> {
+    run_conversion(state, LocationKind::Zone, id).await
+}
+
+#[cfg(feature = "admin")]
+pub async fn convert_instance_form(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Html<String>, StatusCode> {
+    conversion_form(state, LocationKind::Instance, id).await
+}
+
+#[cfg(feature = "admin")]
+pub async fn convert_instance(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Response, StatusCode> {
+    run_conversion(state, LocationKind::Instance, id).await
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "admin")]
-use axum::{extract::State, http::StatusCode, response::Html};
+use axum::{Extension, extract::State, http::StatusCode, response::Html};
 #[cfg(feature = "admin")]
 use sqlx::{Row, SqlitePool};
 #[cfg(feature = "admin")]
@@ -8,6 +8,18 @@ use tracing::{info, warn};
 use urlencoding;
 
 use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::auth::AdminUser;
+#[cfg(feature = "admin")]
+use crate::security::escape_html;
+
+/// CSS for the links `generate_sortable_header` builds, for a page's nonce'd `<style>` block
+#[cfg(feature = "admin")]
+pub const SORTABLE_HEADER_STYLES: &str = "
+        .sort-header { text-decoration: none; color: #333; font-weight: bold; cursor: pointer; }
+        .sort-header-current { color: #007bff; }
+        .sort-arrow { color: #007bff; }
+        .sort-arrow-idle { color: #ccc; font-size: 0.8em; }";
 
 #[cfg(feature = "admin")]
 pub fn generate_sortable_header(
@@ -18,7 +30,7 @@ pub fn generate_sortable_header(
     base_url: &str,
     search: &str,
 ) -> String {
-    let is_current = current_sort.as_ref().map_or(false, |s| s == column);
+    let is_current = current_sort.as_deref() == Some(column);
     let next_order = if is_current && current_order.as_ref().map_or("", |o| o) == "asc" {
         "desc"
     } else {
@@ -33,23 +45,24 @@ pub fn generate_sortable_header(
 
     let arrow = if is_current {
         match current_order.as_ref().map_or("", |o| o) {
-            "asc" => " <span style=\"color: #007bff;\">↑</span>",
-            "desc" => " <span style=\"color: #007bff;\">↓</span>",
+            "asc" => " <span class=\"sort-arrow\">↑</span>",
+            "desc" => " <span class=\"sort-arrow\">↓</span>",
             _ => "",
         }
     } else {
-        " <span style=\"color: #ccc; font-size: 0.8em;\">↕</span>"
+        " <span class=\"sort-arrow sort-arrow-idle\">↕</span>"
     };
 
-    let link_style = if is_current {
-        "text-decoration: none; color: #007bff; font-weight: bold; cursor: pointer;"
+    // Styled by the `SORTABLE_HEADER_STYLES` rules on each page that uses these headers
+    let link_class = if is_current {
+        "sort-header sort-header-current"
     } else {
-        "text-decoration: none; color: #333; font-weight: bold; cursor: pointer;"
+        "sort-header"
     };
 
     format!(
-        r#"<a href="{}?sort={}&order={}{}" style="{}" title="Sort by {}">{}{}</a>"#,
-        base_url, column, next_order, search_param, link_style, display_name, display_name, arrow
+        r#"<a href="{}?sort={}&order={}{}" class="{}" title="Sort by {}">{}{}</a>"#,
+        base_url, column, next_order, search_param, link_class, display_name, display_name, arrow
     )
 }
 
@@ -89,9 +102,17 @@ pub fn generate_zone_type_options(zone_types: &[String], selected_zone_type: &st
 
 #[cfg(feature = "admin")]
 pub async fn get_distinct_expansions(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
-    let rows = sqlx::query("SELECT DISTINCT expansion FROM zones ORDER BY expansion ASC")
-        .fetch_all(pool)
-        .await?;
+    // Catalogue expansions sort by release order; unrecognised spellings sort last
+    let rows = sqlx::query(
+        r#"
+        SELECT DISTINCT z.expansion, e.release_order
+        FROM zones z
+        LEFT JOIN expansions e ON e.name = z.expansion
+        ORDER BY e.release_order IS NULL, e.release_order, z.expansion ASC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
 
     let expansions = rows
         .iter()
@@ -133,10 +154,8 @@ pub async fn log_admin_requests(
         .get("user-agent")
         .and_then(|h| h.to_str().ok())
         .unwrap_or("unknown");
-    let remote_addr = request
-        .extensions()
-        .get::<std::net::SocketAddr>()
-        .map(|addr| addr.to_string())
+    let remote_addr = crate::client_ip::ClientIp::from_extensions(request.extensions())
+        .map(|client| client.to_string())
         .unwrap_or_else(|| "unknown".to_string());
 
     // Security monitoring: Check for suspicious patterns
@@ -225,79 +244,28 @@ fn detect_suspicious_patterns(
     false
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use axum::http::{Method, Uri};
-
-    #[test]
-    fn test_detect_suspicious_patterns() {
-        // Test suspicious HTTP methods
-        assert!(detect_suspicious_patterns(
-            &Method::PUT,
-            &Uri::from_static("/admin/dashboard"),
-            "Mozilla/5.0",
-            "192.168.1.1:8080"
-        ));
-
-        // Test suspicious URI patterns
-        assert!(detect_suspicious_patterns(
-            &Method::GET,
-            &Uri::from_static("/admin/..script"),
-            "Mozilla/5.0",
-            "192.168.1.1:8080"
-        ));
-
-        // Test suspicious User-Agent patterns
-        assert!(detect_suspicious_patterns(
-            &Method::GET,
-            &Uri::from_static("/admin/dashboard"),
-            "sqlmap/1.0",
-            "192.168.1.1:8080"
-        ));
-
-        // Test suspicious IP patterns
-        assert!(detect_suspicious_patterns(
-            &Method::GET,
-            &Uri::from_static("/admin/dashboard"),
-            "Mozilla/5.0",
-            "127.0.0.1:8080"
-        ));
-
-        // Test legitimate requests
-        assert!(!detect_suspicious_patterns(
-            &Method::GET,
-            &Uri::from_static("/admin/dashboard"),
-            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
-            "192.168.1.1:8080"
-        ));
-
-        assert!(!detect_suspicious_patterns(
-            &Method::POST,
-            &Uri::from_static("/admin/zones"),
-            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7)",
-            "10.0.0.1:8080"
-        ));
-    }
-}
-
 #[cfg(feature = "admin")]
-pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<String>, StatusCode> {
+pub async fn admin_dashboard(
+    State(state): State<AppState>,
+    Extension(user): Extension<AdminUser>,
+) -> Result<Html<String>, StatusCode> {
     let pool = &state.zone_state.pool;
     let instance_pool = &state.instance_state.pool;
 
     // Get statistics
-    let zone_count: i32 = sqlx::query("SELECT COUNT(*) as count FROM zones")
-        .fetch_one(pool.as_ref())
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .get("count");
+    let zone_count: i32 =
+        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL")
+            .fetch_one(pool.as_ref())
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .get("count");
 
-    let instance_count: i32 = sqlx::query("SELECT COUNT(*) as count FROM instances")
-        .fetch_one(instance_pool.as_ref())
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .get("count");
+    let instance_count: i32 =
+        sqlx::query("SELECT COUNT(*) as count FROM instances WHERE deleted_at IS NULL")
+            .fetch_one(instance_pool.as_ref())
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .get("count");
 
     let rating_count: i32 = sqlx::query("SELECT COUNT(*) as count FROM zone_ratings")
         .fetch_one(pool.as_ref())
@@ -313,35 +281,35 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
 
     // Get zone type counts
     let outdoor_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE LOWER(zone_type) LIKE '%outdoor%'")
+        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND LOWER(zone_type) LIKE '%outdoor%'")
             .fetch_one(pool.as_ref())
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
             .get("count");
 
     let dungeon_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE LOWER(zone_type) LIKE '%dungeon%'")
+        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND LOWER(zone_type) LIKE '%dungeon%'")
             .fetch_one(pool.as_ref())
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
             .get("count");
 
     let indoor_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE LOWER(zone_type) LIKE '%indoor%'")
+        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND LOWER(zone_type) LIKE '%indoor%'")
             .fetch_one(pool.as_ref())
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
             .get("count");
 
     let city_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE LOWER(zone_type) LIKE '%city%'")
+        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND LOWER(zone_type) LIKE '%city%'")
             .fetch_one(pool.as_ref())
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
             .get("count");
 
     let raid_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE LOWER(zone_type) LIKE '%raid%'")
+        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND LOWER(zone_type) LIKE '%raid%'")
             .fetch_one(pool.as_ref())
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
@@ -355,43 +323,48 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    let verified_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE verified = 1")
-            .fetch_one(pool.as_ref())
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-            .get("count");
+    let verified_zone_count: i32 = sqlx::query(
+        "SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND verified = 1",
+    )
+    .fetch_one(pool.as_ref())
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .get("count");
 
-    let unverified_zone_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM zones WHERE verified = 0")
-            .fetch_one(pool.as_ref())
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-            .get("count");
+    let unverified_zone_count: i32 = sqlx::query(
+        "SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL AND verified = 0",
+    )
+    .fetch_one(pool.as_ref())
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .get("count");
 
-    let verified_instance_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM instances WHERE verified = 1")
-            .fetch_one(instance_pool.as_ref())
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-            .get("count");
+    let verified_instance_count: i32 = sqlx::query(
+        "SELECT COUNT(*) as count FROM instances WHERE deleted_at IS NULL AND verified = 1",
+    )
+    .fetch_one(instance_pool.as_ref())
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .get("count");
 
-    let unverified_instance_count: i32 =
-        sqlx::query("SELECT COUNT(*) as count FROM instances WHERE verified = 0")
-            .fetch_one(instance_pool.as_ref())
-            .await
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-            .get("count");
+    let unverified_instance_count: i32 = sqlx::query(
+        "SELECT COUNT(*) as count FROM instances WHERE deleted_at IS NULL AND verified = 0",
+    )
+    .fetch_one(instance_pool.as_ref())
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .get("count");
 
     let avg_rating_display = avg_rating.map_or("N/A".to_string(), |r| format!("{:.1}", r));
 
+    let nonce = crate::security::csp_nonce();
     let html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -402,6 +375,12 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
         .stat-card:hover {{ background: #e9ecef; }}
         .stat-number {{ font-size: 2em; font-weight: bold; color: #007bff; }}
         .stat-label {{ font-size: 14px; color: #666; margin-top: 5px; }}
+        .flags-heading {{ border-left: 4px solid #ef4444; }}
+        .flags-heading .stat-label {{ font-weight: bold; color: #495057; }}
+        .dump {{ margin-top: 20px; padding-top: 20px; border-top: 1px solid #ddd; }}
+        .dump form {{ margin: 0; }}
+        .dump-button {{ background-color: #dc2626; color: white; padding: 8px 16px; border: none; border-radius: 4px; cursor: pointer; font-weight: bold; }}
+        .dump-hint {{ margin-top: 8px; font-size: 0.875rem; color: #666; }}
     </style>
 </head>
 <body>
@@ -414,6 +393,11 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
         <a href="/admin/flag-types">Manage Flag Types</a>
         <a href="/admin/ratings">Manage Ratings</a>
         <a href="/admin/links">Manage Links</a>
+        <a href="/admin/backups">Backups</a>
+        <a href="/admin/audit">Audit Log</a>
+        <a href="/admin/trash">Trash</a>
+        <a href="/admin/users">Admin Users</a>
+        <a href="/admin/api-keys">API Keys</a>
     </div>
 
     <h1>EQ RNG Admin Dashboard</h1>
@@ -458,8 +442,8 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
     </div>
 
     <div class="stats">
-        <div class="stat-card" style="background: #f8f9fa; border-left: 4px solid #ef4444;">
-            <div class="stat-label" style="font-weight: bold; color: #495057;">Zone Flags</div>
+        <div class="stat-card flags-heading">
+            <div class="stat-label">Zone Flags</div>
         </div>
         {}
         <a href="/admin/zones?verified=true" class="stat-card">
@@ -488,17 +472,20 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
         <p><a href="/admin/note-types">Manage note types</a> - Configure pill icons for zone notes</p>
         <p><a href="/admin/flag-types">Manage flag types</a> - Configure zone flags and their appearance</p>
 
-        <p><a href="/admin/ratings">Manage all ratings</a> - View and delete zone ratings</p>
+        <p><a href="/admin/ratings">Manage all ratings</a> - View and delete zone and instance ratings</p>
         <p><a href="/admin/links">Manage links</a> - View, edit, and delete links organized by category</p>
-
-        <div style="margin-top: 20px; padding-top: 20px; border-top: 1px solid #ddd;">
-            <form action="/admin/dump-database" method="post" style="margin: 0;">
-                <button type="submit" style="background-color: #dc2626; color: white; padding: 8px 16px; border: none; border-radius: 4px; cursor: pointer; font-weight: bold;"
-                        onclick="return confirm('This will create a new timestamped data.sql file. Continue?')">
+        <p><a href="/admin/backups">Database backups</a> - View snapshots and take a backup now</p>
+        <p><a href="/admin/audit">Audit log</a> - Browse every admin change with before/after values</p>
+        <p><a href="/admin/trash">Trash</a> - Restore or permanently delete removed zones, instances and links</p>
+
+        <div class="dump">
+            <form action="/admin/dump-database" method="post">
+                <button type="submit" class="dump-button"
+                        data-confirm="This will create a new timestamped data.sql file. Continue?">
                     🗄️ Dump Database to SQL
                 </button>
             </form>
-            <p style="margin-top: 8px; font-size: 0.875rem; color: #666;">Export complete database to timestamped data-YYYYMMDD_HHMMSS.sql file</p>
+            <p class="dump-hint">Export complete database to timestamped data-YYYYMMDD_HHMMSS.sql file</p>
         </div>
     </div>
 
@@ -506,6 +493,10 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
         <h2>System Status</h2>
         <p>Admin interface is running in development mode.</p>
         <p><strong>Warning:</strong> This interface should only be used locally for development.</p>
+        <form method="post" action="/admin/logout">
+            Signed in as <strong>{}</strong> ({})
+            <button type="submit">Log out</button>
+        </form>
     </div>
 </body>
 </html>
@@ -542,7 +533,65 @@ pub async fn admin_dashboard(State(state): State<AppState>) -> Result<Html<Strin
         verified_zone_count,
         unverified_zone_count,
         verified_instance_count,
-        unverified_instance_count
+        unverified_instance_count,
+        escape_html(&user.username),
+        user.role.label()
     );
     Ok(Html(html))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{Method, Uri};
+
+    #[test]
+    fn test_detect_suspicious_patterns() {
+        // Test suspicious HTTP methods
+        assert!(detect_suspicious_patterns(
+            &Method::PUT,
+            &Uri::from_static("/admin/dashboard"),
+            "Mozilla/5.0",
+            "192.168.1.1:8080"
+        ));
+
+        // Test suspicious URI patterns
+        assert!(detect_suspicious_patterns(
+            &Method::GET,
+            &Uri::from_static("/admin/..script"),
+            "Mozilla/5.0",
+            "192.168.1.1:8080"
+        ));
+
+        // Test suspicious User-Agent patterns
+        assert!(detect_suspicious_patterns(
+            &Method::GET,
+            &Uri::from_static("/admin/dashboard"),
+            "sqlmap/1.0",
+            "192.168.1.1:8080"
+        ));
+
+        // Test suspicious IP patterns
+        assert!(detect_suspicious_patterns(
+            &Method::GET,
+            &Uri::from_static("/admin/dashboard"),
+            "Mozilla/5.0",
+            "127.0.0.1:8080"
+        ));
+
+        // Test legitimate requests
+        assert!(!detect_suspicious_patterns(
+            &Method::GET,
+            &Uri::from_static("/admin/dashboard"),
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
+            "192.168.1.1:8080"
+        ));
+
+        assert!(!detect_suspicious_patterns(
+            &Method::POST,
+            &Uri::from_static("/admin/zones"),
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7)",
+            "10.0.0.1:8080"
+        ));
+    }
+}
@@ -2,7 +2,7 @@
 // This file contains flag type management features for the admin interface
 
 #[cfg(feature = "admin")]
-use crate::security::escape_html;
+use crate::security::{escape_html, escape_html_attribute};
 
 #[cfg(feature = "admin")]
 use axum::{
@@ -19,6 +19,8 @@ use sqlx::Row;
 use crate::AppState;
 #[cfg(feature = "admin")]
 use crate::admin::types::*;
+#[cfg(feature = "admin")]
+use crate::audit::{self, AuditAction, AuditEntity};
 
 #[cfg(feature = "admin")]
 pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<String>, StatusCode> {
@@ -30,13 +32,14 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Flag Types - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1000px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -79,14 +82,12 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
         .bg-neutral-500 {{ background-color: #737373; }}
         .bg-stone-500 {{ background-color: #78716c; }}
         .bg-sky-500 {{ background-color: #0ea5e9; }}
+        .hidden {{ display: none; }}
+        .inline-label {{ display: inline; margin-left: 5px; }}
+        .add-type-form {{ display: flex; align-items: end; gap: 15px; flex-wrap: wrap; }}
+        .usage {{ margin-top: 30px; padding: 20px; background: #e9ecef; border-radius: 5px; }}
     </style>
-    <script>
-        function deleteFlagType(id, name) {{
-            if (confirm('Are you sure you want to delete "' + name + '"? This will affect all existing flags of this type.')) {{
-                document.getElementById('delete-form-' + id).submit();
-            }}
-        }}
-
+    <script nonce="{nonce}">
         function updatePreview() {{
             const displayName = document.getElementById('display_name').value || 'Preview';
             const colorClass = document.getElementById('color_class').value;
@@ -118,7 +119,7 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
 
     <div class="form-section">
         <h2>Add New Flag Type</h2>
-        <form method="post" action="/admin/flag-types" style="display: flex; align-items: end; gap: 15px; flex-wrap: wrap;">
+        <form method="post" action="/admin/flag-types" class="add-type-form">
             <div class="form-group">
                 <label for="name">Internal Name:</label>
                 <input type="text" id="name" name="name" placeholder="e.g., requires_key" required />
@@ -166,7 +167,7 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
 
             <div class="form-group">
                 <input type="checkbox" id="filterable" name="filterable" value="true" checked />
-                <label for="filterable" style="display: inline; margin-left: 5px;">Available as filter on main page</label>
+                <label for="filterable" class="inline-label">Available as filter on main page</label>
             </div>
 
             <div class="form-group">
@@ -228,8 +229,8 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
                 <td>{}</td>
                 <td>
                     <a href="/admin/flag-types/{}" class="btn btn-small">Edit</a>
-                    <button onclick="deleteFlagType({}, '{}')" class="btn btn-danger btn-small">Delete</button>
-                    <form id="delete-form-{}" method="post" action="/admin/flag-types/{}/delete" style="display: none;">
+                    <button type="button" data-submit-form="delete-form-{}" data-confirm="Are you sure you want to delete &quot;{}&quot;? This will affect all existing flags of this type." class="btn btn-danger btn-small">Delete</button>
+                    <form id="delete-form-{}" method="post" action="/admin/flag-types/{}/delete" class="hidden">
                         <input type="hidden" name="_method" value="DELETE" />
                     </form>
                 </td>
@@ -244,7 +245,7 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
             created_at.split('T').next().unwrap_or(&created_at),
             id,
             id,
-            name.replace("'", "\\'"),
+            escape_html_attribute(&name),
             id,
             id
         ));
@@ -255,7 +256,7 @@ pub async fn list_flag_types(State(state): State<AppState>) -> Result<Html<Strin
         </tbody>
     </table>
 
-    <div style="margin-top: 30px; padding: 20px; background: #e9ecef; border-radius: 5px;">
+    <div class="usage">
         <h3>Flag Type Usage</h3>
         <p>Flag types are used to categorize flags attached to zones. They help mark special characteristics or requirements:</p>
         <ul>
@@ -285,8 +286,13 @@ pub async fn create_flag_type(
 
     let filterable = form.filterable.is_some();
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     // Insert the new flag type
-    sqlx::query(
+    let result = sqlx::query(
         r#"
         INSERT INTO flag_types (name, display_name, color_class, filterable)
         VALUES (?, ?, ?, ?)
@@ -296,10 +302,26 @@ pub async fn create_flag_type(
     .bind(&form.display_name)
     .bind(&form.color_class)
     .bind(filterable)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let id = result.last_insert_rowid();
+    let after = audit::capture(&mut tx, AuditEntity::FlagType, id).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Create,
+        AuditEntity::FlagType,
+        id,
+        None,
+        after,
+    )
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     Ok(Redirect::to("/admin/flag-types"))
 }
 
@@ -362,13 +384,14 @@ pub async fn edit_flag_type_form(
         .collect::<Vec<_>>()
         .join("\n");
 
+    let nonce = crate::security::csp_nonce();
     let html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Edit Flag Type - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 800px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -408,14 +431,10 @@ pub async fn edit_flag_type_form(
         .bg-neutral-500 {{ background-color: #737373; }}
         .bg-stone-500 {{ background-color: #78716c; }}
         .bg-sky-500 {{ background-color: #0ea5e9; }}
+        .hidden {{ display: none; }}
+        .inline-label {{ display: inline; margin-left: 5px; }}
     </style>
-    <script>
-        function deleteFlagType() {{
-            if (confirm('Are you sure you want to delete this flag type?')) {{
-                document.getElementById('delete-form').submit();
-            }}
-        }}
-
+    <script nonce="{nonce}">
         function updatePreview() {{
             const displayName = document.getElementById('display_name').value || 'Preview';
             const colorClass = document.getElementById('color_class').value;
@@ -474,17 +493,17 @@ pub async fn edit_flag_type_form(
 
         <div class="form-group">
             <input type="checkbox" id="filterable" name="filterable" value="true" {} />
-            <label for="filterable" style="display: inline; margin-left: 5px;">Available as filter on main page</label>
+            <label for="filterable" class="inline-label">Available as filter on main page</label>
         </div>
 
         <div class="form-group">
             <button type="submit" class="btn">Update Flag Type</button>
             <a href="/admin/flag-types" class="btn btn-secondary">Cancel</a>
-            <button type="button" onclick="deleteFlagType()" class="btn btn-danger">Delete</button>
+            <button type="button" data-submit-form="delete-form" data-confirm="Are you sure you want to delete this flag type?" class="btn btn-danger">Delete</button>
         </div>
     </form>
 
-    <form id="delete-form" method="post" action="/admin/flag-types/{}/delete" style="display: none;">
+    <form id="delete-form" method="post" action="/admin/flag-types/{}/delete" class="hidden">
         <input type="hidden" name="_method" value="DELETE" />
     </form>
 </body>
@@ -512,6 +531,12 @@ pub async fn update_flag_type(
 
     let filterable = form.filterable.is_some();
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::FlagType, id as i64).await;
+
     // Update the flag type
     sqlx::query(
         r#"
@@ -525,10 +550,25 @@ pub async fn update_flag_type(
     .bind(&form.color_class)
     .bind(filterable)
     .bind(id)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let after = audit::capture(&mut tx, AuditEntity::FlagType, id as i64).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Update,
+        AuditEntity::FlagType,
+        id as i64,
+        before,
+        after,
+    )
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     Ok(Redirect::to("/admin/flag-types"))
 }
 
@@ -539,12 +579,27 @@ pub async fn delete_flag_type(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let changes = audit::capture_cascade(&mut tx, AuditEntity::FlagType, id as i64).await;
+
     // Delete the flag type (this will cascade to related flags due to foreign key constraints)
-    sqlx::query("DELETE FROM flag_types WHERE id = ?")
+    let result = sqlx::query("DELETE FROM flag_types WHERE id = ?")
         .bind(id)
-        .execute(pool.as_ref())
+        .execute(&mut *tx)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    if result.rows_affected() > 0 {
+        audit::record_batch_change(&mut tx, changes)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        tx.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
     Ok(Redirect::to("/admin/flag-types"))
 }
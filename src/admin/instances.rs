@@ -4,7 +4,7 @@ use axum::{
     Form,
     extract::{Path, Query, State},
     http::StatusCode,
-    response::{Html, Redirect},
+    response::{Html, IntoResponse, Redirect, Response},
 };
 
 #[cfg(feature = "admin")]
@@ -15,11 +15,15 @@ use urlencoding;
 #[cfg(feature = "admin")]
 use crate::AppState;
 #[cfg(feature = "admin")]
-use crate::admin::dashboard::generate_sortable_header;
+use crate::admin::dashboard::{SORTABLE_HEADER_STYLES, generate_sortable_header};
 #[cfg(feature = "admin")]
 use crate::admin::types::*;
 #[cfg(feature = "admin")]
+use crate::audit::{self, AuditAction, AuditEntity};
+#[cfg(feature = "admin")]
 use crate::security;
+#[cfg(feature = "admin")]
+use crate::trash::TrashKind;
 
 #[cfg(feature = "admin")]
 pub async fn list_instances(
@@ -56,8 +60,8 @@ pub async fn list_instances(
 
     let sort_order = if order == "asc" { "ASC" } else { "DESC" };
 
-    // Build search query
-    let mut where_conditions = Vec::new();
+    // Build search query; trashed rows are listed on the trash page instead
+    let mut where_conditions = vec!["deleted_at IS NULL".to_string()];
     let mut search_param = None;
 
     if !search.is_empty() {
@@ -100,8 +104,11 @@ pub async fn list_instances(
 
     // Get instances
     let instances_query = format!(
-        "SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, hot_zone, verified FROM instances {} ORDER BY {} {} LIMIT ? OFFSET ?",
-        where_clause, sort_column, sort_order
+        "SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, EXISTS (SELECT 1 FROM instance_flags f JOIN flag_types ft ON f.flag_type_id = ft.id WHERE f.instance_id = instances.id AND ft.name = '{}') AS hot_zone, verified FROM instances {} ORDER BY {} {} LIMIT ? OFFSET ?",
+        crate::locations::HOT_ZONE_FLAG,
+        where_clause,
+        sort_column,
+        sort_order
     );
 
     let instance_rows = if let Some(ref search_term) = search_param {
@@ -150,13 +157,14 @@ pub async fn list_instances(
 
     let total_pages = (total_count + per_page - 1) / per_page;
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Instances - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1400px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -177,14 +185,11 @@ pub async fn list_instances(
         .truncate {{ max-width: 200px; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; }}
         th {{ background-color: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
         th a:hover {{ background-color: #e9ecef; padding: 4px; border-radius: 3px; }}
+        .btn-secondary {{ background: #6c757d; }}
+        .btn-success {{ background: #28a745; }}
+        .search-form {{ display: flex; gap: 10px; align-items: center; }}
+        .inline-form {{ display: inline; }}{SORTABLE_HEADER_STYLES}
     </style>
-    <script>
-        function deleteInstance(id, name) {{
-            if (confirm('Are you sure you want to delete "' + name + '"?')) {{
-                document.getElementById('delete-form-' + id).submit();
-            }}
-        }}
-    </script>
 </head>
 <body>
     <div class="nav">
@@ -196,7 +201,7 @@ pub async fn list_instances(
     <h1>Manage Instances{}</h1>
 
     <div class="controls">
-        <form method="get" style="display: flex; gap: 10px; align-items: center;">
+        <form method="get" class="search-form">
             <input type="text" name="search" placeholder="Search instances..." value="{}" />
             <input type="hidden" name="page" value="1" />
             <input type="hidden" name="per_page" value="{}" />
@@ -242,7 +247,7 @@ pub async fn list_instances(
         per_page,
         // Clear filters link
         if verified.is_some() {
-            r#"<a href="/admin/instances" class="btn" style="background: #6c757d;">Clear Filters</a>"#
+            r#"<a href="/admin/instances" class="btn btn-secondary">Clear Filters</a>"#
         } else {
             ""
         },
@@ -330,9 +335,10 @@ pub async fn list_instances(
                 <td>
                     <a href="/admin/instances/{}" class="btn btn-small">Edit</a>
                     <a href="/admin/instances/{}/notes" class="btn btn-small">Notes</a>
-                    <form id="delete-form-{}" method="post" action="/admin/instances/{}" style="display: inline;">
+                    <a href="/admin/instances/{}/convert" class="btn btn-small btn-success">Convert to Zone</a>
+                    <form method="post" action="/admin/instances/{}" class="inline-form" data-confirm="Are you sure you want to delete &quot;{}&quot;?">
                         <input type="hidden" name="_method" value="DELETE" />
-                        <button type="button" onclick="deleteInstance({}, '{}')" class="btn btn-danger btn-small">Delete</button>
+                        <button type="submit" class="btn btn-danger btn-small">Delete</button>
                     </form>
                 </td>
             </tr>
@@ -351,8 +357,7 @@ pub async fn list_instances(
             instance.id.unwrap_or(0),
             instance.id.unwrap_or(0),
             instance.id.unwrap_or(0),
-            instance.id.unwrap_or(0),
-            instance.name.replace("'", "\\'")
+            security::escape_html_attribute(&instance.name)
         ));
     }
 
@@ -425,16 +430,33 @@ pub async fn edit_instance_form(
 ) -> Result<Html<String>, StatusCode> {
     let pool = &state.instance_state.pool;
 
-    let instance_row = sqlx::query("SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, hot_zone, verified FROM instances WHERE id = ?")
-        .bind(instance_id)
-        .fetch_one(pool.as_ref())
-        .await
-        .map_err(|_| StatusCode::NOT_FOUND)?;
+    let instance_row = sqlx::query(&format!(
+        "SELECT id, {} FROM instances WHERE id = ?",
+        crate::instances::INSTANCE_COLUMNS
+    ))
+    .bind(instance_id)
+    .fetch_one(pool.as_ref())
+    .await
+    .map_err(|_| StatusCode::NOT_FOUND)?;
 
     let header = get_instance_form_header("Edit Instance");
+    let history = format!(
+        r#"<p><a href="/admin/audit?entity_type=instance&entity_id={}">View change history</a> | <a href="/admin/instances/{}/convert">Convert to Zone</a></p>"#,
+        instance_id, instance_id
+    );
+    let flags = crate::instances::get_instance_flags(pool.as_ref(), instance_id as i64)
+        .await
+        .unwrap_or_default();
+    let flag_types = crate::zones::get_all_flag_types(pool.as_ref())
+        .await
+        .unwrap_or_default();
+    let flags_section = get_instance_flags_section(instance_id, &flags, &flag_types);
     let body = get_instance_form_body(&instance_row, Some(instance_id));
 
-    Ok(Html(format!("{}{}", header, body)))
+    Ok(Html(format!(
+        "{}{}{}{}",
+        header, history, flags_section, body
+    )))
 }
 
 #[cfg(feature = "admin")]
@@ -442,9 +464,11 @@ pub async fn handle_instance_update_or_delete(
     State(state): State<AppState>,
     Path(id): Path<i32>,
     Form(form): Form<InstanceForm>,
-) -> Result<Redirect, StatusCode> {
+) -> Result<Response, StatusCode> {
     if form._method.as_deref() == Some("DELETE") {
-        delete_instance(State(state), Path(id)).await
+        delete_instance(State(state), Path(id))
+            .await
+            .map(IntoResponse::into_response)
     } else {
         update_instance(State(state), Path(id), Form(form)).await
     }
@@ -455,35 +479,120 @@ pub async fn update_instance(
     State(state): State<AppState>,
     Path(id): Path<i32>,
     Form(form): Form<InstanceForm>,
-) -> Result<Redirect, StatusCode> {
+) -> Result<Response, StatusCode> {
     let pool = &state.instance_state.pool;
 
-    let hot_zone = form.hot_zone.is_some();
     let verified = form.verified.is_some();
 
+    let details = match parse_instance_details(&form) {
+        Ok(details) => details,
+        Err(message) => {
+            return Ok(Html(format!(
+                r#"<h1>Error</h1><p>{}</p><a href="/admin/instances/{}">Go back</a>"#,
+                security::escape_html(&message),
+                id
+            ))
+            .into_response());
+        }
+    };
+
+    // Resolve codes like "PoP" to the catalogue's display name
+    let expansion = crate::expansions::find_expansion(pool.as_ref(), &form.expansion)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::BAD_REQUEST)?
+        .name;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::Instance, id as i64).await;
+
     let _ = sqlx::query(
-        "UPDATE instances SET name = ?, level_ranges = ?, expansion = ?, continent = ?, zone_type = ?, connections = ?, image_url = ?, map_url = ?, rating = ?, hot_zone = ?, verified = ? WHERE id = ?",
+        "UPDATE instances SET name = ?, level_ranges = ?, expansion = ?, continent = ?, zone_type = ?, connections = ?, image_url = ?, map_url = ?, rating = ?, verified = ?, min_players = ?, max_players = ?, lockout_minutes = ?, request_npc = ?, request_zone = ?, task_type = ?, classification = ? WHERE id = ?",
     )
     .bind(&form.name)
     .bind(&form.level_ranges)
-    .bind(&form.expansion)
+    .bind(&expansion)
     .bind(&form.continent)
     .bind(&form.zone_type)
     .bind(&form.connections)
     .bind(&form.image_url)
     .bind(&form.map_url)
     .bind(form.rating)
-    .bind(hot_zone)
     .bind(verified)
+    .bind(details.min_players)
+    .bind(details.max_players)
+    .bind(details.lockout_minutes)
+    .bind(&details.request_npc)
+    .bind(&details.request_zone)
+    .bind(&details.task_type)
+    .bind(&details.classification)
     .bind(id)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let after = audit::capture(&mut tx, AuditEntity::Instance, id as i64).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Update,
+        AuditEntity::Instance,
+        id as i64,
+        before,
+        after,
+    )
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     // Force WAL checkpoint to immediately update main database file
     let _ = crate::checkpoint_wal(pool.as_ref()).await;
 
-    Ok(Redirect::to("/admin/instances"))
+    Ok(Redirect::to("/admin/instances").into_response())
+}
+
+/// Instance details from the edit form, validated and ready to bind
+#[cfg(feature = "admin")]
+fn parse_instance_details(
+    form: &InstanceForm,
+) -> Result<crate::instances::InstanceDetails, String> {
+    fn number<T: std::str::FromStr>(value: &str, label: &str) -> Result<Option<T>, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+        value
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("{} must be a whole number", label))
+    }
+
+    let min_players = number::<u8>(&form.min_players, "Minimum players")?;
+    let max_players = number::<u8>(&form.max_players, "Maximum players")?;
+    let lockout_minutes = number::<u32>(&form.lockout_minutes, "Lockout")?;
+    let task_type = form.task_type.trim().to_lowercase();
+    let classification = form.classification.trim().to_lowercase();
+
+    crate::instances::validate_instance_details(
+        min_players,
+        max_players,
+        &task_type,
+        &classification,
+    )?;
+
+    Ok(crate::instances::InstanceDetails {
+        min_players,
+        max_players,
+        lockout_minutes,
+        request_npc: form.request_npc.trim().to_string(),
+        request_zone: form.request_zone.trim().to_string(),
+        task_type,
+        classification,
+    })
 }
 
 #[cfg(feature = "admin")]
@@ -493,9 +602,8 @@ pub async fn delete_instance(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.instance_state.pool;
 
-    let _ = sqlx::query("DELETE FROM instances WHERE id = ?")
-        .bind(id)
-        .execute(pool.as_ref())
+    // Move the instance to the trash; it can be restored until purged
+    crate::trash::soft_delete(pool.as_ref(), TrashKind::Instance, id as i64)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
@@ -531,13 +639,14 @@ pub async fn instance_notes(
         .await
         .unwrap_or_default();
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Instance Notes - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1000px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -553,7 +662,8 @@ pub async fn instance_notes(
         .btn-small {{ padding: 4px 8px; font-size: 0.8em; }}
         .note-card {{ border: 1px solid #ddd; padding: 15px; margin-bottom: 15px; border-radius: 5px; }}
         .note-header {{ display: flex; justify-content: space-between; align-items: center; margin-bottom: 10px; }}
-        .note-type {{ padding: 4px 8px; border-radius: 3px; color: white; font-size: 0.8em; }}
+        .note-type {{ padding: 4px 8px; border-radius: 3px; color: white; font-size: 0.8em; background: #007bff; }}
+        .inline-form {{ display: inline; }}
     </style>
 </head>
 <body>
@@ -589,7 +699,8 @@ pub async fn instance_notes(
             </div>
             <div class="form-group">
                 <label for="content">Content:</label>
-                <textarea id="content" name="content" required></textarea>
+                <textarea id="content" name="content" required data-note-preview></textarea>
+                <small>Markdown: **bold**, *italic*, [links](https://...), lists and headings</small>
             </div>
             <button type="submit" class="btn">Add Note</button>
         </form>
@@ -607,12 +718,12 @@ pub async fn instance_notes(
                 r#"
     <div class="note-card">
         <div class="note-header">
-            <span class="note-type" style="background: #007bff;">{}</span>
-            <form method="post" action="/admin/instances/{}/notes/{}/delete" style="display: inline;">
-                <button type="submit" class="btn btn-danger btn-small" onclick="return confirm('Delete this note?')">Delete</button>
+            <span class="note-type">{}</span>
+            <form method="post" action="/admin/instances/{}/notes/{}/delete" class="inline-form" data-confirm="Delete this note?">
+                <button type="submit" class="btn btn-danger btn-small">Delete</button>
             </form>
         </div>
-        <p>{}</p>
+        <div class="note-content">{}</div>
     </div>
 "#,
                 note.note_type
@@ -621,11 +732,12 @@ pub async fn instance_notes(
                     .unwrap_or("Unknown"),
                 instance_id,
                 note.id.unwrap_or(0),
-                note.content
+                note.content_html
             ));
         }
     }
 
+    html.push_str(&super::note_preview_script());
     html.push_str("</body></html>");
 
     Ok(Html(html))
@@ -639,23 +751,36 @@ pub async fn create_instance_note(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.instance_state.pool;
 
-    let sanitized_content = security::sanitize_user_input_with_formatting(&form.content);
-    if sanitized_content != form.content {
-        tracing::warn!(
-            instance_id,
-            "sanitized instance note content differed from input"
-        );
-    }
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    let _ = sqlx::query(
+    let result = sqlx::query(
         "INSERT INTO instance_notes (instance_id, note_type_id, content) VALUES (?, ?, ?)",
     )
     .bind(instance_id)
     .bind(form.note_type_id)
-    .bind(&sanitized_content)
-    .execute(pool.as_ref())
+    .bind(&form.content)
+    .execute(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let note_id = result.last_insert_rowid();
+    let after = audit::capture(&mut tx, AuditEntity::InstanceNote, note_id).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Create,
+        AuditEntity::InstanceNote,
+        note_id,
+        None,
+        after,
+    )
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     // Force WAL checkpoint to immediately update main database file
     let _ = crate::checkpoint_wal(pool.as_ref()).await;
@@ -673,12 +798,34 @@ pub async fn delete_instance_note(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.instance_state.pool;
 
-    let _ = sqlx::query("DELETE FROM instance_notes WHERE id = ? AND instance_id = ?")
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::InstanceNote, note_id as i64).await;
+
+    let result = sqlx::query("DELETE FROM instance_notes WHERE id = ? AND instance_id = ?")
         .bind(note_id)
         .bind(instance_id)
-        .execute(pool.as_ref())
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if result.rows_affected() > 0 {
+        audit::record_change(
+            &mut tx,
+            AuditAction::Delete,
+            AuditEntity::InstanceNote,
+            note_id as i64,
+            before,
+            None,
+        )
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        tx.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
 
     // Force WAL checkpoint to immediately update main database file
     let _ = crate::checkpoint_wal(pool.as_ref()).await;
@@ -689,15 +836,175 @@ pub async fn delete_instance_note(
     )))
 }
 
+#[cfg(feature = "admin")]
+pub async fn create_instance_flag(
+    State(state): State<AppState>,
+    Path(instance_id): Path<i64>,
+    Form(form): Form<InstanceFlagForm>,
+) -> Result<Redirect, StatusCode> {
+    let pool = &state.instance_state.pool;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // Insert the new flag (use INSERT OR IGNORE to handle duplicates)
+    let result = sqlx::query(
+        "INSERT OR IGNORE INTO instance_flags (instance_id, flag_type_id) VALUES (?, ?)",
+    )
+    .bind(instance_id)
+    .bind(form.flag_type_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    // Ignored duplicates change nothing
+    if result.rows_affected() > 0 {
+        let flag_id = result.last_insert_rowid();
+        let after = audit::capture(&mut tx, AuditEntity::InstanceFlag, flag_id).await;
+        audit::record_change(
+            &mut tx,
+            AuditAction::Create,
+            AuditEntity::InstanceFlag,
+            flag_id,
+            None,
+            after,
+        )
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        tx.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    let _ = crate::checkpoint_wal(pool.as_ref()).await;
+
+    Ok(Redirect::to(&format!("/admin/instances/{}", instance_id)))
+}
+
+#[cfg(feature = "admin")]
+pub async fn delete_instance_flag(
+    State(state): State<AppState>,
+    Path((instance_id, flag_id)): Path<(i64, i64)>,
+) -> Result<Redirect, StatusCode> {
+    let pool = &state.instance_state.pool;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::InstanceFlag, flag_id).await;
+
+    let result = sqlx::query("DELETE FROM instance_flags WHERE id = ? AND instance_id = ?")
+        .bind(flag_id)
+        .bind(instance_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if result.rows_affected() > 0 {
+        audit::record_change(
+            &mut tx,
+            AuditAction::Delete,
+            AuditEntity::InstanceFlag,
+            flag_id,
+            before,
+            None,
+        )
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        tx.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
+    let _ = crate::checkpoint_wal(pool.as_ref()).await;
+
+    Ok(Redirect::to(&format!("/admin/instances/{}", instance_id)))
+}
+
+#[cfg(feature = "admin")]
+fn get_instance_flags_section(
+    instance_id: i32,
+    flags: &[crate::instances::InstanceFlag],
+    flag_types: &[crate::zones::FlagType],
+) -> String {
+    let current = if flags.is_empty() {
+        r#"<p class="no-items">No flags assigned</p>"#.to_string()
+    } else {
+        flags
+            .iter()
+            .map(|flag| {
+                let display = flag
+                    .flag_type
+                    .as_ref()
+                    .map(|ft| ft.display_name.as_str())
+                    .unwrap_or("Unknown");
+                let color = flag
+                    .flag_type
+                    .as_ref()
+                    .map(|ft| ft.color_class.as_str())
+                    .unwrap_or("bg-gray-500");
+                format!(
+                    r#"<div class="item-pill">
+                    <span class="flag-badge {}">{}</span>
+                    <form method="post" action="/admin/instances/{}/flags/{}/delete" class="inline-form" data-confirm="Remove this flag?">
+                        <button type="submit" class="remove-link">×</button>
+                    </form>
+                </div>"#,
+                    security::escape_html(color),
+                    security::escape_html(display),
+                    instance_id,
+                    flag.id.unwrap_or(0)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("")
+    };
+
+    let options = flag_types
+        .iter()
+        .map(|ft| {
+            format!(
+                r#"<option value="{}">{}</option>"#,
+                ft.id.unwrap_or(0),
+                security::escape_html(&ft.display_name)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        r#"
+    <div class="management-section">
+        <h3>Instance Flags</h3>
+        <div class="current-items">
+            {}
+        </div>
+        <form method="post" action="/admin/instances/{}/flags" class="add-form">
+            <select name="flag_type_id" required>
+                <option value="">Add flag...</option>
+                {}
+            </select>
+            <button type="submit" class="btn">Add</button>
+        </form>
+    </div>
+"#,
+        current, instance_id, options
+    )
+}
+
 #[cfg(feature = "admin")]
 fn get_instance_form_header(title: &str) -> String {
+    let nonce = crate::security::csp_nonce();
     format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>{} - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 800px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -710,6 +1017,19 @@ fn get_instance_form_header(title: &str) -> String {
         .btn:hover {{ background: #0056b3; }}
         .checkbox-group {{ display: flex; align-items: center; }}
         .checkbox-group input {{ width: auto; margin-right: 10px; }}
+        .management-section {{ background: #f8f9fa; border: 1px solid #dee2e6; border-radius: 5px; padding: 15px; margin-bottom: 20px; }}
+        .management-section h3 {{ margin-top: 0; }}
+        .current-items {{ display: flex; flex-wrap: wrap; gap: 8px; margin-bottom: 10px; }}
+        .item-pill {{ display: inline-flex; align-items: center; gap: 4px; background: white; border: 1px solid #dee2e6; border-radius: 12px; padding: 2px 8px; }}
+        .flag-badge {{ font-size: 0.85em; }}
+        .remove-link {{ background: none; border: none; color: #dc3545; cursor: pointer; font-weight: bold; padding: 0 2px; }}
+        .no-items {{ color: #6c757d; margin: 0; }}
+        .add-form {{ display: flex; gap: 8px; }}
+        .add-form select {{ flex: 1; }}
+        .inline-form {{ display: inline; }}
+        .half {{ width: 48%; }}
+        .half-right {{ width: 48%; float: right; }}
+        .btn-cancel {{ background: #6c757d; margin-left: 10px; }}
     </style>
 </head>
 <body>
@@ -725,6 +1045,67 @@ fn get_instance_form_header(title: &str) -> String {
     )
 }
 
+#[cfg(feature = "admin")]
+fn get_instance_details_fields(instance_row: &sqlx::sqlite::SqliteRow) -> String {
+    let details = crate::instances::InstanceDetails::from_row(instance_row);
+    let number = |value: Option<u32>| value.map(|n| n.to_string()).unwrap_or_default();
+    let options = |allowed: &[&str], current: &str| {
+        std::iter::once(("", "Unknown"))
+            .chain(allowed.iter().map(|v| (*v, *v)))
+            .map(|(value, label)| {
+                format!(
+                    r#"<option value="{}"{}>{}</option>"#,
+                    value,
+                    if value == current { " selected" } else { "" },
+                    label
+                )
+            })
+            .collect::<String>()
+    };
+
+    format!(
+        r#"
+        <div class="form-group">
+            <label for="min_players">Players (min / max):</label>
+            <input type="number" id="min_players" name="min_players" min="1" max="255" value="{}" class="half">
+            <input type="number" id="max_players" name="max_players" min="1" max="255" value="{}" class="half-right">
+        </div>
+
+        <div class="form-group">
+            <label for="lockout_minutes">Lockout / replay timer (minutes):</label>
+            <input type="number" id="lockout_minutes" name="lockout_minutes" min="0" value="{}">
+        </div>
+
+        <div class="form-group">
+            <label for="request_npc">Requested from (NPC):</label>
+            <input type="text" id="request_npc" name="request_npc" value="{}">
+        </div>
+
+        <div class="form-group">
+            <label for="request_zone">Requested in (zone):</label>
+            <input type="text" id="request_zone" name="request_zone" value="{}">
+        </div>
+
+        <div class="form-group">
+            <label for="task_type">Task Type:</label>
+            <select id="task_type" name="task_type">{}</select>
+        </div>
+
+        <div class="form-group">
+            <label for="classification">Classification:</label>
+            <select id="classification" name="classification">{}</select>
+        </div>
+"#,
+        number(details.min_players.map(u32::from)),
+        number(details.max_players.map(u32::from)),
+        number(details.lockout_minutes),
+        security::escape_html_attribute(&details.request_npc),
+        security::escape_html_attribute(&details.request_zone),
+        options(&crate::instances::TASK_TYPES, &details.task_type),
+        options(&crate::instances::CLASSIFICATIONS, &details.classification),
+    )
+}
+
 #[cfg(feature = "admin")]
 fn get_instance_form_body(
     instance_row: &sqlx::sqlite::SqliteRow,
@@ -792,19 +1173,14 @@ fn get_instance_form_body(
             <label for="rating">Rating (0-5):</label>
             <input type="number" id="rating" name="rating" min="0" max="5" value="{}">
         </div>
-
-        <div class="form-group checkbox-group">
-            <input type="checkbox" id="hot_zone" name="hot_zone" value="true" {}>
-            <label for="hot_zone">Hot Zone</label>
-        </div>
-
+{}
         <div class="form-group checkbox-group">
             <input type="checkbox" id="verified" name="verified" value="true" {}>
             <label for="verified">Verified</label>
         </div>
 
         <button type="submit" class="btn">Save Instance</button>
-        <a href="/admin/instances" class="btn" style="background: #6c757d; margin-left: 10px;">Cancel</a>
+        <a href="/admin/instances" class="btn btn-cancel">Cancel</a>
     </form>
 
 </body>
@@ -821,11 +1197,7 @@ fn get_instance_form_body(
         instance_row.get::<String, _>("image_url"),
         instance_row.get::<String, _>("map_url"),
         instance_row.get::<i32, _>("rating"),
-        if instance_row.get::<bool, _>("hot_zone") {
-            "checked"
-        } else {
-            ""
-        },
+        get_instance_details_fields(instance_row),
         if instance_row.get::<bool, _>("verified") {
             "checked"
         } else {
@@ -837,9 +1209,9 @@ fn get_instance_form_body(
 #[cfg(all(test, feature = "admin"))]
 mod tests {
     use super::*;
-    use axum::extract::{Path, State};
     use axum::Form;
-    use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+    use axum::extract::{Path, State};
+    use sqlx::{Row, SqlitePool, sqlite::SqlitePoolOptions};
     use std::collections::HashMap;
     use std::sync::Arc;
 
@@ -863,9 +1235,15 @@ mod tests {
                 backup_dir: "".to_string(),
                 migrate_on_startup: false,
             },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
             security: SecurityConfig {
                 rating_ip_hash_key: "test".to_string(),
                 min_ip_hash_key_length: 0,
+                headers: Default::default(),
             },
             ratings: RatingsConfig {
                 min_rating: 1,
@@ -912,6 +1290,7 @@ mod tests {
         .execute(&pool)
         .await
         .unwrap();
+        crate::audit::create_audit_log_table(&pool).await.unwrap();
 
         let pool_arc = Arc::new(pool);
         let state = AppState {
@@ -925,13 +1304,15 @@ mod tests {
             class_race_state: ClassRaceState {
                 class_race_map: Arc::new(HashMap::new()),
             },
+            snapshot: Default::default(),
+            rate_limiter: Default::default(),
         };
 
         (state, pool_arc)
     }
 
     #[tokio::test]
-    async fn create_instance_note_sanitizes_script_tags() {
+    async fn create_instance_note_stores_markdown_and_renders_safely() {
         let (state, pool) = setup_state().await;
         let raw = "<script>alert('y')</script>";
         let form = InstanceNoteForm {
@@ -949,7 +1330,7 @@ mod tests {
             .await
             .unwrap();
         let stored: String = row.get("content");
-        let expected = crate::security::sanitize_user_input_with_formatting(raw);
-        assert_eq!(stored, expected);
+        assert_eq!(stored, raw);
+        assert!(!crate::markdown::render(&stored).contains("<script"));
     }
 }
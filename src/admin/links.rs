@@ -2,7 +2,7 @@
 // This file contains link management features for the admin interface
 
 #[cfg(feature = "admin")]
-use crate::security::escape_html;
+use crate::security::{escape_html, escape_html_attribute};
 
 #[cfg(feature = "admin")]
 use axum::{
@@ -19,6 +19,10 @@ use sqlx::Row;
 use crate::AppState;
 #[cfg(feature = "admin")]
 use crate::admin::types::*;
+#[cfg(feature = "admin")]
+use crate::audit::{self, AuditAction, AuditEntity};
+#[cfg(feature = "admin")]
+use crate::trash::TrashKind;
 
 #[cfg(feature = "admin")]
 pub async fn list_links(
@@ -45,9 +49,10 @@ pub async fn list_links(
     let sort_order = if order == "asc" { "ASC" } else { "DESC" };
 
     // Build search query
-    let mut where_clause = String::new();
+    // Trashed links are listed on the trash page instead
+    let mut where_clause = "WHERE deleted_at IS NULL".to_string();
     let search_param = if !search.is_empty() {
-        where_clause = "WHERE (name LIKE ? OR category LIKE ? OR description LIKE ?)".to_string();
+        where_clause.push_str(" AND (name LIKE ? OR category LIKE ? OR description LIKE ?)");
         Some(format!("%{}%", search))
     } else {
         None
@@ -129,20 +134,21 @@ pub async fn list_links(
         }
 
         format!(
-            r#"<a href="/admin/links?{}" style="text-decoration: none; color: inherit;">{}{}</a>"#,
+            r#"<a href="/admin/links?{}" class="sort-link">{}{}</a>"#,
             query_params.join("&"),
             display_name,
             arrow
         )
     };
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Links - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1400px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -163,14 +169,11 @@ pub async fn list_links(
         .pagination a.current {{ background: #007bff; color: white; }}
         .truncate {{ max-width: 200px; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; }}
         .category-badge {{ display: inline-block; padding: 4px 8px; border-radius: 12px; background: #007bff; color: white; font-size: 0.8em; }}
+        .search-form {{ display: flex; gap: 10px; align-items: center; }}
+        .sort-link {{ text-decoration: none; color: inherit; }}
+        .url-link {{ color: #007bff; }}
+        .hidden {{ display: none; }}
     </style>
-    <script>
-        function deleteLink(id, name) {{
-            if (confirm('Are you sure you want to delete "' + name + '"?')) {{
-                document.getElementById('delete-form-' + id).submit();
-            }}
-        }}
-    </script>
 </head>
 <body>
     <div class="nav">
@@ -186,7 +189,7 @@ pub async fn list_links(
     <h1>Manage Links</h1>
 
     <div class="controls">
-        <form method="get" style="display: flex; gap: 10px; align-items: center;">
+        <form method="get" class="search-form">
             <input type="text" name="search" placeholder="Search links..." value="{}" />
             <input type="hidden" name="page" value="1" />
             <input type="hidden" name="per_page" value="{}" />
@@ -236,14 +239,14 @@ pub async fn list_links(
             <tr>
                 <td>{}</td>
                 <td><span class="category-badge">{}</span></td>
-                <td><a href="{}" target="_blank" style="color: #007bff;">{}</a></td>
+                <td><a href="{}" target="_blank" class="url-link">{}</a></td>
                 <td>{}</td>
                 <td class="truncate">{}</td>
                 <td>{}</td>
                 <td>
                     <a href="/admin/links/{}" class="btn btn-small">Edit</a>
-                    <button onclick="deleteLink({}, '{}')" class="btn btn-danger btn-small">Delete</button>
-                    <form id="delete-form-{}" method="post" action="/admin/links/{}/delete" style="display: none;">
+                    <button type="button" data-submit-form="delete-form-{}" data-confirm="Are you sure you want to delete &quot;{}&quot;?" class="btn btn-danger btn-small">Delete</button>
+                    <form id="delete-form-{}" method="post" action="/admin/links/{}/delete" class="hidden">
                         <input type="hidden" name="_method" value="DELETE" />
                     </form>
                 </td>
@@ -262,7 +265,7 @@ pub async fn list_links(
             created_at.split('T').next().unwrap_or(&created_at),
             id,
             id,
-            name.replace("'", "\\'"),
+            escape_html_attribute(&name),
             id,
             id
         ));
@@ -349,13 +352,14 @@ pub async fn list_links(
 
 #[cfg(feature = "admin")]
 pub async fn new_link_form() -> Result<Html<String>, StatusCode> {
+    let nonce = crate::security::csp_nonce();
     let html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Add New Link - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 800px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -466,13 +470,14 @@ pub async fn edit_link_form(
         .collect::<Vec<_>>()
         .join("\n");
 
+    let nonce = crate::security::csp_nonce();
     let html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Edit Link - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 800px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -487,14 +492,8 @@ pub async fn edit_link_form(
         .btn-secondary:hover {{ background: #545b62; }}
         .btn-danger {{ background: #dc3545; }}
         .btn-danger:hover {{ background: #c82333; }}
+        .hidden {{ display: none; }}
     </style>
-    <script>
-        function deleteLink() {{
-            if (confirm('Are you sure you want to delete this link?')) {{
-                document.getElementById('delete-form').submit();
-            }}
-        }}
-    </script>
 </head>
 <body>
     <div class="nav">
@@ -537,11 +536,11 @@ pub async fn edit_link_form(
         <div class="form-group">
             <button type="submit" class="btn">Update Link</button>
             <a href="/admin/links" class="btn btn-secondary">Cancel</a>
-            <button type="button" onclick="deleteLink()" class="btn btn-danger">Delete</button>
+            <button type="button" data-submit-form="delete-form" data-confirm="Are you sure you want to delete this link?" class="btn btn-danger">Delete</button>
         </div>
     </form>
 
-    <form id="delete-form" method="post" action="/admin/links/{}/delete" style="display: none;">
+    <form id="delete-form" method="post" action="/admin/links/{}/delete" class="hidden">
         <input type="hidden" name="_method" value="DELETE" />
     </form>
 </body>
@@ -565,8 +564,13 @@ pub async fn create_link_admin(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     // Insert the new link
-    sqlx::query(
+    let result = sqlx::query(
         r#"
         INSERT INTO links (name, url, category, description)
         VALUES (?, ?, ?, ?)
@@ -576,10 +580,26 @@ pub async fn create_link_admin(
     .bind(&form.url)
     .bind(&form.category)
     .bind(&form.description)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let id = result.last_insert_rowid();
+    let after = audit::capture(&mut tx, AuditEntity::Link, id).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Create,
+        AuditEntity::Link,
+        id,
+        None,
+        after,
+    )
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     Ok(Redirect::to("/admin/links"))
 }
 
@@ -589,10 +609,8 @@ pub async fn handle_link_update_or_delete(
     Path(id): Path<i32>,
     Form(form): Form<LinkForm>,
 ) -> Result<Redirect, StatusCode> {
-    if let Some(ref method) = form._method {
-        if method == "DELETE" {
-            return delete_link_admin(State(state), Path(id)).await;
-        }
+    if form._method.as_deref() == Some("DELETE") {
+        return delete_link_admin(State(state), Path(id)).await;
     }
 
     update_link(State(state), Path(id), Form(form)).await
@@ -606,6 +624,12 @@ pub async fn update_link(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::Link, id as i64).await;
+
     // Update the link
     sqlx::query(
         r#"
@@ -619,9 +643,24 @@ pub async fn update_link(
     .bind(&form.category)
     .bind(&form.description)
     .bind(id)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let after = audit::capture(&mut tx, AuditEntity::Link, id as i64).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Update,
+        AuditEntity::Link,
+        id as i64,
+        before,
+        after,
+    )
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(Redirect::to("/admin/links"))
 }
@@ -633,10 +672,8 @@ pub async fn delete_link_admin(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
-    // Delete the link
-    sqlx::query("DELETE FROM links WHERE id = ?")
-        .bind(id)
-        .execute(pool.as_ref())
+    // Move the link to the trash; it can be restored until purged
+    crate::trash::soft_delete(pool.as_ref(), TrashKind::Link, id as i64)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
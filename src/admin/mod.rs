@@ -14,6 +14,16 @@ use crate::AppState;
 
 // Module declarations
 #[cfg(feature = "admin")]
+pub mod api_keys;
+#[cfg(feature = "admin")]
+pub mod audit;
+#[cfg(feature = "admin")]
+pub mod auth;
+#[cfg(feature = "admin")]
+pub mod backups;
+#[cfg(feature = "admin")]
+pub mod conversion;
+#[cfg(feature = "admin")]
 pub mod dashboard;
 #[cfg(feature = "admin")]
 pub mod flags;
@@ -26,8 +36,12 @@ pub mod notes;
 #[cfg(feature = "admin")]
 pub mod ratings;
 #[cfg(feature = "admin")]
+pub mod trash;
+#[cfg(feature = "admin")]
 pub mod types;
 #[cfg(feature = "admin")]
+pub mod users;
+#[cfg(feature = "admin")]
 pub mod utils;
 #[cfg(feature = "admin")]
 pub mod zones;
@@ -38,6 +52,16 @@ pub use types::*;
 
 // Re-export main functions
 #[cfg(feature = "admin")]
+pub use api_keys::*;
+#[cfg(feature = "admin")]
+pub use audit::*;
+#[cfg(feature = "admin")]
+pub use auth::{check_permission, embed_csrf_tokens, login, login_form, logout, require_admin};
+#[cfg(feature = "admin")]
+pub use backups::*;
+#[cfg(feature = "admin")]
+pub use conversion::*;
+#[cfg(feature = "admin")]
 pub use dashboard::{admin_dashboard, log_admin_requests};
 #[cfg(feature = "admin")]
 pub use flags::*;
@@ -50,6 +74,10 @@ pub use notes::*;
 #[cfg(feature = "admin")]
 pub use ratings::*;
 #[cfg(feature = "admin")]
+pub use trash::*;
+#[cfg(feature = "admin")]
+pub use users::*;
+#[cfg(feature = "admin")]
 pub use utils::*;
 #[cfg(feature = "admin")]
 pub use zones::*;
@@ -67,8 +95,38 @@ async fn trigger_migrations(State(state): State<AppState>) -> Result<String, Sta
     }
 }
 
+// Admin mutations are POSTs, and deletes also end in `/delete`
+#[cfg(feature = "admin")]
+fn is_admin_mutation(method: &axum::http::Method, path: &str) -> bool {
+    if path == NOTE_PREVIEW_PATH {
+        return false;
+    }
+    (method != axum::http::Method::GET && method != axum::http::Method::HEAD)
+        || path.ends_with("/delete")
+}
+
+/// Rebuild the roll snapshot after an admin change commits so rolls see it immediately
+#[cfg(feature = "admin")]
+async fn refresh_snapshot(
+    State(state): State<AppState>,
+    request: axum::http::Request<axum::body::Body>,
+    next: middleware::Next,
+) -> axum::response::Response {
+    let mutation = is_admin_mutation(request.method(), request.uri().path());
+    let response = next.run(request).await;
+
+    if mutation && (response.status().is_success() || response.status().is_redirection()) {
+        let pool = &state.zone_state.pool;
+        if let Err(e) = state.snapshot.rebuild(pool.as_ref()).await {
+            tracing::error!(error = %e, "Failed to rebuild roll snapshot after admin change");
+        }
+    }
+
+    response
+}
+
 #[cfg(feature = "admin")]
-pub fn admin_routes() -> Router<AppState> {
+pub fn admin_routes(state: AppState) -> Router<AppState> {
     Router::new()
         .route("/admin", axum::routing::get(admin_dashboard))
         .route("/admin/zones", axum::routing::get(list_zones))
@@ -81,8 +139,8 @@ pub fn admin_routes() -> Router<AppState> {
         )
         .route("/admin/zones/:id/delete", axum::routing::post(delete_zone))
         .route(
-            "/admin/zones/:id/move-to-instances",
-            axum::routing::post(move_zone_to_instances),
+            "/admin/zones/:id/convert",
+            axum::routing::get(convert_zone_form).post(convert_zone),
         )
         .route("/admin/zones/:id/ratings", axum::routing::get(zone_ratings))
         .route("/admin/zones/:id/notes", axum::routing::get(zone_notes))
@@ -95,20 +153,20 @@ pub fn admin_routes() -> Router<AppState> {
             axum::routing::post(delete_zone_note),
         )
         .route(
-            "/admin/zones/:id/flags",
-            axum::routing::post(create_zone_flag),
+            "/admin/zones/:id/aliases",
+            axum::routing::post(create_zone_alias),
         )
         .route(
-            "/admin/zones/:id/flags/:flag_id/delete",
-            axum::routing::post(delete_zone_flag),
+            "/admin/zones/:id/aliases/:alias_id/delete",
+            axum::routing::post(delete_zone_alias),
         )
         .route(
-            "/admin/zones/:id/flags/:flag_id/delete",
-            axum::routing::get(delete_zone_flag),
+            "/admin/zones/:id/flags",
+            axum::routing::post(create_zone_flag),
         )
         .route(
-            "/admin/zones/:id/remove-flag/:flag_id",
-            axum::routing::get(delete_zone_flag_simple),
+            "/admin/zones/:id/flags/:flag_id/delete",
+            axum::routing::post(delete_zone_flag),
         )
         .layer(middleware::from_fn(log_admin_requests))
         .route("/admin/instances", axum::routing::get(list_instances))
@@ -124,6 +182,18 @@ pub fn admin_routes() -> Router<AppState> {
             "/admin/instances/:id/delete",
             axum::routing::post(delete_instance),
         )
+        .route(
+            "/admin/instances/:id/convert",
+            axum::routing::get(convert_instance_form).post(convert_instance),
+        )
+        .route(
+            "/admin/instances/:id/flags",
+            axum::routing::post(create_instance_flag),
+        )
+        .route(
+            "/admin/instances/:id/flags/:flag_id/delete",
+            axum::routing::post(delete_instance_flag),
+        )
         .route(
             "/admin/instances/:id/notes",
             axum::routing::get(instance_notes),
@@ -137,6 +207,7 @@ pub fn admin_routes() -> Router<AppState> {
             axum::routing::post(delete_instance_note),
         )
         .route("/admin/note-types", axum::routing::get(list_note_types))
+        .route(NOTE_PREVIEW_PATH, axum::routing::post(preview_note))
         .route("/admin/note-types", axum::routing::post(create_note_type))
         .route(
             "/admin/note-types/:id/delete",
@@ -158,7 +229,7 @@ pub fn admin_routes() -> Router<AppState> {
         )
         .route("/admin/ratings", axum::routing::get(list_all_ratings))
         .route(
-            "/admin/ratings/:id/delete",
+            "/admin/ratings/:kind/:id/delete",
             axum::routing::post(handle_rating_delete),
         )
         .route("/admin/links", axum::routing::get(list_links))
@@ -177,10 +248,235 @@ pub fn admin_routes() -> Router<AppState> {
             "/admin/dump-database",
             axum::routing::post(dump_database_sql),
         )
+        .route("/admin/backups", axum::routing::get(list_backups_admin))
+        .route("/admin/backups", axum::routing::post(create_backup_admin))
+        .route("/admin/audit", axum::routing::get(list_audit_log))
+        .route("/admin/audit/:id", axum::routing::get(view_audit_entry))
+        .route(
+            "/admin/audit/:id/revert",
+            axum::routing::post(revert_audit_entry),
+        )
+        .route("/admin/trash", axum::routing::get(list_trash_admin))
+        .route(
+            "/admin/trash/:kind/:id/restore",
+            axum::routing::post(restore_trash_item),
+        )
+        .route(
+            "/admin/trash/:kind/:id/purge",
+            axum::routing::post(purge_trash_item),
+        )
         .route("/admin/migrate", axum::routing::post(trigger_migrations))
+        .route("/admin/users", axum::routing::get(list_admin_users))
+        .route("/admin/users", axum::routing::post(create_admin_user))
+        .route(
+            "/admin/users/:id/role",
+            axum::routing::post(update_admin_role),
+        )
+        .route(
+            "/admin/users/:id/delete",
+            axum::routing::post(delete_admin_user),
+        )
+        .route("/admin/api-keys", axum::routing::get(list_api_keys_admin))
+        .route("/admin/api-keys", axum::routing::post(create_api_key_admin))
+        .route(
+            "/admin/api-keys/:id/revoke",
+            axum::routing::post(revoke_api_key_admin),
+        )
+        // The JSON API's changes go through the same sign-in and roles as the pages
+        .route(
+            "/api/ratings/:id",
+            axum::routing::delete(crate::ratings::delete_rating),
+        )
+        .route(
+            "/api/ratings/:kind/:id",
+            axum::routing::delete(crate::ratings::delete_kind_rating),
+        )
+        .route("/api/links", axum::routing::post(crate::links::create_link))
+        .route(
+            "/api/links/:id",
+            axum::routing::put(crate::links::update_link).delete(crate::links::delete_link),
+        )
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            refresh_snapshot,
+        ))
+        // Everything above needs a signed-in admin whose role covers the request; the
+        // login routes below do not
+        .layer(middleware::from_fn(check_permission))
+        .layer(middleware::from_fn_with_state(state.clone(), require_admin))
+        .route("/admin/login", axum::routing::get(login_form).post(login))
+        .route("/admin/logout", axum::routing::post(logout))
+        // Every admin page, the login page included, carries CSRF tokens in its forms
+        .layer(middleware::from_fn_with_state(state, embed_csrf_tokens))
 }
 
 #[cfg(not(feature = "admin"))]
-pub fn admin_routes() -> Router<AppState> {
+pub fn admin_routes(_state: AppState) -> Router<AppState> {
     Router::new()
 }
+
+#[cfg(all(test, feature = "admin"))]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Method, Request};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    use crate::classes::ClassRaceState;
+    use crate::config::{
+        AdminConfig, AppConfig, CorsConfig, DatabaseConfig, LoggingConfig, RatingsConfig,
+        SecurityConfig, ServerConfig,
+    };
+    use crate::instances::InstanceState;
+    use crate::zones::ZoneState;
+
+    fn test_state() -> AppState {
+        let config = AppConfig {
+            server: ServerConfig {
+                port: 0,
+                host: "localhost".to_string(),
+            },
+            database: DatabaseConfig {
+                path: "".to_string(),
+                backup_dir: "".to_string(),
+                migrate_on_startup: false,
+            },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
+            security: SecurityConfig {
+                rating_ip_hash_key: "test".to_string(),
+                min_ip_hash_key_length: 0,
+                headers: Default::default(),
+            },
+            ratings: RatingsConfig {
+                min_rating: 1,
+                max_rating: 5,
+                transaction_log_path: "".to_string(),
+            },
+            admin: AdminConfig {
+                enabled: true,
+                page_size: 10,
+                min_page_size: 1,
+                max_page_size: 100,
+                default_sort_column: "id".to_string(),
+                default_sort_order: "asc".to_string(),
+            },
+            cors: CorsConfig {
+                development_origins: vec![],
+                production_origins: vec![],
+            },
+            logging: LoggingConfig {
+                level: "info".to_string(),
+                format: "text".to_string(),
+                file_path: "".to_string(),
+                max_file_size: "1MB".to_string(),
+                max_files: 1,
+            },
+        };
+        // Nothing reaches the database without a session
+        let pool = Arc::new(sqlx::SqlitePool::connect_lazy("sqlite::memory:").unwrap());
+
+        AppState {
+            config: Arc::new(config),
+            zone_state: ZoneState { pool: pool.clone() },
+            instance_state: InstanceState { pool },
+            class_race_state: ClassRaceState {
+                class_race_map: Arc::new(HashMap::new()),
+            },
+            snapshot: Default::default(),
+            rate_limiter: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn changes_without_a_session_are_refused() {
+        let state = test_state();
+        let app = admin_routes(state.clone()).with_state(state);
+
+        let changes = [
+            (Method::POST, "/admin/zones"),
+            (Method::POST, "/admin/zones/1/delete"),
+            (Method::POST, "/admin/ratings/zone/1/delete"),
+            (Method::POST, "/admin/links/1"),
+            (Method::POST, "/admin/audit/1/revert"),
+            (Method::POST, "/admin/users"),
+            (Method::DELETE, "/api/ratings/1"),
+            (Method::POST, "/api/links"),
+            (Method::PUT, "/api/links/1"),
+            (Method::DELETE, "/api/links/1"),
+        ];
+        for (method, path) in changes {
+            let request = Request::builder()
+                .method(method.clone())
+                .uri(path)
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(
+                response.status(),
+                StatusCode::UNAUTHORIZED,
+                "{} {}",
+                method,
+                path
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn flags_cannot_be_removed_with_a_get() {
+        let pool = Arc::new(
+            sqlx::sqlite::SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect("sqlite::memory:")
+                .await
+                .unwrap(),
+        );
+        crate::auth::create_auth_tables(&pool).await.unwrap();
+        let user_id = crate::auth::create_admin_user(
+            &pool,
+            "guide",
+            "correct horse battery",
+            crate::auth::Role::Owner,
+        )
+        .await
+        .unwrap();
+        let token = crate::auth::start_session(&pool, user_id, 60)
+            .await
+            .unwrap();
+
+        let mut state = test_state();
+        state.zone_state.pool = pool.clone();
+        state.instance_state.pool = pool;
+        let app = admin_routes(state.clone()).with_state(state);
+
+        for path in [
+            "/admin/zones/1/remove-flag/2",
+            "/admin/zones/1/flags/2/delete",
+        ] {
+            let request = Request::builder()
+                .method(Method::GET)
+                .uri(path)
+                .header(
+                    axum::http::header::COOKIE,
+                    format!("{}={}", auth::SESSION_COOKIE, token),
+                )
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert!(
+                matches!(
+                    response.status(),
+                    StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED
+                ),
+                "GET {} answered {}",
+                path,
+                response.status()
+            );
+        }
+    }
+}
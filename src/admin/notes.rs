@@ -16,6 +16,8 @@ use sqlx::Row;
 use crate::AppState;
 #[cfg(feature = "admin")]
 use crate::admin::types::*;
+#[cfg(feature = "admin")]
+use crate::audit::{self, AuditAction, AuditEntity};
 
 #[cfg(feature = "admin")]
 pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<String>, StatusCode> {
@@ -27,13 +29,14 @@ pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<Strin
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Note Types - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1000px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -75,14 +78,11 @@ pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<Strin
         .bg-neutral-500 {{ background-color: #737373; }}
         .bg-stone-500 {{ background-color: #78716c; }}
         .bg-sky-500 {{ background-color: #0ea5e9; }}
+        .hidden {{ display: none; }}
+        .add-type-form {{ display: flex; align-items: end; gap: 15px; flex-wrap: wrap; }}
+        .usage {{ margin-top: 30px; padding: 20px; background: #e9ecef; border-radius: 5px; }}
     </style>
-    <script>
-        function deleteNoteType(id, name) {{
-            if (confirm('Are you sure you want to delete "' + name + '"? This will affect all existing notes of this type.')) {{
-                document.getElementById('delete-form-' + id).submit();
-            }}
-        }}
-
+    <script nonce="{nonce}">
         function updatePreview() {{
             const displayName = document.getElementById('display_name').value || 'Preview';
             const colorClass = document.getElementById('color_class').value;
@@ -114,7 +114,7 @@ pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<Strin
 
     <div class="form-section">
         <h2>Add New Note Type</h2>
-        <form method="post" action="/admin/note-types" style="display: flex; align-items: end; gap: 15px; flex-wrap: wrap;">
+        <form method="post" action="/admin/note-types" class="add-type-form">
             <div class="form-group">
                 <label for="name">Internal Name:</label>
                 <input type="text" id="name" name="name" placeholder="e.g., important_info" required />
@@ -215,8 +215,8 @@ pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<Strin
                 <td><span class="note-type-preview {}">{}</span></td>
                 <td>{}</td>
                 <td>
-                    <button onclick="deleteNoteType({}, '{}')" class="btn btn-danger btn-small">Delete</button>
-                    <form id="delete-form-{}" method="post" action="/admin/note-types/{}/delete" style="display: none;">
+                    <button type="button" data-submit-form="delete-form-{}" data-confirm="Are you sure you want to delete &quot;{}&quot;? This will affect all existing notes of this type." class="btn btn-danger btn-small">Delete</button>
+                    <form id="delete-form-{}" method="post" action="/admin/note-types/{}/delete" class="hidden">
                         <input type="hidden" name="_method" value="DELETE" />
                     </form>
                 </td>
@@ -229,7 +229,7 @@ pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<Strin
             display_name,
             created_at.split('T').next().unwrap_or(&created_at),
             id,
-            name.replace("'", "\\'"),
+            crate::security::escape_html_attribute(&name),
             id,
             id
         ));
@@ -240,7 +240,7 @@ pub async fn list_note_types(State(state): State<AppState>) -> Result<Html<Strin
         </tbody>
     </table>
 
-    <div style="margin-top: 30px; padding: 20px; background: #e9ecef; border-radius: 5px;">
+    <div class="usage">
         <h3>Note Type Usage</h3>
         <p>Note types are used to categorize notes attached to zones and instances. They help organize different kinds of information:</p>
         <ul>
@@ -265,8 +265,13 @@ pub async fn create_note_type(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     // Insert the new note type
-    sqlx::query(
+    let result = sqlx::query(
         r#"
         INSERT INTO note_types (name, display_name, color_class)
         VALUES (?, ?, ?)
@@ -275,9 +280,25 @@ pub async fn create_note_type(
     .bind(&form.name)
     .bind(&form.display_name)
     .bind(&form.color_class)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let id = result.last_insert_rowid();
+    let after = audit::capture(&mut tx, AuditEntity::NoteType, id).await;
+    audit::record_change(
+        &mut tx,
+        AuditAction::Create,
+        AuditEntity::NoteType,
+        id,
+        None,
+        after,
+    )
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    tx.commit()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(Redirect::to("/admin/note-types"))
 }
@@ -289,12 +310,68 @@ pub async fn delete_note_type(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let changes = audit::capture_cascade(&mut tx, AuditEntity::NoteType, id as i64).await;
+
     // Delete the note type (this will cascade to related notes due to foreign key constraints)
-    sqlx::query("DELETE FROM note_types WHERE id = ?")
+    let result = sqlx::query("DELETE FROM note_types WHERE id = ?")
         .bind(id)
-        .execute(pool.as_ref())
+        .execute(&mut *tx)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    if result.rows_affected() > 0 {
+        audit::record_batch_change(&mut tx, changes)
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        tx.commit()
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    }
+
     Ok(Redirect::to("/admin/note-types"))
 }
+
+/// Renders note Markdown for the editor's preview; nothing is stored
+#[cfg(feature = "admin")]
+pub const NOTE_PREVIEW_PATH: &str = "/admin/notes/preview";
+
+#[cfg(feature = "admin")]
+pub async fn preview_note(Form(form): Form<NotePreviewForm>) -> Html<String> {
+    Html(crate::markdown::render(&form.content))
+}
+
+/// Script that shows a live preview under every `textarea[data-note-preview]`,
+/// rendered by the server so it matches what the site will show
+#[cfg(feature = "admin")]
+pub fn note_preview_script() -> String {
+    format!(
+        r#"
+    <script nonce="{nonce}">
+        document.querySelectorAll('textarea[data-note-preview]').forEach((textarea) => {{
+            const preview = document.createElement('div');
+            preview.className = 'note-preview';
+            preview.style.cssText = 'border: 1px dashed #ccc; border-radius: 4px; padding: 8px; margin-top: 8px; min-height: 1em;';
+            textarea.insertAdjacentElement('afterend', preview);
+
+            let timer;
+            textarea.addEventListener('input', () => {{
+                clearTimeout(timer);
+                timer = setTimeout(async () => {{
+                    const body = new URLSearchParams({{ content: textarea.value }});
+                    const token = textarea.form && textarea.form.querySelector('input[name="csrf_token"]');
+                    if (token) body.set('csrf_token', token.value);
+                    const response = await fetch('{path}', {{ method: 'POST', body }});
+                    if (response.ok) preview.innerHTML = await response.text();
+                }}, 300);
+            }});
+        }});
+    </script>
+"#,
+        nonce = crate::security::csp_nonce(),
+        path = NOTE_PREVIEW_PATH,
+    )
+}
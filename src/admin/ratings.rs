@@ -19,6 +19,8 @@ use sqlx::Row;
 use crate::AppState;
 #[cfg(feature = "admin")]
 use crate::admin::types::*;
+#[cfg(feature = "admin")]
+use crate::locations::LocationKind;
 
 #[cfg(feature = "admin")]
 pub async fn list_all_ratings(
@@ -34,35 +36,41 @@ pub async fn list_all_ratings(
         .clone()
         .unwrap_or_else(|| "created_at".to_string());
     let order = params.order.clone().unwrap_or_else(|| "desc".to_string());
+    let kind = params.kind.unwrap_or(LocationKind::Zone);
+    let table = kind.child_table("ratings");
+    let location_table = kind.table();
+    let location_column = kind.id_column();
 
     let pool = &state.zone_state.pool;
 
-    // Validate sort column and order
-    let valid_columns = ["id", "zone_id", "rating", "created_at", "updated_at"];
-    let sort_column = if valid_columns.contains(&sort.as_str()) {
-        sort.as_str()
-    } else {
-        "created_at"
+    // Validate sort column and order; "location" sorts by the rated zone or instance
+    let valid_columns = ["id", "location", "rating", "created_at", "updated_at"];
+    let sort_column = match sort.as_str() {
+        "location" => location_column,
+        column if valid_columns.contains(&column) => column,
+        _ => "created_at",
     };
 
     let sort_order = if order == "asc" { "ASC" } else { "DESC" };
 
-    // Check if zone_ratings table exists first
+    // Check if the ratings table exists first
     let table_exists = sqlx::query_scalar::<_, i64>(
-        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='zone_ratings'",
+        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name=?",
     )
+    .bind(&table)
     .fetch_one(pool.as_ref())
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     if table_exists == 0 {
+        let nonce = crate::security::csp_nonce();
         return Ok(Html(format!(
             r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Ratings - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -76,46 +84,46 @@ pub async fn list_all_ratings(
     </div>
     <h1>Manage Ratings</h1>
     <p>No ratings table found. The ratings system may not be initialized yet.</p>
-    <p>Try creating some zone ratings first from the main application.</p>
+    <p>Try creating some {kind} ratings first from the main application.</p>
 </body>
 </html>
-"#
+"#,
+            kind = kind.as_str()
         )));
     }
 
     // Get total count - simplified query with error fallback
     let total_count: i64 = if search.is_empty() {
-        sqlx::query_scalar("SELECT COUNT(*) FROM zone_ratings")
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
             .fetch_one(pool.as_ref())
             .await
             .unwrap_or(0)
     } else {
-        sqlx::query_scalar(
-            "SELECT COUNT(*) FROM zone_ratings LEFT JOIN zones ON zone_ratings.zone_id = zones.id WHERE zones.name LIKE ?"
-        )
+        sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM {table} r LEFT JOIN {location_table} l ON r.{location_column} = l.id WHERE l.name LIKE ?"
+        ))
         .bind(format!("%{}%", search))
         .fetch_one(pool.as_ref())
         .await
         .unwrap_or(0)
     };
 
-    // Get ratings with zone names - simplified query with better error handling
+    // Get ratings with location names - simplified query with better error handling
     let rating_rows = if search.is_empty() {
         sqlx::query(&format!(
             r#"
             SELECT
-                zone_ratings.id,
-                zone_ratings.zone_id,
-                zone_ratings.rating,
-                zone_ratings.created_at,
-                zone_ratings.updated_at,
-                COALESCE(zones.name, 'Unknown Zone') as zone_name
-            FROM zone_ratings
-            LEFT JOIN zones ON zone_ratings.zone_id = zones.id
-            ORDER BY zone_ratings.{} {}
+                r.id,
+                r.{location_column} AS location_id,
+                r.rating,
+                r.created_at,
+                r.updated_at,
+                l.name AS location_name
+            FROM {table} r
+            LEFT JOIN {location_table} l ON r.{location_column} = l.id
+            ORDER BY r.{sort_column} {sort_order}
             LIMIT ? OFFSET ?
-            "#,
-            sort_column, sort_order
+            "#
         ))
         .bind(per_page)
         .bind(offset)
@@ -126,19 +134,18 @@ pub async fn list_all_ratings(
         sqlx::query(&format!(
             r#"
             SELECT
-                zone_ratings.id,
-                zone_ratings.zone_id,
-                zone_ratings.rating,
-                zone_ratings.created_at,
-                zone_ratings.updated_at,
-                COALESCE(zones.name, 'Unknown Zone') as zone_name
-            FROM zone_ratings
-            LEFT JOIN zones ON zone_ratings.zone_id = zones.id
-            WHERE zones.name LIKE ?
-            ORDER BY zone_ratings.{} {}
+                r.id,
+                r.{location_column} AS location_id,
+                r.rating,
+                r.created_at,
+                r.updated_at,
+                l.name AS location_name
+            FROM {table} r
+            LEFT JOIN {location_table} l ON r.{location_column} = l.id
+            WHERE l.name LIKE ?
+            ORDER BY r.{sort_column} {sort_order}
             LIMIT ? OFFSET ?
-            "#,
-            sort_column, sort_order
+            "#
         ))
         .bind(format!("%{}%", search))
         .bind(per_page)
@@ -164,6 +171,7 @@ pub async fn list_all_ratings(
         };
 
         let mut query_params = vec![
+            format!("kind={}", kind.as_str()),
             format!("sort={}", column),
             format!("order={}", new_order),
             format!("per_page={}", per_page),
@@ -173,48 +181,67 @@ pub async fn list_all_ratings(
         }
 
         format!(
-            r#"<a href="/admin/ratings?{}" style="text-decoration: none; color: inherit;">{}{}</a>"#,
+            r#"<a href="/admin/ratings?{}" class="sort-link">{}{}</a>"#,
             query_params.join("&"),
             display_name,
             arrow
         )
     };
 
+    // Zone and instance ratings live in separate tables, so each gets its own tab
+    let kind_tabs = LocationKind::ALL
+        .into_iter()
+        .map(|tab| {
+            let class = if tab == kind {
+                "btn"
+            } else {
+                "btn btn-secondary"
+            };
+            format!(
+                r#"<a href="/admin/ratings?kind={}" class="{}">{} ratings</a>"#,
+                tab.as_str(),
+                class,
+                tab.as_str()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Ratings - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
         .nav a:hover {{ color: #007bff; }}
         .controls {{ margin-bottom: 20px; display: flex; gap: 10px; align-items: center; flex-wrap: wrap; }}
         .controls input {{ padding: 8px; border: 1px solid #ddd; border-radius: 4px; }}
+        .search-form {{ display: flex; gap: 10px; align-items: center; }}
         .btn {{ background: #007bff; color: white; padding: 8px 15px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
         .btn:hover {{ background: #0056b3; }}
         .btn-danger {{ background: #dc3545; }}
         .btn-danger:hover {{ background: #c82333; }}
+        .btn-secondary {{ background: #6c757d; }}
+        .btn-secondary:hover {{ background: #545b62; }}
         .btn-small {{ padding: 4px 8px; font-size: 0.8em; }}
         table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
         th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; }}
         th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
         th a:hover {{ background-color: #e9ecef; padding: 4px; border-radius: 3px; }}
+        .sort-link {{ text-decoration: none; color: inherit; }}
+        .location-link {{ text-decoration: none; color: #007bff; }}
+        .hidden {{ display: none; }}
         .pagination {{ display: flex; gap: 5px; align-items: center; }}
         .pagination a {{ padding: 8px 12px; text-decoration: none; border: 1px solid #ddd; border-radius: 4px; }}
         .pagination a.current {{ background: #007bff; color: white; }}
         .rating-stars {{ color: #ffc107; }}
         .text-center {{ text-align: center; }}
     </style>
-    <script>
-        function deleteRating(id, zoneName) {{
-            if (confirm('Are you sure you want to delete this rating for "' + zoneName + '"?')) {{
-                document.getElementById('delete-form-' + id).submit();
-            }}
-        }}
-    </script>
 </head>
 <body>
     <div class="nav">
@@ -230,8 +257,10 @@ pub async fn list_all_ratings(
     <h1>Manage Ratings</h1>
 
     <div class="controls">
-        <form method="get" style="display: flex; gap: 10px; align-items: center;">
-            <input type="text" name="search" placeholder="Search by zone name..." value="{}" />
+        {}
+        <form method="get" class="search-form">
+            <input type="hidden" name="kind" value="{}" />
+            <input type="text" name="search" placeholder="Search by {} name..." value="{}" />
             <input type="hidden" name="page" value="1" />
             <input type="hidden" name="per_page" value="{}" />
             <button type="submit" class="btn">Search</button>
@@ -253,13 +282,23 @@ pub async fn list_all_ratings(
         </thead>
         <tbody>
 "#,
+        kind_tabs,
+        kind.as_str(),
+        kind.as_str(),
         search,
         per_page,
         total_count as i32,
         page,
         total_pages,
         make_sort_link("id", "ID"),
-        make_sort_link("zone_id", "Zone Name"),
+        make_sort_link(
+            "location",
+            if kind == LocationKind::Zone {
+                "Zone Name"
+            } else {
+                "Instance Name"
+            },
+        ),
         make_sort_link("rating", "Rating"),
         make_sort_link("created_at", "Created"),
         make_sort_link("updated_at", "Updated"),
@@ -268,11 +307,11 @@ pub async fn list_all_ratings(
     // Add rating rows
     for row in rating_rows {
         let id: i64 = row.get("id");
-        let zone_id: i64 = row.get("zone_id");
+        let location_id: i64 = row.get("location_id");
         let rating: i32 = row.get("rating");
-        let zone_name: String = row
-            .get::<Option<String>, _>("zone_name")
-            .unwrap_or_else(|| format!("Zone #{}", zone_id));
+        let location_name: String = row
+            .get::<Option<String>, _>("location_name")
+            .unwrap_or_else(|| format!("{} #{}", kind.as_str(), location_id));
         let created_at: String = row.get("created_at");
         let updated_at: String = row.get("updated_at");
 
@@ -283,28 +322,30 @@ pub async fn list_all_ratings(
             r#"
             <tr>
                 <td class="text-center">{}</td>
-                <td><a href="/admin/zones/{}" style="text-decoration: none; color: #007bff;">{}</a></td>
+                <td><a href="/admin/{}/{}" class="location-link">{}</a></td>
                 <td class="text-center"><span class="rating-stars" title="{}/5">{}</span></td>
                 <td class="text-center">{}</td>
                 <td class="text-center">{}</td>
                 <td class="text-center">
-                    <button onclick="deleteRating({}, '{}')" class="btn btn-danger btn-small">Delete</button>
-                    <form id="delete-form-{}" method="post" action="/admin/ratings/{}/delete" style="display: none;">
+                    <button type="button" data-submit-form="delete-form-{}" data-confirm="Are you sure you want to delete this rating for &quot;{}&quot;?" class="btn btn-danger btn-small">Delete</button>
+                    <form id="delete-form-{}" method="post" action="/admin/ratings/{}/{}/delete" class="hidden">
                         <input type="hidden" name="_method" value="DELETE" />
                     </form>
                 </td>
             </tr>
             "#,
             id,
-            zone_id,
-            zone_name,
+            location_table,
+            location_id,
+            location_name,
             rating,
             rating_stars,
             created_at.split('T').next().unwrap_or(&created_at),
             updated_at.split('T').next().unwrap_or(&updated_at),
             id,
-            zone_name.replace("'", "\\'"),
+            crate::security::escape_html_attribute(&location_name),
             id,
+            kind.as_str(),
             id
         ));
     }
@@ -317,6 +358,7 @@ pub async fn list_all_ratings(
 
         if page > 1 {
             let mut prev_params = vec![
+                format!("kind={}", kind.as_str()),
                 format!("page={}", page - 1),
                 format!("per_page={}", per_page),
             ];
@@ -340,7 +382,11 @@ pub async fn list_all_ratings(
             if p == page {
                 html.push_str(&format!(r#"<span class="pagination current">{}</span>"#, p));
             } else {
-                let mut page_params = vec![format!("page={}", p), format!("per_page={}", per_page)];
+                let mut page_params = vec![
+                    format!("kind={}", kind.as_str()),
+                    format!("page={}", p),
+                    format!("per_page={}", per_page),
+                ];
                 if !search.is_empty() {
                     page_params.push(format!("search={}", urlencoding::encode(&search)));
                 }
@@ -361,6 +407,7 @@ pub async fn list_all_ratings(
 
         if page < total_pages {
             let mut next_params = vec![
+                format!("kind={}", kind.as_str()),
                 format!("page={}", page + 1),
                 format!("per_page={}", per_page),
             ];
@@ -391,34 +438,15 @@ pub async fn list_all_ratings(
 #[cfg(feature = "admin")]
 pub async fn handle_rating_delete(
     State(state): State<AppState>,
-    Path(id): Path<i32>,
+    Path((kind, id)): Path<(LocationKind, i64)>,
     Form(_form): Form<HashMap<String, String>>,
 ) -> Result<Redirect, StatusCode> {
-    let pool = &state.zone_state.pool;
-
-    // Delete the rating
-    sqlx::query("DELETE FROM zone_ratings WHERE id = ?")
-        .bind(id)
-        .execute(pool.as_ref())
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    Ok(Redirect::to("/admin/ratings"))
-}
-
-#[cfg(feature = "admin")]
-pub async fn delete_rating_admin(
-    State(state): State<AppState>,
-    Path(id): Path<i32>,
-) -> Result<Redirect, StatusCode> {
-    let pool = &state.zone_state.pool;
-
-    // Delete the rating
-    sqlx::query("DELETE FROM zone_ratings WHERE id = ?")
-        .bind(id)
-        .execute(pool.as_ref())
+    crate::ratings::delete_location_rating(&state, kind, id)
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    Ok(Redirect::to("/admin/ratings"))
+    Ok(Redirect::to(&format!(
+        "/admin/ratings?kind={}",
+        kind.as_str()
+    )))
 }
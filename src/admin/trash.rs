@@ -0,0 +1,164 @@
+// Trash-related admin functionality
+// This file lists soft-deleted zones, instances and links and lets an admin
+// restore them or purge them for good
+
+#[cfg(feature = "admin")]
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, Redirect},
+};
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::security::escape_html;
+#[cfg(feature = "admin")]
+use crate::trash::{TrashKind, list_trash, purge, restore};
+
+#[cfg(feature = "admin")]
+pub async fn list_trash_admin(State(state): State<AppState>) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let items = list_trash(pool.as_ref()).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list trash");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let rows: String = items
+        .iter()
+        .map(|item| {
+            let kind = item.kind.as_str();
+            format!(
+                r#"<tr>
+                <td>{}</td>
+                <td>{} <a href="/admin/audit?entity_type={}&entity_id={}" class="small">history</a></td>
+                <td>{}</td>
+                <td>
+                    <form method="post" action="/admin/trash/{}/{}/restore" class="inline-form">
+                        <button type="submit" class="btn">Restore</button>
+                    </form>
+                    <form method="post" action="/admin/trash/{}/{}/purge" class="inline-form" data-confirm="Permanently delete {} #{}? Its notes, flags, aliases and ratings go with it.">
+                        <button type="submit" class="btn btn-danger">Delete permanently</button>
+                    </form>
+                </td>
+            </tr>"#,
+                kind,
+                escape_html(&item.name),
+                kind,
+                item.id,
+                escape_html(&item.deleted_at),
+                kind,
+                item.id,
+                kind,
+                item.id,
+                kind,
+                item.id,
+            )
+        })
+        .collect();
+
+    let retention_days = state.config.trash.retention_days;
+    let retention = if retention_days > 0 {
+        format!(
+            "Items are purged automatically {} days after deletion.",
+            retention_days
+        )
+    } else {
+        "Items stay here until purged by hand.".to_string()
+    };
+
+    let nonce = crate::security::csp_nonce();
+    Ok(Html(format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Trash - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .btn {{ background: #007bff; color: white; padding: 6px 12px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        .btn-danger {{ background: #dc3545; }}
+        .btn-danger:hover {{ background: #b02a37; }}
+        table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; }}
+        th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
+        .small {{ font-size: 0.85em; }}
+        .inline-form {{ display: inline; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/links">Manage Links</a>
+        <a href="/admin/audit">Audit Log</a>
+        <a href="/admin/trash">Trash</a>
+    </div>
+
+    <h1>Trash</h1>
+    <p>Deleted zones, instances and links are hidden from the site but keep their notes, flags, aliases and ratings. {}</p>
+    <table>
+        <thead>
+            <tr><th>Type</th><th>Name</th><th>Deleted (UTC)</th><th>Actions</th></tr>
+        </thead>
+        <tbody>
+            {}
+        </tbody>
+    </table>
+</body>
+</html>
+        "#,
+        retention,
+        if rows.is_empty() {
+            r#"<tr><td colspan="4">The trash is empty</td></tr>"#.to_string()
+        } else {
+            rows
+        },
+    )))
+}
+
+#[cfg(feature = "admin")]
+pub async fn restore_trash_item(
+    State(state): State<AppState>,
+    Path((kind, id)): Path<(String, i64)>,
+) -> Result<Redirect, StatusCode> {
+    let pool = &state.zone_state.pool;
+    let kind = TrashKind::parse(&kind).ok_or(StatusCode::NOT_FOUND)?;
+
+    let restored = restore(pool.as_ref(), kind, id).await.map_err(|e| {
+        tracing::error!(error = %e, kind = kind.as_str(), id, "Failed to restore from trash");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !restored {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let _ = crate::checkpoint_wal(pool.as_ref()).await;
+    Ok(Redirect::to("/admin/trash"))
+}
+
+#[cfg(feature = "admin")]
+pub async fn purge_trash_item(
+    State(state): State<AppState>,
+    Path((kind, id)): Path<(String, i64)>,
+) -> Result<Redirect, StatusCode> {
+    let pool = &state.zone_state.pool;
+    let kind = TrashKind::parse(&kind).ok_or(StatusCode::NOT_FOUND)?;
+
+    let purged = purge(pool.as_ref(), kind, id).await.map_err(|e| {
+        tracing::error!(error = %e, kind = kind.as_str(), id, "Failed to purge from trash");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !purged {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let _ = crate::checkpoint_wal(pool.as_ref()).await;
+    Ok(Redirect::to("/admin/trash"))
+}
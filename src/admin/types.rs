@@ -47,9 +47,23 @@ pub struct InstanceForm {
     pub image_url: String,
     pub map_url: String,
     pub rating: i32,
-    pub hot_zone: Option<String>, // HTML forms send "on" or nothing
     pub verified: Option<String>, // HTML forms send "on" or nothing
-    pub _method: Option<String>,  // For method override
+    // Blank numbers mean unknown
+    #[serde(default)]
+    pub min_players: String,
+    #[serde(default)]
+    pub max_players: String,
+    #[serde(default)]
+    pub lockout_minutes: String,
+    #[serde(default)]
+    pub request_npc: String,
+    #[serde(default)]
+    pub request_zone: String,
+    #[serde(default)]
+    pub task_type: String,
+    #[serde(default)]
+    pub classification: String,
+    pub _method: Option<String>, // For method override
 }
 
 #[cfg(feature = "admin")]
@@ -82,6 +96,7 @@ pub struct PaginationQuery {
     pub zone_type: Option<String>,
     pub expansion: Option<String>,
     pub flags: Option<String>,
+    pub kind: Option<crate::locations::LocationKind>,
 }
 
 #[cfg(feature = "admin")]
@@ -97,6 +112,18 @@ pub struct ZoneFlagForm {
     pub flag_type_id: i64,
 }
 
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct InstanceFlagForm {
+    pub flag_type_id: i64,
+}
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct AliasForm {
+    pub alias: String,
+}
+
 #[cfg(feature = "admin")]
 #[derive(Debug, Deserialize)]
 pub struct InstanceNoteForm {
@@ -104,6 +131,11 @@ pub struct InstanceNoteForm {
     pub content: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct NotePreviewForm {
+    pub content: String,
+}
+
 #[cfg(feature = "admin")]
 #[derive(Debug, Deserialize)]
 pub struct NoteTypeForm {
@@ -130,3 +162,43 @@ pub struct LinkForm {
     pub description: Option<String>,
     pub _method: Option<String>,
 }
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct LoginForm {
+    pub username: String,
+    pub password: String,
+    pub next: Option<String>,
+}
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct LoginQuery {
+    pub next: Option<String>,
+}
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct AdminUserForm {
+    pub username: String,
+    pub password: String,
+    pub role: String,
+}
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct AdminRoleForm {
+    pub role: String,
+}
+
+#[cfg(feature = "admin")]
+#[derive(Debug, Deserialize)]
+pub struct ApiKeyForm {
+    pub name: String,
+    /// Checkboxes are only sent when ticked
+    pub scope_read: Option<String>,
+    pub scope_rate: Option<String>,
+    pub scope_admin_read: Option<String>,
+    pub rate_limit_per_minute: u32,
+    pub daily_quota: u32,
+}
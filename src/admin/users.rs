@@ -0,0 +1,265 @@
+// Admin user management
+// This file lists admin accounts and lets owners add admins, change their roles and
+// remove them
+
+#[cfg(feature = "admin")]
+use axum::{
+    Extension,
+    extract::{Form, Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+};
+
+#[cfg(feature = "admin")]
+use crate::AppState;
+#[cfg(feature = "admin")]
+use crate::admin::types::{AdminRoleForm, AdminUserForm};
+#[cfg(feature = "admin")]
+use crate::auth::{self, AdminUser, Role};
+#[cfg(feature = "admin")]
+use crate::error::AppError;
+#[cfg(feature = "admin")]
+use crate::security::{escape_html, escape_html_attribute};
+
+#[cfg(feature = "admin")]
+fn role_options(selected: Role) -> String {
+    Role::ALL
+        .iter()
+        .map(|role| {
+            format!(
+                r#"<option value="{}"{}>{}</option>"#,
+                role.as_str(),
+                if *role == selected { " selected" } else { "" },
+                role.label()
+            )
+        })
+        .collect()
+}
+
+#[cfg(feature = "admin")]
+fn parse_role(value: &str) -> Result<Role, AppError> {
+    Role::parse(value).ok_or_else(|| AppError::Validation(format!("Unknown role '{}'", value)))
+}
+
+/// Redirect back to the users page, or show why the change was refused
+#[cfg(feature = "admin")]
+fn finish(result: Result<(), AppError>, action: &str) -> Result<Response, StatusCode> {
+    match result {
+        Ok(()) => Ok(Redirect::to("/admin/users").into_response()),
+        Err(AppError::Validation(message)) => Ok(Html(format!(
+            r#"<h1>Error</h1><p>{}</p><a href="/admin/users">Go back</a>"#,
+            escape_html(&message)
+        ))
+        .into_response()),
+        Err(e) => {
+            tracing::error!(error = %e, action, "Failed to update admin users");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[cfg(feature = "admin")]
+pub async fn list_admin_users(
+    State(state): State<AppState>,
+    Extension(current): Extension<AdminUser>,
+) -> Result<Html<String>, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let accounts = auth::list_admin_users(pool.as_ref()).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list admin users");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let rows: String = accounts
+        .iter()
+        .map(|account| {
+            let remove = if account.id == current.id {
+                "<em>you</em>".to_string()
+            } else {
+                format!(
+                    r#"<form method="post" action="/admin/users/{id}/delete" class="inline-form" data-confirm="Remove {name}? They are signed out at once.">
+                    <button type="submit" class="btn btn-danger btn-small">Remove</button>
+                </form>"#,
+                    id = account.id,
+                    name = escape_html_attribute(&account.username)
+                )
+            };
+            format!(
+                r#"
+            <tr>
+                <td>{name}</td>
+                <td>
+                    <form method="post" action="/admin/users/{id}/role" class="inline-form">
+                        <select name="role">{options}</select>
+                        <button type="submit" class="btn btn-small">Save</button>
+                    </form>
+                </td>
+                <td>{created}</td>
+                <td>{last_login}</td>
+                <td>{remove}</td>
+            </tr>"#,
+                name = escape_html(&account.username),
+                id = account.id,
+                options = role_options(account.role),
+                created = escape_html(account.created_at.as_deref().unwrap_or("")),
+                last_login = escape_html(account.last_login_at.as_deref().unwrap_or("never")),
+                remove = remove,
+            )
+        })
+        .collect();
+
+    let role_help: String = Role::ALL
+        .iter()
+        .map(|role| {
+            format!(
+                "<li><strong>{}</strong>: {}</li>",
+                role.label(),
+                match role {
+                    Role::Viewer => "browse every admin page but change nothing",
+                    Role::Editor =>
+                        "edit zones, instances, notes, flags, aliases, note/flag types and links",
+                    Role::Moderator =>
+                        "also delete ratings, purge the trash and revert audited changes",
+                    Role::Owner =>
+                        "also manage admin users, backups, database dumps and migrations",
+                }
+            )
+        })
+        .collect();
+
+    let nonce = crate::security::csp_nonce();
+    Ok(Html(format!(
+        r#"
+<!DOCTYPE html>
+<html>
+<head>
+    <title>Admin Users - EQ RNG Admin</title>
+    <style nonce="{nonce}">
+        body {{ font-family: Arial, sans-serif; max-width: 1000px; margin: 0 auto; padding: 20px; }}
+        .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
+        .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
+        .nav a:hover {{ color: #007bff; }}
+        .form-section {{ background: #f8f9fa; padding: 20px; margin-bottom: 20px; border-radius: 5px; }}
+        .form-group {{ margin-bottom: 15px; display: inline-block; margin-right: 15px; }}
+        label {{ display: block; margin-bottom: 5px; font-weight: bold; }}
+        input, select {{ padding: 8px; border: 1px solid #ddd; border-radius: 4px; }}
+        .btn {{ background: #007bff; color: white; padding: 8px 15px; text-decoration: none; border-radius: 4px; border: none; cursor: pointer; }}
+        .btn:hover {{ background: #0056b3; }}
+        .btn-danger {{ background: #dc3545; }}
+        .btn-danger:hover {{ background: #c82333; }}
+        .btn-small {{ padding: 4px 8px; font-size: 0.8em; }}
+        table {{ width: 100%; border-collapse: collapse; margin-bottom: 20px; }}
+        th, td {{ padding: 8px; border: 1px solid #ddd; text-align: left; }}
+        th {{ background: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
+        .inline-form {{ display: inline; }}
+        .add-user-form {{ display: flex; align-items: end; gap: 15px; flex-wrap: wrap; }}
+    </style>
+</head>
+<body>
+    <div class="nav">
+        <a href="/admin">Dashboard</a>
+        <a href="/admin/zones">Manage Zones</a>
+        <a href="/admin/instances">Manage Instances</a>
+        <a href="/admin/audit">Audit Log</a>
+        <a href="/admin/users">Admin Users</a>
+    </div>
+
+    <h1>Admin Users</h1>
+
+    <div class="form-section">
+        <h2>Add Admin</h2>
+        <form method="post" action="/admin/users" class="add-user-form">
+            <div class="form-group">
+                <label for="username">Username:</label>
+                <input type="text" id="username" name="username" maxlength="{max_username}" required />
+            </div>
+            <div class="form-group">
+                <label for="password">Password:</label>
+                <input type="password" id="password" name="password" minlength="{min_password}" autocomplete="new-password" required />
+            </div>
+            <div class="form-group">
+                <label for="role">Role:</label>
+                <select id="role" name="role">{new_role_options}</select>
+            </div>
+            <div class="form-group">
+                <button type="submit" class="btn">Add Admin</button>
+            </div>
+        </form>
+        <ul>{role_help}</ul>
+    </div>
+
+    <table>
+        <tr><th>Username</th><th>Role</th><th>Created</th><th>Last sign-in</th><th></th></tr>
+        {rows}
+    </table>
+</body>
+</html>
+        "#,
+        max_username = auth::MAX_USERNAME_LENGTH,
+        min_password = auth::MIN_PASSWORD_LENGTH,
+        new_role_options = role_options(Role::Viewer),
+        role_help = role_help,
+        rows = rows,
+    )))
+}
+
+#[cfg(feature = "admin")]
+pub async fn create_admin_user(
+    State(state): State<AppState>,
+    Form(form): Form<AdminUserForm>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let result = async {
+        let role = parse_role(&form.role)?;
+        auth::create_admin_user(pool.as_ref(), form.username.trim(), &form.password, role).await
+    }
+    .await;
+    if let Ok(id) = result {
+        crate::logging::log_admin_action("create", "admin_user", Some(id), None);
+    }
+    finish(result.map(|_| ()), "create")
+}
+
+#[cfg(feature = "admin")]
+pub async fn update_admin_role(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<AdminRoleForm>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let result = async {
+        let role = parse_role(&form.role)?;
+        auth::set_admin_role(pool.as_ref(), id, role).await
+    }
+    .await;
+    if result.is_ok() {
+        crate::logging::log_admin_action("update", "admin_user", Some(id), None);
+    }
+    finish(result, "update role")
+}
+
+#[cfg(feature = "admin")]
+pub async fn delete_admin_user(
+    State(state): State<AppState>,
+    Extension(current): Extension<AdminUser>,
+    Path(id): Path<i64>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    if id == current.id {
+        return finish(
+            Err(AppError::Validation(
+                "You cannot remove your own account".to_string(),
+            )),
+            "delete",
+        );
+    }
+
+    let result = auth::delete_admin_user(pool.as_ref(), id).await;
+    if result.is_ok() {
+        crate::logging::log_admin_action("delete", "admin_user", Some(id), None);
+    }
+    finish(result, "delete")
+}
@@ -27,7 +27,7 @@ pub async fn dump_database_sql(State(_state): State<AppState>) -> Result<Html<St
     // Use SQLite's built-in .dump command for complete schema and data
     let output = std::process::Command::new("sqlite3")
         .arg("data/zones.db")
-        .args(&[".mode insert", ".headers off", ".dump"])
+        .args([".mode insert", ".headers off", ".dump"])
         .output()
         .map_err(|e| {
             tracing::error!(error = %e, "Failed to execute sqlite3 command");
@@ -53,6 +53,7 @@ pub async fn dump_database_sql(State(_state): State<AppState>) -> Result<Html<St
     let total_records = stdout_clone.iter().filter(|&&b| b == b';').count();
 
     // Return success page
+    let nonce = crate::security::csp_nonce();
     let html = format!(
         r#"
         <!DOCTYPE html>
@@ -61,35 +62,38 @@ pub async fn dump_database_sql(State(_state): State<AppState>) -> Result<Html<St
             <title>Database Export Complete</title>
             <meta charset="utf-8">
             <meta name="viewport" content="width=device-width, initial-scale=1">
-            <script src="https://cdn.tailwindcss.com"></script>
+            <style nonce="{nonce}">
+                body {{ font-family: Arial, sans-serif; background: #f3f4f6; margin: 0; padding: 32px 16px; }}
+                .card {{ max-width: 640px; margin: 0 auto; background: white; border-radius: 8px; box-shadow: 0 2px 6px rgba(0, 0, 0, 0.1); padding: 24px; text-align: center; }}
+                .icon {{ font-size: 3.5em; margin-bottom: 16px; }}
+                h1 {{ color: #1f2937; margin-bottom: 16px; }}
+                p {{ color: #4b5563; }}
+                code {{ background: #f3f4f6; padding: 2px 8px; border-radius: 4px; font-size: 0.9em; }}
+                .summary {{ background: #eff6ff; border: 1px solid #bfdbfe; border-radius: 8px; padding: 16px; margin: 24px 0; }}
+                .summary p {{ color: #1e40af; margin: 0; }}
+                .btn {{ display: inline-block; background: #007bff; color: white; padding: 8px 24px; margin: 6px 0; text-decoration: none; border-radius: 8px; font-weight: bold; }}
+                .btn:hover {{ background: #0056b3; }}
+                .btn-secondary {{ background: #6c757d; }}
+                .btn-secondary:hover {{ background: #545b62; }}
+            </style>
         </head>
-        <body class="bg-gray-100 min-h-screen">
-            <div class="container mx-auto px-4 py-8">
-                <div class="max-w-2xl mx-auto bg-white rounded-lg shadow-md p-6">
-                    <div class="text-center">
-                        <div class="text-green-500 text-6xl mb-4">✅</div>
-                        <h1 class="text-2xl font-bold text-gray-800 mb-4">Database Export Complete</h1>
-                        <p class="text-gray-600 mb-6">
-                            Your database has been successfully exported to:<br>
-                            <code class="bg-gray-100 px-2 py-1 rounded text-sm font-mono">{}</code>
-                        </p>
-                        <div class="bg-blue-50 border border-blue-200 rounded-lg p-4 mb-6">
-                            <p class="text-blue-800">
-                                <strong>Records exported:</strong> {} (estimated)<br>
-                                <strong>File size:</strong> {:.1} KB
-                            </p>
-                        </div>
-                        <div class="space-y-3">
-                            <a href="/admin" class="inline-block bg-blue-500 hover:bg-blue-600 text-white font-semibold py-2 px-6 rounded-lg transition-colors">
-                                ← Back to Admin Dashboard
-                            </a>
-                            <br>
-                            <a href="/admin/zones" class="inline-block bg-gray-500 hover:bg-gray-600 text-white font-semibold py-2 px-6 rounded-lg transition-colors">
-                                View Zones
-                            </a>
-                        </div>
-                    </div>
+        <body>
+            <div class="card">
+                <div class="icon">✅</div>
+                <h1>Database Export Complete</h1>
+                <p>
+                    Your database has been successfully exported to:<br>
+                    <code>{}</code>
+                </p>
+                <div class="summary">
+                    <p>
+                        <strong>Records exported:</strong> {} (estimated)<br>
+                        <strong>File size:</strong> {:.1} KB
+                    </p>
                 </div>
+                <a href="/admin" class="btn">← Back to Admin Dashboard</a>
+                <br>
+                <a href="/admin/zones" class="btn btn-secondary">View Zones</a>
             </div>
         </body>
         </html>
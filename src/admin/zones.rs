@@ -3,17 +3,20 @@ use axum::{
     Form,
     extract::{Path, Query, State},
     http::StatusCode,
-    response::{Html, Redirect},
+    response::{Html, IntoResponse, Redirect, Response},
 };
 
 use sqlx::Row;
 use urlencoding;
 
 use crate::admin::dashboard::{
-    generate_expansion_options, generate_sortable_header, generate_zone_type_options,
-    get_distinct_expansions, get_distinct_zone_types,
+    SORTABLE_HEADER_STYLES, generate_expansion_options, generate_sortable_header,
+    generate_zone_type_options, get_distinct_expansions, get_distinct_zone_types,
 };
 use crate::admin::types::*;
+use crate::aliases::{self, LocationKind};
+use crate::audit::{self, AuditAction, AuditEntity};
+use crate::trash::TrashKind;
 use crate::{AppState, security};
 
 #[cfg(feature = "admin")]
@@ -60,12 +63,20 @@ pub async fn list_zones(
 
     let sort_order = if order == "asc" { "ASC" } else { "DESC" };
 
-    // Build search query
-    let mut where_conditions = Vec::new();
+    // Build search query; trashed rows are listed on the trash page instead
+    let mut where_conditions = vec!["deleted_at IS NULL".to_string()];
     let mut search_param = None;
+    let mut fts_param = None;
 
     if !search.is_empty() {
-        where_conditions.push("(name LIKE ? OR expansion LIKE ? OR zone_type LIKE ?)".to_string());
+        // Full-text matches on names and note content widen the substring search
+        fts_param = crate::search::build_match_query(&search);
+        if fts_param.is_some() {
+            where_conditions.push("(name LIKE ? OR expansion LIKE ? OR zone_type LIKE ? OR id IN (SELECT CASE kind WHEN 'zone' THEN ref_id ELSE (SELECT zone_id FROM zone_notes WHERE zone_notes.id = ref_id) END FROM search_fts WHERE search_fts MATCH ? AND kind IN ('zone', 'zone_note')))".to_string());
+        } else {
+            where_conditions
+                .push("(name LIKE ? OR expansion LIKE ? OR zone_type LIKE ?)".to_string());
+        }
         search_param = Some(format!("%{}%", search));
     }
 
@@ -106,6 +117,10 @@ pub async fn list_zones(
             .bind(search_term);
     }
 
+    if let Some(ref fts_query) = fts_param {
+        count_query_builder = count_query_builder.bind(fts_query);
+    }
+
     if let Some(ref zone_type) = params.zone_type {
         count_query_builder = count_query_builder.bind(format!("%{}%", zone_type));
     }
@@ -139,6 +154,10 @@ pub async fn list_zones(
             .bind(search_term);
     }
 
+    if let Some(ref fts_query) = fts_param {
+        zones_query_builder = zones_query_builder.bind(fts_query);
+    }
+
     if let Some(ref zone_type) = params.zone_type {
         zones_query_builder = zones_query_builder.bind(format!("%{}%", zone_type));
     }
@@ -188,13 +207,14 @@ pub async fn list_zones(
 
     let total_pages = (total_count + per_page - 1) / per_page;
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Manage Zones - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1400px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -216,15 +236,34 @@ pub async fn list_zones(
         th {{ background-color: #f8f9fa; border-bottom: 2px solid #dee2e6; }}
         th a:hover {{ background-color: #e9ecef; padding: 4px; border-radius: 3px; }}
         .flag-pills {{ display: flex; flex-wrap: wrap; gap: 4px; max-width: 150px; }}
-        .flag-pill {{ display: inline-block; padding: 4px 8px; margin: 2px; border-radius: 12px; color: white; font-size: 0.75em; font-weight: bold; white-space: nowrap; }}
+        .flag-pill {{ display: inline-block; padding: 4px 8px; margin: 2px; border-radius: 12px; color: white; font-size: 0.75em; font-weight: bold; white-space: nowrap; background-color: #3b82f6; }}
+        .flag-pill.bg-red-500 {{ background-color: #ef4444; }}
+        .flag-pill.bg-orange-500 {{ background-color: #f97316; }}
+        .flag-pill.bg-yellow-500 {{ background-color: #eab308; }}
+        .flag-pill.bg-green-500 {{ background-color: #22c55e; }}
+        .flag-pill.bg-blue-500 {{ background-color: #3b82f6; }}
+        .flag-pill.bg-indigo-500 {{ background-color: #6366f1; }}
+        .flag-pill.bg-purple-500 {{ background-color: #a855f7; }}
+        .flag-pill.bg-pink-500 {{ background-color: #ec4899; }}
+        .flag-pill.bg-gray-500 {{ background-color: #6b7280; }}
+        .flag-pill.bg-slate-500 {{ background-color: #64748b; }}
+        .flag-pill.bg-cyan-500 {{ background-color: #06b6d4; }}
+        .flag-pill.bg-teal-500 {{ background-color: #14b8a6; }}
+        .flag-pill.bg-emerald-500 {{ background-color: #10b981; }}
+        .flag-pill.bg-lime-500 {{ background-color: #84cc16; }}
+        .flag-pill.bg-amber-500 {{ background-color: #f59e0b; }}
+        .flag-pill.bg-rose-500 {{ background-color: #f43f5e; }}
+        .flag-pill.bg-fuchsia-500 {{ background-color: #d946ef; }}
+        .flag-pill.bg-violet-500 {{ background-color: #8b5cf6; }}
+        .flag-pill.bg-sky-500 {{ background-color: #0ea5e9; }}
+        .flag-pill.bg-zinc-500 {{ background-color: #71717a; }}
+        .flag-pill.bg-neutral-500 {{ background-color: #737373; }}
+        .flag-pill.bg-stone-500 {{ background-color: #78716c; }}
+        .btn-secondary {{ background: #6c757d; }}
+        .btn-success {{ background: #28a745; }}
+        .search-form {{ display: flex; gap: 10px; align-items: center; flex-wrap: wrap; }}
+        .inline-form {{ display: inline; }}{SORTABLE_HEADER_STYLES}
     </style>
-    <script>
-        function deleteZone(id, name) {{
-            if (confirm('Are you sure you want to delete "' + name + '"?')) {{
-                document.getElementById('delete-form-' + id).submit();
-            }}
-        }}
-    </script>
 </head>
 <body>
     <div class="nav">
@@ -237,9 +276,9 @@ pub async fn list_zones(
     <h1>Manage Zones{}</h1>
 
     <div class="controls">
-        <form method="get" style="display: flex; gap: 10px; align-items: center; flex-wrap: wrap;">
+        <form method="get" class="search-form">
             <input type="text" name="search" placeholder="Search zones..." value="{}" />
-            <select name="expansion" onchange="this.form.submit()">
+            <select name="expansion" data-autosubmit>
                 <option value="">All Expansions</option>
                 {}
             </select>
@@ -298,7 +337,7 @@ pub async fn list_zones(
         per_page,
         // Clear filters link
         if verified.is_some() || zone_type.is_some() || expansion.is_some() || flags.is_some() {
-            r#"<a href="/admin/zones" class="btn" style="background: #6c757d;">Clear Filters</a>"#
+            r#"<a href="/admin/zones" class="btn btn-secondary">Clear Filters</a>"#
         } else {
             ""
         },
@@ -387,36 +426,12 @@ pub async fn list_zones(
                     let display_name = flag_type
                         .map(|ft| ft.display_name.as_str())
                         .unwrap_or("Unknown");
-                    let color = flag_type
-                        .map(|ft| match ft.color_class.as_str() {
-                            "bg-red-500" => "#ef4444",
-                            "bg-orange-500" => "#f97316",
-                            "bg-yellow-500" => "#eab308",
-                            "bg-green-500" => "#22c55e",
-                            "bg-blue-500" => "#3b82f6",
-                            "bg-indigo-500" => "#6366f1",
-                            "bg-purple-500" => "#a855f7",
-                            "bg-pink-500" => "#ec4899",
-                            "bg-gray-500" => "#6b7280",
-                            "bg-slate-500" => "#64748b",
-                            "bg-cyan-500" => "#06b6d4",
-                            "bg-teal-500" => "#14b8a6",
-                            "bg-emerald-500" => "#10b981",
-                            "bg-lime-500" => "#84cc16",
-                            "bg-amber-500" => "#f59e0b",
-                            "bg-rose-500" => "#f43f5e",
-                            "bg-fuchsia-500" => "#d946ef",
-                            "bg-violet-500" => "#8b5cf6",
-                            "bg-sky-500" => "#0ea5e9",
-                            "bg-zinc-500" => "#71717a",
-                            "bg-neutral-500" => "#737373",
-                            "bg-stone-500" => "#78716c",
-                            _ => "#3b82f6",
-                        })
-                        .unwrap_or("#3b82f6");
+                    // Known color classes are styled on the page; others keep the default blue
+                    let color = flag_type.map(|ft| ft.color_class.as_str()).unwrap_or("");
                     format!(
-                        r#"<span class="flag-pill" style="background-color: {};">{}</span>"#,
-                        color, display_name
+                        r#"<span class="flag-pill {}">{}</span>"#,
+                        security::escape_html_attribute(color),
+                        display_name
                     )
                 })
                 .collect::<Vec<_>>()
@@ -443,12 +458,10 @@ pub async fn list_zones(
                     <a href="/admin/zones/{}" class="btn btn-small">Edit</a>
                     <a href="/admin/zones/{}/ratings" class="btn btn-small">Ratings</a>
                     <a href="/admin/zones/{}/notes" class="btn btn-small">Notes</a>
-                    <form method="post" action="/admin/zones/{}/move-to-instances" style="display: inline;">
-                        <button type="submit" class="btn btn-small" style="background: #28a745;" onclick="return confirm('Move this zone to instances?')">Move to Instances</button>
-                    </form>
-                    <form id="delete-form-{}" method="post" action="/admin/zones/{}" style="display: inline;">
+                    <a href="/admin/zones/{}/convert" class="btn btn-small btn-success">Convert to Instance</a>
+                    <form method="post" action="/admin/zones/{}" class="inline-form" data-confirm="Are you sure you want to delete &quot;{}&quot;?">
                         <input type="hidden" name="_method" value="DELETE" />
-                        <button type="button" onclick="deleteZone({}, '{}')" class="btn btn-danger btn-small">Delete</button>
+                        <button type="submit" class="btn btn-danger btn-small">Delete</button>
                     </form>
                 </td>
             </tr>
@@ -467,9 +480,7 @@ pub async fn list_zones(
             zone.id.unwrap_or(0),
             zone.id.unwrap_or(0),
             zone.id.unwrap_or(0),
-            zone.id.unwrap_or(0),
-            zone.id.unwrap_or(0),
-            zone.name.replace("'", "\\'")
+            security::escape_html_attribute(&zone.name)
             ));
     }
 
@@ -624,6 +635,10 @@ pub async fn edit_zone_form(
         .await
         .unwrap_or_default();
 
+    let zone_aliases = aliases::get_aliases(pool.as_ref(), LocationKind::Zone, zone_id as i64)
+        .await
+        .unwrap_or_default();
+
     // Get ratings for this zone
     let ratings = sqlx::query(
         "SELECT id, rating, created_at FROM zone_ratings WHERE zone_id = ? ORDER BY created_at DESC LIMIT 10"
@@ -652,7 +667,14 @@ pub async fn edit_zone_form(
     let html = format!(
         "{}{}",
         get_zone_form_header(),
-        get_enhanced_zone_form_body(&zone, &zone_types, &note_types, &flag_types, &ratings)
+        get_enhanced_zone_form_body(
+            &zone,
+            &zone_types,
+            &note_types,
+            &flag_types,
+            &ratings,
+            &zone_aliases
+        )
     );
 
     Ok(Html(html))
@@ -667,17 +689,36 @@ pub async fn create_zone(
 
     // Validate JSON fields
     if serde_json::from_str::<serde_json::Value>(&form.level_ranges).is_err() {
-        return Ok(Html(format!(
+        return Ok(Html(
             r#"<h1>Error</h1><p>Invalid level_ranges JSON format</p><a href="/admin/zones/new">Go back</a>"#
-        )));
+                .to_string(),
+        ));
     }
 
     if serde_json::from_str::<serde_json::Value>(&form.connections).is_err() {
-        return Ok(Html(format!(
+        return Ok(Html(
             r#"<h1>Error</h1><p>Invalid connections JSON format</p><a href="/admin/zones/new">Go back</a>"#
-        )));
+                .to_string(),
+        ));
     }
 
+    // Resolve codes like "PoP" to the catalogue's display name
+    let expansion = match crate::expansions::find_expansion(pool.as_ref(), &form.expansion).await {
+        Ok(Some(expansion)) => expansion.name,
+        Ok(None) => {
+            return Ok(Html(
+                r#"<h1>Error</h1><p>Unknown expansion; use a name or code from /expansions</p><a href="/admin/zones/new">Go back</a>"#
+                    .to_string(),
+            ));
+        }
+        Err(_) => return Err(StatusCode::INTERNAL_SERVER_ERROR),
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     let result = sqlx::query(
         r#"
         INSERT INTO zones (
@@ -688,7 +729,7 @@ pub async fn create_zone(
     )
     .bind(&form.name)
     .bind(&form.level_ranges)
-    .bind(&form.expansion)
+    .bind(&expansion)
     .bind(&form.continent)
     .bind(&form.zone_type)
     .bind(&form.connections)
@@ -696,11 +737,27 @@ pub async fn create_zone(
     .bind(&form.map_url)
     .bind(form.rating)
     .bind(form.verified.is_some())
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
     .await;
 
     match result {
-        Ok(_) => {
+        Ok(result) => {
+            let zone_id = result.last_insert_rowid();
+            let after = audit::capture(&mut tx, AuditEntity::Zone, zone_id).await;
+            audit::record_change(
+                &mut tx,
+                AuditAction::Create,
+                AuditEntity::Zone,
+                zone_id,
+                None,
+                after,
+            )
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            tx.commit()
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
             // Force WAL checkpoint to immediately update main database file
             let _ = crate::checkpoint_wal(pool.as_ref()).await;
 
@@ -709,9 +766,10 @@ pub async fn create_zone(
                 form.name
             )))
         }
-        Err(_) => Ok(Html(format!(
+        Err(_) => Ok(Html(
             r#"<h1>Error</h1><p>Failed to create zone</p><a href="/admin/zones/new">Go back</a>"#
-        ))),
+                .to_string(),
+        )),
     }
 }
 
@@ -722,80 +780,35 @@ pub async fn delete_zone(
 ) -> Result<StatusCode, StatusCode> {
     let pool = &state.zone_state.pool;
 
-    // Delete the zone (cascade will handle related records)
-    let result = sqlx::query("DELETE FROM zones WHERE id = ?")
-        .bind(zone_id)
-        .execute(pool.as_ref())
-        .await;
-
-    match result {
+    // Move the zone to the trash; notes, flags, aliases and ratings stay with it
+    // until it is purged
+    match crate::trash::soft_delete(pool.as_ref(), TrashKind::Zone, zone_id as i64).await {
         Ok(_) => Ok(StatusCode::OK),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
 
-#[cfg(feature = "admin")]
-pub async fn move_zone_to_instances(
-    State(state): State<AppState>,
-    Path(zone_id): Path<i32>,
-) -> Result<Redirect, StatusCode> {
-    let pool = &state.zone_state.pool;
-
-    // Get the zone data
-    let _zone_row = sqlx::query("SELECT * FROM zones WHERE id = ?")
-        .bind(zone_id)
-        .fetch_optional(pool.as_ref())
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .ok_or(StatusCode::NOT_FOUND)?;
-
-    // Insert into instances table
-    let result = sqlx::query(
-        r#"
-        INSERT INTO instances (name, level_ranges, expansion, continent, zone_type,
-                             connections, image_url, map_url, rating, hot_zone, verified)
-        SELECT name, level_ranges, expansion, continent, zone_type,
-               connections, image_url, map_url, rating, 0, verified
-        FROM zones WHERE id = ?
-        "#,
-    )
-    .bind(zone_id)
-    .execute(pool.as_ref())
-    .await;
-
-    if result.is_ok() {
-        // Delete the zone after successful copy
-        let _ = sqlx::query("DELETE FROM zones WHERE id = ?")
-            .bind(zone_id)
-            .execute(pool.as_ref())
-            .await;
-    }
-
-    Ok(Redirect::to("/admin/zones"))
-}
-
 #[cfg(feature = "admin")]
 pub async fn handle_zone_update_or_delete(
     State(state): State<AppState>,
     Path(zone_id): Path<i32>,
     Form(form): Form<ZoneForm>,
 ) -> Result<Html<String>, StatusCode> {
-    if let Some(ref method) = form._method {
-        if method == "DELETE" {
-            return match delete_zone(State(state), Path(zone_id)).await {
-                Ok(_) => Ok(Html(format!(
-                    r#"<h1>Zone Deleted</h1>
-                    <p>Zone has been successfully deleted.</p>
-                    <a href="/admin/zones">Return to Zones List</a>"#
-                ))),
-                Err(_) => Ok(Html(format!(
-                    r#"<h1>Error</h1>
-                    <p>Failed to delete zone.</p>
-                    <a href="/admin/zones/{}">Go back</a>"#,
-                    zone_id
-                ))),
-            };
-        }
+    if form._method.as_deref() == Some("DELETE") {
+        return match delete_zone(State(state), Path(zone_id)).await {
+            Ok(_) => Ok(Html(
+                r#"<h1>Zone Deleted</h1>
+                <p>Zone has been successfully deleted.</p>
+                <a href="/admin/zones">Return to Zones List</a>"#
+                    .to_string(),
+            )),
+            Err(_) => Ok(Html(format!(
+                r#"<h1>Error</h1>
+                <p>Failed to delete zone.</p>
+                <a href="/admin/zones/{}">Go back</a>"#,
+                zone_id
+            ))),
+        };
     }
 
     // Handle update
@@ -825,8 +838,26 @@ async fn update_zone(
         )));
     }
 
+    // Resolve codes like "PoP" to the catalogue's display name
+    let expansion = match crate::expansions::find_expansion(pool.as_ref(), &form.expansion).await {
+        Ok(Some(expansion)) => expansion.name,
+        Ok(None) => {
+            return Ok(Html(format!(
+                r#"<h1>Error</h1><p>Unknown expansion; use a name or code from /expansions</p><a href="/admin/zones/{}">Go back</a>"#,
+                zone_id
+            )));
+        }
+        Err(_) => return Err(StatusCode::INTERNAL_SERVER_ERROR),
+    };
+
     let verified = form.verified.is_some();
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::Zone, zone_id as i64).await;
+
     // Update the zone
     let result = sqlx::query(
         r#"
@@ -838,7 +869,7 @@ async fn update_zone(
     )
     .bind(&form.name)
     .bind(&form.level_ranges)
-    .bind(&form.expansion)
+    .bind(&expansion)
     .bind(&form.continent)
     .bind(&form.zone_type)
     .bind(&form.connections)
@@ -847,17 +878,34 @@ async fn update_zone(
     .bind(form.rating)
     .bind(verified)
     .bind(zone_id)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
     .await;
 
     match result {
-        Ok(_) => Ok(Html(format!(
-            r#"<h1>Zone Updated</h1>
+        Ok(_) => {
+            let after = audit::capture(&mut tx, AuditEntity::Zone, zone_id as i64).await;
+            audit::record_change(
+                &mut tx,
+                AuditAction::Update,
+                AuditEntity::Zone,
+                zone_id as i64,
+                before,
+                after,
+            )
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            tx.commit()
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+            Ok(Html(format!(
+                r#"<h1>Zone Updated</h1>
             <p>Zone "{}" has been successfully updated.</p>
-            <a href="/admin/zones">Return to Zones List</a>
-            <a href="/admin/zones/{}" style="margin-left: 15px;">Edit Again</a>"#,
-            form.name, zone_id
-        ))),
+            <a href="/admin/zones">Return to Zones List</a> |
+            <a href="/admin/zones/{}">Edit Again</a>"#,
+                form.name, zone_id
+            )))
+        }
         Err(_) => Ok(Html(format!(
             r#"<h1>Error</h1>
             <p>Failed to update zone.</p>
@@ -898,13 +946,14 @@ pub async fn zone_ratings(
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Zone Ratings - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1000px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -917,6 +966,7 @@ pub async fn zone_ratings(
         .btn-danger {{ background: #dc3545; }}
         .btn-danger:hover {{ background: #c82333; }}
         .btn-small {{ padding: 4px 8px; font-size: 0.8em; }}
+        .inline-form {{ display: inline; }}
     </style>
 </head>
 <body>
@@ -963,8 +1013,8 @@ pub async fn zone_ratings(
                 <td>{}</td>
                 <td>{}</td>
                 <td>
-                    <form method="post" action="/admin/ratings/{}/delete" style="display: inline;">
-                        <button type="submit" class="btn btn-danger btn-small" onclick="return confirm('Delete this rating?')">Delete</button>
+                    <form method="post" action="/admin/ratings/zone/{}/delete" class="inline-form" data-confirm="Delete this rating?">
+                        <button type="submit" class="btn btn-danger btn-small">Delete</button>
                     </form>
                 </td>
             </tr>
@@ -984,10 +1034,10 @@ pub async fn zone_ratings(
     </table>
 
     <a href="/admin/zones" class="btn">Back to Zones</a>
-</body>
-</html>
 "#,
     );
+    html.push_str(&super::note_preview_script());
+    html.push_str("</body>\n</html>\n");
 
     Ok(Html(html))
 }
@@ -1020,13 +1070,14 @@ pub async fn zone_notes(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let nonce = crate::security::csp_nonce();
     let mut html = format!(
         r#"
 <!DOCTYPE html>
 <html>
 <head>
     <title>Zone Notes - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body {{ font-family: Arial, sans-serif; max-width: 1000px; margin: 0 auto; padding: 20px; }}
         .nav {{ background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }}
         .nav a {{ margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }}
@@ -1045,6 +1096,7 @@ pub async fn zone_notes(
         .btn-danger:hover {{ background: #c82333; }}
         .btn-small {{ padding: 4px 8px; font-size: 0.8em; }}
         .note-type-badge {{ display: inline-block; padding: 4px 8px; border-radius: 12px; color: white; font-size: 0.8em; font-weight: bold; }}
+        .inline-form {{ display: inline; }}
     </style>
 </head>
 <body>
@@ -1083,7 +1135,8 @@ pub async fn zone_notes(
 
             <div class="form-group">
                 <label for="content">Content:</label>
-                <textarea id="content" name="content" required placeholder="Enter note content..."></textarea>
+                <textarea id="content" name="content" required placeholder="Enter note content..." data-note-preview></textarea>
+                <small>Markdown: **bold**, *italic*, [links](https://...), lists and headings</small>
             </div>
 
             <div class="form-group">
@@ -1126,15 +1179,15 @@ pub async fn zone_notes(
                 <td>{}</td>
                 <td>Just now</td>
                 <td>
-                    <form method="post" action="/admin/zones/{}/notes/{}/delete" style="display: inline;">
-                        <button type="submit" class="btn btn-danger btn-small" onclick="return confirm('Delete this note?')">Delete</button>
+                    <form method="post" action="/admin/zones/{}/notes/{}/delete" class="inline-form" data-confirm="Delete this note?">
+                        <button type="submit" class="btn btn-danger btn-small">Delete</button>
                     </form>
                 </td>
             </tr>
             "#,
             note_type_color,
             note_type_display,
-            note.content,
+            note.content_html,
             zone_id,
             note.id.unwrap_or(0)
         ));
@@ -1162,10 +1215,10 @@ pub async fn create_zone_note(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
-    let sanitized_content = security::sanitize_user_input_with_formatting(&form.content);
-    if sanitized_content != form.content {
-        tracing::warn!(zone_id, "sanitized zone note content differed from input");
-    }
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     // Insert the new note
     let result = sqlx::query(
@@ -1176,12 +1229,29 @@ pub async fn create_zone_note(
     )
     .bind(zone_id)
     .bind(form.note_type_id)
-    .bind(&sanitized_content)
-    .execute(pool.as_ref())
+    .bind(&form.content)
+    .execute(&mut *tx)
     .await;
 
     match result {
-        Ok(_) => Ok(Redirect::to(&format!("/admin/zones/{}", zone_id))),
+        Ok(result) => {
+            let note_id = result.last_insert_rowid();
+            let after = audit::capture(&mut tx, AuditEntity::ZoneNote, note_id).await;
+            audit::record_change(
+                &mut tx,
+                AuditAction::Create,
+                AuditEntity::ZoneNote,
+                note_id,
+                None,
+                after,
+            )
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            tx.commit()
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            Ok(Redirect::to(&format!("/admin/zones/{}", zone_id)))
+        }
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -1193,15 +1263,115 @@ pub async fn delete_zone_note(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::ZoneNote, note_id).await;
+
     // Delete the note
     let result = sqlx::query("DELETE FROM zone_notes WHERE id = ? AND zone_id = ?")
         .bind(note_id)
         .bind(zone_id)
-        .execute(pool.as_ref())
+        .execute(&mut *tx)
         .await;
 
     match result {
-        Ok(_) => Ok(Redirect::to(&format!("/admin/zones/{}", zone_id))),
+        Ok(result) => {
+            if result.rows_affected() > 0 {
+                audit::record_change(
+                    &mut tx,
+                    AuditAction::Delete,
+                    AuditEntity::ZoneNote,
+                    note_id,
+                    before,
+                    None,
+                )
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                tx.commit()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            }
+            Ok(Redirect::to(&format!("/admin/zones/{}", zone_id)))
+        }
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[cfg(feature = "admin")]
+pub async fn create_zone_alias(
+    State(state): State<AppState>,
+    Path(zone_id): Path<i64>,
+    Form(form): Form<AliasForm>,
+) -> Result<Response, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    match aliases::add_alias(&mut tx, LocationKind::Zone, zone_id, &form.alias).await {
+        Ok(alias_id) => {
+            let after = audit::capture(&mut tx, AuditEntity::ZoneAlias, alias_id).await;
+            audit::record_change(
+                &mut tx,
+                AuditAction::Create,
+                AuditEntity::ZoneAlias,
+                alias_id,
+                None,
+                after,
+            )
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            tx.commit()
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            Ok(Redirect::to(&format!("/admin/zones/{}", zone_id)).into_response())
+        }
+        Err(crate::error::AppError::Validation(message)) => Ok(Html(format!(
+            r#"<h1>Error</h1><p>{}</p><a href="/admin/zones/{}">Go back</a>"#,
+            security::escape_html(&message),
+            zone_id
+        ))
+        .into_response()),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+#[cfg(feature = "admin")]
+pub async fn delete_zone_alias(
+    State(state): State<AppState>,
+    Path((zone_id, alias_id)): Path<(i64, i64)>,
+) -> Result<Redirect, StatusCode> {
+    let pool = &state.zone_state.pool;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::ZoneAlias, alias_id).await;
+
+    match aliases::delete_alias(&mut tx, LocationKind::Zone, zone_id, alias_id).await {
+        Ok(deleted) => {
+            if deleted {
+                audit::record_change(
+                    &mut tx,
+                    AuditAction::Delete,
+                    AuditEntity::ZoneAlias,
+                    alias_id,
+                    before,
+                    None,
+                )
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                tx.commit()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            }
+            Ok(Redirect::to(&format!("/admin/zones/{}", zone_id)))
+        }
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -1214,6 +1384,11 @@ pub async fn create_zone_flag(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     // Insert the new flag (use INSERT OR IGNORE to handle duplicates)
     let result = sqlx::query(
         r#"
@@ -1223,31 +1398,31 @@ pub async fn create_zone_flag(
     )
     .bind(zone_id)
     .bind(form.flag_type_id)
-    .execute(pool.as_ref())
+    .execute(&mut *tx)
     .await;
 
     match result {
-        Ok(_) => Ok(Redirect::to(&format!("/admin/zones/{}", zone_id))),
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-    }
-}
-
-#[cfg(feature = "admin")]
-pub async fn delete_zone_flag_simple(
-    State(state): State<AppState>,
-    Path((zone_id, flag_id)): Path<(i64, i64)>,
-) -> Result<Redirect, StatusCode> {
-    let pool = &state.zone_state.pool;
-
-    // Delete the flag
-    let result = sqlx::query("DELETE FROM zone_flags WHERE id = ? AND zone_id = ?")
-        .bind(flag_id)
-        .bind(zone_id)
-        .execute(pool.as_ref())
-        .await;
-
-    match result {
-        Ok(_) => Ok(Redirect::to(&format!("/admin/zones/{}", zone_id))),
+        Ok(result) => {
+            // Ignored duplicates change nothing
+            if result.rows_affected() > 0 {
+                let flag_id = result.last_insert_rowid();
+                let after = audit::capture(&mut tx, AuditEntity::ZoneFlag, flag_id).await;
+                audit::record_change(
+                    &mut tx,
+                    AuditAction::Create,
+                    AuditEntity::ZoneFlag,
+                    flag_id,
+                    None,
+                    after,
+                )
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                tx.commit()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            }
+            Ok(Redirect::to(&format!("/admin/zones/{}", zone_id)))
+        }
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -1259,15 +1434,38 @@ pub async fn delete_zone_flag(
 ) -> Result<Redirect, StatusCode> {
     let pool = &state.zone_state.pool;
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::ZoneFlag, flag_id).await;
+
     // Delete the flag
     let result = sqlx::query("DELETE FROM zone_flags WHERE id = ? AND zone_id = ?")
         .bind(flag_id)
         .bind(zone_id)
-        .execute(pool.as_ref())
+        .execute(&mut *tx)
         .await;
 
     match result {
-        Ok(_) => Ok(Redirect::to(&format!("/admin/zones/{}", zone_id))),
+        Ok(result) => {
+            if result.rows_affected() > 0 {
+                audit::record_change(
+                    &mut tx,
+                    AuditAction::Delete,
+                    AuditEntity::ZoneFlag,
+                    flag_id,
+                    before,
+                    None,
+                )
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                tx.commit()
+                    .await
+                    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            }
+            Ok(Redirect::to(&format!("/admin/zones/{}", zone_id)))
+        }
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -1279,7 +1477,7 @@ fn get_zone_form_header() -> String {
 <html>
 <head>
     <title>Zone Form - EQ RNG Admin</title>
-    <style>
+    <style nonce="{nonce}">
         body { font-family: Arial, sans-serif; max-width: 1400px; margin: 0 auto; padding: 20px; }
         .nav { background: #f5f5f5; padding: 15px; margin-bottom: 20px; border-radius: 5px; }
         .nav a { margin-right: 15px; text-decoration: none; color: #333; font-weight: bold; }
@@ -1430,6 +1628,15 @@ fn get_zone_form_header() -> String {
             color: #6c757d;
             font-size: 12px;
         }
+
+        .edit-grid {
+            display: grid;
+            grid-template-columns: 1fr 1fr;
+            gap: 30px;
+        }
+        .inline-form {
+            display: inline;
+        }
     </style>
 </head>
 <body>
@@ -1438,7 +1645,8 @@ fn get_zone_form_header() -> String {
         <a href="/admin/zones">Manage Zones</a>
         <a href="/admin/zones/new">Add New Zone</a>
     </div>
-"#.to_string()
+"#
+    .replace("{nonce}", &crate::security::csp_nonce())
 }
 
 #[cfg(feature = "admin")]
@@ -1448,17 +1656,20 @@ fn get_enhanced_zone_form_body(
     note_types: &[sqlx::sqlite::SqliteRow],
     flag_types: &[crate::zones::FlagType],
     ratings: &[sqlx::sqlite::SqliteRow],
+    zone_aliases: &[aliases::Alias],
 ) -> String {
     use sqlx::Row;
 
     let zone_id = zone.id.unwrap_or(0);
+    let note_preview = super::note_preview_script();
 
     format!(
         r#"
-    <div style="display: grid; grid-template-columns: 1fr 1fr; gap: 30px;">
+    <div class="edit-grid">
         <!-- Left Column: Zone Form -->
         <div>
             <h2>Edit Zone</h2>
+            <p><a href="/admin/audit?entity_type=zone&entity_id={}">View change history</a></p>
             <form method="post" action="/admin/zones/{}">
                 <input type="hidden" name="_method" value="PUT" />
 
@@ -1517,14 +1728,25 @@ fn get_enhanced_zone_form_body(
                 <div class="form-group">
                     <button type="submit" class="btn">Update Zone</button>
                     <a href="/admin/zones" class="btn btn-secondary">Cancel</a>
-                    <a href="/admin/zones/{}/move-to-instances" class="btn btn-warning"
-                       onclick="return confirm('Convert this zone to an instance?')">Convert to Instance</a>
+                    <a href="/admin/zones/{}/convert" class="btn btn-warning">Convert to Instance</a>
                 </div>
             </form>
         </div>
 
         <!-- Right Column: Management Sections -->
         <div>
+            <!-- Aliases Section -->
+            <div class="management-section">
+                <h3>Aliases</h3>
+                <div class="current-items">
+                    {}
+                </div>
+                <form method="post" action="/admin/zones/{}/aliases" class="add-form">
+                    <input type="text" name="alias" placeholder="Short name, e.g. solb" maxlength="{}" required>
+                    <button type="submit" class="btn btn-small">Add</button>
+                </form>
+            </div>
+
             <!-- Flags Section -->
             <div class="management-section">
                 <h3>Zone Flags</h3>
@@ -1551,7 +1773,7 @@ fn get_enhanced_zone_form_body(
                         <option value="">Select note type...</option>
                         {}
                     </select>
-                    <textarea name="content" placeholder="Note content..." required></textarea>
+                    <textarea name="content" placeholder="Note content (Markdown)..." required data-note-preview></textarea>
                     <button type="submit" class="btn btn-small">Add Note</button>
                 </form>
             </div>
@@ -1566,10 +1788,11 @@ fn get_enhanced_zone_form_body(
             </div>
         </div>
     </div>
-
+{note_preview}
 </body>
 </html>
 "#,
+        zone_id,
         zone_id,
         zone.name.replace('"', "&quot;"),
         zone.level_ranges.replace('"', "&quot;"),
@@ -1582,6 +1805,30 @@ fn get_enhanced_zone_form_body(
         zone.rating,
         if zone.verified { "checked" } else { "" },
         zone_id,
+        // Current aliases
+        if zone_aliases.is_empty() {
+            "<p class=\"no-items\">No aliases</p>".to_string()
+        } else {
+            zone_aliases
+                .iter()
+                .map(|alias| {
+                    format!(
+                        r#"<div class="item-pill">
+                        <span>{}</span>
+                        <form method="post" action="/admin/zones/{}/aliases/{}/delete" class="inline-form" data-confirm="Remove this alias?">
+                            <button type="submit" class="remove-link">×</button>
+                        </form>
+                    </div>"#,
+                        security::escape_html(&alias.alias),
+                        zone_id,
+                        alias.id.unwrap_or(0)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("")
+        },
+        zone_id,
+        aliases::MAX_ALIAS_LENGTH,
         // Current flags
         if zone.flags.is_empty() {
             "<p class=\"no-items\">No flags assigned</p>".to_string()
@@ -1604,8 +1851,9 @@ fn get_enhanced_zone_form_body(
                     format!(
                         r#"<div class="item-pill">
                         <span class="flag-badge {}">{}</span>
-                        <a href="/admin/zones/{}/remove-flag/{}" class="remove-link"
-                           onclick="return confirm('Remove this flag?')">×</a>
+                        <form method="post" action="/admin/zones/{}/flags/{}/delete" class="inline-form" data-confirm="Remove this flag?">
+                            <button type="submit" class="remove-link">×</button>
+                        </form>
                     </div>"#,
                         flag_color, flag_display, zone_id, flag_id
                     )
@@ -1646,8 +1894,8 @@ fn get_enhanced_zone_form_body(
                         r#"<div class="note-item">
                         <div class="note-header">
                             <span class="note-badge {}">{}</span>
-                            <form method="post" action="/admin/zones/{}/notes/{}/delete" style="display:inline;">
-                                <button type="submit" class="remove-link" onclick="return confirm('Delete this note?')">×</button>
+                            <form method="post" action="/admin/zones/{}/notes/{}/delete" class="inline-form" data-confirm="Delete this note?">
+                                <button type="submit" class="remove-link">×</button>
                             </form>
                         </div>
                         <div class="note-content">{}</div>
@@ -1656,7 +1904,7 @@ fn get_enhanced_zone_form_body(
                         note_type_display,
                         zone_id,
                         note_id,
-                        note.content.replace('<', "&lt;").replace('>', "&gt;")
+                        note.content_html
                     )
                 })
                 .collect::<Vec<_>>()
@@ -1705,9 +1953,9 @@ fn get_enhanced_zone_form_body(
 #[cfg(all(test, feature = "admin"))]
 mod tests {
     use super::*;
-    use axum::extract::{Path, State};
     use axum::Form;
-    use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+    use axum::extract::{Path, State};
+    use sqlx::{Row, SqlitePool, sqlite::SqlitePoolOptions};
     use std::collections::HashMap;
     use std::sync::Arc;
 
@@ -1731,9 +1979,15 @@ mod tests {
                 backup_dir: "".to_string(),
                 migrate_on_startup: false,
             },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
             security: SecurityConfig {
                 rating_ip_hash_key: "test".to_string(),
                 min_ip_hash_key_length: 0,
+                headers: Default::default(),
             },
             ratings: RatingsConfig {
                 min_rating: 1,
@@ -1780,6 +2034,7 @@ mod tests {
         .execute(&pool)
         .await
         .unwrap();
+        crate::audit::create_audit_log_table(&pool).await.unwrap();
 
         let pool_arc = Arc::new(pool);
         let state = AppState {
@@ -1793,13 +2048,15 @@ mod tests {
             class_race_state: ClassRaceState {
                 class_race_map: Arc::new(HashMap::new()),
             },
+            snapshot: Default::default(),
+            rate_limiter: Default::default(),
         };
 
         (state, pool_arc)
     }
 
     #[tokio::test]
-    async fn create_zone_note_sanitizes_script_tags() {
+    async fn create_zone_note_stores_markdown_and_renders_safely() {
         let (state, pool) = setup_state().await;
         let raw = "<script>alert('x')</script>";
         let form = ZoneNoteForm {
@@ -1817,8 +2074,65 @@ mod tests {
             .await
             .unwrap();
         let stored: String = row.get("content");
-        let expected = crate::security::sanitize_user_input_with_formatting(raw);
-        assert_eq!(stored, expected);
+        assert_eq!(stored, raw);
+        assert!(!crate::markdown::render(&stored).contains("<script"));
+    }
+
+    #[tokio::test]
+    async fn note_changes_are_audited_with_actor() {
+        let (state, pool) = setup_state().await;
+        sqlx::query("DROP TABLE zone_notes")
+            .execute(&*pool)
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        sqlx::query("INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (1, 'Befallen', '[[7,25]]', 'Classic', 'Dungeon')")
+            .execute(&*pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO note_types (id, name, display_name) VALUES (1, 'epic', 'Epic')")
+            .execute(&*pool)
+            .await
+            .unwrap();
+
+        let form = ZoneNoteForm {
+            note_type_id: 1,
+            content: "Shadowed man".to_string(),
+        };
+        let redirects = crate::audit::with_actor("admin@10.0.0.1".to_string(), async {
+            let created = create_zone_note(Path(1), State(state.clone()), Form(form))
+                .await
+                .unwrap();
+            let deleted = delete_zone_note(State(state.clone()), Path((1, 1)))
+                .await
+                .unwrap();
+            [created, deleted]
+        })
+        .await;
+        for redirect in redirects {
+            let response = axum::response::IntoResponse::into_response(redirect);
+            assert_eq!(
+                response.headers()[axum::http::header::LOCATION],
+                "/admin/zones/1"
+            );
+        }
+
+        let filter = crate::audit::AuditFilter {
+            entity_type: Some("zone_note".to_string()),
+            entity_id: Some(1),
+            ..Default::default()
+        };
+        let entries = crate::audit::list_entries(&pool, &filter, 10, 0)
+            .await
+            .unwrap();
+        let actions: Vec<&str> = entries.iter().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, vec!["delete", "create"]);
+        assert!(entries.iter().all(|e| e.actor == "admin@10.0.0.1"));
+        assert_eq!(
+            entries[0].before.as_ref().unwrap()["content"],
+            "Shadowed man"
+        );
+        assert!(entries[0].after.is_none());
     }
 }
 
@@ -0,0 +1,349 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqliteConnection, SqlitePool};
+
+use crate::error::{AppError, AppResult};
+
+pub const MAX_ALIAS_LENGTH: usize = 64;
+
+pub use crate::locations::LocationKind;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Alias {
+    pub id: Option<i64>,
+    pub location_id: i64,
+    pub alias: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ResolvedLocation {
+    pub kind: LocationKind,
+    pub id: i64,
+    pub name: String,
+    /// The alias that matched, when the name was not a canonical zone or instance name
+    pub matched_alias: Option<String>,
+}
+
+pub async fn get_aliases(
+    pool: &SqlitePool,
+    kind: LocationKind,
+    location_id: i64,
+) -> Result<Vec<Alias>, sqlx::Error> {
+    let query = format!(
+        "SELECT id, {id} AS location_id, alias FROM {table} WHERE {id} = ? ORDER BY alias COLLATE NOCASE",
+        id = kind.id_column(),
+        table = kind.alias_table()
+    );
+
+    let rows = sqlx::query(&query)
+        .bind(location_id)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| Alias {
+            id: Some(row.get("id")),
+            location_id: row.get("location_id"),
+            alias: row.get("alias"),
+        })
+        .collect())
+}
+
+/// Every alias of every zone and instance, for checks that resolve names in bulk
+pub async fn get_all_alias_names(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT alias FROM zone_aliases UNION ALL SELECT alias FROM instance_aliases",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Resolve a zone or instance by canonical name first, then by alias, ignoring case.
+/// Zones win over instances when both match.
+pub async fn resolve_name(
+    pool: &SqlitePool,
+    name: &str,
+) -> Result<Option<ResolvedLocation>, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    resolve_name_on(&mut conn, name).await
+}
+
+async fn resolve_name_on(
+    conn: &mut SqliteConnection,
+    name: &str,
+) -> Result<Option<ResolvedLocation>, sqlx::Error> {
+    let key = name.trim();
+    if key.is_empty() {
+        return Ok(None);
+    }
+
+    for kind in LocationKind::ALL {
+        let query = format!(
+            "SELECT id, name FROM {} WHERE LOWER(TRIM(name)) = LOWER(?) AND deleted_at IS NULL ORDER BY id LIMIT 1",
+            kind.table()
+        );
+        if let Some(row) = sqlx::query(&query)
+            .bind(key)
+            .fetch_optional(&mut *conn)
+            .await?
+        {
+            return Ok(Some(ResolvedLocation {
+                kind,
+                id: row.get("id"),
+                name: row.get("name"),
+                matched_alias: None,
+            }));
+        }
+    }
+
+    for kind in LocationKind::ALL {
+        let query = format!(
+            "SELECT l.id, l.name, a.alias FROM {aliases} a JOIN {table} l ON l.id = a.{id} WHERE a.alias = ? COLLATE NOCASE AND l.deleted_at IS NULL LIMIT 1",
+            aliases = kind.alias_table(),
+            table = kind.table(),
+            id = kind.id_column()
+        );
+        if let Some(row) = sqlx::query(&query)
+            .bind(key)
+            .fetch_optional(&mut *conn)
+            .await?
+        {
+            return Ok(Some(ResolvedLocation {
+                kind,
+                id: row.get("id"),
+                name: row.get("name"),
+                matched_alias: Some(row.get("alias")),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Add an alias to a zone or instance. An alias must not already resolve to anything,
+/// whether as another alias or as a canonical name, so every alias has one meaning.
+/// Runs on the caller's transaction so the audit entry can be written alongside it.
+pub async fn add_alias(
+    conn: &mut SqliteConnection,
+    kind: LocationKind,
+    location_id: i64,
+    alias: &str,
+) -> AppResult<i64> {
+    let alias = alias.trim();
+    if alias.is_empty() {
+        return Err(AppError::Validation("Alias cannot be empty".to_string()));
+    }
+    if alias.chars().count() > MAX_ALIAS_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Alias cannot be longer than {} characters",
+            MAX_ALIAS_LENGTH
+        )));
+    }
+
+    if let Some(existing) = resolve_name_on(&mut *conn, alias).await? {
+        return Err(AppError::Validation(format!(
+            "\"{}\" already refers to {} {} ({})",
+            alias,
+            existing.kind.as_str(),
+            existing.id,
+            existing.name
+        )));
+    }
+
+    // Aliases of trashed items no longer resolve but still hold their name
+    let held: Option<i64> = sqlx::query_scalar(
+        "SELECT 1 FROM zone_aliases WHERE alias = ? UNION ALL SELECT 1 FROM instance_aliases WHERE alias = ?",
+    )
+    .bind(alias)
+    .bind(alias)
+    .fetch_optional(&mut *conn)
+    .await?;
+    if held.is_some() {
+        return Err(AppError::Validation(format!(
+            "\"{}\" is still held by an item in the trash",
+            alias
+        )));
+    }
+
+    let query = format!(
+        "INSERT INTO {} ({}, alias) VALUES (?, ?)",
+        kind.alias_table(),
+        kind.id_column()
+    );
+    let result = sqlx::query(&query)
+        .bind(location_id)
+        .bind(alias)
+        .execute(conn)
+        .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Remove one alias from a zone or instance. Returns false if it did not exist.
+pub async fn delete_alias(
+    conn: &mut SqliteConnection,
+    kind: LocationKind,
+    location_id: i64,
+    alias_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let query = format!(
+        "DELETE FROM {} WHERE id = ? AND {} = ?",
+        kind.alias_table(),
+        kind.id_column()
+    );
+    let result = sqlx::query(&query)
+        .bind(alias_id)
+        .bind(location_id)
+        .execute(conn)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[derive(Deserialize)]
+pub struct ResolveQuery {
+    name: Option<String>,
+}
+
+pub async fn resolve_location_api(
+    Query(params): Query<ResolveQuery>,
+    State(state): State<crate::AppState>,
+) -> Result<Json<ResolvedLocation>, StatusCode> {
+    let pool = &*state.zone_state.pool;
+
+    let name = params.name.unwrap_or_default();
+    if name.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    resolve_name(pool, &name)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Database error resolving location name");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (34, 'Nagafen''s Lair', '[[40,55]]', 'Classic', 'Dungeon'), (52, 'Solusek''s Eye', '[[20,40]]', 'Classic', 'Dungeon')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO instances (id, name, level_ranges, expansion, zone_type) VALUES (158, 'Nagafen''s Lair: Defending the Lair', '[[45,70]]', 'Depths of Darkhollow', 'Indoors')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn resolves_names_then_aliases_case_insensitively() {
+        let pool = setup_pool().await;
+        add_alias(
+            &mut pool.acquire().await.unwrap(),
+            LocationKind::Zone,
+            34,
+            "Sol B",
+        )
+        .await
+        .unwrap();
+        add_alias(
+            &mut pool.acquire().await.unwrap(),
+            LocationKind::Instance,
+            158,
+            "defend the lair",
+        )
+        .await
+        .unwrap();
+
+        let by_name = resolve_name(&pool, " solusek's eye ")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((by_name.kind, by_name.id), (LocationKind::Zone, 52));
+        assert_eq!(by_name.matched_alias, None);
+
+        let by_alias = resolve_name(&pool, "SOL B").await.unwrap().unwrap();
+        assert_eq!(by_alias.name, "Nagafen's Lair");
+        assert_eq!(by_alias.matched_alias.as_deref(), Some("Sol B"));
+
+        let instance = resolve_name(&pool, "Defend The Lair")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((instance.kind, instance.id), (LocationKind::Instance, 158));
+
+        assert!(resolve_name(&pool, "sola").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn aliases_must_be_unambiguous() {
+        let pool = setup_pool().await;
+        let id = add_alias(
+            &mut pool.acquire().await.unwrap(),
+            LocationKind::Zone,
+            52,
+            "sola",
+        )
+        .await
+        .unwrap();
+
+        for taken in ["SolA", "nagafen's lair", "  "] {
+            assert!(matches!(
+                add_alias(
+                    &mut pool.acquire().await.unwrap(),
+                    LocationKind::Zone,
+                    34,
+                    taken
+                )
+                .await,
+                Err(AppError::Validation(_))
+            ));
+        }
+
+        let aliases = get_aliases(&pool, LocationKind::Zone, 52).await.unwrap();
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(aliases[0].alias, "sola");
+
+        // Aliases go with their zone
+        assert!(
+            !delete_alias(
+                &mut pool.acquire().await.unwrap(),
+                LocationKind::Zone,
+                34,
+                id
+            )
+            .await
+            .unwrap()
+        );
+        sqlx::query("DELETE FROM zones WHERE id = 52")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(get_all_alias_names(&pool).await.unwrap().is_empty());
+    }
+}
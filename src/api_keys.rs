@@ -0,0 +1,371 @@
+// API keys for third-party clients
+// Bots and overlays send `Authorization: Bearer <key>` instead of sharing the
+// anonymous per-IP limits. Each key carries scopes, its own per-minute limit and
+// daily quota, and usage counters. Only a hash of each key is stored.
+
+use axum::http::Method;
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+
+use crate::error::{AppError, AppResult};
+
+/// Every key starts with this, so leaked keys are easy to spot
+pub const KEY_PREFIX: &str = "eqrng_";
+pub const MAX_NAME_LENGTH: usize = 64;
+pub const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 60;
+pub const DEFAULT_DAILY_QUOTA: u32 = 10_000;
+
+/// What a key may be used for
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// GET the public API: rolls, search, notes, ratings, links
+    #[serde(rename = "read")]
+    Read,
+    /// Submit zone and instance ratings
+    #[serde(rename = "rate")]
+    Rate,
+    /// GET admin pages as a viewer
+    #[serde(rename = "admin-read")]
+    AdminRead,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 3] = [Scope::Read, Scope::Rate, Scope::AdminRead];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scope::Read => "read",
+            Scope::Rate => "rate",
+            Scope::AdminRead => "admin-read",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == value.trim())
+    }
+}
+
+/// The scope a request needs, or None when keys cannot be used for it at all
+pub fn required_scope(method: &Method, path: &str) -> Option<Scope> {
+    let read = method == Method::GET || method == Method::HEAD;
+
+    if path == "/admin" || path.starts_with("/admin/") {
+        return read.then_some(Scope::AdminRead);
+    }
+    if read {
+        return Some(Scope::Read);
+    }
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["zones" | "instances", id, "rating"]
+            if method == Method::POST && id.parse::<i64>().is_ok() =>
+        {
+            Some(Scope::Rate)
+        }
+        _ => None,
+    }
+}
+
+/// An API key as listed on the admin page. The key itself is never stored.
+#[derive(Debug, Serialize, Clone)]
+pub struct ApiKey {
+    pub id: i64,
+    pub name: String,
+    /// The first characters of the key, to tell keys apart
+    pub key_prefix: String,
+    pub scopes: Vec<Scope>,
+    pub rate_limit_per_minute: u32,
+    /// Requests allowed per UTC day; 0 is unlimited
+    pub daily_quota: u32,
+    pub request_count: i64,
+    /// Requests made so far today
+    pub quota_used: u32,
+    pub created_at: Option<String>,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+}
+
+impl ApiKey {
+    pub fn allows(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> Self {
+        Self {
+            id: row.get("id"),
+            name: row.get("name"),
+            key_prefix: row.get("key_prefix"),
+            scopes: parse_scopes(&row.get::<String, _>("scopes")),
+            rate_limit_per_minute: row.get::<i64, _>("rate_limit_per_minute").max(0) as u32,
+            daily_quota: row.get::<i64, _>("daily_quota").max(0) as u32,
+            request_count: row.get("request_count"),
+            quota_used: row.get::<i64, _>("quota_used_today").max(0) as u32,
+            created_at: row.get("created_at"),
+            last_used_at: row.get("last_used_at"),
+            revoked_at: row.get("revoked_at"),
+        }
+    }
+}
+
+/// Scopes stored as a comma-separated list; unknown names grant nothing
+fn parse_scopes(value: &str) -> Vec<Scope> {
+    value.split(',').filter_map(Scope::parse).collect()
+}
+
+fn hash_key(key: &str) -> String {
+    blake3::hash(key.as_bytes()).to_hex().to_string()
+}
+
+const SELECT_KEYS: &str = r#"
+    SELECT id, name, key_prefix, scopes, rate_limit_per_minute, daily_quota,
+           request_count, created_at, last_used_at, revoked_at,
+           CASE WHEN quota_day = date('now') THEN quota_used ELSE 0 END AS quota_used_today
+    FROM api_keys
+"#;
+
+pub async fn create_api_keys_table(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='api_keys'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !table_exists {
+        println!("Creating api_keys table...");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE api_keys (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                key_prefix TEXT NOT NULL,
+                key_hash TEXT NOT NULL UNIQUE,
+                scopes TEXT NOT NULL,
+                rate_limit_per_minute INTEGER NOT NULL,
+                daily_quota INTEGER NOT NULL DEFAULT 0,
+                request_count INTEGER NOT NULL DEFAULT 0,
+                quota_day TEXT,
+                quota_used INTEGER NOT NULL DEFAULT 0,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                last_used_at DATETIME,
+                revoked_at DATETIME
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Issue a key and return its id with the key itself, which is shown once and
+/// cannot be recovered later
+pub async fn create_api_key(
+    pool: &SqlitePool,
+    name: &str,
+    scopes: &[Scope],
+    rate_limit_per_minute: u32,
+    daily_quota: u32,
+) -> AppResult<(i64, String)> {
+    if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Name must be 1 to {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+    if scopes.is_empty() {
+        return Err(AppError::Validation(
+            "Choose at least one scope".to_string(),
+        ));
+    }
+    if rate_limit_per_minute == 0 {
+        return Err(AppError::Validation(
+            "Rate limit must be at least one request per minute".to_string(),
+        ));
+    }
+
+    let key: String = std::iter::once(KEY_PREFIX.to_string())
+        .chain(
+            rand::random::<[u8; 32]>()
+                .iter()
+                .map(|b| format!("{:02x}", b)),
+        )
+        .collect();
+    let scopes = scopes
+        .iter()
+        .map(|scope| scope.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let id = sqlx::query(
+        r#"
+        INSERT INTO api_keys (name, key_prefix, key_hash, scopes, rate_limit_per_minute, daily_quota)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(name)
+    .bind(&key[..KEY_PREFIX.len() + 8])
+    .bind(hash_key(&key))
+    .bind(scopes)
+    .bind(rate_limit_per_minute as i64)
+    .bind(daily_quota as i64)
+    .execute(pool)
+    .await?
+    .last_insert_rowid();
+
+    Ok((id, key))
+}
+
+/// Every key, live ones first
+pub async fn list_api_keys(pool: &SqlitePool) -> AppResult<Vec<ApiKey>> {
+    let rows = sqlx::query(&format!(
+        "{} ORDER BY revoked_at IS NOT NULL, created_at DESC, id DESC",
+        SELECT_KEYS
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(ApiKey::from_row).collect())
+}
+
+/// Revoke a key. It stays listed with its usage but is refused from then on.
+pub async fn revoke_api_key(pool: &SqlitePool, id: i64) -> AppResult<()> {
+    let result = sqlx::query(
+        "UPDATE api_keys SET revoked_at = CURRENT_TIMESTAMP WHERE id = ? AND revoked_at IS NULL",
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::Validation(
+            "That API key does not exist or is already revoked".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// The live key matching a presented key, if any
+pub async fn find_api_key(pool: &SqlitePool, key: &str) -> AppResult<Option<ApiKey>> {
+    if !key.starts_with(KEY_PREFIX) {
+        return Ok(None);
+    }
+
+    let row = sqlx::query(&format!(
+        "{} WHERE key_hash = ? AND revoked_at IS NULL",
+        SELECT_KEYS
+    ))
+    .bind(hash_key(key))
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.as_ref().map(ApiKey::from_row))
+}
+
+/// Count a request against a key. Returns false, counting nothing, once the key
+/// has used up today's quota.
+pub async fn record_use(pool: &SqlitePool, id: i64) -> AppResult<bool> {
+    let result = sqlx::query(
+        r#"
+        UPDATE api_keys SET
+            quota_used = CASE WHEN quota_day = date('now') THEN quota_used + 1 ELSE 1 END,
+            quota_day = date('now'),
+            request_count = request_count + 1,
+            last_used_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+          AND (daily_quota = 0 OR quota_day IS NOT date('now') OR quota_used < daily_quota)
+        "#,
+    )
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        create_api_keys_table(&pool).await.unwrap();
+        pool
+    }
+
+    #[test]
+    fn requests_need_the_matching_scope() {
+        assert_eq!(
+            required_scope(&Method::GET, "/random_zone"),
+            Some(Scope::Read)
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/zones/4/rating"),
+            Some(Scope::Rate)
+        );
+        assert_eq!(
+            required_scope(&Method::POST, "/instances/4/rating"),
+            Some(Scope::Rate)
+        );
+        assert_eq!(
+            required_scope(&Method::GET, "/admin/zones"),
+            Some(Scope::AdminRead)
+        );
+        assert_eq!(required_scope(&Method::POST, "/admin/zones/4"), None);
+        assert_eq!(required_scope(&Method::DELETE, "/api/ratings/4"), None);
+        assert_eq!(required_scope(&Method::POST, "/api/links"), None);
+    }
+
+    #[tokio::test]
+    async fn keys_are_hashed_counted_and_revocable() {
+        let pool = setup_pool().await;
+        let (id, key) = create_api_key(&pool, "discord bot", &[Scope::Read, Scope::Rate], 30, 2)
+            .await
+            .unwrap();
+
+        assert!(key.starts_with(KEY_PREFIX));
+        let stored: String = sqlx::query_scalar("SELECT key_hash FROM api_keys WHERE id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_ne!(stored, key);
+
+        let found = find_api_key(&pool, &key).await.unwrap().unwrap();
+        assert_eq!(found.id, id);
+        assert!(found.allows(Scope::Rate));
+        assert!(!found.allows(Scope::AdminRead));
+        assert!(find_api_key(&pool, "eqrng_wrong").await.unwrap().is_none());
+
+        assert!(record_use(&pool, id).await.unwrap());
+        assert!(record_use(&pool, id).await.unwrap());
+        assert!(!record_use(&pool, id).await.unwrap());
+        let found = find_api_key(&pool, &key).await.unwrap().unwrap();
+        assert_eq!(found.request_count, 2);
+        assert_eq!(found.quota_used, 2);
+        assert!(found.last_used_at.is_some());
+
+        // A new day starts the quota over
+        sqlx::query("UPDATE api_keys SET quota_day = date('now', '-1 day')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(record_use(&pool, id).await.unwrap());
+
+        revoke_api_key(&pool, id).await.unwrap();
+        assert!(find_api_key(&pool, &key).await.unwrap().is_none());
+        assert!(revoke_api_key(&pool, id).await.is_err());
+        assert_eq!(list_api_keys(&pool).await.unwrap().len(), 1);
+
+        assert!(create_api_key(&pool, "overlay", &[], 30, 0).await.is_err());
+    }
+}
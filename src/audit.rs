@@ -0,0 +1,734 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::{QueryBuilder, Row, Sqlite, SqliteConnection, SqlitePool, Transaction};
+
+/// Actor recorded for changes made outside a request (CLI tools, startup tasks, tests)
+pub const SYSTEM_ACTOR: &str = "system";
+
+tokio::task_local! {
+    static ACTOR: String;
+}
+
+/// Who is making the change in the current request, or `SYSTEM_ACTOR` outside one
+pub fn current_actor() -> String {
+    ACTOR
+        .try_with(|actor| actor.clone())
+        .unwrap_or_else(|_| SYSTEM_ACTOR.to_string())
+}
+
+/// Run `fut` with `actor` as the actor for any audit entries it records
+pub async fn with_actor<F: std::future::Future>(actor: String, fut: F) -> F::Output {
+    ACTOR.scope(actor, fut).await
+}
+
+/// Middleware that names the actor for audit entries recorded while handling a request.
+/// Signed-in admins are named again, by username, once their session is checked.
+pub async fn scope_request_actor(
+    request: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let client = crate::client_ip::ClientIp::from_extensions(request.extensions())
+        .map(|client| client.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    with_actor(format!("anonymous@{}", client), next.run(request)).await
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl AuditAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Update => "update",
+            AuditAction::Delete => "delete",
+        }
+    }
+}
+
+/// Kinds of record the audit log tracks, each backed by one table
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEntity {
+    Zone,
+    Instance,
+    ZoneNote,
+    InstanceNote,
+    ZoneFlag,
+    ZoneAlias,
+    InstanceAlias,
+    InstanceFlag,
+    InstanceRating,
+    NoteType,
+    FlagType,
+    Link,
+    Rating,
+}
+
+impl AuditEntity {
+    pub const ALL: &'static [AuditEntity] = &[
+        AuditEntity::Zone,
+        AuditEntity::Instance,
+        AuditEntity::ZoneNote,
+        AuditEntity::InstanceNote,
+        AuditEntity::ZoneFlag,
+        AuditEntity::ZoneAlias,
+        AuditEntity::InstanceAlias,
+        AuditEntity::InstanceFlag,
+        AuditEntity::InstanceRating,
+        AuditEntity::NoteType,
+        AuditEntity::FlagType,
+        AuditEntity::Link,
+        AuditEntity::Rating,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditEntity::Zone => "zone",
+            AuditEntity::Instance => "instance",
+            AuditEntity::ZoneNote => "zone_note",
+            AuditEntity::InstanceNote => "instance_note",
+            AuditEntity::ZoneFlag => "zone_flag",
+            AuditEntity::ZoneAlias => "zone_alias",
+            AuditEntity::InstanceAlias => "instance_alias",
+            AuditEntity::InstanceFlag => "instance_flag",
+            AuditEntity::InstanceRating => "instance_rating",
+            AuditEntity::NoteType => "note_type",
+            AuditEntity::FlagType => "flag_type",
+            AuditEntity::Link => "link",
+            AuditEntity::Rating => "rating",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<AuditEntity> {
+        Self::ALL.iter().copied().find(|e| e.as_str() == value)
+    }
+
+    pub fn table(self) -> &'static str {
+        match self {
+            AuditEntity::Zone => "zones",
+            AuditEntity::Instance => "instances",
+            AuditEntity::ZoneNote => "zone_notes",
+            AuditEntity::InstanceNote => "instance_notes",
+            AuditEntity::ZoneFlag => "zone_flags",
+            AuditEntity::ZoneAlias => "zone_aliases",
+            AuditEntity::InstanceAlias => "instance_aliases",
+            AuditEntity::InstanceFlag => "instance_flags",
+            AuditEntity::InstanceRating => "instance_ratings",
+            AuditEntity::NoteType => "note_types",
+            AuditEntity::FlagType => "flag_types",
+            AuditEntity::Link => "links",
+            AuditEntity::Rating => "zone_ratings",
+        }
+    }
+
+    /// Rows removed by `ON DELETE CASCADE` when a row of this kind is deleted, as
+    /// (child kind, foreign key column)
+    pub fn cascades(self) -> &'static [(AuditEntity, &'static str)] {
+        match self {
+            AuditEntity::Zone => &[
+                (AuditEntity::ZoneNote, "zone_id"),
+                (AuditEntity::ZoneFlag, "zone_id"),
+                (AuditEntity::ZoneAlias, "zone_id"),
+                (AuditEntity::Rating, "zone_id"),
+            ],
+            AuditEntity::Instance => &[
+                (AuditEntity::InstanceNote, "instance_id"),
+                (AuditEntity::InstanceAlias, "instance_id"),
+                (AuditEntity::InstanceFlag, "instance_id"),
+                (AuditEntity::InstanceRating, "instance_id"),
+            ],
+            AuditEntity::NoteType => &[
+                (AuditEntity::ZoneNote, "note_type_id"),
+                (AuditEntity::InstanceNote, "note_type_id"),
+            ],
+            AuditEntity::FlagType => &[
+                (AuditEntity::ZoneFlag, "flag_type_id"),
+                (AuditEntity::InstanceFlag, "flag_type_id"),
+            ],
+            _ => &[],
+        }
+    }
+}
+
+/// A change to one row, ready to be written to the audit log
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditChange {
+    pub action: AuditAction,
+    pub entity: AuditEntity,
+    pub entity_id: i64,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// One field that differs between the before and after images of a row
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub action: String,
+    pub actor: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub changes: Vec<FieldChange>,
+    /// First entry of the operation this entry belongs to, when it wrote several
+    pub batch_id: Option<i64>,
+    /// Root of the operation this entry undid, for changes made by a revert
+    pub revert_of: Option<i64>,
+    pub created_at: String,
+}
+
+impl AuditEntry {
+    /// Id shared by every entry written by the same operation
+    pub fn batch_root(&self) -> i64 {
+        self.batch_id.unwrap_or(self.id)
+    }
+}
+
+pub(crate) async fn create_audit_log_table(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='audit_log'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !table_exists {
+        println!("Creating audit_log table...");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                entity_type TEXT NOT NULL,
+                entity_id INTEGER NOT NULL,
+                action TEXT NOT NULL CHECK (action IN ('create', 'update', 'delete')),
+                actor TEXT NOT NULL,
+                before_json TEXT,
+                after_json TEXT,
+                changes_json TEXT NOT NULL DEFAULT '[]',
+                batch_id INTEGER,
+                revert_of INTEGER,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        let audit_indexes = [
+            "CREATE INDEX IF NOT EXISTS idx_audit_log_entity ON audit_log(entity_type, entity_id)",
+            "CREATE INDEX IF NOT EXISTS idx_audit_log_created_at ON audit_log(created_at)",
+            "CREATE INDEX IF NOT EXISTS idx_audit_log_batch_id ON audit_log(batch_id)",
+            "CREATE INDEX IF NOT EXISTS idx_audit_log_revert_of ON audit_log(revert_of)",
+        ];
+
+        for index_sql in &audit_indexes {
+            sqlx::query(index_sql).execute(pool).await?;
+        }
+
+        println!("Audit log table created successfully.");
+    } else {
+        println!("Audit log table already exists");
+    }
+
+    Ok(())
+}
+
+/// Column names of an audited table, in table order
+pub(crate) async fn table_columns(
+    conn: &mut SqliteConnection,
+    entity: AuditEntity,
+) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT name FROM pragma_table_info(?)")
+        .bind(entity.table())
+        .fetch_all(conn)
+        .await
+}
+
+/// The current row for an entity as a JSON object keyed by column name
+pub(crate) async fn fetch_row_json_on(
+    conn: &mut SqliteConnection,
+    entity: AuditEntity,
+    id: i64,
+) -> Result<Option<Value>, sqlx::Error> {
+    Ok(fetch_rows_json(conn, entity, "id", id).await?.pop())
+}
+
+/// Rows of `entity` whose `column` equals `value`, as JSON objects ordered by id
+async fn fetch_rows_json(
+    conn: &mut SqliteConnection,
+    entity: AuditEntity,
+    column: &str,
+    value: i64,
+) -> Result<Vec<Value>, sqlx::Error> {
+    let columns = table_columns(&mut *conn, entity).await?;
+
+    let pairs = columns
+        .iter()
+        .map(|column| format!("'{}', \"{}\"", column, column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let query = format!(
+        "SELECT json_object({}) FROM {} WHERE \"{}\" = ? ORDER BY id",
+        pairs,
+        entity.table(),
+        column
+    );
+
+    let rows: Vec<String> = sqlx::query_scalar(&query)
+        .bind(value)
+        .fetch_all(conn)
+        .await?;
+
+    Ok(rows
+        .iter()
+        .filter_map(|json| serde_json::from_str(json).ok())
+        .collect())
+}
+
+/// Delete entries for a row and every row that would cascade with it, parent first.
+/// Take this before deleting, in the same transaction, so the whole operation can be
+/// recorded and reverted.
+pub async fn capture_cascade(
+    conn: &mut SqliteConnection,
+    entity: AuditEntity,
+    id: i64,
+) -> Vec<AuditChange> {
+    let result: Result<Vec<AuditChange>, sqlx::Error> = async {
+        let mut changes = Vec::new();
+
+        let Some(parent) = fetch_row_json_on(&mut *conn, entity, id).await? else {
+            return Ok(changes);
+        };
+        changes.push(AuditChange {
+            action: AuditAction::Delete,
+            entity,
+            entity_id: id,
+            before: Some(parent),
+            after: None,
+        });
+
+        for (child, column) in entity.cascades() {
+            for row in fetch_rows_json(&mut *conn, *child, column, id).await? {
+                changes.push(AuditChange {
+                    action: AuditAction::Delete,
+                    entity: *child,
+                    entity_id: row["id"].as_i64().unwrap_or_default(),
+                    before: Some(row),
+                    after: None,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+    .await;
+
+    result.unwrap_or_else(|e| {
+        tracing::error!(error = %e, entity = entity.as_str(), id, "Failed to read rows for audit log");
+        Vec::new()
+    })
+}
+
+/// Snapshot a row for the audit log on the transaction making the change. Failures
+/// are logged and yield None so a missing image never blocks the change itself.
+pub async fn capture(conn: &mut SqliteConnection, entity: AuditEntity, id: i64) -> Option<Value> {
+    match fetch_row_json_on(conn, entity, id).await {
+        Ok(row) => row,
+        Err(e) => {
+            tracing::error!(error = %e, entity = entity.as_str(), id, "Failed to read row for audit log");
+            None
+        }
+    }
+}
+
+/// Fields that differ between two row images. A missing image (create or delete)
+/// counts as null for every field of the other.
+pub fn diff_fields(before: Option<&Value>, after: Option<&Value>) -> Vec<FieldChange> {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let old = before.get(field).cloned().unwrap_or(Value::Null);
+            let new = after.get(field).cloned().unwrap_or(Value::Null);
+            (old != new).then(|| FieldChange {
+                field: field.clone(),
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+/// Write the audit entries for one operation on an open connection or transaction.
+/// The first entry is the batch root; the rest point at it through `batch_id`.
+/// `revert_of` is the root of the operation being undone, for reverts.
+/// Updates that changed nothing are skipped. Returns the root entry's id.
+pub(crate) async fn insert_batch(
+    conn: &mut SqliteConnection,
+    changes: Vec<AuditChange>,
+    revert_of: Option<i64>,
+) -> Result<Option<i64>, sqlx::Error> {
+    let actor = current_actor();
+    let mut root = None;
+
+    for change in changes {
+        let fields = diff_fields(change.before.as_ref(), change.after.as_ref());
+        if change.action == AuditAction::Update && fields.is_empty() {
+            continue;
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO audit_log (entity_type, entity_id, action, actor, before_json, after_json, changes_json, batch_id, revert_of) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(change.entity.as_str())
+        .bind(change.entity_id)
+        .bind(change.action.as_str())
+        .bind(&actor)
+        .bind(change.before.map(|v| v.to_string()))
+        .bind(change.after.map(|v| v.to_string()))
+        .bind(serde_json::to_string(&fields).unwrap_or_else(|_| "[]".to_string()))
+        .bind(root)
+        .bind(revert_of)
+        .execute(&mut *conn)
+        .await?;
+
+        root.get_or_insert(result.last_insert_rowid());
+
+        crate::logging::log_admin_action(
+            change.action.as_str(),
+            change.entity.as_str(),
+            Some(change.entity_id),
+            Some(&actor),
+        );
+    }
+
+    Ok(root)
+}
+
+/// Write the audit entries for an operation in the transaction that applies it, so
+/// the change and its history commit or roll back together. Returns the root entry's id.
+pub async fn record_batch_change(
+    tx: &mut Transaction<'_, Sqlite>,
+    changes: Vec<AuditChange>,
+) -> Result<Option<i64>, sqlx::Error> {
+    insert_batch(tx, changes, None).await
+}
+
+/// Write one audit entry in the transaction that applies the change. Updates that
+/// changed nothing are skipped.
+pub async fn record_change(
+    tx: &mut Transaction<'_, Sqlite>,
+    action: AuditAction,
+    entity: AuditEntity,
+    entity_id: i64,
+    before: Option<Value>,
+    after: Option<Value>,
+) -> Result<(), sqlx::Error> {
+    record_batch_change(
+        tx,
+        vec![AuditChange {
+            action,
+            entity,
+            entity_id,
+            before,
+            after,
+        }],
+    )
+    .await
+    .map(|_| ())
+}
+
+#[derive(Debug, Default, Deserialize, Clone)]
+pub struct AuditFilter {
+    pub entity_type: Option<String>,
+    pub entity_id: Option<i64>,
+    /// Inclusive lower bound, `YYYY-MM-DD` or a full timestamp
+    pub from: Option<String>,
+    /// Inclusive upper bound; a bare date covers that whole day
+    pub to: Option<String>,
+}
+
+fn push_filter(builder: &mut QueryBuilder<'_, Sqlite>, filter: &AuditFilter) {
+    builder.push(" WHERE 1=1");
+    if let Some(entity_type) = filter.entity_type.as_deref().filter(|s| !s.is_empty()) {
+        builder
+            .push(" AND entity_type = ")
+            .push_bind(entity_type.to_string());
+    }
+    if let Some(entity_id) = filter.entity_id {
+        builder.push(" AND entity_id = ").push_bind(entity_id);
+    }
+    if let Some(from) = filter.from.as_deref().filter(|s| !s.is_empty()) {
+        builder
+            .push(" AND created_at >= datetime(")
+            .push_bind(from.to_string())
+            .push(")");
+    }
+    if let Some(to) = filter.to.as_deref().filter(|s| !s.is_empty()) {
+        // A bare date means the end of that day
+        let modifier = if to.len() == 10 {
+            "+1 day"
+        } else {
+            "+1 second"
+        };
+        builder
+            .push(" AND created_at < datetime(")
+            .push_bind(to.to_string())
+            .push(", ")
+            .push_bind(modifier)
+            .push(")");
+    }
+}
+
+pub async fn count_entries(pool: &SqlitePool, filter: &AuditFilter) -> Result<i64, sqlx::Error> {
+    let mut builder = QueryBuilder::new("SELECT COUNT(*) FROM audit_log");
+    push_filter(&mut builder, filter);
+    builder.build_query_scalar().fetch_one(pool).await
+}
+
+const ENTRY_COLUMNS: &str = "id, entity_type, entity_id, action, actor, before_json, after_json, changes_json, batch_id, revert_of, created_at";
+
+fn entry_from_row(row: &sqlx::sqlite::SqliteRow) -> AuditEntry {
+    let parse = |json: Option<String>| json.and_then(|json| serde_json::from_str(&json).ok());
+
+    AuditEntry {
+        id: row.get("id"),
+        entity_type: row.get("entity_type"),
+        entity_id: row.get("entity_id"),
+        action: row.get("action"),
+        actor: row.get("actor"),
+        before: parse(row.get("before_json")),
+        after: parse(row.get("after_json")),
+        changes: serde_json::from_str(&row.get::<String, _>("changes_json")).unwrap_or_default(),
+        batch_id: row.get("batch_id"),
+        revert_of: row.get("revert_of"),
+        created_at: row.get("created_at"),
+    }
+}
+
+/// Audit entries matching `filter`, newest first
+pub async fn list_entries(
+    pool: &SqlitePool,
+    filter: &AuditFilter,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<AuditEntry>, sqlx::Error> {
+    let mut builder = QueryBuilder::new(format!("SELECT {} FROM audit_log", ENTRY_COLUMNS));
+    push_filter(&mut builder, filter);
+    builder
+        .push(" ORDER BY id DESC LIMIT ")
+        .push_bind(limit)
+        .push(" OFFSET ")
+        .push_bind(offset);
+
+    let rows = builder.build().fetch_all(pool).await?;
+    Ok(rows.iter().map(entry_from_row).collect())
+}
+
+pub async fn get_entry(pool: &SqlitePool, id: i64) -> Result<Option<AuditEntry>, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {} FROM audit_log WHERE id = ?",
+        ENTRY_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.as_ref().map(entry_from_row))
+}
+
+/// Every entry written by the operation rooted at `root`, in the order written
+pub async fn get_batch(
+    conn: &mut SqliteConnection,
+    root: i64,
+) -> Result<Vec<AuditEntry>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {} FROM audit_log WHERE id = ? OR batch_id = ? ORDER BY id",
+        ENTRY_COLUMNS
+    ))
+    .bind(root)
+    .bind(root)
+    .fetch_all(conn)
+    .await?;
+    Ok(rows.iter().map(entry_from_row).collect())
+}
+
+/// Root entry of the revert that undid the operation rooted at `root`, if any
+pub async fn find_revert(
+    conn: &mut SqliteConnection,
+    root: i64,
+) -> Result<Option<i64>, sqlx::Error> {
+    sqlx::query_scalar("SELECT id FROM audit_log WHERE revert_of = ? ORDER BY id LIMIT 1")
+        .bind(root)
+        .fetch_optional(conn)
+        .await
+}
+
+/// Entries for the same row as `entry` written after it by other operations, oldest first
+pub async fn later_entries(
+    conn: &mut SqliteConnection,
+    entry: &AuditEntry,
+) -> Result<Vec<AuditEntry>, sqlx::Error> {
+    let rows = sqlx::query(&format!(
+        "SELECT {} FROM audit_log WHERE entity_type = ? AND entity_id = ? AND id > ? AND COALESCE(batch_id, id) != ? ORDER BY id",
+        ENTRY_COLUMNS
+    ))
+    .bind(&entry.entity_type)
+    .bind(entry.entity_id)
+    .bind(entry.id)
+    .bind(entry.batch_root())
+    .fetch_all(conn)
+    .await?;
+    Ok(rows.iter().map(entry_from_row).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[test]
+    fn diff_lists_only_changed_fields() {
+        let before = serde_json::json!({"id": 1, "name": "Befallen", "rating": 3});
+        let after = serde_json::json!({"id": 1, "name": "Befallen", "rating": 4});
+
+        let changes = diff_fields(Some(&before), Some(&after));
+        assert_eq!(
+            changes,
+            vec![FieldChange {
+                field: "rating".to_string(),
+                before: 3.into(),
+                after: 4.into(),
+            }]
+        );
+        assert_eq!(diff_fields(None, Some(&after)).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn records_changes_with_actor_and_filters() {
+        let pool = setup_pool().await;
+
+        with_actor("admin@127.0.0.1".to_string(), async {
+            let mut tx = pool.begin().await.unwrap();
+            sqlx::query("INSERT INTO links (id, name, url, category) VALUES (1, 'Alla', 'https://example.com', 'Database')")
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+            let created = capture(&mut tx, AuditEntity::Link, 1).await;
+            assert_eq!(created.as_ref().unwrap()["name"], "Alla");
+            record_change(
+                &mut tx,
+                AuditAction::Create,
+                AuditEntity::Link,
+                1,
+                None,
+                created.clone(),
+            )
+            .await
+            .unwrap();
+
+            sqlx::query("UPDATE links SET name = 'Allakhazam' WHERE id = 1")
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+            let updated = capture(&mut tx, AuditEntity::Link, 1).await;
+            record_change(
+                &mut tx,
+                AuditAction::Update,
+                AuditEntity::Link,
+                1,
+                created.clone(),
+                updated.clone(),
+            )
+            .await
+            .unwrap();
+            // No-op updates leave no entry
+            record_change(
+                &mut tx,
+                AuditAction::Update,
+                AuditEntity::Link,
+                1,
+                updated.clone(),
+                updated,
+            )
+            .await
+            .unwrap();
+            tx.commit().await.unwrap();
+
+            // A change that rolls back takes its entry with it
+            let mut tx = pool.begin().await.unwrap();
+            let before = capture(&mut tx, AuditEntity::Link, 1).await;
+            sqlx::query("DELETE FROM links WHERE id = 1")
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+            record_change(&mut tx, AuditAction::Delete, AuditEntity::Link, 1, before, None)
+                .await
+                .unwrap();
+            tx.rollback().await.unwrap();
+        })
+        .await;
+
+        let filter = AuditFilter {
+            entity_type: Some("link".to_string()),
+            entity_id: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(count_entries(&pool, &filter).await.unwrap(), 2);
+
+        let entries = list_entries(&pool, &filter, 10, 0).await.unwrap();
+        assert_eq!(entries[0].action, "update");
+        assert_eq!(entries[0].actor, "admin@127.0.0.1");
+        assert_eq!(entries[0].changes.len(), 1);
+        assert_eq!(entries[0].changes[0].field, "name");
+        assert_eq!(entries[0].changes[0].after, "Allakhazam");
+        assert_eq!(entries[1].action, "create");
+        assert!(entries[1].before.is_none());
+
+        let future = AuditFilter {
+            from: Some("2999-01-01".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(count_entries(&pool, &future).await.unwrap(), 0);
+        let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        let until_today = AuditFilter {
+            to: Some(today),
+            ..Default::default()
+        };
+        assert_eq!(count_entries(&pool, &until_today).await.unwrap(), 2);
+
+        // Outside a request the actor is the system
+        assert_eq!(current_actor(), SYSTEM_ACTOR);
+    }
+}
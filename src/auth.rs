@@ -0,0 +1,551 @@
+use argon2::Argon2;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use crate::error::{AppError, AppResult};
+
+pub const MIN_PASSWORD_LENGTH: usize = 12;
+pub const MAX_USERNAME_LENGTH: usize = 64;
+
+/// Hash checked when a login names an unknown user, so the response takes as long
+/// as a wrong password would. Made with the same parameters as `hash_password`.
+const DUMMY_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$HI5q77EvG3PzlfxbAVnwPQ$8xjNom5yE1UxwbZnzSGAHtl5k1pY4FrHikNjgAY28gk";
+
+/// What an admin may do. Each role can do everything the roles before it can.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Browse every admin page but change nothing
+    Viewer,
+    /// Edit zones, instances, notes, flags, aliases, types and links
+    Editor,
+    /// Also delete ratings, purge the trash and revert audited changes
+    Moderator,
+    /// Also manage admin users, backups, database dumps and migrations
+    Owner,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [Role::Viewer, Role::Editor, Role::Moderator, Role::Owner];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Editor => "editor",
+            Role::Moderator => "moderator",
+            Role::Owner => "owner",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|role| role.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Role::Viewer => "Viewer",
+            Role::Editor => "Data editor",
+            Role::Moderator => "Moderator",
+            Role::Owner => "Owner",
+        }
+    }
+
+    pub fn allows(self, required: Role) -> bool {
+        self >= required
+    }
+}
+
+/// A signed-in admin, attached to admin requests by the session middleware
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct AdminUser {
+    pub id: i64,
+    pub username: String,
+    pub role: Role,
+}
+
+/// An admin account as listed on the users page
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct AdminAccount {
+    pub id: i64,
+    pub username: String,
+    pub role: Role,
+    pub created_at: Option<String>,
+    pub last_login_at: Option<String>,
+}
+
+fn row_role(row: &sqlx::sqlite::SqliteRow) -> Role {
+    // An unreadable role grants nothing beyond viewing
+    Role::parse(&row.get::<String, _>("role")).unwrap_or(Role::Viewer)
+}
+
+pub async fn create_auth_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let users_exist =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='admin_users'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !users_exist {
+        println!("Creating admin_users table...");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE admin_users (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                username TEXT NOT NULL COLLATE NOCASE UNIQUE,
+                password_hash TEXT NOT NULL,
+                role TEXT NOT NULL CHECK (role IN ('viewer', 'editor', 'moderator', 'owner')),
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                last_login_at DATETIME
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    let sessions_exist =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='admin_sessions'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !sessions_exist {
+        println!("Creating admin_sessions table...");
+
+        // Only a hash of each session token is stored, so a leaked database cannot be
+        // used to sign in
+        sqlx::query(
+            r#"
+            CREATE TABLE admin_sessions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                token_hash TEXT NOT NULL UNIQUE,
+                user_id INTEGER NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                expires_at DATETIME NOT NULL,
+                FOREIGN KEY (user_id) REFERENCES admin_users (id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_admin_sessions_user_id ON admin_sessions(user_id)",
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub fn hash_password(password: &str) -> AppResult<String> {
+    let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>())
+        .map_err(|e| AppError::Server(format!("Failed to generate salt: {}", e)))?;
+
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AppError::Server(format!("Failed to hash password: {}", e)))
+}
+
+fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .map(|parsed| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+fn hash_token(token: &str) -> String {
+    blake3::hash(token.as_bytes()).to_hex().to_string()
+}
+
+/// Check a username and password before an account is created or its password changed
+pub fn validate_credentials(username: &str, password: &str) -> AppResult<()> {
+    if username.is_empty() || username.len() > MAX_USERNAME_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Username must be 1 to {} characters",
+            MAX_USERNAME_LENGTH
+        )));
+    }
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(AppError::Validation(
+            "Username may only contain letters, digits, '_', '-' and '.'".to_string(),
+        ));
+    }
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+pub async fn create_admin_user(
+    pool: &SqlitePool,
+    username: &str,
+    password: &str,
+    role: Role,
+) -> AppResult<i64> {
+    validate_credentials(username, password)?;
+
+    let exists = sqlx::query("SELECT id FROM admin_users WHERE username = ?")
+        .bind(username)
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+    if exists {
+        return Err(AppError::Validation(format!(
+            "Admin user '{}' already exists",
+            username
+        )));
+    }
+
+    let result =
+        sqlx::query("INSERT INTO admin_users (username, password_hash, role) VALUES (?, ?, ?)")
+            .bind(username)
+            .bind(hash_password(password)?)
+            .bind(role.as_str())
+            .execute(pool)
+            .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Replace an admin's password and sign them out everywhere
+pub async fn set_admin_password(
+    pool: &SqlitePool,
+    username: &str,
+    password: &str,
+) -> AppResult<()> {
+    validate_credentials(username, password)?;
+
+    let user_id: i64 = sqlx::query_scalar("SELECT id FROM admin_users WHERE username = ?")
+        .bind(username)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::Validation(format!("No admin user named '{}'", username)))?;
+
+    sqlx::query("UPDATE admin_users SET password_hash = ? WHERE id = ?")
+        .bind(hash_password(password)?)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM admin_sessions WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn admin_user_count(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM admin_users")
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn list_admin_users(pool: &SqlitePool) -> Result<Vec<AdminAccount>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, username, role, created_at, last_login_at FROM admin_users ORDER BY username",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| AdminAccount {
+            id: row.get("id"),
+            username: row.get("username"),
+            role: row_role(row),
+            created_at: row.get("created_at"),
+            last_login_at: row.get("last_login_at"),
+        })
+        .collect())
+}
+
+/// Refuse a change that would leave nobody able to manage admin users
+async fn ensure_other_owner(pool: &SqlitePool, user_id: i64) -> AppResult<()> {
+    let other_owners: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM admin_users WHERE role = 'owner' AND id != ?")
+            .bind(user_id)
+            .fetch_one(pool)
+            .await?;
+    if other_owners == 0 {
+        return Err(AppError::Validation(
+            "There must always be at least one owner".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub async fn set_admin_role(pool: &SqlitePool, user_id: i64, role: Role) -> AppResult<()> {
+    if role != Role::Owner {
+        ensure_other_owner(pool, user_id).await?;
+    }
+
+    let result = sqlx::query("UPDATE admin_users SET role = ? WHERE id = ?")
+        .bind(role.as_str())
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::Validation(format!(
+            "No admin user with id {}",
+            user_id
+        )));
+    }
+    Ok(())
+}
+
+/// Remove an admin account along with its sessions
+pub async fn delete_admin_user(pool: &SqlitePool, user_id: i64) -> AppResult<()> {
+    ensure_other_owner(pool, user_id).await?;
+
+    sqlx::query("DELETE FROM admin_sessions WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    sqlx::query("DELETE FROM admin_users WHERE id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// The admin with this username and password, or None when either is wrong
+pub async fn authenticate(
+    pool: &SqlitePool,
+    username: &str,
+    password: &str,
+) -> AppResult<Option<AdminUser>> {
+    let row =
+        sqlx::query("SELECT id, username, role, password_hash FROM admin_users WHERE username = ?")
+            .bind(username.trim())
+            .fetch_optional(pool)
+            .await?;
+
+    let Some(row) = row else {
+        verify_password(password, DUMMY_HASH);
+        return Ok(None);
+    };
+    if !verify_password(password, &row.get::<String, _>("password_hash")) {
+        return Ok(None);
+    }
+
+    let user = AdminUser {
+        id: row.get("id"),
+        username: row.get("username"),
+        role: row_role(&row),
+    };
+    sqlx::query("UPDATE admin_users SET last_login_at = CURRENT_TIMESTAMP WHERE id = ?")
+        .bind(user.id)
+        .execute(pool)
+        .await?;
+
+    Ok(Some(user))
+}
+
+/// Start a session for `user_id` lasting `ttl_minutes` and return its token for the
+/// cookie. Expired sessions are cleared out on the way.
+pub async fn start_session(pool: &SqlitePool, user_id: i64, ttl_minutes: u64) -> AppResult<String> {
+    sqlx::query("DELETE FROM admin_sessions WHERE expires_at <= CURRENT_TIMESTAMP")
+        .execute(pool)
+        .await?;
+
+    let token: String = rand::random::<[u8; 32]>()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+
+    sqlx::query(
+        "INSERT INTO admin_sessions (token_hash, user_id, expires_at) VALUES (?, ?, datetime('now', ?))",
+    )
+    .bind(hash_token(&token))
+    .bind(user_id)
+    .bind(format!("+{} minutes", ttl_minutes))
+    .execute(pool)
+    .await?;
+
+    Ok(token)
+}
+
+/// The admin a session token belongs to, if the session exists and has not expired
+pub async fn session_user(pool: &SqlitePool, token: &str) -> AppResult<Option<AdminUser>> {
+    let row = sqlx::query(
+        r#"
+        SELECT u.id, u.username, u.role
+        FROM admin_sessions s
+        JOIN admin_users u ON u.id = s.user_id
+        WHERE s.token_hash = ? AND s.expires_at > CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(hash_token(token))
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|row| AdminUser {
+        id: row.get("id"),
+        username: row.get("username"),
+        role: row_role(&row),
+    }))
+}
+
+pub async fn end_session(pool: &SqlitePool, token: &str) -> AppResult<()> {
+    sqlx::query("DELETE FROM admin_sessions WHERE token_hash = ?")
+        .bind(hash_token(token))
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        create_auth_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn passwords_are_hashed_and_checked() {
+        let pool = setup_pool().await;
+        create_admin_user(&pool, "guide", "correct horse battery", Role::Owner)
+            .await
+            .unwrap();
+
+        let stored: String =
+            sqlx::query_scalar("SELECT password_hash FROM admin_users WHERE username = 'guide'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(stored.starts_with("$argon2"));
+
+        let user = authenticate(&pool, "Guide", "correct horse battery")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.username, "guide");
+        assert!(
+            authenticate(&pool, "guide", "wrong password!!")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            authenticate(&pool, "nobody", "correct horse battery")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        assert!(matches!(
+            create_admin_user(&pool, "GUIDE", "another long password", Role::Viewer).await,
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            create_admin_user(&pool, "short", "too short", Role::Viewer).await,
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn dummy_hash_costs_the_same_as_a_real_one() {
+        let dummy = PasswordHash::new(DUMMY_HASH).unwrap();
+        let real_hash = hash_password("correct horse battery").unwrap();
+        let real = PasswordHash::new(&real_hash).unwrap();
+        assert_eq!(dummy.algorithm, real.algorithm);
+        assert_eq!(dummy.version, real.version);
+        assert_eq!(dummy.params, real.params);
+        assert!(verify_password("not-a-real-password", DUMMY_HASH));
+    }
+
+    #[tokio::test]
+    async fn sessions_expire_and_end() {
+        let pool = setup_pool().await;
+        let id = create_admin_user(&pool, "guide", "correct horse battery", Role::Owner)
+            .await
+            .unwrap();
+
+        let token = start_session(&pool, id, 60).await.unwrap();
+        let user = session_user(&pool, &token).await.unwrap().unwrap();
+        assert_eq!(user.id, id);
+        assert!(
+            session_user(&pool, "not-a-session")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        end_session(&pool, &token).await.unwrap();
+        assert!(session_user(&pool, &token).await.unwrap().is_none());
+
+        let expired = start_session(&pool, id, 60).await.unwrap();
+        sqlx::query("UPDATE admin_sessions SET expires_at = datetime('now', '-1 minute')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(session_user(&pool, &expired).await.unwrap().is_none());
+
+        // A new password signs the admin out everywhere
+        let token = start_session(&pool, id, 60).await.unwrap();
+        set_admin_password(&pool, "guide", "an even longer password")
+            .await
+            .unwrap();
+        assert!(session_user(&pool, &token).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn roles_rank_and_keep_an_owner() {
+        assert!(Role::Owner.allows(Role::Moderator));
+        assert!(Role::Editor.allows(Role::Viewer));
+        assert!(!Role::Editor.allows(Role::Moderator));
+        assert_eq!(Role::parse("Moderator"), Some(Role::Moderator));
+        assert_eq!(Role::parse("admin"), None);
+
+        let pool = setup_pool().await;
+        let owner = create_admin_user(&pool, "owner", "correct horse battery", Role::Owner)
+            .await
+            .unwrap();
+        let viewer = create_admin_user(&pool, "viewer", "correct horse battery", Role::Viewer)
+            .await
+            .unwrap();
+
+        // The only owner can be neither demoted nor removed
+        assert!(matches!(
+            set_admin_role(&pool, owner, Role::Editor).await,
+            Err(AppError::Validation(_))
+        ));
+        assert!(delete_admin_user(&pool, owner).await.is_err());
+
+        set_admin_role(&pool, viewer, Role::Owner).await.unwrap();
+        set_admin_role(&pool, owner, Role::Editor).await.unwrap();
+        let token = start_session(&pool, owner, 60).await.unwrap();
+        let user = session_user(&pool, &token).await.unwrap().unwrap();
+        assert_eq!(user.role, Role::Editor);
+
+        delete_admin_user(&pool, owner).await.unwrap();
+        assert!(session_user(&pool, &token).await.unwrap().is_none());
+        let accounts = list_admin_users(&pool).await.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].role, Role::Owner);
+    }
+}
@@ -0,0 +1,344 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+use crate::config::BackupConfig;
+use crate::error::{AppError, AppResult};
+
+pub const BACKUP_PREFIX: &str = "zones_backup_";
+pub const BACKUP_EXTENSION: &str = ".db";
+pub const MANIFEST_FILE: &str = "MANIFEST.b3";
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S_%3f";
+
+// Serializes backups so the scheduler and the admin route never race on the manifest
+static BACKUP_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupInfo {
+    pub file_name: String,
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub created_at: DateTime<Utc>,
+    pub size_bytes: u64,
+    /// BLAKE3 checksum recorded in the manifest, if any
+    pub blake3: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupRun {
+    pub backup: BackupInfo,
+    pub removed: Vec<String>,
+}
+
+fn parse_backup_timestamp(file_name: &str) -> Option<DateTime<Utc>> {
+    let stamp = file_name
+        .strip_prefix(BACKUP_PREFIX)?
+        .strip_suffix(BACKUP_EXTENSION)?;
+    NaiveDateTime::parse_from_str(stamp, TIMESTAMP_FORMAT)
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+pub fn hash_file(path: &Path) -> io::Result<String> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = blake3::Hasher::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Read `MANIFEST.b3` as (file name, checksum) pairs; a missing manifest is empty
+pub fn read_manifest(dir: &Path) -> io::Result<Vec<(String, String)>> {
+    let content = match fs::read_to_string(dir.join(MANIFEST_FILE)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    Ok(content
+        .lines()
+        .filter_map(|line| {
+            let (hash, name) = line.split_once("  ")?;
+            Some((name.trim().to_string(), hash.trim().to_string()))
+        })
+        .collect())
+}
+
+// Written in b3sum format so `b3sum --check MANIFEST.b3` works from the backup directory
+fn write_manifest(dir: &Path, entries: &[(String, String)]) -> io::Result<()> {
+    let content: String = entries
+        .iter()
+        .map(|(name, hash)| format!("{}  {}\n", hash, name))
+        .collect();
+
+    let tmp_path = dir.join(format!("{}.tmp", MANIFEST_FILE));
+    fs::write(&tmp_path, content)?;
+    fs::rename(tmp_path, dir.join(MANIFEST_FILE))
+}
+
+/// Take a consistent snapshot of the live database into `backup_dir` and record its checksum
+pub async fn create_backup(pool: &SqlitePool, backup_dir: &str) -> AppResult<BackupInfo> {
+    let dir = Path::new(backup_dir);
+    fs::create_dir_all(dir)?;
+
+    let created_at = Utc::now();
+    let file_name = format!(
+        "{}{}{}",
+        BACKUP_PREFIX,
+        created_at.format(TIMESTAMP_FORMAT),
+        BACKUP_EXTENSION
+    );
+    let path = dir.join(&file_name);
+
+    // VACUUM INTO reads a single transaction snapshot, so writers are never blocked for long
+    // and the copy never contains a half-applied change
+    sqlx::query("VACUUM INTO ?")
+        .bind(path.to_string_lossy().to_string())
+        .execute(pool)
+        .await?;
+
+    let blake3 = hash_file(&path)?;
+    let size_bytes = fs::metadata(&path)?.len();
+
+    let mut manifest = read_manifest(dir)?;
+    manifest.retain(|(name, _)| name != &file_name);
+    manifest.push((file_name.clone(), blake3.clone()));
+    write_manifest(dir, &manifest)?;
+
+    tracing::info!(file = %file_name, size_bytes, "Database backup created");
+
+    Ok(BackupInfo {
+        file_name,
+        path,
+        created_at,
+        size_bytes,
+        blake3: Some(blake3),
+    })
+}
+
+/// All backups in `backup_dir`, newest first
+pub fn list_backups(backup_dir: &str) -> AppResult<Vec<BackupInfo>> {
+    let dir = Path::new(backup_dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let manifest = read_manifest(dir)?;
+    let mut backups = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(created_at) = parse_backup_timestamp(&file_name) else {
+            continue;
+        };
+
+        let blake3 = manifest
+            .iter()
+            .find(|(name, _)| *name == file_name)
+            .map(|(_, hash)| hash.clone());
+
+        backups.push(BackupInfo {
+            size_bytes: entry.metadata()?.len(),
+            path: entry.path(),
+            file_name,
+            created_at,
+            blake3,
+        });
+    }
+
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+    Ok(backups)
+}
+
+/// Check a backup against its manifest checksum.
+/// Backups missing from the manifest fail verification rather than being trusted blindly.
+pub fn verify_backup(backup: &BackupInfo) -> AppResult<()> {
+    let Some(expected) = &backup.blake3 else {
+        return Err(AppError::Validation(format!(
+            "Backup {} has no entry in {}",
+            backup.file_name, MANIFEST_FILE
+        )));
+    };
+
+    let actual = hash_file(&backup.path)?;
+    if &actual != expected {
+        return Err(AppError::Validation(format!(
+            "Checksum mismatch for backup {}: expected {}, found {}",
+            backup.file_name, expected, actual
+        )));
+    }
+
+    Ok(())
+}
+
+/// Delete backups beyond `keep_count` or older than `max_age_days`.
+/// The newest backup is always kept. Returns the removed file names.
+pub fn apply_retention(
+    backup_dir: &str,
+    keep_count: usize,
+    max_age_days: u64,
+) -> AppResult<Vec<String>> {
+    let backups = list_backups(backup_dir)?;
+    let cutoff = Utc::now() - chrono::Duration::days(max_age_days as i64);
+    let mut removed = Vec::new();
+
+    for (index, backup) in backups.iter().enumerate().skip(1) {
+        let over_count = keep_count > 0 && index >= keep_count;
+        let too_old = max_age_days > 0 && backup.created_at < cutoff;
+        if over_count || too_old {
+            fs::remove_file(&backup.path)?;
+            removed.push(backup.file_name.clone());
+        }
+    }
+
+    if !removed.is_empty() {
+        let dir = Path::new(backup_dir);
+        let mut manifest = read_manifest(dir)?;
+        manifest.retain(|(name, _)| !removed.contains(name));
+        write_manifest(dir, &manifest)?;
+        tracing::info!(count = removed.len(), "Removed expired database backups");
+    }
+
+    Ok(removed)
+}
+
+/// Create a backup and then apply the configured retention policy
+pub async fn run_backup(
+    pool: &SqlitePool,
+    backup_dir: &str,
+    config: &BackupConfig,
+) -> AppResult<BackupRun> {
+    let _guard = BACKUP_LOCK.lock().await;
+
+    let backup = create_backup(pool, backup_dir).await?;
+    let removed = apply_retention(backup_dir, config.keep_count, config.max_age_days)?;
+
+    Ok(BackupRun { backup, removed })
+}
+
+/// Start the periodic backup task. Returns None when scheduled backups are disabled.
+pub fn spawn_backup_scheduler(
+    pool: SqlitePool,
+    backup_dir: String,
+    config: BackupConfig,
+) -> Option<tokio::task::JoinHandle<()>> {
+    if !config.enabled || config.interval_minutes == 0 {
+        tracing::info!("Scheduled database backups are disabled");
+        return None;
+    }
+
+    let period = Duration::from_secs(config.interval_minutes * 60);
+    tracing::info!(
+        interval_minutes = config.interval_minutes,
+        backup_dir = %backup_dir,
+        "Scheduled database backups enabled"
+    );
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_backup(&pool, &backup_dir, &config).await {
+                tracing::error!(error = %e, "Scheduled database backup failed");
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+
+    // File-backed because VACUUM INTO from an in-memory connection writes to memory too
+    async fn setup_pool(dir: &Path) -> SqlitePool {
+        let options = SqliteConnectOptions::new()
+            .filename(dir.join("zones.db"))
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        sqlx::query(
+            "INSERT INTO zones (name, level_ranges, expansion, zone_type) VALUES ('Befallen', '[[7,25]]', 'Classic', 'Indoor')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn backup_is_a_readable_copy_with_manifest_entry() {
+        let db_dir = tempfile::tempdir().unwrap();
+        let pool = setup_pool(db_dir.path()).await;
+        let dir = tempfile::tempdir().unwrap();
+        let backup_dir = dir.path().to_str().unwrap();
+
+        let backup = create_backup(&pool, backup_dir).await.unwrap();
+        assert!(backup.path.exists());
+        assert_eq!(
+            backup.blake3.as_deref(),
+            Some(hash_file(&backup.path).unwrap().as_str())
+        );
+
+        let manifest = read_manifest(dir.path()).unwrap();
+        assert_eq!(
+            manifest,
+            vec![(backup.file_name.clone(), backup.blake3.clone().unwrap())]
+        );
+
+        let copy = SqlitePool::connect(&format!("sqlite:{}?mode=ro", backup.path.display()))
+            .await
+            .unwrap();
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM zones")
+            .fetch_one(&copy)
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let listed = list_backups(backup_dir).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].file_name, backup.file_name);
+    }
+
+    #[tokio::test]
+    async fn retention_keeps_newest_by_count_and_age() {
+        let dir = tempfile::tempdir().unwrap();
+        let backup_dir = dir.path().to_str().unwrap();
+
+        let now = Utc::now();
+        let mut manifest = Vec::new();
+        for days_ago in [0, 1, 2, 40] {
+            let stamp = (now - chrono::Duration::days(days_ago)).format(TIMESTAMP_FORMAT);
+            let name = format!("{}{}{}", BACKUP_PREFIX, stamp, BACKUP_EXTENSION);
+            fs::write(dir.path().join(&name), b"x").unwrap();
+            manifest.push((name, "00".to_string()));
+        }
+        write_manifest(dir.path(), &manifest).unwrap();
+        fs::write(dir.path().join("unrelated.db"), b"x").unwrap();
+
+        // Age removes the 40-day-old backup; count then trims to the newest three
+        let removed = apply_retention(backup_dir, 3, 30).unwrap();
+        assert_eq!(removed, vec![manifest[3].0.clone()]);
+
+        let removed = apply_retention(backup_dir, 1, 0).unwrap();
+        assert_eq!(removed.len(), 2);
+
+        let remaining = list_backups(backup_dir).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].file_name, manifest[0].0);
+        assert_eq!(
+            read_manifest(dir.path()).unwrap(),
+            vec![manifest[0].clone()]
+        );
+        assert!(dir.path().join("unrelated.db").exists());
+    }
+}
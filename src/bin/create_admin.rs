@@ -0,0 +1,107 @@
+use clap::Parser;
+use sqlx::SqlitePool;
+use std::io::{BufRead, IsTerminal, Write};
+use std::process::ExitCode;
+
+use eq_rng::auth::{Role, create_admin_user, create_auth_tables, set_admin_password};
+
+#[derive(Parser)]
+#[command(name = "create_admin")]
+#[command(
+    about = "Create an admin account, or reset an existing admin's password",
+    long_about = "Create an admin account, or reset an existing admin's password.\n\nThe password is read from EQ_RNG_ADMIN_PASSWORD if set, otherwise from the first line of standard input."
+)]
+struct Args {
+    /// Username to create
+    username: String,
+
+    /// Database file holding the admin accounts
+    #[arg(long, default_value = "./data/zones.db")]
+    db: String,
+
+    /// Role for a new admin: viewer, editor, moderator or owner
+    #[arg(long, default_value = "owner", value_parser = parse_role)]
+    role: Role,
+
+    /// Set a new password for an existing admin and sign them out everywhere
+    #[arg(long)]
+    reset_password: bool,
+}
+
+fn parse_role(value: &str) -> Result<Role, String> {
+    Role::parse(value).ok_or_else(|| {
+        format!(
+            "unknown role '{}' (expected one of: viewer, editor, moderator, owner)",
+            value
+        )
+    })
+}
+
+fn read_password() -> std::io::Result<String> {
+    if let Ok(password) = std::env::var("EQ_RNG_ADMIN_PASSWORD") {
+        return Ok(password);
+    }
+
+    let stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        eprint!("Password (shown as you type): ");
+        std::io::stderr().flush()?;
+    }
+    let mut line = String::new();
+    stdin.lock().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let password = match read_password() {
+        Ok(password) => password,
+        Err(e) => {
+            eprintln!("Failed to read password: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // The server creates the database; refuse to start a fresh one here
+    let pool = match SqlitePool::connect(&format!("sqlite:{}?mode=rw", args.db)).await {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Failed to open {}: {}", args.db, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = create_auth_tables(&pool).await {
+        eprintln!("Failed to create admin tables: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    let result = if args.reset_password {
+        set_admin_password(&pool, &args.username, &password)
+            .await
+            .map(|_| format!("Password reset for admin '{}'", args.username))
+    } else {
+        create_admin_user(&pool, &args.username, &password, args.role)
+            .await
+            .map(|id| {
+                format!(
+                    "Created {} '{}' (id {})",
+                    args.role.as_str(),
+                    args.username,
+                    id
+                )
+            })
+    };
+
+    match result {
+        Ok(message) => {
+            println!("{}", message);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
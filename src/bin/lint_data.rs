@@ -0,0 +1,97 @@
+use clap::{Parser, ValueEnum};
+use sqlx::SqlitePool;
+use std::process::ExitCode;
+
+use eq_rng::lint::{LintReport, Severity, lint_database, load_sql_file};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
+    Text,
+}
+
+#[derive(Parser)]
+#[command(name = "lint_data")]
+#[command(about = "Validate zone and instance content in data.sql or a SQLite database")]
+struct Args {
+    /// SQL dump to validate (loaded into an in-memory database)
+    #[arg(long, default_value = "./data/data.sql", conflicts_with = "db")]
+    sql: String,
+
+    /// Validate an existing SQLite database file instead of a SQL dump
+    #[arg(long)]
+    db: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "json")]
+    format: OutputFormat,
+
+    /// Exit with a failure status when warnings are found
+    #[arg(long)]
+    deny_warnings: bool,
+}
+
+fn print_text(report: &LintReport) {
+    for issue in &report.issues {
+        let level = match issue.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        println!(
+            "{}[{}]: {} {} ({}): {}",
+            level, issue.rule, issue.table, issue.id, issue.name, issue.message
+        );
+    }
+    println!(
+        "Checked {} zones and {} instances: {} errors, {} warnings",
+        report.summary.zones_checked,
+        report.summary.instances_checked,
+        report.summary.errors,
+        report.summary.warnings
+    );
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let pool = match &args.db {
+        Some(db_path) => SqlitePool::connect(&format!("sqlite:{}?mode=ro", db_path))
+            .await
+            .map_err(|e| e.to_string()),
+        None => load_sql_file(&args.sql).await.map_err(|e| e.to_string()),
+    };
+
+    let pool = match pool {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Failed to open dataset: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let report = match lint_database(&pool).await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Failed to lint dataset: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    match args.format {
+        OutputFormat::Json => match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Failed to serialize report: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+        OutputFormat::Text => print_text(&report),
+    }
+
+    if report.has_errors() || (args.deny_warnings && report.has_warnings()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use std::process::ExitCode;
+
+use eq_rng::backup::list_backups;
+use eq_rng::restore::{RestoreOptions, restore_database};
+
+#[derive(Parser)]
+#[command(name = "restore_db")]
+#[command(
+    about = "Restore the database from a verified backup and replay the rating transaction log"
+)]
+struct Args {
+    /// Directory holding backups and MANIFEST.b3
+    #[arg(long, default_value = "./backups/database")]
+    backup_dir: String,
+
+    /// Database file to replace
+    #[arg(long, default_value = "./data/zones.db")]
+    db: String,
+
+    /// Rating transaction log to replay after the snapshot
+    #[arg(long, default_value = "./data/rating_transaction.log")]
+    log: String,
+
+    /// Restore the snapshot without replaying the transaction log
+    #[arg(long)]
+    no_replay: bool,
+
+    /// Backup file name to restore (defaults to the newest at or before --at)
+    #[arg(long)]
+    backup: Option<String>,
+
+    /// Restore to this point in time (RFC 3339, e.g. 2024-05-01T12:00:00Z)
+    #[arg(long)]
+    at: Option<DateTime<Utc>>,
+
+    /// Build and check the restored database without replacing the live file
+    #[arg(long)]
+    dry_run: bool,
+
+    /// List available backups and exit
+    #[arg(long)]
+    list: bool,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if args.list {
+        return match list_backups(&args.backup_dir) {
+            Ok(backups) => {
+                for backup in backups {
+                    println!(
+                        "{}  {}  {} bytes{}",
+                        backup.file_name,
+                        backup.created_at.to_rfc3339(),
+                        backup.size_bytes,
+                        if backup.blake3.is_some() {
+                            ""
+                        } else {
+                            "  (not in manifest)"
+                        }
+                    );
+                }
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("Failed to list backups: {}", e);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let options = RestoreOptions {
+        backup_dir: args.backup_dir,
+        database_path: args.db,
+        transaction_log_path: (!args.no_replay).then_some(args.log),
+        backup: args.backup,
+        target_time: args.at,
+        dry_run: args.dry_run,
+    };
+
+    match restore_database(&options).await {
+        Ok(report) => {
+            println!(
+                "Restored {} (taken {}) and replayed {} log entries",
+                report.backup.file_name,
+                report.backup.created_at.to_rfc3339(),
+                report.replayed
+            );
+            if let Some(previous) = &report.previous_database {
+                println!("Previous database kept at {}", previous);
+            }
+            if !report.swapped {
+                println!("Dry run: {} was not modified", report.database_path);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Restore failed: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
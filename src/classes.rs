@@ -2,6 +2,7 @@ use axum::extract::FromRef;
 use axum::{
     Json,
     extract::{Query, State},
+    http::StatusCode,
 };
 use rand::seq::SliceRandom;
 use rand::thread_rng;
@@ -44,6 +45,7 @@ pub const CLASSES: &[&str] = &[
 #[derive(Deserialize)]
 pub struct ClassQuery {
     race: Option<String>,
+    ruleset: Option<String>,
 }
 
 pub fn load_classes() -> Arc<RaceClassMap> {
@@ -65,26 +67,39 @@ pub fn load_classes() -> Arc<RaceClassMap> {
 pub async fn random_class(
     Query(query): Query<ClassQuery>,
     State(state): State<crate::AppState>,
-) -> Json<Option<String>> {
+) -> Result<Json<Option<String>>, StatusCode> {
+    let ruleset =
+        crate::rulesets::resolve_ruleset_param(&state.zone_state.pool, query.ruleset.as_deref())
+            .await?;
+    let allowed = |class: &str| ruleset.as_ref().is_none_or(|r| r.allows_class(class));
+
     let mut rng = thread_rng();
 
     let map = state.class_race_state.class_race_map.clone();
 
     if let Some(race) = query.race {
+        if ruleset.as_ref().is_some_and(|r| !r.allows_race(&race)) {
+            return Ok(Json(None));
+        }
         if let Some(classes) = map.get(&race) {
-            return Json(classes.choose(&mut rng).cloned());
+            let classes: Vec<&String> = classes.iter().filter(|c| allowed(c)).collect();
+            return Ok(Json(classes.choose(&mut rng).map(|c| c.to_string())));
         } else {
-            return Json(None);
+            return Ok(Json(None));
         }
     }
 
-    // Safe to unwrap here since CLASSES is a constant array that's never empty
+    // Every ruleset unlocks the classic classes, so this is never empty
     let class_name = CLASSES
+        .iter()
+        .copied()
+        .filter(|c| allowed(c))
+        .collect::<Vec<_>>()
         .choose(&mut rng)
         .expect("CLASSES array should never be empty")
         .to_string();
 
-    Json(Some(class_name))
+    Ok(Json(Some(class_name)))
 }
 
 #[cfg(test)]
@@ -96,11 +111,12 @@ mod tests {
     use tempfile::tempdir;
 
     use crate::{
+        AppState,
         config::{
             AdminConfig, AppConfig, CorsConfig, DatabaseConfig, LoggingConfig, RatingsConfig,
             SecurityConfig, ServerConfig,
         },
-        instances, zones, AppState,
+        instances, zones,
     };
 
     static DIR_LOCK: Mutex<()> = Mutex::new(());
@@ -116,9 +132,15 @@ mod tests {
                 backup_dir: String::new(),
                 migrate_on_startup: false,
             },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
             security: SecurityConfig {
                 rating_ip_hash_key: "test".into(),
                 min_ip_hash_key_length: 0,
+                headers: Default::default(),
             },
             ratings: RatingsConfig {
                 min_rating: 0,
@@ -158,6 +180,8 @@ mod tests {
             zone_state: zones::ZoneState { pool: pool.clone() },
             instance_state: instances::InstanceState { pool: pool.clone() },
             class_race_state,
+            snapshot: Default::default(),
+            rate_limiter: Default::default(),
         }
     }
 
@@ -191,16 +215,20 @@ mod tests {
 
     #[tokio::test]
     async fn random_class_with_race() {
-        let map = HashMap::from([(
-            "Human".to_string(),
-            vec!["Warrior".to_string()],
-        )]);
+        let map = HashMap::from([("Human".to_string(), vec!["Warrior".to_string()])]);
         let state = test_app_state(ClassRaceState {
             class_race_map: Arc::new(map),
         });
 
-        let result =
-            random_class(Query(ClassQuery { race: Some("Human".into()) }), State(state)).await;
+        let result = random_class(
+            Query(ClassQuery {
+                race: Some("Human".into()),
+                ruleset: None,
+            }),
+            State(state),
+        )
+        .await
+        .unwrap();
         assert_eq!(result.0, Some("Warrior".to_string()));
     }
 
@@ -211,7 +239,15 @@ mod tests {
         });
 
         for _ in 0..10 {
-            let result = random_class(Query(ClassQuery { race: None }), State(state.clone())).await;
+            let result = random_class(
+                Query(ClassQuery {
+                    race: None,
+                    ruleset: None,
+                }),
+                State(state.clone()),
+            )
+            .await
+            .unwrap();
             let class = result.0.expect("expected a class");
             assert!(CLASSES.contains(&class.as_str()));
         }
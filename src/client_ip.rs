@@ -0,0 +1,309 @@
+// Client IP resolution
+// Behind a reverse proxy every connection comes from the proxy, so the client's
+// address has to be read from the forwarding headers the proxy adds. Those headers
+// are only believed when the connection comes from a configured trusted proxy;
+// anyone else could write whatever they like into them.
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts, State},
+    http::{HeaderMap, StatusCode, request::Parts},
+    middleware::Next,
+    response::Response,
+};
+use serde::Deserialize;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+
+use crate::AppState;
+
+/// A network in CIDR notation, such as `10.0.0.0/8` or `::1/128`. A bare address
+/// is a network of one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct IpNetwork {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpNetwork {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix) = match value.trim().split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (value.trim(), None),
+        };
+        let addr = IpAddr::from_str(addr)
+            .map_err(|_| format!("'{}' is not an IP address or CIDR network", value))?
+            .to_canonical();
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max)
+                .ok_or_else(|| format!("'{}' has an invalid prefix length", value))?,
+            None => max,
+        };
+        Ok(Self { addr, prefix })
+    }
+}
+
+impl TryFrom<String> for IpNetwork {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+/// An address from a forwarding header: `203.0.113.7`, `203.0.113.7:4711`,
+/// `2001:db8::1` or `[2001:db8::1]:4711`, optionally quoted
+fn parse_hop(value: &str) -> Option<IpAddr> {
+    let value = value.trim().trim_matches('"');
+    if let Ok(ip) = IpAddr::from_str(value) {
+        return Some(ip.to_canonical());
+    }
+    if let Some(rest) = value.strip_prefix('[') {
+        return rest
+            .split_once(']')
+            .and_then(|(ip, _)| IpAddr::from_str(ip).ok())
+            .map(|ip| ip.to_canonical());
+    }
+    SocketAddr::from_str(value)
+        .ok()
+        .map(|addr| addr.ip().to_canonical())
+}
+
+/// The addresses a request passed through, client first, from `Forwarded` if
+/// present and otherwise `X-Forwarded-For`. Entries that are not addresses, such
+/// as `unknown` or obfuscated identifiers, come back as None.
+fn forwarded_chain(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let values = |name: &str| -> Vec<String> {
+        headers
+            .get_all(name)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::to_string)
+            .collect()
+    };
+
+    let forwarded = values("forwarded");
+    if !forwarded.is_empty() {
+        return forwarded
+            .iter()
+            .map(|element| {
+                element
+                    .split(';')
+                    .filter_map(|pair| pair.trim().split_once('='))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("for"))
+                    .and_then(|(_, value)| parse_hop(value))
+            })
+            .collect();
+    }
+
+    values("x-forwarded-for")
+        .iter()
+        .map(|hop| parse_hop(hop))
+        .collect()
+}
+
+/// The client address for a connection from `peer`. When the peer is a trusted
+/// proxy, the forwarding chain is walked from the nearest hop back, skipping
+/// trusted proxies, and the first address not under our control is the client.
+pub fn resolve(peer: IpAddr, headers: &HeaderMap, trusted: &[IpNetwork]) -> IpAddr {
+    let is_trusted = |ip: IpAddr| trusted.iter().any(|network| network.contains(ip));
+    let peer = peer.to_canonical();
+    if !is_trusted(peer) {
+        return peer;
+    }
+
+    let chain = forwarded_chain(headers);
+    if chain.is_empty() {
+        return headers
+            .get("x-real-ip")
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_hop)
+            .unwrap_or(peer);
+    }
+
+    let mut client = peer;
+    for hop in chain.into_iter().rev() {
+        let Some(hop) = hop else {
+            break;
+        };
+        client = hop;
+        if !is_trusted(hop) {
+            break;
+        }
+    }
+    client
+}
+
+/// The address of the client that made a request, after trusted proxies are
+/// accounted for. Use this rather than `ConnectInfo` wherever the client matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+impl fmt::Display for ClientIp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ClientIp {
+    /// The client address worked out by `resolve_client_ip`, or the connection's
+    /// own address when the middleware did not run
+    pub fn from_extensions(extensions: &axum::http::Extensions) -> Option<Self> {
+        extensions.get::<ClientIp>().copied().or_else(|| {
+            extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| ClientIp(addr.ip().to_canonical()))
+        })
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ClientIp {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Self::from_extensions(&parts.extensions).ok_or_else(|| {
+            tracing::error!("No client address for request; is connect info enabled?");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+    }
+}
+
+/// Middleware that works out the client address once and attaches it to the
+/// request for everything after it
+pub async fn resolve_client_ip(
+    State(state): State<AppState>,
+    mut request: axum::http::Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    if let Some(ConnectInfo(peer)) = request.extensions().get::<ConnectInfo<SocketAddr>>() {
+        let client = resolve(
+            peer.ip(),
+            request.headers(),
+            &state.config.proxy.trusted_proxies,
+        );
+        request.extensions_mut().insert(ClientIp(client));
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn networks_parse_and_match() {
+        let net: IpNetwork = "10.0.0.0/8".parse().unwrap();
+        assert!(net.contains(ip("10.20.30.40")));
+        assert!(net.contains(ip("::ffff:10.1.2.3")));
+        assert!(!net.contains(ip("11.0.0.1")));
+
+        let single: IpNetwork = "::1".parse().unwrap();
+        assert_eq!(single.to_string(), "::1/128");
+        assert!(single.contains(ip("::1")));
+
+        let everything: IpNetwork = "0.0.0.0/0".parse().unwrap();
+        assert!(everything.contains(ip("203.0.113.7")));
+
+        assert!("10.0.0.0/33".parse::<IpNetwork>().is_err());
+        assert!("proxy.internal".parse::<IpNetwork>().is_err());
+    }
+
+    #[test]
+    fn forwarding_headers_count_only_from_trusted_proxies() {
+        let trusted: Vec<IpNetwork> =
+            vec!["127.0.0.1".parse().unwrap(), "10.0.0.0/8".parse().unwrap()];
+        let spoofed = headers(&[("x-forwarded-for", "198.51.100.1")]);
+
+        // A direct client cannot claim another address
+        assert_eq!(
+            resolve(ip("203.0.113.7"), &spoofed, &trusted),
+            ip("203.0.113.7")
+        );
+
+        // Through the proxy, the nearest untrusted hop is the client, whatever it prepended
+        let chain = headers(&[("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.2")]);
+        assert_eq!(
+            resolve(ip("127.0.0.1"), &chain, &trusted),
+            ip("203.0.113.7")
+        );
+
+        let split = headers(&[
+            ("x-forwarded-for", "198.51.100.1"),
+            ("x-forwarded-for", "203.0.113.7:4711"),
+        ]);
+        assert_eq!(
+            resolve(ip("127.0.0.1"), &split, &trusted),
+            ip("203.0.113.7")
+        );
+
+        let forwarded = headers(&[
+            (
+                "forwarded",
+                r#"for="[2001:db8:cafe::17]:4711";proto=https, for=10.0.0.2"#,
+            ),
+            ("x-forwarded-for", "198.51.100.1"),
+        ]);
+        assert_eq!(
+            resolve(ip("127.0.0.1"), &forwarded, &trusted),
+            ip("2001:db8:cafe::17")
+        );
+
+        let unknown = headers(&[("forwarded", "for=unknown, for=10.0.0.2")]);
+        assert_eq!(resolve(ip("127.0.0.1"), &unknown, &trusted), ip("10.0.0.2"));
+
+        let real_ip = headers(&[("x-real-ip", "203.0.113.9")]);
+        assert_eq!(
+            resolve(ip("::ffff:127.0.0.1"), &real_ip, &trusted),
+            ip("203.0.113.9")
+        );
+        assert_eq!(
+            resolve(ip("127.0.0.1"), &HeaderMap::new(), &trusted),
+            ip("127.0.0.1")
+        );
+    }
+}
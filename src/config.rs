@@ -1,5 +1,6 @@
 use config::{Config, Environment, File};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::env;
 use tracing::{info, warn};
 
@@ -16,11 +17,215 @@ pub struct DatabaseConfig {
     pub migrate_on_startup: bool,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BackupConfig {
+    pub enabled: bool,
+    /// Minutes between scheduled backups; 0 disables the schedule
+    pub interval_minutes: u64,
+    /// Newest backups to keep; 0 disables count-based retention
+    pub keep_count: usize,
+    /// Delete backups older than this many days; 0 disables age-based retention
+    pub max_age_days: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_minutes: 360,
+            keep_count: 14,
+            max_age_days: 30,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TrashConfig {
+    /// Days a deleted zone, instance or link stays restorable; 0 keeps it until purged by hand
+    pub retention_days: u64,
+    /// Minutes between checks for expired trash
+    pub purge_interval_minutes: u64,
+}
+
+impl Default for TrashConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: 30,
+            purge_interval_minutes: 60,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthConfig {
+    /// Minutes an admin stays signed in before having to log in again
+    pub session_ttl_minutes: u64,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            session_ttl_minutes: 720,
+        }
+    }
+}
+
+/// A token bucket: up to `capacity` requests at once, refilled at a steady rate
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct BucketPolicy {
+    /// Largest burst; 0 leaves the group unlimited
+    pub capacity: u32,
+    pub refill_per_minute: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    /// Seconds between sweeps that drop buckets which have refilled completely
+    pub eviction_interval_seconds: u64,
+    /// `GET /random_zone`, `/random_instance`, `/random_race` and `/random_class`
+    pub rolls: BucketPolicy,
+    /// Rating submissions
+    pub ratings: BucketPolicy,
+    /// Every `/admin` request, the login form included
+    pub admin: BucketPolicy,
+    /// Any other request that is not a GET
+    pub writes: BucketPolicy,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            eviction_interval_seconds: 60,
+            rolls: BucketPolicy {
+                capacity: 30,
+                refill_per_minute: 60,
+            },
+            ratings: BucketPolicy {
+                capacity: 5,
+                refill_per_minute: 5,
+            },
+            admin: BucketPolicy {
+                capacity: 60,
+                refill_per_minute: 60,
+            },
+            writes: BucketPolicy {
+                capacity: 5,
+                refill_per_minute: 5,
+            },
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// A group with a burst but no refill would refuse every client for good once
+    /// drained, so that is a configuration mistake rather than a policy
+    pub fn validate(&self) -> Result<(), String> {
+        let groups = [
+            ("rolls", self.rolls),
+            ("ratings", self.ratings),
+            ("admin", self.admin),
+            ("writes", self.writes),
+        ];
+        for (name, policy) in groups {
+            if policy.capacity > 0 && policy.refill_per_minute == 0 {
+                return Err(format!(
+                    "rate_limit.{}.refill_per_minute must be above 0 when capacity is set; set capacity = 0 to leave the group unlimited",
+                    name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ProxyConfig {
+    /// Networks whose `Forwarded`, `X-Forwarded-For` and `X-Real-IP` headers are
+    /// believed; requests from anywhere else are attributed to the connecting address
+    pub trusted_proxies: Vec<crate::client_ip::IpNetwork>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct SecurityConfig {
     #[serde(skip)]
     pub rating_ip_hash_key: String,
     pub min_ip_hash_key_length: usize,
+    #[serde(default)]
+    pub headers: SecurityHeadersConfig,
+}
+
+/// Response headers that harden the site in the browser. Each named header can be
+/// replaced, or left out with an empty value, and `extra` adds any others.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SecurityHeadersConfig {
+    /// Send the headers at all; unset sends them only in production, which is
+    /// served over HTTPS
+    pub enabled: Option<bool>,
+    /// CSP directives for the public site. `{nonce}` is replaced with the
+    /// request's nonce.
+    pub csp: Vec<String>,
+    /// CSP directives for `/admin` pages
+    pub admin_csp: Vec<String>,
+    /// Send the policy as `Content-Security-Policy-Report-Only`, so violations are
+    /// reported but nothing is blocked
+    pub csp_report_only: bool,
+    /// Where browsers report violations; empty asks for no reports
+    pub csp_report_uri: String,
+    pub frame_options: String,
+    pub content_type_options: String,
+    pub xss_protection: String,
+    pub referrer_policy: String,
+    pub permissions_policy: String,
+    pub strict_transport_security: String,
+    /// Further headers by name, which also override any of the above
+    pub extra: BTreeMap<String, String>,
+}
+
+impl Default for SecurityHeadersConfig {
+    fn default() -> Self {
+        let directives = |list: &[&str]| list.iter().map(|d| d.to_string()).collect();
+        Self {
+            enabled: None,
+            csp: directives(&[
+                "default-src 'self'",
+                "script-src 'self' 'nonce-{nonce}'",
+                "style-src 'self' 'nonce-{nonce}' https://fonts.googleapis.com",
+                "font-src 'self' https://fonts.gstatic.com",
+                "img-src 'self' data: https:",
+                "connect-src 'self'",
+                "frame-ancestors 'none'",
+                "base-uri 'self'",
+                "form-action 'self'",
+            ]),
+            admin_csp: directives(&[
+                "default-src 'self'",
+                "script-src 'self' 'nonce-{nonce}'",
+                "style-src 'self' 'nonce-{nonce}'",
+                "img-src 'self' data:",
+                "connect-src 'self'",
+                "frame-ancestors 'none'",
+                "base-uri 'self'",
+                "form-action 'self'",
+            ]),
+            csp_report_only: false,
+            csp_report_uri: crate::security::CSP_REPORT_PATH.to_string(),
+            frame_options: "DENY".to_string(),
+            content_type_options: "nosniff".to_string(),
+            xss_protection: "1; mode=block".to_string(),
+            referrer_policy: "strict-origin-when-cross-origin".to_string(),
+            permissions_policy: "geolocation=(), microphone=(), camera=()".to_string(),
+            strict_transport_security: "max-age=31536000; includeSubDomains; preload".to_string(),
+            extra: BTreeMap::new(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -59,6 +264,16 @@ pub struct LoggingConfig {
 pub struct AppConfig {
     pub server: ServerConfig,
     pub database: DatabaseConfig,
+    #[serde(default)]
+    pub backup: BackupConfig,
+    #[serde(default)]
+    pub trash: TrashConfig,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+    #[serde(default)]
+    pub proxy: ProxyConfig,
     pub security: SecurityConfig,
     pub ratings: RatingsConfig,
     pub admin: AdminConfig,
@@ -113,6 +328,15 @@ impl AppConfig {
             )));
         }
 
+        self.rate_limit
+            .validate()
+            .map_err(config::ConfigError::Message)?;
+
+        for admin in [false, true] {
+            crate::security::security_headers(&self.security.headers, admin, "nonce")
+                .map_err(|e| config::ConfigError::Message(format!("security.headers: {}", e)))?;
+        }
+
         // Validate rating range
         if self.ratings.min_rating >= self.ratings.max_rating {
             warn!(
@@ -149,6 +373,14 @@ impl AppConfig {
         env::var("EQ_RNG_ENV").unwrap_or_else(|_| "development".to_string()) == "production"
     }
 
+    /// Whether responses carry the `security.headers` headers
+    pub fn security_headers_enabled(&self) -> bool {
+        self.security
+            .headers
+            .enabled
+            .unwrap_or_else(|| self.is_production())
+    }
+
     pub fn get_cors_origins(&self) -> Vec<String> {
         if self.is_development() {
             self.cors.development_origins.clone()
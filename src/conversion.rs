@@ -0,0 +1,343 @@
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+
+use crate::audit::{self, AuditAction, AuditChange};
+use crate::error::{AppError, AppResult};
+use crate::locations::LocationKind;
+
+/// Columns zones and instances share; everything a conversion copies from the row itself
+const LOCATION_COLUMNS: &str = "name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, hot_zone, mission, verified, created_at";
+
+/// Child tables that follow a location through a conversion, with the columns copied
+/// besides the owner id
+const CHILD_TABLES: [(&str, &str); 4] = [
+    ("notes", "note_type_id, content, created_at, updated_at"),
+    ("flags", "flag_type_id, created_at"),
+    ("ratings", "user_ip, rating, created_at, updated_at"),
+    ("aliases", "alias, created_at"),
+];
+
+/// What a conversion would carry over, and anything that stops it
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct ConversionReport {
+    pub from: LocationKind,
+    pub to: LocationKind,
+    pub source_id: i64,
+    pub name: String,
+    pub notes: i64,
+    pub flags: i64,
+    pub ratings: i64,
+    pub aliases: i64,
+    /// Reasons the conversion cannot go ahead; empty when it is safe
+    pub conflicts: Vec<String>,
+    /// Instance-only details that zones have no place for; the audit log keeps them
+    pub dropped: Vec<String>,
+}
+
+/// Check what converting a zone to an instance (or back) would do without changing anything
+pub async fn plan_conversion(
+    pool: &SqlitePool,
+    from: LocationKind,
+    id: i64,
+) -> AppResult<ConversionReport> {
+    let to = from.other();
+
+    let name: String = sqlx::query_scalar(&format!(
+        "SELECT name FROM {} WHERE id = ? AND deleted_at IS NULL",
+        from.table()
+    ))
+    .bind(id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| from.not_found(id))?;
+
+    let mut counts = [0i64; 4];
+    for (count, (child, _)) in counts.iter_mut().zip(CHILD_TABLES) {
+        *count = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM {} WHERE {} = ?",
+            from.child_table(child),
+            from.id_column()
+        ))
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+    }
+
+    let mut conflicts = Vec::new();
+
+    let same_name = sqlx::query(&format!(
+        "SELECT id, name, deleted_at FROM {} WHERE LOWER(TRIM(name)) = LOWER(TRIM(?)) ORDER BY id",
+        to.table()
+    ))
+    .bind(&name)
+    .fetch_all(pool)
+    .await?;
+    for row in same_name {
+        let trashed = row.get::<Option<String>, _>("deleted_at").is_some();
+        conflicts.push(format!(
+            "{} named \"{}\" already exists (#{}){}",
+            match to {
+                LocationKind::Zone => "A zone",
+                LocationKind::Instance => "An instance",
+            },
+            row.get::<String, _>("name"),
+            row.get::<i64, _>("id"),
+            if trashed { " in the trash" } else { "" }
+        ));
+    }
+
+    let alias_owner: Option<i64> = sqlx::query_scalar(&format!(
+        "SELECT {} FROM {} WHERE alias = TRIM(?) COLLATE NOCASE",
+        to.id_column(),
+        to.alias_table()
+    ))
+    .bind(&name)
+    .fetch_optional(pool)
+    .await?;
+    if let Some(owner) = alias_owner {
+        conflicts.push(format!(
+            "\"{}\" is an alias of {} #{}",
+            name,
+            to.as_str(),
+            owner
+        ));
+    }
+
+    let mut dropped = Vec::new();
+    if from == LocationKind::Instance {
+        let row = sqlx::query(
+            "SELECT min_players, max_players, lockout_minutes, request_npc, request_zone, task_type, classification FROM instances WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+        for column in ["min_players", "max_players", "lockout_minutes"] {
+            if row.get::<Option<i64>, _>(column).is_some() {
+                dropped.push(column.to_string());
+            }
+        }
+        for column in ["request_npc", "request_zone", "task_type", "classification"] {
+            if !row.get::<String, _>(column).is_empty() {
+                dropped.push(column.to_string());
+            }
+        }
+    }
+
+    let [notes, flags, ratings, aliases] = counts;
+    Ok(ConversionReport {
+        from,
+        to,
+        source_id: id,
+        name,
+        notes,
+        flags,
+        ratings,
+        aliases,
+        conflicts,
+        dropped,
+    })
+}
+
+/// Convert a zone to an instance or an instance to a zone in one transaction, carrying
+/// over notes, flags, ratings and aliases. Refuses with the conflict report when the
+/// name is already taken on the other side. Returns the new row's id.
+pub async fn convert_location(pool: &SqlitePool, from: LocationKind, id: i64) -> AppResult<i64> {
+    let report = plan_conversion(pool, from, id).await?;
+    if !report.conflicts.is_empty() {
+        return Err(AppError::Validation(report.conflicts.join("; ")));
+    }
+    let to = report.to;
+
+    let mut tx = pool.begin().await?;
+
+    // Snapshot the source and its children first so the whole move can be reverted
+    let mut changes = audit::capture_cascade(&mut tx, from.entity(), id).await;
+
+    let new_id = sqlx::query(&format!(
+        "INSERT INTO {to} ({cols}) SELECT {cols} FROM {from} WHERE id = ?",
+        to = to.table(),
+        from = from.table(),
+        cols = LOCATION_COLUMNS
+    ))
+    .bind(id)
+    .execute(&mut *tx)
+    .await?
+    .last_insert_rowid();
+
+    for (child, columns) in CHILD_TABLES {
+        sqlx::query(&format!(
+            "INSERT INTO {to_table} ({to_id}, {columns}) SELECT ?, {columns} FROM {from_table} WHERE {from_id} = ? ORDER BY id",
+            to_table = to.child_table(child),
+            to_id = to.id_column(),
+            from_table = from.child_table(child),
+            from_id = from.id_column(),
+            columns = columns
+        ))
+        .bind(new_id)
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    // Children of the source go with it
+    sqlx::query(&format!("DELETE FROM {} WHERE id = ?", from.table()))
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    changes.extend(
+        audit::capture_cascade(&mut tx, to.entity(), new_id)
+            .await
+            .into_iter()
+            .map(|change| AuditChange {
+                action: AuditAction::Create,
+                after: change.before,
+                before: None,
+                ..change
+            }),
+    );
+    audit::record_batch_change(&mut tx, changes).await?;
+
+    tx.commit().await?;
+
+    Ok(new_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+
+        for statement in [
+            "INSERT INTO zones (id, name, level_ranges, expansion, continent, zone_type, rating, verified) VALUES (34, 'Nagafen''s Lair', '[[40,55]]', 'Classic', 'Antonica', 'Dungeon', 4, 1)",
+            "INSERT INTO note_types (id, name, display_name, color_class) VALUES (1, 'epic', 'Epic', 'bg-yellow-500')",
+            "INSERT INTO flag_types (id, name, display_name, color_class) VALUES (1, 'raid', 'Raid', 'bg-red-500')",
+            "INSERT INTO zone_notes (zone_id, note_type_id, content) VALUES (34, 1, 'Lord Nagafen')",
+            "INSERT INTO zone_flags (zone_id, flag_type_id) VALUES (34, 1)",
+            "INSERT INTO zone_ratings (zone_id, user_ip, rating) VALUES (34, 'abc', 5)",
+            "INSERT INTO zone_aliases (zone_id, alias) VALUES (34, 'solb')",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+
+        pool
+    }
+
+    async fn count(pool: &SqlitePool, table: &str) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn converts_both_ways_without_losing_children() {
+        let pool = setup_pool().await;
+
+        let report = plan_conversion(&pool, LocationKind::Zone, 34)
+            .await
+            .unwrap();
+        assert_eq!(
+            (report.notes, report.flags, report.ratings, report.aliases),
+            (1, 1, 1, 1)
+        );
+        assert!(report.conflicts.is_empty());
+
+        let instance_id = convert_location(&pool, LocationKind::Zone, 34)
+            .await
+            .unwrap();
+        assert_eq!(count(&pool, "zones").await, 0);
+        for table in [
+            "instance_notes",
+            "instance_flags",
+            "instance_ratings",
+            "instance_aliases",
+        ] {
+            assert_eq!(count(&pool, table).await, 1, "{}", table);
+        }
+        let resolved = crate::aliases::resolve_name(&pool, "solb")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (resolved.kind, resolved.id),
+            (LocationKind::Instance, instance_id)
+        );
+
+        sqlx::query("UPDATE instances SET task_type = 'raid' WHERE id = ?")
+            .bind(instance_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let report = plan_conversion(&pool, LocationKind::Instance, instance_id)
+            .await
+            .unwrap();
+        assert_eq!(report.dropped, vec!["task_type"]);
+
+        let zone_id = convert_location(&pool, LocationKind::Instance, instance_id)
+            .await
+            .unwrap();
+        let row = sqlx::query("SELECT name, continent, rating, verified FROM zones WHERE id = ?")
+            .bind(zone_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(row.get::<String, _>("name"), "Nagafen's Lair");
+        assert_eq!(row.get::<String, _>("continent"), "Antonica");
+        assert_eq!(row.get::<i64, _>("rating"), 4);
+        assert!(row.get::<bool, _>("verified"));
+        for table in ["zone_notes", "zone_flags", "zone_ratings", "zone_aliases"] {
+            assert_eq!(count(&pool, table).await, 1, "{}", table);
+        }
+        assert_eq!(count(&pool, "instances").await, 0);
+
+        // The conversion is one audited operation, so one revert undoes it
+        let latest = audit::list_entries(&pool, &Default::default(), 1, 0)
+            .await
+            .unwrap()
+            .remove(0);
+        crate::revisions::revert_entry(&pool, latest.id)
+            .await
+            .unwrap();
+        assert_eq!(count(&pool, "zones").await, 0);
+        assert_eq!(count(&pool, "instance_notes").await, 1);
+        assert_eq!(count(&pool, "instance_aliases").await, 1);
+    }
+
+    #[tokio::test]
+    async fn reports_name_conflicts_instead_of_converting() {
+        let pool = setup_pool().await;
+        sqlx::query(
+            "INSERT INTO instances (id, name, level_ranges, expansion, zone_type) VALUES (5, 'nagafen''s lair ', '[[45,70]]', 'Classic', 'Dungeon')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let report = plan_conversion(&pool, LocationKind::Zone, 34)
+            .await
+            .unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert!(report.conflicts[0].contains("#5"));
+
+        assert!(matches!(
+            convert_location(&pool, LocationKind::Zone, 34).await,
+            Err(AppError::Validation(_))
+        ));
+        assert_eq!(count(&pool, "zones").await, 1);
+        assert_eq!(count(&pool, "instances").await, 1);
+
+        assert!(matches!(
+            plan_conversion(&pool, LocationKind::Zone, 99).await,
+            Err(AppError::ZoneNotFound(99))
+        ));
+    }
+}
@@ -0,0 +1,303 @@
+// Cross-site request forgery protection
+// Every browser gets a random cookie, and every non-GET request must carry a token
+// signed over that cookie, either in the `x-csrf-token` header or, for plain HTML
+// forms, in a `csrf_token` field. Another site can make the browser send the
+// cookie but cannot read it or the token, so it cannot forge the pair.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderMap, Request, StatusCode, header},
+    response::{IntoResponse, Json, Response},
+};
+use serde::Serialize;
+
+use crate::{AppConfig, AppState};
+
+pub const CSRF_COOKIE: &str = "eqrng_csrf";
+pub const CSRF_HEADER: &str = "x-csrf-token";
+/// Form field carrying the token for requests that cannot set headers
+pub const CSRF_FIELD: &str = "csrf_token";
+/// Largest form body read while looking for the token field
+const MAX_FORM_BYTES: usize = 1024 * 1024;
+
+/// Key that signs tokens. It is derived from the server secret, so the secret
+/// itself never appears in anything sent to a browser.
+fn signing_key(config: &AppConfig) -> [u8; 32] {
+    blake3::derive_key(
+        "eq_rng csrf token signing",
+        config.security.rating_ip_hash_key.as_bytes(),
+    )
+}
+
+/// The token that goes with a CSRF cookie value
+pub fn token_for(config: &AppConfig, cookie: &str) -> String {
+    blake3::keyed_hash(&signing_key(config), cookie.as_bytes())
+        .to_hex()
+        .to_string()
+}
+
+/// Whether `token` was signed over `cookie`. The comparison is constant time.
+pub fn verify(config: &AppConfig, cookie: &str, token: &str) -> bool {
+    blake3::Hash::from_hex(token)
+        .map(|token| token == blake3::keyed_hash(&signing_key(config), cookie.as_bytes()))
+        .unwrap_or(false)
+}
+
+/// A fresh random cookie value
+pub fn new_cookie_value() -> String {
+    rand::random::<[u8; 32]>()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// The CSRF cookie from the request, if any
+pub fn request_cookie(headers: &HeaderMap) -> Option<String> {
+    crate::security::read_cookie(headers, CSRF_COOKIE)
+}
+
+/// `Set-Cookie` value for a CSRF cookie. It lasts for the browser session and,
+/// outside development, is only sent over HTTPS.
+pub fn cookie_header(value: &str, secure: bool) -> String {
+    format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Strict{}",
+        CSRF_COOKIE,
+        value,
+        if secure { "; Secure" } else { "" }
+    )
+}
+
+/// The token field of a URL-encoded form body
+fn form_token(body: &[u8]) -> Option<String> {
+    url::form_urlencoded::parse(body)
+        .find(|(name, _)| name == CSRF_FIELD)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Check the token on a non-GET request. The token is taken from the header, or
+/// from the form field when the body is a URL-encoded form; the body is read and
+/// handed back untouched in that case.
+pub async fn verify_request(
+    config: &AppConfig,
+    request: Request<Body>,
+) -> Result<Request<Body>, StatusCode> {
+    let Some(cookie) = request_cookie(request.headers()) else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    let header_token = request
+        .headers()
+        .get(CSRF_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    if let Some(token) = header_token {
+        return if verify(config, &cookie, &token) {
+            Ok(request)
+        } else {
+            Err(StatusCode::UNAUTHORIZED)
+        };
+    }
+
+    let is_form = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/x-www-form-urlencoded"));
+    if !is_form {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = axum::body::to_bytes(body, MAX_FORM_BYTES)
+        .await
+        .map_err(|_| StatusCode::PAYLOAD_TOO_LARGE)?;
+    match form_token(&bytes) {
+        Some(token) if verify(config, &cookie, &token) => {
+            Ok(Request::from_parts(parts, Body::from(bytes)))
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+#[derive(Serialize)]
+pub struct CsrfToken {
+    pub token: String,
+}
+
+/// `GET /csrf-token`: the token to send in `x-csrf-token`, setting the cookie it
+/// belongs to if the browser does not have one yet
+pub async fn csrf_token(State(state): State<AppState>, headers: HeaderMap) -> Response {
+    let existing = request_cookie(&headers);
+    let cookie = existing.clone().unwrap_or_else(new_cookie_value);
+    let body = Json(CsrfToken {
+        token: token_for(&state.config, &cookie),
+    });
+
+    let mut response = ([(header::CACHE_CONTROL, "no-store")], body).into_response();
+    if existing.is_none()
+        && let Ok(value) = cookie_header(&cookie, !state.config.is_development()).parse()
+    {
+        response.headers_mut().insert(header::SET_COOKIE, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::*;
+    use axum::http::HeaderValue;
+
+    fn config(key: &str) -> AppConfig {
+        AppConfig {
+            server: ServerConfig {
+                port: 0,
+                host: "localhost".into(),
+            },
+            database: DatabaseConfig {
+                path: "".into(),
+                backup_dir: "".into(),
+                migrate_on_startup: false,
+            },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
+            security: SecurityConfig {
+                rating_ip_hash_key: key.into(),
+                min_ip_hash_key_length: 1,
+                headers: Default::default(),
+            },
+            ratings: RatingsConfig {
+                min_rating: 1,
+                max_rating: 5,
+                transaction_log_path: "ratings.log".into(),
+            },
+            admin: AdminConfig {
+                enabled: false,
+                page_size: 10,
+                min_page_size: 1,
+                max_page_size: 100,
+                default_sort_column: "name".into(),
+                default_sort_order: "asc".into(),
+            },
+            cors: CorsConfig {
+                development_origins: vec![],
+                production_origins: vec![],
+            },
+            logging: LoggingConfig {
+                level: "info".into(),
+                format: "json".into(),
+                file_path: "log".into(),
+                max_file_size: "1MB".into(),
+                max_files: 1,
+            },
+        }
+    }
+
+    fn post(
+        cookie: Option<&str>,
+        content_type: &str,
+        header_token: Option<&str>,
+        body: &str,
+    ) -> Request<Body> {
+        let mut builder =
+            Request::post("/zones/1/rating").header(header::CONTENT_TYPE, content_type);
+        if let Some(cookie) = cookie {
+            builder = builder.header(
+                header::COOKIE,
+                format!("theme=dark; {}={}", CSRF_COOKIE, cookie),
+            );
+        }
+        if let Some(token) = header_token {
+            builder = builder.header(CSRF_HEADER, token);
+        }
+        builder.body(Body::from(body.to_string())).unwrap()
+    }
+
+    #[test]
+    fn tokens_are_signed_over_the_cookie_without_revealing_the_key() {
+        let key = "secret-rating-hash-key-0123456789";
+        let config = config(key);
+        let token = token_for(&config, "abc");
+
+        assert!(verify(&config, "abc", &token));
+        assert!(!verify(&config, "abd", &token));
+        assert!(!verify(&config, "abc", "not-hex"));
+        assert!(!verify(&self::config("another-key"), "abc", &token));
+        assert!(!token.contains(key));
+        assert_ne!(token, blake3::hash(key.as_bytes()).to_hex().to_string());
+    }
+
+    #[tokio::test]
+    async fn requests_carry_the_token_in_a_header_or_form_field() {
+        let config = config("secret-rating-hash-key-0123456789");
+        let token = token_for(&config, "abc");
+
+        assert!(
+            verify_request(
+                &config,
+                post(Some("abc"), "application/json", Some(&token), "{}")
+            )
+            .await
+            .is_ok()
+        );
+        assert_eq!(
+            verify_request(&config, post(None, "application/json", Some(&token), "{}"))
+                .await
+                .unwrap_err(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert!(
+            verify_request(
+                &config,
+                post(Some("xyz"), "application/json", Some(&token), "{}")
+            )
+            .await
+            .is_err()
+        );
+        assert!(
+            verify_request(&config, post(Some("abc"), "application/json", None, "{}"))
+                .await
+                .is_err()
+        );
+
+        let form = format!("name=Befallen&{}={}", CSRF_FIELD, token);
+        let request = verify_request(
+            &config,
+            post(
+                Some("abc"),
+                "application/x-www-form-urlencoded",
+                None,
+                &form,
+            ),
+        )
+        .await
+        .unwrap();
+        let body = axum::body::to_bytes(request.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, form.as_bytes());
+
+        let forged = "name=Befallen&csrf_token=00";
+        assert!(
+            verify_request(
+                &config,
+                post(
+                    Some("abc"),
+                    "application/x-www-form-urlencoded",
+                    None,
+                    forged
+                )
+            )
+            .await
+            .is_err()
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(header::COOKIE, HeaderValue::from_static("eqrng_csrf="));
+        assert_eq!(request_cookie(&headers), None);
+    }
+}
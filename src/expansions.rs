@@ -0,0 +1,372 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+/// Seed catalogue used to populate the `expansions` table on databases that predate it.
+/// Tuples are (code, name, release_date, level_cap) in release order; names match the
+/// spelling used by the `expansion` column on zones and instances.
+pub const EXPANSIONS: &[(&str, &str, &str, u8)] = &[
+    ("Classic", "Classic", "1999-03-16", 50),
+    ("RoK", "Kunark", "2000-04-24", 60),
+    ("SoV", "Velious", "2000-12-05", 60),
+    ("SoL", "Shadows of Luclin", "2001-12-04", 60),
+    ("PoP", "Planes of Power", "2002-10-29", 65),
+    ("LoY", "The Legacy of Ykesha", "2003-02-25", 65),
+    ("LDoN", "Lost Dungeons of Norrath", "2003-09-09", 65),
+    ("GoD", "Gates of Discord", "2004-02-10", 65),
+    ("OoW", "Omens of War", "2004-09-14", 70),
+    ("DoN", "Dragons of Norrath", "2005-02-15", 70),
+    ("DoD", "Depths of Darkhollow", "2005-09-13", 70),
+    ("PoR", "Prophecy of Ro", "2006-02-21", 70),
+    ("TSS", "The Serpent's Spine", "2006-09-19", 75),
+    ("TBS", "The Buried Sea", "2007-02-13", 75),
+    ("SoF", "Secrets of Faydwer", "2007-11-13", 80),
+    ("SoD", "Seeds of Destruction", "2008-10-21", 85),
+    ("UF", "Underfoot", "2009-12-15", 85),
+    ("HoT", "House of Thule", "2010-10-12", 90),
+    ("VoA", "Veil of Alaris", "2011-11-15", 95),
+    ("RoF", "Rain of Fear", "2012-11-28", 100),
+    ("CotF", "Call of the Forsaken", "2013-10-08", 100),
+    ("TDS", "The Darkened Sea", "2014-10-28", 105),
+    ("TBM", "The Broken Mirror", "2015-11-18", 105),
+    ("EoK", "Empires of Kunark", "2016-11-16", 105),
+    ("RoS", "Ring of Scale", "2017-11-28", 110),
+    ("TBL", "The Burning Lands", "2018-12-11", 110),
+    ("ToV", "Torment of Velious", "2019-12-18", 115),
+    ("CoV", "Claws of Veeshan", "2020-12-08", 115),
+    ("ToL", "Terror of Luclin", "2021-12-07", 120),
+    ("NoS", "Night of Shadows", "2022-12-06", 120),
+    ("LS", "Laurion's Song", "2023-12-05", 125),
+    ("ToB", "The Outer Brood", "2024-12-10", 125),
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Expansion {
+    pub id: Option<i64>,
+    pub code: String,
+    pub name: String,
+    pub release_date: String,
+    pub release_order: i64,
+    pub level_cap: u8,
+}
+
+fn expansion_from_row(row: &sqlx::sqlite::SqliteRow) -> Expansion {
+    Expansion {
+        id: Some(row.get::<i64, _>("id")),
+        code: row.get("code"),
+        name: row.get("name"),
+        release_date: row.get("release_date"),
+        release_order: row.get("release_order"),
+        level_cap: row.get::<i64, _>("level_cap") as u8,
+    }
+}
+
+/// Populate the expansions table from `EXPANSIONS` if it is empty.
+/// Returns the number of rows inserted.
+pub async fn seed_expansions(pool: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM expansions")
+        .fetch_one(pool)
+        .await?;
+
+    if count > 0 {
+        return Ok(0);
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut inserted = 0;
+
+    for (index, (code, name, release_date, level_cap)) in EXPANSIONS.iter().enumerate() {
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO expansions (code, name, release_date, release_order, level_cap) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(code)
+        .bind(name)
+        .bind(release_date)
+        .bind(index as i64 + 1)
+        .bind(*level_cap as i64)
+        .execute(&mut *tx)
+        .await?;
+        inserted += result.rows_affected();
+    }
+
+    tx.commit().await?;
+    Ok(inserted)
+}
+
+pub async fn get_expansions(pool: &SqlitePool) -> Result<Vec<Expansion>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT id, code, name, release_date, release_order, level_cap FROM expansions ORDER BY release_order",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.iter().map(expansion_from_row).collect())
+}
+
+/// Look up an expansion by short code or display name, ignoring case
+pub async fn find_expansion(
+    pool: &SqlitePool,
+    code_or_name: &str,
+) -> Result<Option<Expansion>, sqlx::Error> {
+    let key = code_or_name.trim();
+
+    let row = sqlx::query(
+        "SELECT id, code, name, release_date, release_order, level_cap FROM expansions WHERE LOWER(code) = LOWER(?) OR LOWER(name) = LOWER(?) LIMIT 1",
+    )
+    .bind(key)
+    .bind(key)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.as_ref().map(expansion_from_row))
+}
+
+/// Rewrite zone and instance expansion values that match a catalogue code or a
+/// differently-cased name to the catalogue's display name.
+/// Returns the number of rows updated.
+pub async fn normalize_expansion_names(pool: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let mut updated = 0;
+
+    for table in ["zones", "instances"] {
+        let query = format!(
+            r#"
+            UPDATE {table}
+            SET expansion = (
+                SELECT e.name FROM expansions e
+                WHERE LOWER(e.code) = LOWER(TRIM({table}.expansion))
+                   OR LOWER(e.name) = LOWER(TRIM({table}.expansion))
+                LIMIT 1
+            )
+            WHERE expansion NOT IN (SELECT name FROM expansions)
+              AND EXISTS (
+                SELECT 1 FROM expansions e
+                WHERE LOWER(e.code) = LOWER(TRIM({table}.expansion))
+                   OR LOWER(e.name) = LOWER(TRIM({table}.expansion))
+              )
+            "#
+        );
+        updated += sqlx::query(&query).execute(pool).await?.rows_affected();
+    }
+
+    Ok(updated)
+}
+
+/// Triggers that keep `expansion_id` on zones and instances pointing at the catalogue
+/// row named by `expansion`, and carry a renamed expansion over to the rows that use it.
+/// The foreign key stops an expansion that is still referenced from being deleted.
+const EXPANSION_REFERENCE_TRIGGERS: &[&str] = &[
+    "CREATE TRIGGER IF NOT EXISTS zones_expansion_ref_ai AFTER INSERT ON zones BEGIN UPDATE zones SET expansion_id = (SELECT id FROM expansions WHERE name = new.expansion) WHERE id = new.id; END",
+    "CREATE TRIGGER IF NOT EXISTS zones_expansion_ref_au AFTER UPDATE OF expansion ON zones BEGIN UPDATE zones SET expansion_id = (SELECT id FROM expansions WHERE name = new.expansion) WHERE id = new.id; END",
+    "CREATE TRIGGER IF NOT EXISTS instances_expansion_ref_ai AFTER INSERT ON instances BEGIN UPDATE instances SET expansion_id = (SELECT id FROM expansions WHERE name = new.expansion) WHERE id = new.id; END",
+    "CREATE TRIGGER IF NOT EXISTS instances_expansion_ref_au AFTER UPDATE OF expansion ON instances BEGIN UPDATE instances SET expansion_id = (SELECT id FROM expansions WHERE name = new.expansion) WHERE id = new.id; END",
+    "CREATE TRIGGER IF NOT EXISTS expansions_rename_au AFTER UPDATE OF name ON expansions BEGIN UPDATE zones SET expansion = new.name WHERE expansion_id = old.id; UPDATE instances SET expansion = new.name WHERE expansion_id = old.id; END",
+];
+
+/// Add the `expansion_id` reference to zones and instances on databases that predate
+/// it, along with the triggers that maintain it
+pub async fn ensure_expansion_references(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    for table in ["zones", "instances"] {
+        let column_exists = sqlx::query(&format!(
+            "SELECT name FROM pragma_table_info('{}') WHERE name='expansion_id'",
+            table
+        ))
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+
+        if !column_exists {
+            println!("Adding expansion_id column to {} table...", table);
+            sqlx::query(&format!(
+                "ALTER TABLE {} ADD COLUMN expansion_id INTEGER REFERENCES expansions (id)",
+                table
+            ))
+            .execute(pool)
+            .await?;
+        }
+
+        sqlx::query(&format!(
+            "CREATE INDEX IF NOT EXISTS idx_{table}_expansion_id ON {table}(expansion_id)"
+        ))
+        .execute(pool)
+        .await?;
+    }
+
+    for trigger in EXPANSION_REFERENCE_TRIGGERS {
+        sqlx::query(trigger).execute(pool).await?;
+    }
+
+    Ok(())
+}
+
+/// Point `expansion_id` on every zone and instance at the catalogue row its
+/// `expansion` names, or clear it when the name is not in the catalogue. Rows loaded
+/// before the catalogue was seeded are linked here.
+/// Returns the number of rows updated.
+pub async fn link_expansion_references(pool: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let mut updated = 0;
+
+    for table in ["zones", "instances"] {
+        let query = format!(
+            r#"
+            UPDATE {table}
+            SET expansion_id = (SELECT id FROM expansions WHERE name = {table}.expansion)
+            WHERE expansion_id IS NOT (SELECT id FROM expansions WHERE name = {table}.expansion)
+            "#
+        );
+        updated += sqlx::query(&query).execute(pool).await?.rows_affected();
+    }
+
+    Ok(updated)
+}
+
+pub async fn get_expansions_api(
+    State(state): State<crate::AppState>,
+) -> Result<Json<Vec<Expansion>>, StatusCode> {
+    let pool = &*state.zone_state.pool;
+
+    let expansions = get_expansions(pool).await.map_err(|e| {
+        tracing::error!(error = %e, "Database error loading expansions");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(expansions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn seed_is_ordered_and_idempotent() {
+        let pool = setup_pool().await;
+
+        let inserted = seed_expansions(&pool).await.unwrap();
+        assert_eq!(inserted as usize, EXPANSIONS.len());
+        assert_eq!(seed_expansions(&pool).await.unwrap(), 0);
+
+        let expansions = get_expansions(&pool).await.unwrap();
+        assert_eq!(expansions.len(), EXPANSIONS.len());
+        assert_eq!(expansions[0].name, "Classic");
+        assert!(
+            expansions.windows(2).all(
+                |w| w[0].release_order < w[1].release_order && w[0].level_cap <= w[1].level_cap
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn find_matches_code_or_name_case_insensitively() {
+        let pool = setup_pool().await;
+        seed_expansions(&pool).await.unwrap();
+
+        let pop = find_expansion(&pool, "pop").await.unwrap().unwrap();
+        assert_eq!(pop.name, "Planes of Power");
+        assert_eq!(pop.level_cap, 65);
+
+        let by_name = find_expansion(&pool, " planes of power ")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(by_name, pop);
+
+        assert!(find_expansion(&pool, "Ykesha").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn normalize_rewrites_codes_to_names() {
+        let pool = setup_pool().await;
+        seed_expansions(&pool).await.unwrap();
+
+        for expansion in ["PoP", "kunark", "Ykesha"] {
+            sqlx::query(
+                "INSERT INTO zones (name, level_ranges, expansion, zone_type) VALUES (?, '[[1,10]]', ?, 'outdoor')",
+            )
+            .bind(format!("Zone {}", expansion))
+            .bind(expansion)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(normalize_expansion_names(&pool).await.unwrap(), 2);
+
+        let expansions: Vec<String> = sqlx::query_scalar("SELECT expansion FROM zones ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(expansions, vec!["Planes of Power", "Kunark", "Ykesha"]);
+    }
+
+    #[tokio::test]
+    async fn zones_reference_their_catalogue_expansion() {
+        let pool = setup_pool().await;
+        let expansion_id = |name: &'static str| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, Option<i64>>(
+                    "SELECT expansion_id FROM zones WHERE name = ?",
+                )
+                .bind(name)
+                .fetch_one(&pool)
+                .await
+                .unwrap()
+            }
+        };
+
+        // Rows loaded before the catalogue is seeded are linked afterwards
+        sqlx::query(
+            "INSERT INTO zones (name, level_ranges, expansion, zone_type) VALUES ('Befallen', '[[7,25]]', 'Classic', 'Indoor')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert_eq!(expansion_id("Befallen").await, None);
+        seed_expansions(&pool).await.unwrap();
+        assert_eq!(link_expansion_references(&pool).await.unwrap(), 1);
+        assert_eq!(expansion_id("Befallen").await, Some(1));
+
+        sqlx::query(
+            "INSERT INTO zones (name, level_ranges, expansion, zone_type) VALUES ('Karnor''s Castle', '[[30,50]]', 'Kunark', 'Indoor')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert_eq!(expansion_id("Karnor's Castle").await, Some(2));
+
+        sqlx::query("UPDATE zones SET expansion = 'Velious' WHERE name = 'Befallen'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(expansion_id("Befallen").await, Some(3));
+
+        // Renaming an expansion carries over to its zones, and one in use cannot be removed
+        sqlx::query("UPDATE expansions SET name = 'Ruins of Kunark' WHERE code = 'RoK'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let renamed: String =
+            sqlx::query_scalar("SELECT expansion FROM zones WHERE name = 'Karnor''s Castle'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(renamed, "Ruins of Kunark");
+        assert!(
+            sqlx::query("DELETE FROM expansions WHERE code = 'RoK'")
+                .execute(&pool)
+                .await
+                .is_err()
+        );
+        assert_eq!(link_expansion_references(&pool).await.unwrap(), 0);
+    }
+}
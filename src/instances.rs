@@ -8,23 +8,61 @@ use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct NoteType {
-    pub id: Option<i64>,
-    pub name: String,
-    pub display_name: String,
-    pub color_class: String,
-}
+use crate::locations::{FlagRecord, Location, LocationKind, NoteRecord};
+pub use crate::locations::{FlagType, NoteType};
+use crate::security::sanitize_user_input;
+use crate::snapshot::RollParams;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InstanceNote {
     pub id: Option<i64>,
     pub instance_id: i64,
     pub note_type_id: i64,
+    /// The note as written, in Markdown
     pub content: String,
+    /// The note rendered to sanitized HTML
+    #[serde(default)]
+    pub content_html: String,
     pub note_type: Option<NoteType>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstanceFlag {
+    pub id: Option<i64>,
+    pub instance_id: i64,
+    pub flag_type_id: i64,
+    pub flag_type: Option<FlagType>,
+}
+
+impl From<NoteRecord> for InstanceNote {
+    fn from(record: NoteRecord) -> Self {
+        InstanceNote {
+            id: Some(record.id),
+            instance_id: record.location_id,
+            note_type_id: record.note_type_id,
+            content_html: crate::markdown::render(&record.content),
+            content: record.content,
+            note_type: Some(record.note_type),
+        }
+    }
+}
+
+impl From<FlagRecord> for InstanceFlag {
+    fn from(record: FlagRecord) -> Self {
+        InstanceFlag {
+            id: Some(record.id),
+            instance_id: record.location_id,
+            flag_type_id: record.flag_type_id,
+            flag_type: Some(FlagType {
+                id: record.flag_type.id,
+                name: sanitize_user_input(&record.flag_type.name),
+                display_name: sanitize_user_input(&record.flag_type.display_name),
+                color_class: sanitize_user_input(&record.flag_type.color_class),
+            }),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct InstanceState {
     pub pool: Arc<SqlitePool>,
@@ -51,126 +89,265 @@ pub struct Instance {
     pub hot_zone: bool,
     pub verified: bool,
     pub notes: Vec<InstanceNote>,
+    pub flags: Vec<InstanceFlag>,
+    pub min_players: Option<u8>,
+    pub max_players: Option<u8>,
+    /// Replay timer after completion, in minutes
+    pub lockout_minutes: Option<u32>,
+    /// NPC that hands out the task, and the zone they stand in
+    pub request_npc: String,
+    pub request_zone: String,
+    /// One of `TASK_TYPES`, or empty when unknown
+    pub task_type: String,
+    /// One of `CLASSIFICATIONS`, or empty when unknown
+    pub classification: String,
 }
 
-#[derive(Deserialize)]
-pub struct RangeQuery {
-    pub min: Option<u8>,
-    pub max: Option<u8>,
-    zone_type: Option<String>,
-    expansion: Option<String>,
-    hot_zone: Option<bool>,
-    continent: Option<String>,
-}
+impl Location for Instance {
+    const KIND: LocationKind = LocationKind::Instance;
+    type Note = InstanceNote;
+    type Flag = InstanceFlag;
 
-pub async fn random_instance(
-    Query(params): Query<RangeQuery>,
-    State(state): State<crate::AppState>,
-) -> Result<Json<Instance>, StatusCode> {
-    let pool = &*state.instance_state.pool;
+    fn id(&self) -> Option<i64> {
+        self.id
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn level_ranges(&self) -> &[[u8; 2]] {
+        &self.level_ranges
+    }
+    fn expansion(&self) -> &str {
+        &self.expansion
+    }
+    fn zone_type(&self) -> &str {
+        &self.zone_type
+    }
+    fn continent(&self) -> &str {
+        &self.continent
+    }
+    fn hot_zone(&self) -> Option<bool> {
+        Some(self.hot_zone)
+    }
+}
 
-    let mut query = String::from(
-        "SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, hot_zone, verified FROM instances WHERE 1=1",
-    );
-    let mut bindings: Vec<String> = Vec::new();
+pub const TASK_TYPES: [&str; 3] = ["mission", "raid", "partisan"];
+pub const CLASSIFICATIONS: [&str; 3] = ["solo", "group", "raid"];
+
+/// Columns read into `Instance` besides the id, shared by every query that builds one
+pub const INSTANCE_COLUMNS: &str = "name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, hot_zone, verified, min_players, max_players, lockout_minutes, request_npc, request_zone, task_type, classification";
+
+/// Player count, lockout and request details as read from an instances row
+pub(crate) struct InstanceDetails {
+    pub min_players: Option<u8>,
+    pub max_players: Option<u8>,
+    pub lockout_minutes: Option<u32>,
+    pub request_npc: String,
+    pub request_zone: String,
+    pub task_type: String,
+    pub classification: String,
+}
 
-    if let Some(ref zone_type) = params.zone_type {
-        query.push_str(" AND LOWER(zone_type) = LOWER(?)");
-        bindings.push(zone_type.clone());
+impl InstanceDetails {
+    pub(crate) fn from_row(row: &sqlx::sqlite::SqliteRow) -> Self {
+        InstanceDetails {
+            min_players: row
+                .get::<Option<i64>, _>("min_players")
+                .and_then(|n| u8::try_from(n).ok()),
+            max_players: row
+                .get::<Option<i64>, _>("max_players")
+                .and_then(|n| u8::try_from(n).ok()),
+            lockout_minutes: row
+                .get::<Option<i64>, _>("lockout_minutes")
+                .and_then(|n| u32::try_from(n).ok()),
+            request_npc: row.get("request_npc"),
+            request_zone: row.get("request_zone"),
+            task_type: row.get("task_type"),
+            classification: row.get("classification"),
+        }
     }
+}
 
-    if let Some(ref expansion) = params.expansion {
-        query.push_str(" AND LOWER(expansion) = LOWER(?)");
-        bindings.push(expansion.clone());
+/// Check instance details before they are saved. Empty task type and
+/// classification mean unknown.
+pub fn validate_instance_details(
+    min_players: Option<u8>,
+    max_players: Option<u8>,
+    task_type: &str,
+    classification: &str,
+) -> Result<(), String> {
+    if min_players == Some(0) || max_players == Some(0) {
+        return Err("Player counts must be at least 1".to_string());
     }
-
-    if let Some(ref continent) = params.continent {
-        query.push_str(" AND LOWER(continent) = LOWER(?)");
-        bindings.push(continent.clone());
+    if let (Some(min), Some(max)) = (min_players, max_players)
+        && min > max
+    {
+        return Err(format!(
+            "Minimum players ({}) is more than maximum players ({})",
+            min, max
+        ));
     }
-
-    if let Some(hot_zone) = params.hot_zone {
-        query.push_str(" AND hot_zone = ?");
-        bindings.push(if hot_zone { "1" } else { "0" }.to_string());
+    if !task_type.is_empty() && !TASK_TYPES.contains(&task_type) {
+        return Err(format!(
+            "Task type must be one of {}",
+            TASK_TYPES.join(", ")
+        ));
+    }
+    if !classification.is_empty() && !CLASSIFICATIONS.contains(&classification) {
+        return Err(format!(
+            "Classification must be one of {}",
+            CLASSIFICATIONS.join(", ")
+        ));
     }
+    Ok(())
+}
 
-    query.push_str(" ORDER BY RANDOM() LIMIT 100");
+/// Instance-only roll filters, applied on top of the shared `RollFilter`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct InstanceFilter {
+    pub group_size: Option<u8>,
+    pub task_types: Vec<String>,
+    pub classifications: Vec<String>,
+    pub request_zone: Option<String>,
+    pub max_lockout_minutes: Option<u32>,
+}
 
-    let mut sql_query = sqlx::query(&query);
-    for binding in &bindings {
-        sql_query = sql_query.bind(binding);
-    }
+impl InstanceFilter {
+    /// Parse the query parameters; unknown task types or classifications are a 400
+    pub fn from_params(
+        group_size: Option<u8>,
+        task_type: Option<&str>,
+        classification: Option<&str>,
+        request_zone: Option<&str>,
+        max_lockout_minutes: Option<u32>,
+    ) -> Result<Self, StatusCode> {
+        fn list(value: Option<&str>, allowed: &[&str]) -> Result<Vec<String>, StatusCode> {
+            value
+                .unwrap_or_default()
+                .split(',')
+                .map(|v| v.trim().to_lowercase())
+                .filter(|v| !v.is_empty())
+                .map(|v| {
+                    if allowed.contains(&v.as_str()) {
+                        Ok(v)
+                    } else {
+                        Err(StatusCode::BAD_REQUEST)
+                    }
+                })
+                .collect()
+        }
 
-    let rows = sql_query.fetch_all(pool).await.map_err(|e| {
-        tracing::error!(error = %e, "Database error in random_instance query");
-        StatusCode::INTERNAL_SERVER_ERROR
-    })?;
+        Ok(InstanceFilter {
+            group_size,
+            task_types: list(task_type, &TASK_TYPES)?,
+            classifications: list(classification, &CLASSIFICATIONS)?,
+            request_zone: request_zone
+                .map(str::trim)
+                .filter(|z| !z.is_empty())
+                .map(str::to_string),
+            max_lockout_minutes,
+        })
+    }
 
-    let mut matching_instances: Vec<Instance> = Vec::new();
+    pub fn matches(&self, instance: &Instance) -> bool {
+        // Unrecorded player counts and classifications are unknown, not excluded; a
+        // missing bound is open
+        if let Some(size) = self.group_size
+            && (instance.min_players.is_some_and(|min| size < min)
+                || instance.max_players.is_some_and(|max| size > max))
+        {
+            return false;
+        }
 
-    for row in rows {
-        let level_ranges_json: String = row.get("level_ranges");
-        let connections_json: String = row.get("connections");
+        if !self.task_types.is_empty() && !self.task_types.contains(&instance.task_type) {
+            return false;
+        }
 
-        let level_ranges: Vec<[u8; 2]> = serde_json::from_str(&level_ranges_json)
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        let connections: Vec<String> = serde_json::from_str(&connections_json)
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        if !self.classifications.is_empty()
+            && !instance.classification.is_empty()
+            && !self.classifications.contains(&instance.classification)
+        {
+            return false;
+        }
 
-        let instance = Instance {
-            id: Some(row.get::<i64, _>("id")),
-            name: row.get("name"),
-            level_ranges: level_ranges.clone(),
-            expansion: row.get("expansion"),
-            continent: row.get("continent"),
-            zone_type: row.get("zone_type"),
-            connections,
-            image_url: row.get("image_url"),
-            map_url: row.get("map_url"),
-            rating: row.get::<i32, _>("rating") as u8,
-            hot_zone: row.get("hot_zone"),
-            verified: row.get("verified"),
-            notes: Vec::new(),
-        };
-
-        let mut level_match = true;
-        if params.min.is_some() || params.max.is_some() {
-            level_match = false;
-            if let (Some(min), Some(max)) = (params.min, params.max) {
-                if level_ranges
-                    .iter()
-                    .any(|&[lmin, lmax]| lmin <= min && lmax >= max)
-                {
-                    level_match = true;
-                }
-            } else if let Some(min) = params.min {
-                if level_ranges.iter().any(|&[_lmin, lmax]| lmax >= min) {
-                    level_match = true;
-                }
-            } else if let Some(max) = params.max {
-                if level_ranges.iter().any(|&[lmin, _lmax]| lmin <= max) {
-                    level_match = true;
-                }
-            }
+        if let Some(zone) = &self.request_zone
+            && !instance.request_zone.eq_ignore_ascii_case(zone)
+        {
+            return false;
         }
 
-        if level_match {
-            matching_instances.push(instance);
+        // No lockout counts as no wait at all
+        if let Some(limit) = self.max_lockout_minutes
+            && instance
+                .lockout_minutes
+                .is_some_and(|lockout| lockout > limit)
+        {
+            return false;
         }
-    }
 
-    if matching_instances.is_empty() {
-        return Err(StatusCode::NOT_FOUND);
+        true
     }
+}
+
+#[derive(Deserialize)]
+pub struct RangeQuery {
+    pub min: Option<u8>,
+    pub max: Option<u8>,
+    zone_type: Option<String>,
+    expansion: Option<String>,
+    up_to_expansion: Option<String>, // Expansion code or name; includes every earlier expansion
+    ruleset: Option<String>,         // Server ruleset profile name
+    hot_zone: Option<bool>,
+    continent: Option<String>,
+    flags: Option<String>, // Comma-separated flag names
+    group_size: Option<u8>,
+    task_type: Option<String>, // Comma-separated: mission, raid, partisan
+    classification: Option<String>, // Comma-separated: solo, group, raid
+    request_zone: Option<String>,
+    max_lockout_minutes: Option<u32>,
+}
+
+pub async fn random_instance(
+    Query(params): Query<RangeQuery>,
+    State(state): State<crate::AppState>,
+) -> Result<Json<Instance>, StatusCode> {
+    let pool = &*state.instance_state.pool;
+
+    let snapshot = state.snapshot.get(pool).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to load roll snapshot");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    let filter = snapshot.roll_filter(RollParams {
+        min: params.min,
+        max: params.max,
+        zone_type: params.zone_type.as_deref(),
+        expansion: params.expansion.as_deref(),
+        up_to_expansion: params.up_to_expansion.as_deref(),
+        ruleset: params.ruleset.as_deref(),
+        continent: params.continent.as_deref(),
+        flags: params.flags.as_deref(),
+        hot_zone: params.hot_zone,
+    })?;
+    let instance_filter = InstanceFilter::from_params(
+        params.group_size,
+        params.task_type.as_deref(),
+        params.classification.as_deref(),
+        params.request_zone.as_deref(),
+        params.max_lockout_minutes,
+    )?;
 
     use rand::seq::SliceRandom;
     let mut rng = rand::thread_rng();
 
-    if let Some(instance) = matching_instances.choose(&mut rng) {
-        Ok(Json(instance.clone()))
-    } else {
-        Err(StatusCode::NOT_FOUND)
-    }
+    snapshot
+        .matching_instances(&filter)
+        .into_iter()
+        .filter(|instance| instance_filter.matches(instance))
+        .collect::<Vec<_>>()
+        .choose(&mut rng)
+        .map(|instance| Json((*instance).clone()))
+        .ok_or(StatusCode::NOT_FOUND)
 }
 
 pub async fn get_instance_notes_endpoint(
@@ -179,16 +356,18 @@ pub async fn get_instance_notes_endpoint(
 ) -> Result<Json<Vec<InstanceNote>>, StatusCode> {
     let pool = &*state.instance_state.pool;
 
-    match get_instance_notes(pool, instance_id).await {
-        Ok(notes) => Ok(Json(notes)),
-        Err(_) => Ok(Json(Vec::new())), // Return empty array on error
-    }
+    Ok(Json(
+        crate::locations::public_notes::<Instance>(pool, instance_id).await,
+    ))
 }
 
 pub async fn get_all_instances(pool: &SqlitePool) -> Result<Vec<Instance>, sqlx::Error> {
-    let rows = sqlx::query("SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, hot_zone, verified FROM instances ORDER BY name")
-        .fetch_all(pool)
-        .await?;
+    let rows = sqlx::query(&format!(
+        "SELECT id, {} FROM instances WHERE deleted_at IS NULL ORDER BY name",
+        INSTANCE_COLUMNS
+    ))
+    .fetch_all(pool)
+    .await?;
 
     let mut instances = Vec::new();
 
@@ -199,6 +378,7 @@ pub async fn get_all_instances(pool: &SqlitePool) -> Result<Vec<Instance>, sqlx:
         let level_ranges: Vec<[u8; 2]> =
             serde_json::from_str(&level_ranges_json).unwrap_or_default();
         let connections: Vec<String> = serde_json::from_str(&connections_json).unwrap_or_default();
+        let details = InstanceDetails::from_row(&row);
 
         instances.push(Instance {
             id: Some(row.get::<i64, _>("id")),
@@ -214,6 +394,14 @@ pub async fn get_all_instances(pool: &SqlitePool) -> Result<Vec<Instance>, sqlx:
             hot_zone: row.get("hot_zone"),
             verified: row.get("verified"),
             notes: Vec::new(), // Notes not loaded for bulk operations
+            flags: Vec::new(), // Flags not loaded for bulk operations
+            min_players: details.min_players,
+            max_players: details.max_players,
+            lockout_minutes: details.lockout_minutes,
+            request_npc: details.request_npc,
+            request_zone: details.request_zone,
+            task_type: details.task_type,
+            classification: details.classification,
         });
     }
 
@@ -224,44 +412,14 @@ pub async fn get_instance_notes(
     pool: &SqlitePool,
     instance_id: i64,
 ) -> Result<Vec<InstanceNote>, sqlx::Error> {
-    let rows = sqlx::query(
-        r#"
-        SELECT
-            in_.id,
-            in_.instance_id,
-            in_.note_type_id,
-            in_.content,
-            nt.name as note_type_name,
-            nt.display_name as note_type_display_name,
-            nt.color_class as note_type_color_class
-        FROM instance_notes in_
-        JOIN note_types nt ON in_.note_type_id = nt.id
-        WHERE in_.instance_id = ?
-        ORDER BY in_.created_at ASC
-        "#,
-    )
-    .bind(instance_id)
-    .fetch_all(pool)
-    .await?;
-
-    let mut notes = Vec::new();
-
-    for row in rows {
-        notes.push(InstanceNote {
-            id: Some(row.get::<i64, _>("id")),
-            instance_id: row.get("instance_id"),
-            note_type_id: row.get("note_type_id"),
-            content: row.get("content"),
-            note_type: Some(NoteType {
-                id: Some(row.get("note_type_id")),
-                name: row.get("note_type_name"),
-                display_name: row.get("note_type_display_name"),
-                color_class: row.get("note_type_color_class"),
-            }),
-        });
-    }
+    crate::locations::get_notes::<Instance>(pool, instance_id).await
+}
 
-    Ok(notes)
+pub async fn get_instance_flags(
+    pool: &SqlitePool,
+    instance_id: i64,
+) -> Result<Vec<InstanceFlag>, sqlx::Error> {
+    crate::locations::get_flags::<Instance>(pool, instance_id).await
 }
 
 pub async fn get_note_types(pool: &SqlitePool) -> Result<Vec<NoteType>, sqlx::Error> {
@@ -284,3 +442,77 @@ pub async fn get_note_types(pool: &SqlitePool) -> Result<Vec<NoteType>, sqlx::Er
 
     Ok(note_types)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(min: Option<u8>, max: Option<u8>, task_type: &str) -> Instance {
+        Instance {
+            id: Some(1),
+            name: "Miragul's Menagerie".to_string(),
+            level_ranges: vec![[55, 65]],
+            expansion: "Luclin".to_string(),
+            continent: String::new(),
+            zone_type: "dungeon".to_string(),
+            connections: Vec::new(),
+            image_url: String::new(),
+            map_url: String::new(),
+            rating: 0,
+            hot_zone: false,
+            verified: false,
+            notes: Vec::new(),
+            flags: Vec::new(),
+            min_players: min,
+            max_players: max,
+            lockout_minutes: Some(180),
+            request_npc: "Abinos".to_string(),
+            request_zone: "Plane of Knowledge".to_string(),
+            task_type: task_type.to_string(),
+            classification: "group".to_string(),
+        }
+    }
+
+    #[test]
+    fn group_size_and_task_filters() {
+        let filter =
+            InstanceFilter::from_params(Some(3), Some("Mission, raid"), None, None, None).unwrap();
+        assert!(filter.matches(&instance(Some(1), Some(6), "mission")));
+        assert!(filter.matches(&instance(None, Some(6), "raid")));
+        assert!(!filter.matches(&instance(Some(6), Some(54), "raid")));
+        assert!(filter.matches(&instance(None, None, "mission")));
+        assert!(!filter.matches(&instance(Some(1), Some(6), "partisan")));
+
+        let filter = InstanceFilter::from_params(
+            None,
+            None,
+            Some("group"),
+            Some("plane of knowledge"),
+            Some(120),
+        )
+        .unwrap();
+        assert!(!filter.matches(&instance(None, None, "")));
+        let mut no_lockout = instance(None, None, "");
+        no_lockout.lockout_minutes = None;
+        assert!(filter.matches(&no_lockout));
+        no_lockout.classification = String::new();
+        assert!(filter.matches(&no_lockout));
+        no_lockout.classification = "raid".to_string();
+        assert!(!filter.matches(&no_lockout));
+
+        assert_eq!(
+            InstanceFilter::from_params(None, Some("heroic"), None, None, None).unwrap_err(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[test]
+    fn validates_details_before_saving() {
+        assert!(validate_instance_details(Some(1), Some(6), "mission", "group").is_ok());
+        assert!(validate_instance_details(None, None, "", "").is_ok());
+        assert!(validate_instance_details(Some(6), Some(3), "", "").is_err());
+        assert!(validate_instance_details(Some(0), None, "", "").is_err());
+        assert!(validate_instance_details(None, None, "quest", "").is_err());
+        assert!(validate_instance_details(None, None, "", "duo").is_err());
+    }
+}
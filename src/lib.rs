@@ -2,15 +2,34 @@ use sqlx::{Row, Sqlite, SqlitePool, migrate::MigrateDatabase};
 use std::path::Path;
 
 pub mod admin;
+pub mod aliases;
+pub mod api_keys;
+pub mod audit;
+pub mod auth;
+pub mod backup;
 pub mod classes;
+pub mod client_ip;
 pub mod config;
+pub mod conversion;
+pub mod csrf;
 pub mod error;
+pub mod expansions;
 pub mod instances;
 pub mod links;
+pub mod lint;
+pub mod locations;
 pub mod logging;
+pub mod markdown;
 pub mod races;
+pub mod rate_limit;
 pub mod ratings;
+pub mod restore;
+pub mod revisions;
+pub mod rulesets;
+pub mod search;
 pub mod security;
+pub mod snapshot;
+pub mod trash;
 pub mod version;
 pub mod zones;
 
@@ -18,8 +37,9 @@ pub mod zones;
 pub use config::AppConfig;
 pub use error::{AppError, AppResult};
 
+use locations::{HOT_ZONE_FLAG, LocationKind};
+
 // Anonymize IP migration utilities
-use blake3;
 use chrono::Utc;
 
 fn hash_ip(ip: &str, config: &AppConfig) -> String {
@@ -124,11 +144,11 @@ pub struct AppState {
     pub zone_state: zones::ZoneState,
     pub instance_state: instances::InstanceState,
     pub class_race_state: classes::ClassRaceState,
+    pub snapshot: snapshot::SnapshotCache,
+    pub rate_limiter: rate_limit::RateLimiter,
 }
 
-pub async fn setup_database(
-    config: &AppConfig,
-) -> Result<SqlitePool, Box<dyn std::error::Error>> {
+pub async fn setup_database(config: &AppConfig) -> Result<SqlitePool, Box<dyn std::error::Error>> {
     let database_url = "sqlite:./data/zones.db";
     let db_path = "./data/zones.db";
 
@@ -145,6 +165,11 @@ pub async fn setup_database(
     println!("Creating/verifying database tables...");
     create_tables(&pool).await?;
 
+    // Connections opened while the schema was being built can keep a stale copy of it,
+    // which breaks statements that fire the newer triggers; start again with fresh ones
+    pool.close().await;
+    let pool = SqlitePool::connect(database_url).await?;
+
     // Check if data.sql exists - if so, load from it
     if std::path::Path::new("./data/data.sql").exists() {
         // Check if we already have data in the zones table
@@ -210,7 +235,9 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
                 hot_zone BOOLEAN NOT NULL DEFAULT FALSE,
                 mission BOOLEAN NOT NULL DEFAULT FALSE,
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-                verified BOOLEAN NOT NULL DEFAULT FALSE
+                verified BOOLEAN NOT NULL DEFAULT FALSE,
+                deleted_at DATETIME,
+                expansion_id INTEGER REFERENCES expansions (id)
             )
             "#,
         )
@@ -393,7 +420,8 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
                 category TEXT NOT NULL,
                 description TEXT,
                 created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                deleted_at DATETIME
             )
             "#,
         )
@@ -442,7 +470,16 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
                 hot_zone BOOLEAN NOT NULL DEFAULT FALSE,
                 mission BOOLEAN NOT NULL DEFAULT FALSE,
                 verified BOOLEAN NOT NULL DEFAULT FALSE,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                deleted_at DATETIME,
+                min_players INTEGER,
+                max_players INTEGER,
+                lockout_minutes INTEGER,
+                request_npc TEXT NOT NULL DEFAULT '',
+                request_zone TEXT NOT NULL DEFAULT '',
+                task_type TEXT NOT NULL DEFAULT '',
+                classification TEXT NOT NULL DEFAULT '',
+                expansion_id INTEGER REFERENCES expansions (id)
             )
             "#,
         )
@@ -471,6 +508,76 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
         // The table schema above already excludes the mission column
     }
 
+    // Soft-deleted rows carry a deleted_at timestamp until purged from the trash
+    for table in ["zones", "instances", "links"] {
+        let deleted_at_exists = sqlx::query(&format!(
+            "SELECT name FROM pragma_table_info('{}') WHERE name='deleted_at'",
+            table
+        ))
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+
+        if !deleted_at_exists {
+            println!("Adding deleted_at column to {} table...", table);
+            sqlx::query(&format!(
+                "ALTER TABLE {} ADD COLUMN deleted_at DATETIME",
+                table
+            ))
+            .execute(pool)
+            .await?;
+        }
+
+        sqlx::query(&format!(
+            "CREATE INDEX IF NOT EXISTS idx_{table}_deleted_at ON {table}(deleted_at)"
+        ))
+        .execute(pool)
+        .await?;
+    }
+
+    // Instance-only details: group size, lockout and where the task is requested
+    for (column, definition) in [
+        ("min_players", "INTEGER"),
+        ("max_players", "INTEGER"),
+        ("lockout_minutes", "INTEGER"),
+        ("request_npc", "TEXT NOT NULL DEFAULT ''"),
+        ("request_zone", "TEXT NOT NULL DEFAULT ''"),
+        ("task_type", "TEXT NOT NULL DEFAULT ''"),
+        ("classification", "TEXT NOT NULL DEFAULT ''"),
+    ] {
+        let column_exists = sqlx::query(&format!(
+            "SELECT name FROM pragma_table_info('instances') WHERE name='{}'",
+            column
+        ))
+        .fetch_optional(pool)
+        .await?
+        .is_some();
+
+        if !column_exists {
+            println!("Adding {} column to instances table...", column);
+            sqlx::query(&format!(
+                "ALTER TABLE instances ADD COLUMN {} {}",
+                column, definition
+            ))
+            .execute(pool)
+            .await?;
+
+            // Existing mission rows were the only task type tracked before
+            if column == "task_type" {
+                sqlx::query("UPDATE instances SET task_type = 'mission' WHERE mission = 1")
+                    .execute(pool)
+                    .await?;
+            }
+        }
+    }
+
+    for index_sql in [
+        "CREATE INDEX IF NOT EXISTS idx_instances_task_type ON instances(task_type)",
+        "CREATE INDEX IF NOT EXISTS idx_instances_classification ON instances(classification)",
+    ] {
+        sqlx::query(index_sql).execute(pool).await?;
+    }
+
     // Check if instance_notes table exists
     let instance_notes_table_exists =
         sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='instance_notes'")
@@ -514,6 +621,45 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
         println!("Instance notes table already exists");
     }
 
+    // Check if instance_ratings table exists
+    let instance_ratings_table_exists = sqlx::query(
+        "SELECT name FROM sqlite_master WHERE type='table' AND name='instance_ratings'",
+    )
+    .fetch_optional(pool)
+    .await?
+    .is_some();
+
+    if !instance_ratings_table_exists {
+        println!("Creating instance_ratings table...");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE instance_ratings (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id INTEGER NOT NULL,
+                user_ip TEXT NOT NULL,
+                rating INTEGER NOT NULL CHECK (rating >= 1 AND rating <= 5),
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (instance_id) REFERENCES instances (id) ON DELETE CASCADE,
+                UNIQUE(instance_id, user_ip)
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_instance_ratings_instance_id ON instance_ratings(instance_id)",
+        )
+        .execute(pool)
+        .await?;
+
+        println!("Instance ratings table and indexes created successfully");
+    } else {
+        println!("Instance ratings table already exists");
+    }
+
     // Check if flag_types table exists
     let flag_types_table_exists =
         sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='flag_types'")
@@ -586,11 +732,164 @@ async fn create_tables(pool: &SqlitePool) -> Result<(), sqlx::Error> {
         println!("Zone flags table already exists");
     }
 
+    // Check if instance_flags table exists
+    let instance_flags_table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='instance_flags'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !instance_flags_table_exists {
+        println!("Creating instance_flags table...");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE instance_flags (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                instance_id INTEGER NOT NULL,
+                flag_type_id INTEGER NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                FOREIGN KEY (instance_id) REFERENCES instances (id) ON DELETE CASCADE,
+                FOREIGN KEY (flag_type_id) REFERENCES flag_types (id) ON DELETE CASCADE,
+                UNIQUE(instance_id, flag_type_id)
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        let instance_flag_indexes = [
+            "CREATE INDEX IF NOT EXISTS idx_instance_flags_instance_id ON instance_flags(instance_id)",
+            "CREATE INDEX IF NOT EXISTS idx_instance_flags_flag_type_id ON instance_flags(flag_type_id)",
+        ];
+
+        for index_sql in &instance_flag_indexes {
+            sqlx::query(index_sql).execute(pool).await?;
+        }
+
+        println!("Instance flags table and indexes created successfully");
+    } else {
+        println!("Instance flags table already exists");
+    }
+
+    // Check if expansions table exists
+    let expansions_table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='expansions'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !expansions_table_exists {
+        println!("Creating expansions table...");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE expansions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                code TEXT NOT NULL UNIQUE,
+                name TEXT NOT NULL UNIQUE,
+                release_date TEXT NOT NULL,
+                release_order INTEGER NOT NULL UNIQUE,
+                level_cap INTEGER NOT NULL,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        println!("Expansions table created successfully.");
+    } else {
+        println!("Expansions table already exists");
+    }
+
+    // Zones and instances reference their catalogue expansion through expansion_id
+    expansions::ensure_expansion_references(pool).await?;
+
+    // Check if rulesets table exists
+    let rulesets_table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='rulesets'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !rulesets_table_exists {
+        println!("Creating rulesets table...");
+
+        // level_cap overrides the max expansion's cap when set (e.g. a server holding at 60)
+        sqlx::query(
+            r#"
+            CREATE TABLE rulesets (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                display_name TEXT NOT NULL,
+                max_expansion TEXT NOT NULL,
+                level_cap INTEGER,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        println!("Rulesets table created successfully.");
+    } else {
+        println!("Rulesets table already exists");
+    }
+
+    // Alias tables: alternate names players use for zones and instances ("solb", "Sol B")
+    for (table, owner_column, owner_table) in [
+        ("zone_aliases", "zone_id", "zones"),
+        ("instance_aliases", "instance_id", "instances"),
+    ] {
+        let table_exists =
+            sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name=?")
+                .bind(table)
+                .fetch_optional(pool)
+                .await?
+                .is_some();
+
+        if !table_exists {
+            println!("Creating {} table...", table);
+
+            sqlx::query(&format!(
+                r#"
+                CREATE TABLE {table} (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    {owner_column} INTEGER NOT NULL,
+                    alias TEXT NOT NULL COLLATE NOCASE UNIQUE,
+                    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                    FOREIGN KEY ({owner_column}) REFERENCES {owner_table} (id) ON DELETE CASCADE
+                )
+                "#
+            ))
+            .execute(pool)
+            .await?;
+
+            sqlx::query(&format!(
+                "CREATE INDEX IF NOT EXISTS idx_{table}_{owner_column} ON {table}({owner_column})"
+            ))
+            .execute(pool)
+            .await?;
+
+            println!("{} table created successfully.", table);
+        } else {
+            println!("{} table already exists", table);
+        }
+    }
+
+    audit::create_audit_log_table(pool).await?;
+    auth::create_auth_tables(pool).await?;
+    api_keys::create_api_keys_table(pool).await?;
+
+    // Full-text index over the tables above; must come last
+    search::create_search_index(pool).await?;
+
     Ok(())
 }
 
 pub async fn get_zones_count(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
-    let row = sqlx::query("SELECT COUNT(*) as count FROM zones")
+    let row = sqlx::query("SELECT COUNT(*) as count FROM zones WHERE deleted_at IS NULL")
         .fetch_one(pool)
         .await?;
 
@@ -598,7 +897,7 @@ pub async fn get_zones_count(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
 }
 
 pub async fn get_instances_count(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
-    let row = sqlx::query("SELECT COUNT(*) as count FROM instances")
+    let row = sqlx::query("SELECT COUNT(*) as count FROM instances WHERE deleted_at IS NULL")
         .fetch_one(pool)
         .await?;
 
@@ -680,6 +979,20 @@ async fn should_load_data_sql(pool: &SqlitePool) -> Result<bool, Box<dyn std::er
     }
 }
 
+/// Statements from a `sqlite3 .dump` that belong to the full-text index. The index is
+/// maintained by triggers on the source tables, so its triggers, FTS5 shadow tables and
+/// schema rows are skipped (trigger bodies also fall apart when split on ';').
+fn is_search_index_statement(lower: &str) -> bool {
+    let unquoted = lower.replace('"', "");
+    unquoted.starts_with("create trigger")
+        || unquoted.starts_with("create virtual table")
+        || unquoted == "end"
+        || unquoted.starts_with("pragma writable_schema")
+        || unquoted.starts_with("insert into sqlite_schema")
+        || unquoted.starts_with("insert into sqlite_master")
+        || unquoted.starts_with("insert into search_fts")
+}
+
 /// Load data from data.sql file if it's newer than the last migration
 pub async fn load_data_sql(pool: &SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     use std::fs;
@@ -724,6 +1037,7 @@ pub async fn load_data_sql(pool: &SqlitePool) -> Result<(), Box<dyn std::error::
             || lower.starts_with("commit")
             || lower.starts_with("begin")
             || lower.starts_with("rollback")
+            || is_search_index_statement(&lower)
         {
             // Log that we're intentionally skipping transaction/DDL control statements
             tracing::debug!(statement = %trimmed, "Skipping control/DDL statement while loading data.sql");
@@ -795,7 +1109,7 @@ pub async fn dump_database_to_sql(pool: &SqlitePool) -> Result<String, Box<dyn s
     // Use a more comprehensive dump approach
     let output = Command::new("sqlite3")
         .arg("./data/zones.db")
-        .args(&[".mode insert", ".headers off", ".dump"])
+        .args([".mode insert", ".headers off", ".dump"])
         .output()?;
 
     if output.status.success() {
@@ -817,7 +1131,7 @@ pub async fn dump_database_to_sql(pool: &SqlitePool) -> Result<String, Box<dyn s
                 let schema_content = String::from_utf8(schema_output.stdout)?;
                 let data_output = Command::new("sqlite3")
                     .arg("./data/zones.db")
-                    .args(&[
+                    .args([
                         ".mode insert",
                         ".headers off",
                         "SELECT * FROM sqlite_master WHERE type='table'",
@@ -852,13 +1166,29 @@ pub async fn dump_database_to_sql(pool: &SqlitePool) -> Result<String, Box<dyn s
 }
 
 pub async fn migrate_hot_zones_to_flags(pool: &SqlitePool) -> Result<i32, sqlx::Error> {
-    // Migration function to convert existing hot_zone boolean values to zone flags
-    // Returns the number of zones migrated
+    migrate_hot_zone_column_to_flags(pool, LocationKind::Zone).await
+}
+
+pub async fn migrate_hot_instances_to_flags(pool: &SqlitePool) -> Result<i32, sqlx::Error> {
+    migrate_hot_zone_column_to_flags(pool, LocationKind::Instance).await
+}
+
+async fn migrate_hot_zone_column_to_flags(
+    pool: &SqlitePool,
+    kind: LocationKind,
+) -> Result<i32, sqlx::Error> {
+    // Migration function to convert existing hot_zone boolean values to zone or instance flags
+    // Returns the number of rows migrated
 
-    println!("Starting hot zone to flags migration...");
+    let table = kind.table();
+    let flags_table = kind.child_table("flags");
+    let id_column = kind.id_column();
+
+    println!("Starting hot zone to flags migration for {}...", table);
 
     // Get the flag_type_id for hot_zone flag
-    let flag_type_row = sqlx::query("SELECT id FROM flag_types WHERE name = 'hot_zone'")
+    let flag_type_row = sqlx::query("SELECT id FROM flag_types WHERE name = ?")
+        .bind(HOT_ZONE_FLAG)
         .fetch_optional(pool)
         .await?;
 
@@ -870,47 +1200,51 @@ pub async fn migrate_hot_zones_to_flags(pool: &SqlitePool) -> Result<i32, sqlx::
         }
     };
 
-    // Count zones that need migration (hot_zone = true but no flag)
-    let count_row = sqlx::query(
+    // Count rows that need migration (hot_zone = true but no flag)
+    let count_row = sqlx::query(&format!(
         r#"
         SELECT COUNT(*) as count
-        FROM zones z
-        WHERE z.hot_zone = 1
+        FROM {table} l
+        WHERE l.hot_zone = 1
         AND NOT EXISTS (
-            SELECT 1 FROM zone_flags zf
-            WHERE zf.zone_id = z.id AND zf.flag_type_id = ?
+            SELECT 1 FROM {flags_table} f
+            WHERE f.{id_column} = l.id AND f.flag_type_id = ?
         )
-        "#,
-    )
+        "#
+    ))
     .bind(flag_type_id)
     .fetch_one(pool)
     .await?;
 
-    let zones_to_migrate: i32 = count_row.get("count");
+    let rows_to_migrate: i32 = count_row.get("count");
 
-    if zones_to_migrate == 0 {
-        println!("No zones need migration - all hot zones already have flags");
+    if rows_to_migrate == 0 {
+        println!(
+            "No {} need migration - all hot zones already have flags",
+            table
+        );
+        clear_hot_zone_column(pool, table).await?;
         return Ok(0);
     }
 
     println!(
-        "Found {} zones with hot_zone = true that need flag migration",
-        zones_to_migrate
+        "Found {} {} with hot_zone = true that need flag migration",
+        rows_to_migrate, table
     );
 
     // Perform the migration
-    let result = sqlx::query(
+    let result = sqlx::query(&format!(
         r#"
-        INSERT OR IGNORE INTO zone_flags (zone_id, flag_type_id)
-        SELECT z.id, ?
-        FROM zones z
-        WHERE z.hot_zone = 1
+        INSERT OR IGNORE INTO {flags_table} ({id_column}, flag_type_id)
+        SELECT l.id, ?
+        FROM {table} l
+        WHERE l.hot_zone = 1
         AND NOT EXISTS (
-            SELECT 1 FROM zone_flags zf
-            WHERE zf.zone_id = z.id AND zf.flag_type_id = ?
+            SELECT 1 FROM {flags_table} f
+            WHERE f.{id_column} = l.id AND f.flag_type_id = ?
         )
-        "#,
-    )
+        "#
+    ))
     .bind(flag_type_id)
     .bind(flag_type_id)
     .execute(pool)
@@ -918,23 +1252,28 @@ pub async fn migrate_hot_zones_to_flags(pool: &SqlitePool) -> Result<i32, sqlx::
 
     let migrated_count = result.rows_affected() as i32;
 
+    // The flag is the source of truth from here on; a column left set would bring
+    // back a flag an admin removed the next time this runs
+    clear_hot_zone_column(pool, table).await?;
+
     // Verify migration
-    let verification_row = sqlx::query(
+    let verification_row = sqlx::query(&format!(
         r#"
         SELECT COUNT(*) as count
-        FROM zone_flags zf
-        JOIN flag_types ft ON zf.flag_type_id = ft.id
-        WHERE ft.name = 'hot_zone'
-        "#,
-    )
+        FROM {flags_table} f
+        JOIN flag_types ft ON f.flag_type_id = ft.id
+        WHERE ft.name = ?
+        "#
+    ))
+    .bind(HOT_ZONE_FLAG)
     .fetch_one(pool)
     .await?;
 
     let total_flags: i32 = verification_row.get("count");
 
     println!(
-        "Migration completed: {} zones migrated, {} total hot zone flags now exist",
-        migrated_count, total_flags
+        "Migration completed: {} {} migrated, {} total hot zone flags now exist",
+        migrated_count, table, total_flags
     );
 
     // Force WAL checkpoint to consolidate changes
@@ -943,6 +1282,16 @@ pub async fn migrate_hot_zones_to_flags(pool: &SqlitePool) -> Result<i32, sqlx::
     Ok(migrated_count)
 }
 
+async fn clear_hot_zone_column(pool: &SqlitePool, table: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(&format!(
+        "UPDATE {} SET hot_zone = 0 WHERE hot_zone = 1",
+        table
+    ))
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 pub async fn run_migrations(pool: &SqlitePool) -> Result<(), sqlx::Error> {
     println!("Running database migrations...");
 
@@ -963,6 +1312,28 @@ pub async fn run_migrations(pool: &SqlitePool) -> Result<(), sqlx::Error> {
         println!("Filterable column already exists in flag_types table");
     }
 
+    // Migration 2: Seed the expansions catalogue and align zone/instance spellings with it
+    let seeded = expansions::seed_expansions(pool).await?;
+    if seeded > 0 {
+        println!("Seeded {} expansions", seeded);
+    }
+
+    let normalized = expansions::normalize_expansion_names(pool).await?;
+    if normalized > 0 {
+        println!(
+            "Normalized expansion names on {} zone/instance rows",
+            normalized
+        );
+    }
+
+    let linked = expansions::link_expansion_references(pool).await?;
+    if linked > 0 {
+        println!("Linked {} zone/instance rows to their expansion", linked);
+    }
+
+    // Migration 3: Instances mark hot zones with the hot_zone flag, as zones already do
+    migrate_hot_instances_to_flags(pool).await?;
+
     println!("Database migrations completed successfully");
     Ok(())
 }
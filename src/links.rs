@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use std::collections::HashMap;
 
+use crate::audit::{self, AuditAction, AuditEntity};
 use crate::{AppState, security};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -42,7 +43,7 @@ pub async fn get_links(
         sqlx::query(
             "SELECT id, name, url, category, description, created_at
              FROM links
-             WHERE category = ?
+             WHERE category = ? AND deleted_at IS NULL
              ORDER BY category, name COLLATE NOCASE",
         )
         .bind(&category)
@@ -52,6 +53,7 @@ pub async fn get_links(
         sqlx::query(
             "SELECT id, name, url, category, description, created_at
              FROM links
+             WHERE deleted_at IS NULL
              ORDER BY category, name COLLATE NOCASE",
         )
         .fetch_all(pool.as_ref())
@@ -86,6 +88,7 @@ pub async fn get_links_by_category(
     let links = sqlx::query(
         "SELECT id, name, url, category, description, created_at
          FROM links
+         WHERE deleted_at IS NULL
          ORDER BY category, name COLLATE NOCASE",
     )
     .fetch_all(pool.as_ref())
@@ -107,7 +110,7 @@ pub async fn get_links_by_category(
 
                 categorized_links
                     .entry(link.category.clone())
-                    .or_insert_with(Vec::new)
+                    .or_default()
                     .push(link);
             }
 
@@ -126,7 +129,7 @@ pub async fn get_link(
     let link = sqlx::query(
         "SELECT id, name, url, category, description, created_at
          FROM links
-         WHERE id = ?",
+         WHERE id = ? AND deleted_at IS NULL",
     )
     .bind(id)
     .fetch_optional(pool.as_ref())
@@ -170,6 +173,11 @@ pub async fn create_link(
         .as_ref()
         .map(|d| security::sanitize_user_input_with_formatting(d));
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
     let result = sqlx::query(
         "INSERT INTO links (name, url, category, description)
          VALUES (?, ?, ?, ?)
@@ -179,11 +187,27 @@ pub async fn create_link(
     .bind(&url)
     .bind(&form.category)
     .bind(&description)
-    .fetch_one(pool.as_ref())
+    .fetch_one(&mut *tx)
     .await;
 
     match result {
         Ok(row) => {
+            let id: i64 = row.get("id");
+            let after = audit::capture(&mut tx, AuditEntity::Link, id).await;
+            audit::record_change(
+                &mut tx,
+                AuditAction::Create,
+                AuditEntity::Link,
+                id,
+                None,
+                after,
+            )
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            tx.commit()
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
             let link = Link {
                 id: row.get("id"),
                 name: row.get("name"),
@@ -220,6 +244,12 @@ pub async fn update_link(
         .as_ref()
         .map(|d| security::sanitize_user_input_with_formatting(d));
 
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let before = audit::capture(&mut tx, AuditEntity::Link, id).await;
+
     let result = sqlx::query(
         "UPDATE links
          SET name = ?, url = ?, category = ?, description = ?, updated_at = CURRENT_TIMESTAMP
@@ -231,11 +261,26 @@ pub async fn update_link(
     .bind(&form.category)
     .bind(&description)
     .bind(id)
-    .fetch_optional(pool.as_ref())
+    .fetch_optional(&mut *tx)
     .await;
 
     match result {
         Ok(Some(row)) => {
+            let after = audit::capture(&mut tx, AuditEntity::Link, id).await;
+            audit::record_change(
+                &mut tx,
+                AuditAction::Update,
+                AuditEntity::Link,
+                id,
+                before,
+                after,
+            )
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            tx.commit()
+                .await
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
             let link = Link {
                 id: row.get("id"),
                 name: row.get("name"),
@@ -257,19 +302,10 @@ pub async fn delete_link(
 ) -> Result<StatusCode, StatusCode> {
     let pool = &state.zone_state.pool;
 
-    let result = sqlx::query("DELETE FROM links WHERE id = ?")
-        .bind(id)
-        .execute(pool.as_ref())
-        .await;
-
-    match result {
-        Ok(result) => {
-            if result.rows_affected() > 0 {
-                Ok(StatusCode::NO_CONTENT)
-            } else {
-                Err(StatusCode::NOT_FOUND)
-            }
-        }
+    // Deleted links go to the trash, where an admin can restore or purge them
+    match crate::trash::soft_delete(pool.as_ref(), crate::trash::TrashKind::Link, id).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(StatusCode::NOT_FOUND),
         Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -315,6 +351,9 @@ mod tests {
         .execute(&pool)
         .await
         .expect("failed to create table");
+        crate::audit::create_audit_log_table(&pool)
+            .await
+            .expect("failed to create audit log");
 
         let pool = Arc::new(pool);
 
@@ -328,9 +367,15 @@ mod tests {
                 backup_dir: String::new(),
                 migrate_on_startup: false,
             },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
             security: SecurityConfig {
                 rating_ip_hash_key: "key".into(),
                 min_ip_hash_key_length: 0,
+                headers: Default::default(),
             },
             ratings: RatingsConfig {
                 min_rating: 0,
@@ -365,6 +410,8 @@ mod tests {
             class_race_state: classes::ClassRaceState {
                 class_race_map: Arc::new(HashMap::new()),
             },
+            snapshot: Default::default(),
+            rate_limiter: Default::default(),
         }
     }
 
@@ -392,7 +439,7 @@ mod tests {
         let description: Option<String> = row.get("description");
 
         assert_eq!(name, "Good");
-        assert_eq!(description.as_deref(), Some("<b>Bold</b>x"));
+        assert_eq!(description.as_deref(), Some("<b>Bold</b>"));
     }
 
     #[tokio::test]
@@ -444,7 +491,7 @@ mod tests {
 
         assert_eq!(name, "Updated");
         assert_eq!(url, "https://example.org");
-        assert_eq!(description.as_deref(), Some("<i>Desc</i>x"));
+        assert_eq!(description.as_deref(), Some("<i>Desc</i>"));
     }
 
     #[tokio::test]
@@ -0,0 +1,670 @@
+use serde::Serialize;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Row, SqlitePool};
+use std::collections::{HashMap, HashSet};
+
+use crate::expansions::EXPANSIONS;
+use crate::security::sanitize_url;
+
+/// Highest character level any zone or instance range may reference
+pub const MAX_LEVEL: u8 = 130;
+
+/// Continent names as they are spelled in data.sql. An empty continent is allowed.
+pub const KNOWN_CONTINENTS: &[&str] = &[
+    "Antonica",
+    "Faydwer",
+    "Odus",
+    "Kunark",
+    "Velious",
+    "Luclin",
+    "Planes",
+    "Power",
+    "Elemental Planes",
+    "Outer Planes",
+    "Taelosia",
+    "Norrath",
+    "Buried Sea",
+    "Kuua",
+    "Alaris",
+    "Realm of Heroes",
+    "The Void",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintIssue {
+    pub severity: Severity,
+    pub rule: &'static str,
+    pub table: &'static str,
+    pub id: i64,
+    pub name: String,
+    pub message: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct LintSummary {
+    pub zones_checked: usize,
+    pub instances_checked: usize,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct LintReport {
+    pub summary: LintSummary,
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    pub fn has_errors(&self) -> bool {
+        self.summary.errors > 0
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.summary.warnings > 0
+    }
+
+    fn push(&mut self, severity: Severity, rule: &'static str, row: &LocationRow, message: String) {
+        match severity {
+            Severity::Error => self.summary.errors += 1,
+            Severity::Warning => self.summary.warnings += 1,
+        }
+        self.issues.push(LintIssue {
+            severity,
+            rule,
+            table: row.table,
+            id: row.id,
+            name: row.name.clone(),
+            message,
+        });
+    }
+}
+
+struct LocationRow {
+    table: &'static str,
+    id: i64,
+    name: String,
+    level_ranges: String,
+    expansion: String,
+    /// None for dumps that predate the expansions reference
+    expansion_id: Option<Option<i64>>,
+    continent: String,
+    connections: String,
+    image_url: String,
+    map_url: String,
+}
+
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// Load a SQL dump such as data/data.sql into a private in-memory database
+pub async fn load_sql_file(path: &str) -> Result<SqlitePool, Box<dyn std::error::Error>> {
+    let sql_content = std::fs::read_to_string(path)?;
+
+    // A single connection keeps the in-memory database alive for the pool's lifetime
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await?;
+
+    sqlx::raw_sql(&sql_content).execute(&pool).await?;
+
+    Ok(pool)
+}
+
+async fn load_rows(
+    pool: &SqlitePool,
+    table: &'static str,
+) -> Result<Vec<LocationRow>, sqlx::Error> {
+    let has_column = |column: &'static str| async move {
+        sqlx::query(&format!(
+            "SELECT name FROM pragma_table_info('{}') WHERE name='{}'",
+            table, column
+        ))
+        .fetch_optional(pool)
+        .await
+        .map(|row| row.is_some())
+    };
+    let has_reference = has_column("expansion_id").await?;
+    // Trashed rows are on their way out; older dumps predate the column
+    let has_trash = has_column("deleted_at").await?;
+    let query = format!(
+        "SELECT id, name, level_ranges, expansion, {}, continent, connections, image_url, map_url FROM {} {}ORDER BY id",
+        if has_reference {
+            "expansion_id"
+        } else {
+            "NULL AS expansion_id"
+        },
+        table,
+        if has_trash {
+            "WHERE deleted_at IS NULL "
+        } else {
+            ""
+        }
+    );
+
+    let rows = sqlx::query(&query).fetch_all(pool).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| LocationRow {
+            table,
+            id: row.get("id"),
+            name: row.get("name"),
+            level_ranges: row.get("level_ranges"),
+            expansion: row.get("expansion"),
+            expansion_id: has_reference.then(|| row.get("expansion_id")),
+            continent: row.get("continent"),
+            connections: row.get("connections"),
+            image_url: row.get("image_url"),
+            map_url: row.get("map_url"),
+        })
+        .collect())
+}
+
+fn check_level_ranges(report: &mut LintReport, row: &LocationRow) {
+    let ranges: Vec<[u8; 2]> = match serde_json::from_str(&row.level_ranges) {
+        Ok(ranges) => ranges,
+        Err(e) => {
+            report.push(
+                Severity::Error,
+                "level_ranges_json",
+                row,
+                format!(
+                    "level_ranges {:?} is not a list of [min, max] pairs: {}",
+                    row.level_ranges, e
+                ),
+            );
+            return;
+        }
+    };
+
+    if ranges.is_empty() {
+        report.push(
+            Severity::Error,
+            "level_ranges_empty",
+            row,
+            "level_ranges is empty".to_string(),
+        );
+    }
+
+    for [min, max] in ranges {
+        if min > max {
+            report.push(
+                Severity::Error,
+                "level_range_inverted",
+                row,
+                format!("level range [{}, {}] has min greater than max", min, max),
+            );
+        }
+        if min > MAX_LEVEL || max > MAX_LEVEL {
+            report.push(
+                Severity::Error,
+                "level_above_cap",
+                row,
+                format!(
+                    "level range [{}, {}] exceeds the level cap of {}",
+                    min, max, MAX_LEVEL
+                ),
+            );
+        }
+    }
+}
+
+fn check_connections(report: &mut LintReport, row: &LocationRow, known_names: &HashSet<String>) {
+    let connections: Vec<String> = match serde_json::from_str(&row.connections) {
+        Ok(connections) => connections,
+        Err(e) => {
+            report.push(
+                Severity::Error,
+                "connections_json",
+                row,
+                format!(
+                    "connections {:?} is not a list of names: {}",
+                    row.connections, e
+                ),
+            );
+            return;
+        }
+    };
+
+    for connection in connections {
+        if !known_names.contains(&normalize_name(&connection)) {
+            report.push(
+                Severity::Error,
+                "connection_unresolved",
+                row,
+                format!(
+                    "connection {:?} does not match any zone, instance or alias",
+                    connection
+                ),
+            );
+        }
+    }
+}
+
+fn check_spellings(
+    report: &mut LintReport,
+    row: &LocationRow,
+    expansions: &[(String, String)],
+    expansion_ids: &HashMap<String, i64>,
+) {
+    if !expansions.iter().any(|(_, name)| *name == row.expansion) {
+        let hint = expansions
+            .iter()
+            .find(|(code, name)| {
+                code.eq_ignore_ascii_case(row.expansion.trim())
+                    || name.eq_ignore_ascii_case(row.expansion.trim())
+            })
+            .map(|(_, name)| format!(" (did you mean {:?}?)", name))
+            .unwrap_or_default();
+        report.push(
+            Severity::Error,
+            "unknown_expansion",
+            row,
+            format!("unknown expansion {:?}{}", row.expansion, hint),
+        );
+    }
+
+    if let Some(expansion_id) = row.expansion_id {
+        let expected = expansion_ids.get(&row.expansion).copied();
+        if !expansion_ids.is_empty() && expansion_id != expected {
+            report.push(
+                Severity::Error,
+                "expansion_reference",
+                row,
+                format!(
+                    "expansion_id {:?} does not reference expansion {:?} (expected {:?})",
+                    expansion_id, row.expansion, expected
+                ),
+            );
+        }
+    }
+
+    if !row.continent.is_empty() && !KNOWN_CONTINENTS.contains(&row.continent.as_str()) {
+        let hint = KNOWN_CONTINENTS
+            .iter()
+            .find(|c| c.eq_ignore_ascii_case(row.continent.trim()))
+            .map(|c| format!(" (did you mean {:?}?)", c))
+            .unwrap_or_default();
+        report.push(
+            Severity::Error,
+            "unknown_continent",
+            row,
+            format!("unknown continent {:?}{}", row.continent, hint),
+        );
+    }
+}
+
+fn check_urls(report: &mut LintReport, row: &LocationRow) {
+    for (field, value) in [("image_url", &row.image_url), ("map_url", &row.map_url)] {
+        if !value.is_empty() && sanitize_url(value).is_none() {
+            report.push(
+                Severity::Error,
+                "unsafe_url",
+                row,
+                format!("{} {:?} is rejected by sanitize_url", field, value),
+            );
+        }
+    }
+}
+
+fn check_duplicates(report: &mut LintReport, rows: &[LocationRow]) {
+    let mut seen: HashMap<String, i64> = HashMap::new();
+
+    for row in rows {
+        let key = normalize_name(&row.name);
+        if let Some(first_id) = seen.get(&key) {
+            report.push(
+                Severity::Error,
+                "duplicate_name",
+                row,
+                format!("name duplicates {} id {}", row.table, first_id),
+            );
+        } else {
+            seen.insert(key, row.id);
+        }
+    }
+}
+
+async fn check_zone_documentation(
+    report: &mut LintReport,
+    pool: &SqlitePool,
+    zones: &[LocationRow],
+) -> Result<(), sqlx::Error> {
+    let documented: HashSet<i64> =
+        sqlx::query("SELECT zone_id FROM zone_flags UNION SELECT zone_id FROM zone_notes")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|row| row.get::<i64, _>("zone_id"))
+            .collect();
+
+    for zone in zones {
+        if !documented.contains(&zone.id) {
+            report.push(
+                Severity::Warning,
+                "zone_undocumented",
+                zone,
+                "zone has no flags or notes".to_string(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Expansion (code, name) pairs from the database's catalogue, falling back to the
+/// built-in seed list for dumps that predate the expansions table
+async fn load_expansions(pool: &SqlitePool) -> Result<Vec<(String, String)>, sqlx::Error> {
+    let table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='expansions'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    let mut expansions = Vec::new();
+    if table_exists {
+        expansions = sqlx::query("SELECT code, name FROM expansions ORDER BY release_order")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|row| (row.get("code"), row.get("name")))
+            .collect();
+    }
+
+    if expansions.is_empty() {
+        expansions = EXPANSIONS
+            .iter()
+            .map(|(code, name, _, _)| (code.to_string(), name.to_string()))
+            .collect();
+    }
+
+    Ok(expansions)
+}
+
+/// Catalogue ids by expansion name, or nothing for dumps without a catalogue
+async fn load_expansion_ids(pool: &SqlitePool) -> Result<HashMap<String, i64>, sqlx::Error> {
+    let table_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='expansions'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+    if !table_exists {
+        return Ok(HashMap::new());
+    }
+
+    Ok(sqlx::query("SELECT id, name FROM expansions")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|row| (row.get("name"), row.get("id")))
+        .collect())
+}
+
+/// Every zone and instance alias, or nothing for dumps that predate the alias tables
+async fn load_alias_names(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+    let alias_tables: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name IN ('zone_aliases', 'instance_aliases')",
+    )
+    .fetch_one(pool)
+    .await?;
+
+    if alias_tables < 2 {
+        return Ok(Vec::new());
+    }
+
+    crate::aliases::get_all_alias_names(pool).await
+}
+
+/// Check every zone and instance in the database and collect the problems found
+pub async fn lint_database(pool: &SqlitePool) -> Result<LintReport, sqlx::Error> {
+    let zones = load_rows(pool, "zones").await?;
+    let instances = load_rows(pool, "instances").await?;
+    let expansions = load_expansions(pool).await?;
+    let expansion_ids = load_expansion_ids(pool).await?;
+
+    let aliases = load_alias_names(pool).await?;
+
+    // Connections may name a zone or instance directly or through one of its aliases
+    let known_names: HashSet<String> = zones
+        .iter()
+        .chain(instances.iter())
+        .map(|row| normalize_name(&row.name))
+        .chain(aliases.iter().map(|alias| normalize_name(alias)))
+        .collect();
+
+    let mut report = LintReport::default();
+    report.summary.zones_checked = zones.len();
+    report.summary.instances_checked = instances.len();
+
+    for row in zones.iter().chain(instances.iter()) {
+        check_level_ranges(&mut report, row);
+        check_connections(&mut report, row, &known_names);
+        check_spellings(&mut report, row, &expansions, &expansion_ids);
+        check_urls(&mut report, row);
+    }
+
+    check_duplicates(&mut report, &zones);
+    check_duplicates(&mut report, &instances);
+    check_zone_documentation(&mut report, pool, &zones).await?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    async fn insert_zone(
+        pool: &SqlitePool,
+        name: &str,
+        level_ranges: &str,
+        expansion: &str,
+        continent: &str,
+        connections: &str,
+        image_url: &str,
+    ) -> i64 {
+        sqlx::query(
+            "INSERT INTO zones (name, level_ranges, expansion, continent, zone_type, connections, image_url) VALUES (?, ?, ?, ?, 'Outdoor', ?, ?)",
+        )
+        .bind(name)
+        .bind(level_ranges)
+        .bind(expansion)
+        .bind(continent)
+        .bind(connections)
+        .bind(image_url)
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid()
+    }
+
+    fn rules(report: &LintReport) -> Vec<&'static str> {
+        report.issues.iter().map(|i| i.rule).collect()
+    }
+
+    #[tokio::test]
+    async fn clean_zone_has_no_errors() {
+        let pool = setup_pool().await;
+        let a = insert_zone(
+            &pool,
+            "Commonlands",
+            "[[1,20]]",
+            "Classic",
+            "Antonica",
+            "[\"Befallen\", \"bef\"]",
+            "",
+        )
+        .await;
+        let b = insert_zone(
+            &pool,
+            "Befallen",
+            "[[7,25]]",
+            "Classic",
+            "Antonica",
+            "[\"commonlands\"]",
+            "https://example.com/b.png",
+        )
+        .await;
+        sqlx::query("INSERT INTO note_types (id, name, display_name) VALUES (1, 'ref', 'Ref')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO zone_notes (zone_id, note_type_id, content) VALUES (?, 1, 'x')")
+            .bind(a)
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Connections resolve through aliases too
+        crate::aliases::add_alias(
+            &mut pool.acquire().await.unwrap(),
+            crate::aliases::LocationKind::Zone,
+            b,
+            "bef",
+        )
+        .await
+        .unwrap();
+
+        let report = lint_database(&pool).await.unwrap();
+        assert!(!report.has_errors(), "{:?}", report.issues);
+        assert_eq!(report.summary.zones_checked, 2);
+        // Befallen has neither flags nor notes
+        assert_eq!(rules(&report), vec!["zone_undocumented"]);
+    }
+
+    #[tokio::test]
+    async fn reports_bad_ranges_connections_and_spellings() {
+        let pool = setup_pool().await;
+        insert_zone(&pool, "Bad Json", "[[1,", "Classic", "", "[]", "").await;
+        insert_zone(
+            &pool,
+            "Inverted",
+            "[[30,10],[1,200]]",
+            "Kunark",
+            "Kunark",
+            "[]",
+            "",
+        )
+        .await;
+        insert_zone(
+            &pool,
+            "Lost",
+            "[[1,5]]",
+            "Ykesha",
+            "Antonca",
+            "[\"Nowhere\"]",
+            "javascript:alert(1)",
+        )
+        .await;
+        insert_zone(&pool, "lost ", "[[1,5]]", "classic", "", "[]", "").await;
+        insert_zone(&pool, "Coded", "[[1,5]]", "PoP", "", "[]", "").await;
+
+        let report = lint_database(&pool).await.unwrap();
+        let rules = rules(&report);
+        for expected in [
+            "level_ranges_json",
+            "level_range_inverted",
+            "level_above_cap",
+            "unknown_expansion",
+            "unknown_continent",
+            "connection_unresolved",
+            "unsafe_url",
+            "duplicate_name",
+        ] {
+            assert!(
+                rules.contains(&expected),
+                "missing {} in {:?}",
+                expected,
+                rules
+            );
+        }
+
+        let hint = report
+            .issues
+            .iter()
+            .find(|i| i.rule == "unknown_expansion" && i.name == "lost ")
+            .unwrap();
+        assert!(hint.message.contains("did you mean \"Classic\""));
+
+        let hint = report
+            .issues
+            .iter()
+            .find(|i| i.rule == "unknown_expansion" && i.name == "Coded")
+            .unwrap();
+        assert!(hint.message.contains("did you mean \"Planes of Power\""));
+    }
+
+    #[tokio::test]
+    async fn trashed_rows_are_skipped() {
+        let pool = setup_pool().await;
+        insert_zone(&pool, "Twin", "[[1,5]]", "Classic", "", "[]", "").await;
+        let trashed = insert_zone(&pool, "Twin", "[[50,1]]", "Classic", "", "[]", "").await;
+        sqlx::query("UPDATE zones SET deleted_at = CURRENT_TIMESTAMP WHERE id = ?")
+            .bind(trashed)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let report = lint_database(&pool).await.unwrap();
+        assert_eq!(report.summary.zones_checked, 1);
+        let rules = rules(&report);
+        assert!(!rules.contains(&"duplicate_name"), "{:?}", rules);
+        assert!(!rules.contains(&"level_range_inverted"), "{:?}", rules);
+    }
+
+    #[tokio::test]
+    async fn stale_expansion_reference_is_reported() {
+        let pool = setup_pool().await;
+        crate::expansions::seed_expansions(&pool).await.unwrap();
+        let id = insert_zone(&pool, "Befallen", "[[7,25]]", "Classic", "", "[]", "").await;
+        assert!(!rules(&lint_database(&pool).await.unwrap()).contains(&"expansion_reference"));
+
+        sqlx::query("UPDATE zones SET expansion_id = 2 WHERE id = ?")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let report = lint_database(&pool).await.unwrap();
+        let issue = report
+            .issues
+            .iter()
+            .find(|i| i.rule == "expansion_reference")
+            .unwrap();
+        assert_eq!(issue.severity, Severity::Error);
+        assert!(issue.message.contains("expected Some(1)"));
+    }
+
+    #[tokio::test]
+    async fn load_sql_file_reads_dump() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.sql");
+        std::fs::write(
+            &path,
+            "BEGIN TRANSACTION;\nCREATE TABLE zones (id INTEGER PRIMARY KEY, name TEXT, level_ranges TEXT, expansion TEXT, continent TEXT, connections TEXT, image_url TEXT, map_url TEXT);\nCREATE TABLE instances AS SELECT * FROM zones;\nCREATE TABLE zone_flags (zone_id INTEGER);\nCREATE TABLE zone_notes (zone_id INTEGER);\nINSERT INTO zones VALUES(1,'Ak''Anon','[[0,0]]','Classic','Faydwer','[]','','');\nCOMMIT;\n",
+        )
+        .unwrap();
+
+        let pool = load_sql_file(path.to_str().unwrap()).await.unwrap();
+        let report = lint_database(&pool).await.unwrap();
+        assert_eq!(report.summary.zones_checked, 1);
+        assert!(!report.has_errors());
+    }
+}
@@ -0,0 +1,359 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use crate::audit::AuditEntity;
+use crate::error::AppError;
+
+/// Name of the flag type that marks a zone or instance as a hot zone
+pub const HOT_ZONE_FLAG: &str = "hot_zone";
+
+/// Zones and instances are the two kinds of location. They live in separate tables
+/// with the same shape, and every child table is named `{kind}_{child}` with a
+/// `{kind}_id` column pointing back at its owner.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LocationKind {
+    Zone,
+    Instance,
+}
+
+impl LocationKind {
+    pub const ALL: [LocationKind; 2] = [LocationKind::Zone, LocationKind::Instance];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LocationKind::Zone => "zone",
+            LocationKind::Instance => "instance",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    /// The kind a conversion turns this one into
+    pub fn other(self) -> Self {
+        match self {
+            LocationKind::Zone => LocationKind::Instance,
+            LocationKind::Instance => LocationKind::Zone,
+        }
+    }
+
+    pub(crate) fn table(self) -> &'static str {
+        match self {
+            LocationKind::Zone => "zones",
+            LocationKind::Instance => "instances",
+        }
+    }
+
+    pub(crate) fn id_column(self) -> &'static str {
+        match self {
+            LocationKind::Zone => "zone_id",
+            LocationKind::Instance => "instance_id",
+        }
+    }
+
+    /// Child table such as `zone_notes` or `instance_flags`
+    pub(crate) fn child_table(self, child: &str) -> String {
+        format!("{}_{}", self.as_str(), child)
+    }
+
+    pub(crate) fn alias_table(self) -> &'static str {
+        match self {
+            LocationKind::Zone => "zone_aliases",
+            LocationKind::Instance => "instance_aliases",
+        }
+    }
+
+    pub(crate) fn not_found(self, id: i64) -> AppError {
+        match self {
+            LocationKind::Zone => AppError::ZoneNotFound(id),
+            LocationKind::Instance => AppError::InstanceNotFound(id),
+        }
+    }
+
+    pub(crate) fn entity(self) -> AuditEntity {
+        match self {
+            LocationKind::Zone => AuditEntity::Zone,
+            LocationKind::Instance => AuditEntity::Instance,
+        }
+    }
+
+    /// Audit entity for rows of this kind's ratings table
+    pub(crate) fn rating_entity(self) -> AuditEntity {
+        match self {
+            LocationKind::Zone => AuditEntity::Rating,
+            LocationKind::Instance => AuditEntity::InstanceRating,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NoteType {
+    pub id: Option<i64>,
+    pub name: String,
+    pub display_name: String,
+    pub color_class: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FlagType {
+    pub id: Option<i64>,
+    pub name: String,
+    pub display_name: String,
+    pub color_class: String,
+}
+
+/// A note as stored, before it becomes a `ZoneNote` or `InstanceNote`
+#[derive(Debug, Clone)]
+pub struct NoteRecord {
+    pub id: i64,
+    pub location_id: i64,
+    pub note_type_id: i64,
+    pub content: String,
+    pub note_type: NoteType,
+}
+
+/// A flag as stored, before it becomes a `ZoneFlag` or `InstanceFlag`
+#[derive(Debug, Clone)]
+pub struct FlagRecord {
+    pub id: i64,
+    pub location_id: i64,
+    pub flag_type_id: i64,
+    pub flag_type: FlagType,
+    /// Whether rolls can filter on this flag
+    pub filterable: bool,
+}
+
+/// What zones and instances have in common. Notes, flags, rolls and conversions are
+/// written once against this trait; each kind keeps its own JSON shape through its
+/// note and flag types.
+pub trait Location: Clone {
+    const KIND: LocationKind;
+    type Note: From<NoteRecord>;
+    type Flag: From<FlagRecord>;
+
+    fn id(&self) -> Option<i64>;
+    fn name(&self) -> &str;
+    fn level_ranges(&self) -> &[[u8; 2]];
+    fn expansion(&self) -> &str;
+    fn zone_type(&self) -> &str;
+    fn continent(&self) -> &str;
+    /// Only instances track hot zones
+    fn hot_zone(&self) -> Option<bool> {
+        None
+    }
+}
+
+/// Notes of one location, or of every location of a kind when `location_id` is None,
+/// oldest first
+pub async fn load_notes(
+    pool: &SqlitePool,
+    kind: LocationKind,
+    location_id: Option<i64>,
+) -> Result<Vec<NoteRecord>, sqlx::Error> {
+    let query = format!(
+        r#"
+        SELECT
+            n.id,
+            n.{id} AS location_id,
+            n.note_type_id,
+            n.content,
+            nt.name as note_type_name,
+            nt.display_name as note_type_display_name,
+            nt.color_class as note_type_color_class
+        FROM {table} n
+        JOIN note_types nt ON n.note_type_id = nt.id
+        {filter}
+        ORDER BY n.created_at ASC
+        "#,
+        id = kind.id_column(),
+        table = kind.child_table("notes"),
+        filter = if location_id.is_some() {
+            format!("WHERE n.{} = ?", kind.id_column())
+        } else {
+            String::new()
+        }
+    );
+
+    let mut rows = sqlx::query(&query);
+    if let Some(location_id) = location_id {
+        rows = rows.bind(location_id);
+    }
+    let rows = rows.fetch_all(pool).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| NoteRecord {
+            id: row.get("id"),
+            location_id: row.get("location_id"),
+            note_type_id: row.get("note_type_id"),
+            content: row.get("content"),
+            note_type: NoteType {
+                id: Some(row.get("note_type_id")),
+                name: row.get("note_type_name"),
+                display_name: row.get("note_type_display_name"),
+                color_class: row.get("note_type_color_class"),
+            },
+        })
+        .collect())
+}
+
+/// Flags of one location, or of every location of a kind when `location_id` is None,
+/// ordered by display name
+pub async fn load_flags(
+    pool: &SqlitePool,
+    kind: LocationKind,
+    location_id: Option<i64>,
+) -> Result<Vec<FlagRecord>, sqlx::Error> {
+    let query = format!(
+        r#"
+        SELECT
+            f.id,
+            f.{id} AS location_id,
+            f.flag_type_id,
+            ft.name as flag_type_name,
+            ft.display_name as flag_type_display_name,
+            ft.color_class as flag_type_color_class,
+            ft.filterable
+        FROM {table} f
+        JOIN flag_types ft ON f.flag_type_id = ft.id
+        {filter}
+        ORDER BY ft.display_name ASC
+        "#,
+        id = kind.id_column(),
+        table = kind.child_table("flags"),
+        filter = if location_id.is_some() {
+            format!("WHERE f.{} = ?", kind.id_column())
+        } else {
+            String::new()
+        }
+    );
+
+    let mut rows = sqlx::query(&query);
+    if let Some(location_id) = location_id {
+        rows = rows.bind(location_id);
+    }
+    let rows = rows.fetch_all(pool).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| FlagRecord {
+            id: row.get("id"),
+            location_id: row.get("location_id"),
+            flag_type_id: row.get("flag_type_id"),
+            flag_type: FlagType {
+                id: Some(row.get("flag_type_id")),
+                name: row.get("flag_type_name"),
+                display_name: row.get("flag_type_display_name"),
+                color_class: row.get("flag_type_color_class"),
+            },
+            filterable: row.get("filterable"),
+        })
+        .collect())
+}
+
+pub async fn get_notes<L: Location>(
+    pool: &SqlitePool,
+    location_id: i64,
+) -> Result<Vec<L::Note>, sqlx::Error> {
+    Ok(load_notes(pool, L::KIND, Some(location_id))
+        .await?
+        .into_iter()
+        .map(L::Note::from)
+        .collect())
+}
+
+pub async fn get_flags<L: Location>(
+    pool: &SqlitePool,
+    location_id: i64,
+) -> Result<Vec<L::Flag>, sqlx::Error> {
+    Ok(load_flags(pool, L::KIND, Some(location_id))
+        .await?
+        .into_iter()
+        .map(L::Flag::from)
+        .collect())
+}
+
+/// Whether a location exists and is not in the trash
+pub async fn is_live(
+    pool: &SqlitePool,
+    kind: LocationKind,
+    location_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let query = format!(
+        "SELECT id FROM {} WHERE id = ? AND deleted_at IS NULL",
+        kind.table()
+    );
+    Ok(sqlx::query(&query)
+        .bind(location_id)
+        .fetch_optional(pool)
+        .await?
+        .is_some())
+}
+
+/// Notes for the public notes endpoints. A trashed location's notes are hidden along
+/// with it, and errors come back as an empty list.
+pub async fn public_notes<L: Location>(pool: &SqlitePool, location_id: i64) -> Vec<L::Note> {
+    if !matches!(is_live(pool, L::KIND, location_id).await, Ok(true)) {
+        return Vec::new();
+    }
+    get_notes::<L>(pool, location_id).await.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instances::Instance;
+    use crate::zones::Zone;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+
+        for statement in [
+            "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (7, 'Befallen', '[[7,25]]', 'Classic', 'Indoor')",
+            "INSERT INTO instances (id, name, level_ranges, expansion, zone_type) VALUES (7, 'Deepest Guk', '[[55,65]]', 'Luclin', 'Dungeon')",
+            "INSERT INTO note_types (id, name, display_name, color_class) VALUES (1, 'epic', 'Epic', 'bg-yellow-500')",
+            "INSERT INTO flag_types (id, name, display_name, color_class, filterable) VALUES (1, 'raid', 'Raid', 'bg-red-500', 1)",
+            "INSERT INTO zone_notes (zone_id, note_type_id, content) VALUES (7, 1, 'Necro epic')",
+            "INSERT INTO instance_notes (instance_id, note_type_id, content) VALUES (7, 1, 'Frenzied Ghoul')",
+            "INSERT INTO instance_flags (instance_id, flag_type_id) VALUES (7, 1)",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn children_load_per_kind_with_each_json_shape() {
+        let pool = setup_pool().await;
+
+        let zone_notes = get_notes::<Zone>(&pool, 7).await.unwrap();
+        assert_eq!(zone_notes.len(), 1);
+        let json = serde_json::to_value(&zone_notes[0]).unwrap();
+        assert_eq!(json["zone_id"], 7);
+        assert_eq!(json["content"], "Necro epic");
+
+        let instance_notes = get_notes::<Instance>(&pool, 7).await.unwrap();
+        let json = serde_json::to_value(&instance_notes[0]).unwrap();
+        assert_eq!(json["instance_id"], 7);
+        assert_eq!(json["content"], "Frenzied Ghoul");
+
+        assert!(get_flags::<Zone>(&pool, 7).await.unwrap().is_empty());
+        let flags = get_flags::<Instance>(&pool, 7).await.unwrap();
+        assert_eq!(flags[0].flag_type.as_ref().unwrap().name, "raid");
+
+        crate::trash::soft_delete(&pool, crate::trash::TrashKind::Instance, 7)
+            .await
+            .unwrap();
+        assert!(public_notes::<Instance>(&pool, 7).await.is_empty());
+        assert_eq!(public_notes::<Zone>(&pool, 7).await.len(), 1);
+    }
+}
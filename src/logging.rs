@@ -104,9 +104,12 @@ pub fn log_db_operation(
     }
 }
 
-// Helper function to log admin actions
+// Helper function to log admin actions. Without `user_info` the signed-in admin
+// making the current request is named.
 pub fn log_admin_action(action: &str, resource: &str, id: Option<i64>, user_info: Option<&str>) {
-    let user_info = user_info.unwrap_or("unknown");
+    let user_info = user_info
+        .map(str::to_string)
+        .unwrap_or_else(crate::audit::current_actor);
     if let Some(id_val) = id {
         tracing::info!(
             "Admin action: {} on {} with id {} by {}",
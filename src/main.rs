@@ -1,22 +1,24 @@
-use axum::extract::{ConnectInfo, State};
-use axum::http::{HeaderValue, Request, StatusCode};
+use axum::extract::State;
+use axum::http::{Request, StatusCode, header};
 use axum::middleware::Next;
 use axum::response::Response;
 use axum::{Router, http::Method, middleware, routing::get, serve};
 use clap::Parser;
-use once_cell::sync::Lazy;
-use std::collections::HashMap;
-use std::net::{IpAddr, SocketAddr};
-use std::time::{Duration, Instant};
+use std::net::SocketAddr;
 use tokio::net::TcpListener;
-use tokio::sync::Mutex;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::services::ServeDir;
 use tracing::{error, info, warn};
 
 #[cfg(feature = "admin")]
 use eq_rng::admin;
-use eq_rng::{AppConfig, AppState, classes, instances, links, races, ratings, version, zones};
+use eq_rng::client_ip::ClientIp;
+use eq_rng::rate_limit::RouteGroup;
+use eq_rng::security::{CSP_REPORT_PATH, CspNonce};
+use eq_rng::{
+    AppConfig, AppState, aliases, api_keys, audit, classes, csrf, expansions, instances, links,
+    races, ratings, rulesets, search, version, zones,
+};
 
 #[derive(Parser)]
 #[command(name = "eq_rng")]
@@ -27,95 +29,114 @@ struct Args {
     port: Option<u16>,
 }
 
-// Security headers middleware
-async fn security_headers(mut response: Response) -> Response {
-    let headers = response.headers_mut();
-
-    // XSS Protection
-    headers.insert(
-        "X-XSS-Protection",
-        HeaderValue::from_static("1; mode=block"),
-    );
-
-    // Content Type Options
-    headers.insert(
-        "X-Content-Type-Options",
-        HeaderValue::from_static("nosniff"),
-    );
-
-    // Frame Options
-    headers.insert("X-Frame-Options", HeaderValue::from_static("DENY"));
-
-    // Content Security Policy
-    headers.insert(
-        "Content-Security-Policy",
-        HeaderValue::from_static(eq_rng::security::get_csp_header()),
-    );
-
-    // Referrer Policy
-    headers.insert(
-        "Referrer-Policy",
-        HeaderValue::from_static("strict-origin-when-cross-origin"),
-    );
-
-    // Permissions Policy
-    headers.insert(
-        "Permissions-Policy",
-        HeaderValue::from_static("geolocation=(), microphone=(), camera=()"),
-    );
-
-    // Strict Transport Security (HSTS)
-    headers.insert(
-        "Strict-Transport-Security",
-        HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
-    );
+// Security headers middleware, configured by `[security.headers]`
+async fn security_headers(
+    State(state): State<AppState>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let nonce = req
+        .extensions()
+        .get::<CspNonce>()
+        .cloned()
+        .unwrap_or_else(CspNonce::generate);
+    let path = req.uri().path();
+    let admin = path == "/admin" || path.starts_with("/admin/");
+    let mut response = next.run(req).await;
+
+    // The configuration was checked at startup, so this only fails if it is broken
+    let headers = &state.config.security.headers;
+    match eq_rng::security::security_headers(headers, admin, &nonce.0) {
+        Ok(headers) => response.headers_mut().extend(headers),
+        Err(e) => error!("Failed to build security headers: {}", e),
+    }
 
     response
 }
 
-#[cfg(feature = "admin")]
-fn link_admin_routes() -> Router<AppState> {
-    Router::new()
-        .route("/api/links", axum::routing::post(links::create_link))
-        .route("/api/links/:id", axum::routing::put(links::update_link))
-        .route("/api/links/:id", axum::routing::delete(links::delete_link))
-}
+async fn csrf_check(
+    State(state): State<AppState>,
+    mut req: Request<axum::body::Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    // API clients authenticate with their key rather than a cookie
+    if req.extensions().get::<api_keys::ApiKey>().is_some() {
+        return Ok(next.run(req).await);
+    }
 
-static RATE_LIMITER: Lazy<Mutex<HashMap<IpAddr, Vec<Instant>>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
-const MAX_REQUESTS_PER_MINUTE: usize = 5;
-const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
-const CSRF_HEADER: &str = "x-csrf-token";
+    // Browsers send violation reports without a token
+    if req.method() != Method::GET && req.uri().path() != CSP_REPORT_PATH {
+        // Forms carry the token in their body, so this may read it
+        req = csrf::verify_request(&state.config, req).await?;
+    }
+    Ok(next.run(req).await)
+}
 
-async fn csrf_and_rate_limit(
+// Requests sending `Authorization: Bearer <key>` must use a live key whose scopes
+// cover the route, and count against that key's per-minute limit and daily quota.
+// Other schemes pass through untouched.
+async fn api_key_auth(
     State(state): State<AppState>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
-    req: Request<axum::body::Body>,
+    ClientIp(client): ClientIp,
+    mut req: Request<axum::body::Body>,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    if req.method() != Method::GET {
-        let token = req
-            .headers()
-            .get(CSRF_HEADER)
-            .and_then(|v| v.to_str().ok())
-            .unwrap_or("");
-        if token != state.config.security.rating_ip_hash_key {
-            return Err(StatusCode::UNAUTHORIZED);
-        }
+    let Some(value) = req.headers().get(header::AUTHORIZATION) else {
+        return Ok(next.run(req).await);
+    };
+    let Some(presented) = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return Ok(next.run(req).await);
+    };
 
-        let ip = addr.ip();
+    let pool = &state.zone_state.pool;
+    let Some(key) = api_keys::find_api_key(pool, presented.trim())
+        .await
+        .map_err(|e| {
+            error!("Failed to look up API key: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+    else {
+        // Unknown keys are charged to the caller's address so guessing is limited
+        // like any other write, even on reads that are otherwise free
+        let group =
+            RouteGroup::for_request(req.method(), req.uri().path()).unwrap_or(RouteGroup::Writes);
+        if let Some(decision) = state.rate_limiter.check(group, client)
+            && !decision.allowed
         {
-            let mut map = RATE_LIMITER.lock().await;
-            let entry = map.entry(ip).or_insert_with(Vec::new);
-            let now = Instant::now();
-            entry.retain(|t| now.duration_since(*t) < RATE_LIMIT_WINDOW);
-            if entry.len() >= MAX_REQUESTS_PER_MINUTE {
-                return Err(StatusCode::TOO_MANY_REQUESTS);
-            }
-            entry.push(now);
+            return Ok(decision.refusal());
         }
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+
+    match api_keys::required_scope(req.method(), req.uri().path()) {
+        Some(scope) if key.allows(scope) => {}
+        _ => return Err(StatusCode::FORBIDDEN),
     }
-    Ok(next.run(req).await)
+
+    let decision = state
+        .rate_limiter
+        .check_api_key(key.id, key.rate_limit_per_minute);
+    if !decision.allowed {
+        return Ok(decision.refusal());
+    }
+
+    let counted = api_keys::record_use(pool, key.id).await.map_err(|e| {
+        error!("Failed to record API key use: {}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    if !counted {
+        warn!("API key {} ({}) is over its daily quota", key.id, key.name);
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    req.extensions_mut().insert(key);
+    let mut response = next.run(req).await;
+    decision.apply_headers(response.headers_mut());
+    Ok(response)
 }
 
 #[tokio::main]
@@ -162,6 +183,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("Database ready with {} zones", zone_count);
 
+    eq_rng::backup::spawn_backup_scheduler(
+        pool.clone(),
+        config.database.backup_dir.clone(),
+        config.backup.clone(),
+    );
+
+    eq_rng::trash::spawn_purge_scheduler(pool.clone(), config.trash.clone());
+
+    // Random rolls are served from an in-memory snapshot; build it before accepting traffic
+    let snapshot = eq_rng::snapshot::SnapshotCache::default();
+    snapshot.rebuild(&pool).await.map_err(|e| {
+        error!("Failed to build roll snapshot: {}", e);
+        e
+    })?;
+
     let state = AppState {
         config: std::sync::Arc::new(config.clone()),
         zone_state: zones::ZoneState {
@@ -173,8 +209,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         class_race_state: classes::ClassRaceState {
             class_race_map: classes::load_classes(),
         },
+        snapshot,
+        rate_limiter: eq_rng::rate_limit::RateLimiter::new(config.rate_limit.clone()),
     };
 
+    eq_rng::rate_limit::spawn_eviction(state.rate_limiter.clone());
+
     let app = Router::new()
         .route(
             "/random_zone",
@@ -186,7 +226,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/random_race", get(races::random_race))
         .route("/random_class", get(classes::random_class))
         .route("/version", get(version::version))
+        .route("/csrf-token", get(csrf::csrf_token))
+        .route(
+            CSP_REPORT_PATH,
+            axum::routing::post(eq_rng::security::csp_report)
+                .layer(axum::extract::DefaultBodyLimit::max(64 * 1024)),
+        )
         .route("/flag-types", get(zones::get_flag_types_api))
+        .route("/expansions", get(expansions::get_expansions_api))
+        .route("/rulesets", get(rulesets::get_rulesets_api))
+        .route("/search", get(search::search_api))
+        .route("/zones/resolve", get(aliases::resolve_location_api))
         .route("/zones/:zone_id/rating", get(ratings::get_zone_rating))
         .route(
             "/zones/:zone_id/rating",
@@ -194,8 +244,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .route("/zones/:zone_id/ratings", get(ratings::get_zone_ratings))
         .route(
-            "/api/ratings/:id",
-            axum::routing::delete(ratings::delete_rating),
+            "/instances/:instance_id/rating",
+            get(ratings::get_instance_rating),
+        )
+        .route(
+            "/instances/:instance_id/rating",
+            axum::routing::post(ratings::submit_instance_rating),
         )
         .route("/zones/:zone_id/notes", get(zones::get_zone_notes_endpoint))
         .route(
@@ -207,9 +261,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/links/categories", get(links::get_categories))
         .route("/api/links/:id", get(links::get_link));
 
+    #[cfg(feature = "admin")]
+    if state.config.admin.enabled
+        && matches!(
+            eq_rng::auth::admin_user_count(&state.zone_state.pool).await,
+            Ok(0)
+        )
+    {
+        warn!("Admin interface is enabled but no admin users exist; create one with create_admin");
+    }
+
     #[cfg(feature = "admin")]
     let app = if state.config.admin.enabled {
-        app.merge(link_admin_routes()).merge(admin::admin_routes())
+        app.merge(admin::admin_routes(state.clone()))
     } else {
         app
     };
@@ -217,19 +281,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let app = app;
 
     let state_for_middleware = state.clone();
+    let state_for_headers = state.clone();
     let app = app.with_state(state);
+    // Names the actor for audit log entries written by admin changes
+    let app = app.layer(middleware::from_fn(audit::scope_request_actor));
+    let app = app.layer(middleware::from_fn_with_state(
+        state_for_middleware.clone(),
+        csrf_check,
+    ));
+    let app = app.layer(middleware::from_fn_with_state(
+        state_for_middleware.clone(),
+        eq_rng::rate_limit::enforce,
+    ));
+    let app = app.layer(middleware::from_fn_with_state(
+        state_for_middleware.clone(),
+        api_key_auth,
+    ));
+    // Works out the client address, behind trusted proxies, for everything above
     let app = app.layer(middleware::from_fn_with_state(
         state_for_middleware,
-        csrf_and_rate_limit,
+        eq_rng::client_ip::resolve_client_ip,
     ));
 
-    let app = if config.is_production() {
-        // Only apply security headers when running behind HTTPS
-        app.layer(middleware::map_response(security_headers))
-    } else {
-        app
-    };
-
     let app = app
         .layer({
             // Configure CORS based on configuration
@@ -260,6 +333,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         })
         .nest_service("/", ServeDir::new("dist"));
 
+    let app = if config.security_headers_enabled() {
+        app.layer(middleware::from_fn_with_state(
+            state_for_headers,
+            security_headers,
+        ))
+    } else {
+        app
+    };
+    // Every response, static pages included, gets its own CSP nonce
+    let app = app.layer(middleware::from_fn(eq_rng::security::apply_csp_nonce));
+
     let addr: SocketAddr = format!("{}:{}", config.server.host, port)
         .parse()
         .map_err(|e| {
@@ -0,0 +1,79 @@
+// Markdown notes
+// Zone and instance notes are stored as Markdown and rendered on the server. The
+// rendered HTML goes through the sanitizer, so raw HTML written into a note and
+// links with unsafe schemes are filtered just as they are everywhere else.
+
+use once_cell::sync::Lazy;
+use pulldown_cmark::{Options, Parser, html};
+
+use crate::security::HtmlSanitizer;
+
+/// The tags Markdown produces, with links limited to `sanitize_url` schemes.
+/// Images are not allowed, so a note cannot pull in third-party content.
+static NOTE_SANITIZER: Lazy<HtmlSanitizer> = Lazy::new(|| {
+    HtmlSanitizer::new()
+        .allow_tag("h1", &[])
+        .allow_tag("h2", &[])
+        .allow_tag("h3", &[])
+        .allow_tag("h4", &[])
+        .allow_tag("h5", &[])
+        .allow_tag("h6", &[])
+        .allow_tag("ul", &[])
+        .allow_tag("ol", &["start"])
+        .allow_tag("li", &[])
+        .allow_tag("blockquote", &[])
+        .allow_tag("pre", &[])
+        .allow_tag("code", &[])
+        .allow_tag("hr", &[])
+        .allow_tag("del", &[])
+        .allow_tag("table", &[])
+        .allow_tag("thead", &[])
+        .allow_tag("tbody", &[])
+        .allow_tag("tr", &[])
+        .allow_tag("th", &[])
+        .allow_tag("td", &[])
+});
+
+/// Render a note's Markdown to sanitized HTML
+pub fn render(markdown: &str) -> String {
+    let parser = Parser::new_ext(
+        markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    );
+    let mut rendered = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut rendered, parser);
+    NOTE_SANITIZER.sanitize(&rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_walkthrough_markdown() {
+        let note = "### Cleric epic\n\n1. [Lord Bergurgle's Crown](https://everquest.allakhazam.com/db/item.html?item=1)\n2. **Swirling Pearl** in *Sol B*\n\n- `Singed Scroll`\n";
+        assert_eq!(
+            render(note),
+            "<h3>Cleric epic</h3>\n<ol>\n<li><a href=\"https:&#x2F;&#x2F;everquest.allakhazam.com&#x2F;db&#x2F;item.html?item=1\" rel=\"noopener noreferrer\">Lord Bergurgle&#x27;s Crown</a></li>\n<li><strong>Swirling Pearl</strong> in <em>Sol B</em></li>\n</ol>\n<ul>\n<li><code>Singed Scroll</code></li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn notes_cannot_smuggle_markup_or_unsafe_links() {
+        // Notes written before Markdown used inline HTML links, which still work
+        assert_eq!(
+            render(r#"Bard Epic 1.0: <a href="https://example.com/">Onyx Drake Gut</a>"#),
+            "<p>Bard Epic 1.0: <a href=\"https:&#x2F;&#x2F;example.com&#x2F;\" rel=\"noopener noreferrer\">Onyx Drake Gut</a></p>\n"
+        );
+        assert_eq!(
+            render("[click](javascript:alert(1))"),
+            "<p><a rel=\"noopener noreferrer\">click</a></p>\n"
+        );
+        assert_eq!(render("<script>alert(1)</script>"), "");
+        assert_eq!(
+            render("![x](https://example.com/x.png) <img src=x onerror=alert(1)>"),
+            "<p> </p>\n"
+        );
+        assert_eq!(render("<details open ontoggle=alert(1)>hi</details>"), "hi");
+    }
+}
@@ -1,7 +1,11 @@
-use axum::Json;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Serialize)]
 pub struct RaceResult {
@@ -49,13 +53,31 @@ pub const RACE_GENDERS: &[(&str, &[&str])] = &[
     ("Froglok", &["male", "female"]),
 ];
 
-pub async fn random_race() -> Json<RaceResult> {
+#[derive(Deserialize)]
+pub struct RaceQuery {
+    ruleset: Option<String>,
+}
+
+pub async fn random_race(
+    Query(query): Query<RaceQuery>,
+    State(state): State<crate::AppState>,
+) -> Result<Json<RaceResult>, StatusCode> {
+    let ruleset =
+        crate::rulesets::resolve_ruleset_param(&state.zone_state.pool, query.ruleset.as_deref())
+            .await?;
+
+    let candidates: Vec<&str> = RACES
+        .iter()
+        .copied()
+        .filter(|race| ruleset.as_ref().is_none_or(|r| r.allows_race(race)))
+        .collect();
+
     let mut rng = thread_rng();
 
-    // Safe to unwrap since RACES is a constant array that's never empty
-    let race_name = RACES
+    // Every ruleset unlocks the classic races, so candidates is never empty
+    let race_name = candidates
         .choose(&mut rng)
-        .expect("RACES array should never be empty");
+        .expect("candidate races should never be empty");
 
     let available_genders = RACE_GENDERS
         .iter()
@@ -76,11 +98,11 @@ pub async fn random_race() -> Json<RaceResult> {
 
     let image_path = format!("/assets/images/races/{}", image_filename);
 
-    Json(RaceResult {
+    Ok(Json(RaceResult {
         name: race_name.to_string(),
         gender: selected_gender.to_string(),
         image_path: Some(image_path),
-    })
+    }))
 }
 
 #[cfg(test)]
@@ -0,0 +1,431 @@
+// Rate limiting
+// Requests are sorted into route groups, and each client IP gets a token bucket per
+// group: a burst of up to `capacity` requests, refilled at a steady rate. API keys
+// get one bucket per key instead. Buckets that have refilled completely hold no
+// information and are swept away in the background.
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, HeaderValue, Method, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::AppState;
+use crate::api_keys::ApiKey;
+use crate::client_ip::ClientIp;
+use crate::config::{BucketPolicy, RateLimitConfig};
+
+/// Which policy a request is limited by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteGroup {
+    Rolls,
+    Ratings,
+    Admin,
+    Writes,
+}
+
+impl RouteGroup {
+    /// The group a request belongs to; None for reads that are not limited
+    pub fn for_request(method: &Method, path: &str) -> Option<Self> {
+        let read = method == Method::GET || method == Method::HEAD;
+
+        if path == "/admin" || path.starts_with("/admin/") {
+            Some(RouteGroup::Admin)
+        } else if read {
+            matches!(
+                path,
+                "/random_zone" | "/random_instance" | "/random_race" | "/random_class"
+            )
+            .then_some(RouteGroup::Rolls)
+        } else if method == Method::OPTIONS {
+            None
+        } else if method == Method::POST
+            && (path.starts_with("/zones/") || path.starts_with("/instances/"))
+            && path.ends_with("/rating")
+        {
+            Some(RouteGroup::Ratings)
+        } else {
+            Some(RouteGroup::Writes)
+        }
+    }
+
+    fn policy(self, config: &RateLimitConfig) -> BucketPolicy {
+        match self {
+            RouteGroup::Rolls => config.rolls,
+            RouteGroup::Ratings => config.ratings,
+            RouteGroup::Admin => config.admin,
+            RouteGroup::Writes => config.writes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Client {
+    Ip(RouteGroup, IpAddr),
+    ApiKey(i64),
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    policy: BucketPolicy,
+}
+
+impl Bucket {
+    fn refill_per_second(&self) -> f64 {
+        self.policy.refill_per_minute as f64 / 60.0
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * self.refill_per_second()).min(self.policy.capacity as f64);
+        self.updated = now;
+    }
+
+    fn is_full(&self) -> bool {
+        self.tokens >= self.policy.capacity as f64
+    }
+
+    /// Time until `tokens` more tokens have dripped in
+    fn time_until(&self, tokens: f64) -> Duration {
+        let missing = (tokens - self.tokens).max(0.0);
+        if missing == 0.0 {
+            Duration::ZERO
+        } else if self.refill_per_second() == 0.0 {
+            Duration::MAX
+        } else {
+            Duration::from_secs_f64(missing / self.refill_per_second())
+        }
+    }
+}
+
+/// The outcome of taking a token, with what the `RateLimit-*` headers report
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Until the bucket is full again
+    pub reset_after: Duration,
+    /// Until the next request would be allowed; only set when refused
+    pub retry_after: Option<Duration>,
+}
+
+impl Decision {
+    /// Add the `RateLimit-*` headers, and `Retry-After` for a refusal
+    pub fn apply_headers(&self, headers: &mut HeaderMap) {
+        headers.insert("ratelimit-limit", HeaderValue::from(self.limit));
+        headers.insert("ratelimit-remaining", HeaderValue::from(self.remaining));
+        headers.insert(
+            "ratelimit-reset",
+            HeaderValue::from(whole_seconds(self.reset_after)),
+        );
+        if let Some(retry_after) = self.retry_after {
+            headers.insert(
+                header::RETRY_AFTER,
+                HeaderValue::from(whole_seconds(retry_after).max(1)),
+            );
+        }
+    }
+
+    /// The 429 response for a refused request
+    pub fn refusal(&self) -> Response {
+        let mut response = StatusCode::TOO_MANY_REQUESTS.into_response();
+        self.apply_headers(response.headers_mut());
+        response
+    }
+}
+
+/// Seconds rounded up, saturating for a bucket that never refills
+fn whole_seconds(duration: Duration) -> u64 {
+    duration
+        .as_secs()
+        .saturating_add(u64::from(duration.subsec_nanos() > 0))
+}
+
+/// Token buckets for every client seen recently. Cloning shares the buckets.
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: Arc<RateLimitConfig>,
+    buckets: Arc<Mutex<HashMap<Client, Bucket>>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitConfig::default())
+    }
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn take(&self, client: Client, policy: BucketPolicy, now: Instant) -> Decision {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let bucket = buckets.entry(client).or_insert_with(|| Bucket {
+            tokens: policy.capacity as f64,
+            updated: now,
+            policy,
+        });
+        bucket.refill(now);
+
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+
+        Decision {
+            allowed,
+            limit: policy.capacity,
+            remaining: bucket.tokens.floor() as u32,
+            reset_after: bucket.time_until(policy.capacity as f64),
+            retry_after: (!allowed).then(|| bucket.time_until(1.0)),
+        }
+    }
+
+    /// Take a token for an anonymous request from `ip`. None when the group is not
+    /// limited.
+    pub fn check(&self, group: RouteGroup, ip: IpAddr) -> Option<Decision> {
+        self.check_at(group, ip, Instant::now())
+    }
+
+    fn check_at(&self, group: RouteGroup, ip: IpAddr, now: Instant) -> Option<Decision> {
+        let policy = group.policy(&self.config);
+        if !self.config.enabled || policy.capacity == 0 {
+            return None;
+        }
+        Some(self.take(Client::Ip(group, ip), policy, now))
+    }
+
+    /// Take a token for a request made with an API key, which allows
+    /// `per_minute` requests a minute whatever the route
+    pub fn check_api_key(&self, id: i64, per_minute: u32) -> Decision {
+        let policy = BucketPolicy {
+            capacity: per_minute,
+            refill_per_minute: per_minute,
+        };
+        self.take(Client::ApiKey(id), policy, Instant::now())
+    }
+
+    /// Drop buckets that have refilled completely; a new bucket would start the
+    /// same way. Returns how many were dropped.
+    pub fn evict_idle(&self) -> usize {
+        self.evict_idle_at(Instant::now())
+    }
+
+    fn evict_idle_at(&self, now: Instant) -> usize {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let before = buckets.len();
+        buckets.retain(|_, bucket| {
+            bucket.refill(now);
+            !bucket.is_full()
+        });
+        before - buckets.len()
+    }
+
+    pub fn tracked_clients(&self) -> usize {
+        self.buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+    }
+}
+
+/// Sweep idle buckets every `eviction_interval_seconds`
+pub fn spawn_eviction(limiter: RateLimiter) -> Option<tokio::task::JoinHandle<()>> {
+    let seconds = limiter.config.eviction_interval_seconds;
+    if !limiter.config.enabled || seconds == 0 {
+        return None;
+    }
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(seconds));
+        loop {
+            interval.tick().await;
+            let evicted = limiter.evict_idle();
+            if evicted > 0 {
+                tracing::debug!(
+                    evicted,
+                    remaining = limiter.tracked_clients(),
+                    "Evicted idle rate limit buckets"
+                );
+            }
+        }
+    }))
+}
+
+/// Limit anonymous requests by route group and client IP. Requests made with an
+/// API key were already limited by their key.
+pub async fn enforce(
+    State(state): State<AppState>,
+    ClientIp(client): ClientIp,
+    req: axum::http::Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    if req.extensions().get::<ApiKey>().is_some() {
+        return next.run(req).await;
+    }
+    let Some(group) = RouteGroup::for_request(req.method(), req.uri().path()) else {
+        return next.run(req).await;
+    };
+    let Some(decision) = state.rate_limiter.check(group, client) else {
+        return next.run(req).await;
+    };
+
+    if !decision.allowed {
+        tracing::debug!(client = %client, ?group, "Rate limited");
+        return decision.refusal();
+    }
+    let mut response = next.run(req).await;
+    decision.apply_headers(response.headers_mut());
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn limiter(rolls: BucketPolicy) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            rolls,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn requests_fall_into_route_groups() {
+        let group = RouteGroup::for_request;
+        assert_eq!(group(&Method::GET, "/random_zone"), Some(RouteGroup::Rolls));
+        assert_eq!(group(&Method::GET, "/search"), None);
+        assert_eq!(
+            group(&Method::POST, "/zones/4/rating"),
+            Some(RouteGroup::Ratings)
+        );
+        assert_eq!(
+            group(&Method::POST, "/instances/4/rating"),
+            Some(RouteGroup::Ratings)
+        );
+        assert_eq!(group(&Method::GET, "/admin/zones"), Some(RouteGroup::Admin));
+        assert_eq!(
+            group(&Method::POST, "/admin/login"),
+            Some(RouteGroup::Admin)
+        );
+        assert_eq!(
+            group(&Method::DELETE, "/api/ratings/4"),
+            Some(RouteGroup::Writes)
+        );
+        assert_eq!(group(&Method::OPTIONS, "/zones/4/rating"), None);
+    }
+
+    #[test]
+    fn buckets_allow_a_burst_then_refill_steadily() {
+        let limiter = limiter(BucketPolicy {
+            capacity: 3,
+            refill_per_minute: 60,
+        });
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let start = Instant::now();
+
+        for remaining in [2, 1, 0] {
+            let decision = limiter.check_at(RouteGroup::Rolls, ip, start).unwrap();
+            assert!(decision.allowed);
+            assert_eq!(decision.remaining, remaining);
+        }
+        let refused = limiter.check_at(RouteGroup::Rolls, ip, start).unwrap();
+        assert!(!refused.allowed);
+        assert_eq!(refused.retry_after, Some(Duration::from_secs(1)));
+        assert_eq!(refused.reset_after, Duration::from_secs(3));
+
+        let mut headers = HeaderMap::new();
+        refused.apply_headers(&mut headers);
+        assert_eq!(headers["ratelimit-limit"], "3");
+        assert_eq!(headers["ratelimit-remaining"], "0");
+        assert_eq!(headers["retry-after"], "1");
+
+        // Other clients and other groups have buckets of their own
+        let other = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        assert!(
+            limiter
+                .check_at(RouteGroup::Rolls, other, start)
+                .unwrap()
+                .allowed
+        );
+        assert!(
+            limiter
+                .check_at(RouteGroup::Ratings, ip, start)
+                .unwrap()
+                .allowed
+        );
+
+        let later = start + Duration::from_secs(1);
+        assert!(
+            limiter
+                .check_at(RouteGroup::Rolls, ip, later)
+                .unwrap()
+                .allowed
+        );
+        assert!(
+            !limiter
+                .check_at(RouteGroup::Rolls, ip, later)
+                .unwrap()
+                .allowed
+        );
+    }
+
+    #[test]
+    fn full_buckets_are_evicted_and_unlimited_groups_are_untracked() {
+        let limiter = limiter(BucketPolicy {
+            capacity: 0,
+            refill_per_minute: 0,
+        });
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let start = Instant::now();
+
+        assert_eq!(limiter.check_at(RouteGroup::Rolls, ip, start), None);
+        limiter.check_at(RouteGroup::Ratings, ip, start).unwrap();
+        limiter.check_at(RouteGroup::Writes, ip, start).unwrap();
+        assert_eq!(limiter.tracked_clients(), 2);
+
+        assert_eq!(limiter.evict_idle_at(start), 0);
+        // Five a minute: one token is back after twelve seconds
+        assert_eq!(limiter.evict_idle_at(start + Duration::from_secs(12)), 2);
+        assert_eq!(limiter.tracked_clients(), 0);
+    }
+
+    #[test]
+    fn buckets_that_never_refill_are_rejected_and_do_not_overflow() {
+        let mut config = RateLimitConfig::default();
+        assert!(config.validate().is_ok());
+        config.writes = BucketPolicy {
+            capacity: 2,
+            refill_per_minute: 0,
+        };
+        assert!(config.validate().unwrap_err().contains("rate_limit.writes"));
+
+        // A key stored with a limit of 0 still gets such a bucket; its headers must not panic
+        let limiter = RateLimiter::default();
+        assert!(!limiter.check_api_key(7, 0).allowed);
+        let refused = limiter.check_api_key(7, 0);
+        let mut headers = HeaderMap::new();
+        refused.apply_headers(&mut headers);
+        assert_eq!(headers["retry-after"], u64::MAX.to_string().as_str());
+    }
+}
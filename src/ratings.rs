@@ -1,6 +1,6 @@
 use axum::extract::FromRef;
 use axum::{
-    extract::{ConnectInfo, Path, State},
+    extract::{Path, State},
     http::StatusCode,
     response::Json,
 };
@@ -9,10 +9,13 @@ use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::net::SocketAddr;
+use std::net::IpAddr;
 use std::sync::Arc;
 use tracing::{error, warn};
 
+use crate::audit::AuditAction;
+use crate::client_ip::ClientIp;
+use crate::locations::{LocationKind, is_live};
 use crate::{AppError, AppResult, AppState};
 
 #[derive(Clone)]
@@ -64,48 +67,70 @@ pub(crate) fn test_hash_ip(ip: &str, config: &crate::AppConfig) -> String {
     hash_ip(ip, config)
 }
 
-// Get rating statistics for a zone
-pub async fn get_zone_rating(
-    Path(zone_id): Path<i64>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
-    State(state): State<AppState>,
-) -> AppResult<Json<ZoneRatingStats>> {
-    let pool = &*state.zone_state.pool;
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstanceRatingStats {
+    pub instance_id: i64,
+    pub average_rating: f64,
+    pub total_ratings: i64,
+    pub user_rating: Option<u8>,
+}
 
-    // First, verify the zone exists
-    let zone_exists = sqlx::query("SELECT id FROM zones WHERE id = ?")
-        .bind(zone_id)
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| {
-            error!(
-                "Database error checking zone existence for zone {}: {}",
-                zone_id, e
-            );
-            AppError::Database(e)
-        })?;
+/// Average, count and the caller's own rating for one zone or instance
+struct RatingStats {
+    average_rating: f64,
+    total_ratings: i64,
+    user_rating: Option<u8>,
+}
 
-    if zone_exists.is_none() {
-        return Err(AppError::ZoneNotFound(zone_id));
+async fn ensure_live(pool: &SqlitePool, kind: LocationKind, id: i64) -> AppResult<()> {
+    let live = is_live(pool, kind, id).await.map_err(|e| {
+        error!(
+            "Database error checking {} existence for {} {}: {}",
+            kind.as_str(),
+            kind.as_str(),
+            id,
+            e
+        );
+        AppError::Database(e)
+    })?;
+
+    if live {
+        Ok(())
+    } else {
+        Err(kind.not_found(id))
     }
+}
 
-    // Get rating statistics
-    let stats = sqlx::query(
+async fn rating_stats(
+    state: &AppState,
+    kind: LocationKind,
+    id: i64,
+    client: IpAddr,
+) -> AppResult<RatingStats> {
+    let pool = &*state.zone_state.pool;
+    ensure_live(pool, kind, id).await?;
+
+    let table = kind.child_table("ratings");
+    let stats = sqlx::query(&format!(
         r#"
         SELECT
             COUNT(*) as total_ratings,
             AVG(CAST(rating AS FLOAT)) as average_rating
-        FROM zone_ratings
-        WHERE zone_id = ?
+        FROM {}
+        WHERE {} = ?
         "#,
-    )
-    .bind(zone_id)
+        table,
+        kind.id_column()
+    ))
+    .bind(id)
     .fetch_one(pool)
     .await
     .map_err(|e| {
         error!(
-            "Database error getting rating stats for zone {}: {}",
-            zone_id, e
+            "Database error getting rating stats for {} {}: {}",
+            kind.as_str(),
+            id,
+            e
         );
         AppError::Database(e)
     })?;
@@ -113,105 +138,162 @@ pub async fn get_zone_rating(
     let total_ratings: i64 = stats.get("total_ratings");
     let average_rating: Option<f64> = stats.get("average_rating");
 
-    let user_ip = addr.ip().to_string();
+    let user_ip = client.to_string();
     let hashed_ip = hash_ip(&user_ip, &state.config);
-    let user_rating =
-        sqlx::query("SELECT rating FROM zone_ratings WHERE zone_id = ? AND user_ip = ?")
-            .bind(zone_id)
-            .bind(&hashed_ip)
-            .fetch_optional(pool)
-            .await
-            .map_err(|e| {
-                error!(
-                    "Database error getting user rating for zone {}: {}",
-                    zone_id, e
-                );
-                AppError::Database(e)
-            })?
-            .map(|row| row.get::<i32, _>("rating") as u8);
+    let user_rating = sqlx::query(&format!(
+        "SELECT rating FROM {} WHERE {} = ? AND user_ip = ?",
+        table,
+        kind.id_column()
+    ))
+    .bind(id)
+    .bind(&hashed_ip)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!(
+            "Database error getting user rating for {} {}: {}",
+            kind.as_str(),
+            id,
+            e
+        );
+        AppError::Database(e)
+    })?
+    .map(|row| row.get::<i32, _>("rating") as u8);
 
-    Ok(Json(ZoneRatingStats {
-        zone_id,
+    Ok(RatingStats {
         average_rating: average_rating.unwrap_or(0.0),
         total_ratings,
         user_rating,
-    }))
+    })
 }
 
-// Submit or update a rating for a zone
-pub async fn submit_zone_rating(
-    Path(zone_id): Path<i64>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
-    State(state): State<AppState>,
-    Json(payload): Json<SubmitRatingRequest>,
-) -> AppResult<Json<ZoneRatingStats>> {
+async fn upsert_rating(
+    state: &AppState,
+    kind: LocationKind,
+    id: i64,
+    client: IpAddr,
+    rating: u8,
+) -> AppResult<()> {
     let pool = &*state.zone_state.pool;
 
     // Validate rating (using hardcoded values for now, will be configurable later)
-    if payload.rating < 1 || payload.rating > 5 {
-        return Err(AppError::InvalidRating(payload.rating, 1, 5));
+    if !(1..=5).contains(&rating) {
+        return Err(AppError::InvalidRating(rating, 1, 5));
     }
 
-    let user_ip = addr.ip().to_string();
+    let user_ip = client.to_string();
     let hashed_ip = hash_ip(&user_ip, &state.config);
 
-    // First, verify the zone exists
-    let zone_exists = sqlx::query("SELECT id FROM zones WHERE id = ?")
-        .bind(zone_id)
-        .fetch_optional(pool)
+    ensure_live(pool, kind, id).await?;
+
+    // Insert or update the rating. The same statement, with values inlined, goes to the log.
+    let upsert = |id: &str, user_ip: &str, rating: &str| {
+        format!(
+            "INSERT INTO {table} ({column}, user_ip, rating, updated_at) VALUES ({id}, {user_ip}, {rating}, CURRENT_TIMESTAMP) ON CONFLICT({column}, user_ip) DO UPDATE SET rating = excluded.rating, updated_at = CURRENT_TIMESTAMP",
+            table = kind.child_table("ratings"),
+            column = kind.id_column(),
+        )
+    };
+    sqlx::query(&upsert("?", "?", "?"))
+        .bind(id)
+        .bind(&hashed_ip)
+        .bind(rating as i32)
+        .execute(pool)
         .await
         .map_err(|e| {
             error!(
-                "Database error checking zone existence for rating submission on zone {}: {}",
-                zone_id, e
+                "Database error submitting rating for {} {}: {}",
+                kind.as_str(),
+                id,
+                e
             );
             AppError::Database(e)
         })?;
 
-    if zone_exists.is_none() {
-        return Err(AppError::ZoneNotFound(zone_id));
-    }
-
-    // Insert or update the rating
-    sqlx::query(
-        r#"
-        INSERT INTO zone_ratings (zone_id, user_ip, rating, updated_at)
-        VALUES (?, ?, ?, CURRENT_TIMESTAMP)
-        ON CONFLICT(zone_id, user_ip)
-        DO UPDATE SET
-            rating = excluded.rating,
-            updated_at = CURRENT_TIMESTAMP
-        "#,
-    )
-    .bind(zone_id)
-    .bind(&hashed_ip)
-    .bind(payload.rating as i32)
-    .execute(pool)
-    .await
-    .map_err(|e| {
-        error!(
-            "Database error submitting rating for zone {}: {}",
-            zone_id, e
-        );
-        AppError::Database(e)
-    })?;
-
     // Write SQL transaction to log file (only logs hashed IP)
     let sql_statement = format!(
-        "INSERT INTO zone_ratings (zone_id, user_ip, rating, updated_at) VALUES ({}, '{}', {}, CURRENT_TIMESTAMP) ON CONFLICT(zone_id, user_ip) DO UPDATE SET rating = excluded.rating, updated_at = CURRENT_TIMESTAMP; -- {}\n",
-        zone_id,
-        hashed_ip.replace("'", "''"), // Escape single quotes
-        payload.rating,
+        "{}; -- {}\n",
+        upsert(
+            &id.to_string(),
+            &format!("'{}'", hashed_ip.replace("'", "''")), // Escape single quotes
+            &rating.to_string()
+        ),
         chrono::Utc::now().to_rfc3339()
     );
 
-    if let Err(e) = write_to_transaction_log(&sql_statement) {
+    if let Err(e) =
+        write_to_transaction_log(&state.config.ratings.transaction_log_path, &sql_statement)
+    {
         tracing::warn!(error = %e, "Warning: Failed to write to transaction log");
         // Don't fail the request if logging fails
     }
 
+    Ok(())
+}
+
+// Get rating statistics for a zone
+pub async fn get_zone_rating(
+    Path(zone_id): Path<i64>,
+    ClientIp(client): ClientIp,
+    State(state): State<AppState>,
+) -> AppResult<Json<ZoneRatingStats>> {
+    let stats = rating_stats(&state, LocationKind::Zone, zone_id, client).await?;
+
+    Ok(Json(ZoneRatingStats {
+        zone_id,
+        average_rating: stats.average_rating,
+        total_ratings: stats.total_ratings,
+        user_rating: stats.user_rating,
+    }))
+}
+
+// Submit or update a rating for a zone
+pub async fn submit_zone_rating(
+    Path(zone_id): Path<i64>,
+    ClientIp(client): ClientIp,
+    State(state): State<AppState>,
+    Json(payload): Json<SubmitRatingRequest>,
+) -> AppResult<Json<ZoneRatingStats>> {
+    upsert_rating(&state, LocationKind::Zone, zone_id, client, payload.rating).await?;
+
+    // Return updated statistics
+    get_zone_rating(Path(zone_id), ClientIp(client), State(state)).await
+}
+
+// Get rating statistics for an instance
+pub async fn get_instance_rating(
+    Path(instance_id): Path<i64>,
+    ClientIp(client): ClientIp,
+    State(state): State<AppState>,
+) -> AppResult<Json<InstanceRatingStats>> {
+    let stats = rating_stats(&state, LocationKind::Instance, instance_id, client).await?;
+
+    Ok(Json(InstanceRatingStats {
+        instance_id,
+        average_rating: stats.average_rating,
+        total_ratings: stats.total_ratings,
+        user_rating: stats.user_rating,
+    }))
+}
+
+// Submit or update a rating for an instance
+pub async fn submit_instance_rating(
+    Path(instance_id): Path<i64>,
+    ClientIp(client): ClientIp,
+    State(state): State<AppState>,
+    Json(payload): Json<SubmitRatingRequest>,
+) -> AppResult<Json<InstanceRatingStats>> {
+    upsert_rating(
+        &state,
+        LocationKind::Instance,
+        instance_id,
+        client,
+        payload.rating,
+    )
+    .await?;
+
     // Return updated statistics
-    get_zone_rating(Path(zone_id), ConnectInfo(addr), State(state)).await
+    get_instance_rating(Path(instance_id), ClientIp(client), State(state)).await
 }
 
 // Get all ratings for a zone (admin/debug endpoint)
@@ -220,6 +302,7 @@ pub async fn get_zone_ratings(
     State(state): State<AppState>,
 ) -> AppResult<Json<Vec<ZoneRating>>> {
     let pool = &*state.zone_state.pool;
+    ensure_live(pool, LocationKind::Zone, zone_id).await?;
 
     let rows = sqlx::query(
         r#"
@@ -255,70 +338,122 @@ pub async fn get_zone_ratings(
     Ok(Json(ratings))
 }
 
-// Delete a rating by ID (admin/API endpoint)
-pub async fn delete_rating(
-    Path(id): Path<i32>,
-    State(state): State<AppState>,
-) -> AppResult<StatusCode> {
+/// Delete one zone or instance rating by id, recording it in the audit log and, like
+/// a submission, in the transaction log so a restore replays it. Returns false when
+/// there is no such rating.
+pub async fn delete_location_rating(
+    state: &AppState,
+    kind: LocationKind,
+    id: i64,
+) -> AppResult<bool> {
     let pool = &*state.zone_state.pool;
-
-    // First, get the rating details before deletion for transaction logging
-    let rating_details =
-        sqlx::query("SELECT zone_id, user_ip, rating FROM zone_ratings WHERE id = ?")
-            .bind(id)
-            .fetch_optional(pool)
-            .await
-            .map_err(|e| {
-                error!(
-                    "Database error getting rating details for deletion (id {}): {}",
-                    id, e
-                );
-                AppError::Database(e)
-            })?;
-
-    if let Some(details) = rating_details {
-        let zone_id = details.get::<i64, _>("zone_id");
-        let user_ip = details.get::<String, _>("user_ip");
-        let _old_rating = details.get::<i32, _>("rating") as u8;
-
-        // Delete the rating
-        let _ = sqlx::query("DELETE FROM zone_ratings WHERE id = ?")
-            .bind(id)
-            .execute(pool)
-            .await
-            .map_err(|e| {
-                error!("Database error deleting rating (id {}): {}", id, e);
-                AppError::Database(e)
-            })?;
-
-        // Write SQL transaction to log file
-        let sql_statement = format!(
-            "DELETE FROM zone_ratings WHERE zone_id = {} AND user_ip = '{}'; -- {} - Deleted via admin\n",
-            zone_id,
-            user_ip.replace("'", "''"), // Escape single quotes
-            chrono::Utc::now().to_rfc3339()
+    let table = kind.child_table("ratings");
+    let column = kind.id_column();
+
+    let mut tx = pool.begin().await?;
+
+    // Get the rating details before deletion for transaction logging
+    let details = sqlx::query(&format!(
+        "SELECT {} AS location_id, user_ip FROM {} WHERE id = ?",
+        column, table
+    ))
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        error!(
+            "Database error getting {} rating details for deletion (id {}): {}",
+            kind.as_str(),
+            id,
+            e
         );
+        AppError::Database(e)
+    })?;
+    let Some(details) = details else {
+        return Ok(false);
+    };
+    let location_id = details.get::<i64, _>("location_id");
+    let user_ip = details.get::<String, _>("user_ip");
 
-        if let Err(e) = write_to_transaction_log(&sql_statement) {
-            warn!("Warning: Failed to write to transaction log: {}", e);
-            // Don't fail the request if logging fails
-        }
+    let before = crate::audit::capture(&mut tx, kind.rating_entity(), id).await;
+    sqlx::query(&format!("DELETE FROM {} WHERE id = ?", table))
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!(
+                "Database error deleting {} rating (id {}): {}",
+                kind.as_str(),
+                id,
+                e
+            );
+            AppError::Database(e)
+        })?;
+    crate::audit::record_change(
+        &mut tx,
+        AuditAction::Delete,
+        kind.rating_entity(),
+        id,
+        before,
+        None,
+    )
+    .await?;
+    tx.commit().await?;
+
+    // Write SQL transaction to log file
+    let sql_statement = format!(
+        "DELETE FROM {} WHERE {} = {} AND user_ip = '{}'; -- {} - Deleted via admin\n",
+        table,
+        column,
+        location_id,
+        user_ip.replace("'", "''"), // Escape single quotes
+        chrono::Utc::now().to_rfc3339()
+    );
 
-        // Force WAL checkpoint to immediately update main database file
-        let _ = crate::checkpoint_wal(pool).await;
+    if let Err(e) =
+        write_to_transaction_log(&state.config.ratings.transaction_log_path, &sql_statement)
+    {
+        warn!("Warning: Failed to write to transaction log: {}", e);
+        // Don't fail the request if logging fails
+    }
 
+    // Force WAL checkpoint to immediately update main database file
+    let _ = crate::checkpoint_wal(pool).await;
+
+    Ok(true)
+}
+
+// Delete a zone rating by ID (admin API endpoint, kept from before instance ratings)
+pub async fn delete_rating(
+    Path(id): Path<i64>,
+    State(state): State<AppState>,
+) -> AppResult<StatusCode> {
+    delete_kind_rating(Path((LocationKind::Zone, id)), State(state)).await
+}
+
+// Delete a zone or instance rating by ID (admin API endpoint)
+pub async fn delete_kind_rating(
+    Path((kind, id)): Path<(LocationKind, i64)>,
+    State(state): State<AppState>,
+) -> AppResult<StatusCode> {
+    if delete_location_rating(&state, kind, id).await? {
         Ok(StatusCode::OK)
     } else {
-        Err(AppError::RatingNotFound(id as i64))
+        Err(AppError::RatingNotFound(id))
     }
 }
 
-// Helper function to write SQL statements to transaction log file
-fn write_to_transaction_log(sql_statement: &str) -> std::io::Result<()> {
+// Helper function to write SQL statements to transaction log file.
+// An empty path disables the log. The restore tool replays this file, so keep the line format stable.
+fn write_to_transaction_log(log_path: &str, sql_statement: &str) -> std::io::Result<()> {
+    if log_path.is_empty() {
+        return Ok(());
+    }
+
     let mut file = OpenOptions::new()
         .create(true)
         .append(true)
-        .open("data/rating_transaction.log")?;
+        .open(log_path)?;
 
     file.write_all(sql_statement.as_bytes())?;
     file.flush()?;
@@ -337,11 +472,11 @@ mod tests {
     use crate::zones::ZoneState;
     use crate::{AppError, AppState};
     use axum::Json;
-    use axum::extract::{ConnectInfo, Path, State};
+    use axum::extract::{Path, State};
     use sqlx::sqlite::SqlitePoolOptions;
     use sqlx::{Row, SqlitePool};
     use std::collections::HashMap;
-    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::net::{IpAddr, Ipv4Addr};
     use std::sync::Arc;
 
     fn test_config_with_key(key: &str) -> AppConfig {
@@ -355,9 +490,15 @@ mod tests {
                 backup_dir: "".to_string(),
                 migrate_on_startup: false,
             },
+            backup: Default::default(),
+            trash: Default::default(),
+            auth: Default::default(),
+            rate_limit: Default::default(),
+            proxy: Default::default(),
             security: SecurityConfig {
                 rating_ip_hash_key: key.to_string(),
                 min_ip_hash_key_length: 0,
+                headers: Default::default(),
             },
             ratings: RatingsConfig {
                 min_rating: 1,
@@ -393,7 +534,7 @@ mod tests {
             .await
             .unwrap();
 
-        sqlx::query("CREATE TABLE zones (id INTEGER PRIMARY KEY, name TEXT)")
+        sqlx::query("CREATE TABLE zones (id INTEGER PRIMARY KEY, name TEXT, deleted_at DATETIME)")
             .execute(&pool)
             .await
             .unwrap();
@@ -430,6 +571,8 @@ mod tests {
             class_race_state: ClassRaceState {
                 class_race_map: Arc::new(HashMap::new()),
             },
+            snapshot: Default::default(),
+            rate_limiter: Default::default(),
         };
         (state, pool_arc)
     }
@@ -450,10 +593,10 @@ mod tests {
     #[tokio::test]
     async fn submit_zone_rating_rejects_out_of_range() {
         let (state, _) = setup_state().await;
-        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+        let client = ClientIp(IpAddr::V4(Ipv4Addr::LOCALHOST));
         let result = submit_zone_rating(
             Path(1),
-            ConnectInfo(addr),
+            client,
             State(state),
             Json(SubmitRatingRequest { rating: 0 }),
         )
@@ -464,11 +607,11 @@ mod tests {
     #[tokio::test]
     async fn submit_zone_rating_accepts_valid_rating() {
         let (state, pool) = setup_state().await;
-        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+        let client = ClientIp(IpAddr::V4(Ipv4Addr::LOCALHOST));
         let rating = 5;
         let result = submit_zone_rating(
             Path(1),
-            ConnectInfo(addr),
+            client,
             State(state),
             Json(SubmitRatingRequest { rating }),
         )
@@ -489,4 +632,78 @@ mod tests {
         let stored: i64 = row.get("rating");
         assert_eq!(stored as u8, rating);
     }
+
+    #[tokio::test]
+    async fn trashed_zones_hide_their_ratings() {
+        let (state, pool) = setup_state().await;
+        let client = ClientIp(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let stats = submit_zone_rating(
+            Path(1),
+            client,
+            State(state.clone()),
+            Json(SubmitRatingRequest { rating: 4 }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(stats.0.total_ratings, 1);
+        let listed = get_zone_ratings(Path(1), State(state.clone()))
+            .await
+            .unwrap();
+        assert_eq!(listed.0.len(), 1);
+
+        sqlx::query("UPDATE zones SET deleted_at = CURRENT_TIMESTAMP WHERE id = 1")
+            .execute(&*pool)
+            .await
+            .unwrap();
+        let listed = get_zone_ratings(Path(1), State(state.clone())).await;
+        assert!(matches!(listed, Err(AppError::ZoneNotFound(1))));
+        let stats = get_zone_rating(Path(1), client, State(state)).await;
+        assert!(matches!(stats, Err(AppError::ZoneNotFound(1))));
+    }
+
+    #[tokio::test]
+    async fn instance_ratings_are_kept_apart_from_zone_ratings() {
+        let (state, pool) = setup_state().await;
+        for statement in [
+            "CREATE TABLE instances (id INTEGER PRIMARY KEY, name TEXT, deleted_at DATETIME)",
+            "CREATE TABLE instance_ratings (id INTEGER PRIMARY KEY AUTOINCREMENT, instance_id INTEGER NOT NULL, user_ip TEXT NOT NULL, rating INTEGER NOT NULL, created_at TEXT DEFAULT CURRENT_TIMESTAMP, updated_at TEXT DEFAULT CURRENT_TIMESTAMP, UNIQUE(instance_id, user_ip))",
+            "INSERT INTO instances (id, name) VALUES (1, 'Test Instance')",
+        ] {
+            sqlx::query(statement).execute(&*pool).await.unwrap();
+        }
+        let client = ClientIp(IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+        let Json(stats) = submit_instance_rating(
+            Path(1),
+            client,
+            State(state.clone()),
+            Json(SubmitRatingRequest { rating: 4 }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(stats.instance_id, 1);
+        assert_eq!(stats.total_ratings, 1);
+        assert_eq!(stats.user_rating, Some(4));
+
+        let Json(zone_stats) = get_zone_rating(Path(1), client, State(state.clone()))
+            .await
+            .unwrap();
+        assert_eq!(zone_stats.total_ratings, 0);
+
+        let missing = get_instance_rating(Path(2), client, State(state.clone())).await;
+        assert!(matches!(missing, Err(AppError::InstanceNotFound(2))));
+
+        // Deleting goes through the instance table too, never the zone one
+        crate::audit::create_audit_log_table(&pool).await.unwrap();
+        let wrong_kind = delete_rating(Path(1), State(state.clone())).await;
+        assert!(matches!(wrong_kind, Err(AppError::RatingNotFound(1))));
+        let deleted = delete_kind_rating(Path((LocationKind::Instance, 1)), State(state.clone()))
+            .await
+            .unwrap();
+        assert_eq!(deleted, StatusCode::OK);
+        let Json(stats) = get_instance_rating(Path(1), client, State(state))
+            .await
+            .unwrap();
+        assert_eq!(stats.total_ratings, 0);
+    }
 }
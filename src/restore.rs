@@ -0,0 +1,419 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::backup::{BackupInfo, list_backups, verify_backup};
+use crate::error::{AppError, AppResult};
+
+const STAGING_SUFFIX: &str = ".restore-staging";
+const PREVIOUS_SUFFIX: &str = ".pre-restore";
+
+// Only rating writes are logged, so nothing else is ever replayed from the log
+const REPLAYABLE_PREFIXES: &[&str] = &[
+    "INSERT INTO zone_ratings ",
+    "DELETE FROM zone_ratings ",
+    "INSERT INTO instance_ratings ",
+    "DELETE FROM instance_ratings ",
+];
+
+/// A single statement from the rating transaction log
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub line: usize,
+    pub timestamp: DateTime<Utc>,
+    pub statement: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RestoreOptions {
+    pub backup_dir: String,
+    pub database_path: String,
+    /// Transaction log to replay on top of the snapshot; None restores the snapshot as-is
+    pub transaction_log_path: Option<String>,
+    /// Backup file name to restore; defaults to the newest one at or before `target_time`
+    pub backup: Option<String>,
+    /// Point in time to restore to; defaults to the end of the transaction log
+    pub target_time: Option<DateTime<Utc>>,
+    /// Build and check the staging database but leave the live database untouched
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreReport {
+    pub backup: BackupInfo,
+    pub target_time: Option<DateTime<Utc>>,
+    pub replayed: usize,
+    pub database_path: String,
+    pub previous_database: Option<String>,
+    pub swapped: bool,
+}
+
+/// Parse transaction log lines of the form `<statement>; -- <rfc3339>[ - note]`.
+/// Blank lines are ignored; anything else that is not a rating statement is an error.
+pub fn parse_transaction_log(content: &str) -> AppResult<Vec<LogEntry>> {
+    let mut entries = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let malformed =
+            |reason: &str| AppError::Validation(format!("Log line {}: {}", line_number, reason));
+
+        let (statement, comment) = line
+            .split_once("; -- ")
+            .ok_or_else(|| malformed("missing timestamp comment"))?;
+        let stamp = comment.split(' ').next().unwrap_or_default();
+        let timestamp = DateTime::parse_from_rfc3339(stamp)
+            .map_err(|_| malformed("invalid timestamp"))?
+            .with_timezone(&Utc);
+
+        if !REPLAYABLE_PREFIXES.iter().any(|p| statement.starts_with(p)) {
+            return Err(malformed("not a rating statement"));
+        }
+
+        entries.push(LogEntry {
+            line: line_number,
+            timestamp,
+            statement: statement.to_string(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Pick the backup to restore: a named one, or the newest taken at or before `target_time`
+pub fn select_backup(
+    backups: &[BackupInfo],
+    name: Option<&str>,
+    target_time: Option<DateTime<Utc>>,
+) -> AppResult<BackupInfo> {
+    let backup = match name {
+        Some(name) => backups
+            .iter()
+            .find(|b| b.file_name == name)
+            .ok_or_else(|| AppError::Validation(format!("Backup not found: {}", name)))?,
+        None => backups
+            .iter()
+            .find(|b| target_time.is_none_or(|t| b.created_at <= t))
+            .ok_or_else(|| {
+                AppError::Validation("No backup taken at or before the target time".to_string())
+            })?,
+    };
+
+    if let Some(target) = target_time
+        && backup.created_at > target
+    {
+        return Err(AppError::Validation(format!(
+            "Backup {} was taken after the target time {}",
+            backup.file_name,
+            target.to_rfc3339()
+        )));
+    }
+
+    Ok(backup.clone())
+}
+
+async fn open_staging(path: &Path) -> AppResult<SqlitePool> {
+    // Rollback journal keeps the staging database in a single file for the final rename
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .journal_mode(SqliteJournalMode::Delete);
+
+    Ok(SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await?)
+}
+
+/// Replay entries after the snapshot (and up to the target time) in one transaction.
+/// Rating statements are upserts and keyed deletes, so replaying an entry the snapshot
+/// already contains is harmless.
+async fn replay(
+    pool: &SqlitePool,
+    entries: &[LogEntry],
+    after: DateTime<Utc>,
+    until: Option<DateTime<Utc>>,
+) -> AppResult<usize> {
+    let mut tx = pool.begin().await?;
+    let mut replayed = 0;
+
+    for entry in entries
+        .iter()
+        .filter(|e| e.timestamp > after && until.is_none_or(|t| e.timestamp <= t))
+    {
+        sqlx::query(&entry.statement)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::Validation(format!("Replay failed at log line {}: {}", entry.line, e))
+            })?;
+        replayed += 1;
+    }
+
+    tx.commit().await?;
+    Ok(replayed)
+}
+
+async fn check_integrity(pool: &SqlitePool) -> AppResult<()> {
+    let results: Vec<String> = sqlx::query_scalar("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await?;
+    if results != ["ok"] {
+        return Err(AppError::Validation(format!(
+            "Integrity check failed: {}",
+            results.join("; ")
+        )));
+    }
+
+    let violations = sqlx::query("PRAGMA foreign_key_check")
+        .fetch_all(pool)
+        .await?;
+    if !violations.is_empty() {
+        return Err(AppError::Validation(format!(
+            "Foreign key check failed with {} violation(s)",
+            violations.len()
+        )));
+    }
+
+    Ok(())
+}
+
+fn remove_if_exists(path: &str) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Swap the staging file into place. The current database is kept as `<path>.pre-restore`
+/// (hard-linked, so the live path never goes missing) and its WAL files are moved beside it,
+/// otherwise SQLite would apply the old WAL to the restored file.
+fn swap_into_place(staging: &str, database_path: &str) -> io::Result<Option<String>> {
+    let previous = format!("{}{}", database_path, PREVIOUS_SUFFIX);
+    let has_previous = Path::new(database_path).exists();
+
+    if has_previous {
+        remove_if_exists(&previous)?;
+        if fs::hard_link(database_path, &previous).is_err() {
+            fs::copy(database_path, &previous)?;
+        }
+    }
+
+    for suffix in ["-wal", "-shm"] {
+        let live = format!("{}{}", database_path, suffix);
+        let kept = format!("{}{}", previous, suffix);
+        remove_if_exists(&kept)?;
+        if Path::new(&live).exists() {
+            fs::rename(&live, &kept)?;
+        }
+    }
+
+    fs::rename(staging, database_path)?;
+    Ok(has_previous.then_some(previous))
+}
+
+/// Restore `database_path` from a verified backup plus the rating transaction log.
+/// The server must be stopped while this runs; the live file is only replaced after the
+/// staging copy passes SQLite's integrity and foreign key checks.
+pub async fn restore_database(options: &RestoreOptions) -> AppResult<RestoreReport> {
+    let backups = list_backups(&options.backup_dir)?;
+    let backup = select_backup(&backups, options.backup.as_deref(), options.target_time)?;
+    verify_backup(&backup)?;
+
+    let entries = match &options.transaction_log_path {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(content) => parse_transaction_log(&content)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        },
+        None => Vec::new(),
+    };
+
+    let staging = format!("{}{}", options.database_path, STAGING_SUFFIX);
+    remove_if_exists(&staging)?;
+    fs::copy(&backup.path, &staging)?;
+
+    let result = async {
+        let pool = open_staging(Path::new(&staging)).await?;
+        let outcome = async {
+            let replayed = replay(&pool, &entries, backup.created_at, options.target_time).await?;
+            check_integrity(&pool).await?;
+            Ok::<_, AppError>(replayed)
+        }
+        .await;
+        pool.close().await;
+        outcome
+    }
+    .await;
+
+    let replayed = match result {
+        Ok(replayed) => replayed,
+        Err(e) => {
+            let _ = remove_if_exists(&staging);
+            return Err(e);
+        }
+    };
+
+    let (previous_database, swapped) = if options.dry_run {
+        remove_if_exists(&staging)?;
+        (None, false)
+    } else {
+        (swap_into_place(&staging, &options.database_path)?, true)
+    };
+
+    tracing::info!(
+        backup = %backup.file_name,
+        replayed,
+        swapped,
+        "Database restore finished"
+    );
+
+    Ok(RestoreReport {
+        backup,
+        target_time: options.target_time,
+        replayed,
+        database_path: options.database_path.clone(),
+        previous_database,
+        swapped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::create_backup;
+    use chrono::Duration;
+
+    async fn open_live(path: &Path) -> SqlitePool {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        pool
+    }
+
+    fn log_line(statement: &str, at: DateTime<Utc>) -> String {
+        format!("{}; -- {}\n", statement, at.to_rfc3339())
+    }
+
+    fn upsert(rating: u8) -> String {
+        format!(
+            "INSERT INTO zone_ratings (zone_id, user_ip, rating, updated_at) VALUES (1, 'abc', {}, CURRENT_TIMESTAMP) ON CONFLICT(zone_id, user_ip) DO UPDATE SET rating = excluded.rating, updated_at = CURRENT_TIMESTAMP",
+            rating
+        )
+    }
+
+    #[test]
+    fn log_parsing_accepts_rating_statements_only() {
+        let now = Utc::now();
+        let content = format!(
+            "{}\n{}; -- {} - Deleted via admin\n",
+            log_line(&upsert(4), now),
+            "DELETE FROM zone_ratings WHERE zone_id = 1 AND user_ip = 'abc'",
+            now.to_rfc3339()
+        );
+        let entries = parse_transaction_log(&content).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].line, 3);
+        assert!(entries[1].statement.starts_with("DELETE"));
+
+        let instance_delete = format!(
+            "DELETE FROM instance_ratings WHERE instance_id = 2 AND user_ip = 'abc'; -- {} - Deleted via admin\n",
+            now.to_rfc3339()
+        );
+        assert_eq!(parse_transaction_log(&instance_delete).unwrap().len(), 1);
+
+        assert!(parse_transaction_log(&log_line("DROP TABLE zones", now)).is_err());
+        assert!(parse_transaction_log(&format!("{};\n", upsert(1))).is_err());
+    }
+
+    #[tokio::test]
+    async fn restores_snapshot_and_replays_later_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("zones.db");
+        let backup_dir = dir.path().join("backups");
+        let log_path = dir.path().join("rating_transaction.log");
+
+        let live = open_live(&db_path).await;
+        sqlx::query(
+            "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (1, 'Befallen', '[[7,25]]', 'Classic', 'Indoor')",
+        )
+        .execute(&live)
+        .await
+        .unwrap();
+        let backup = create_backup(&live, backup_dir.to_str().unwrap())
+            .await
+            .unwrap();
+        live.close().await;
+
+        // One entry predates the snapshot and must not be replayed over it
+        let log = [
+            log_line(&upsert(1), backup.created_at - Duration::minutes(5)),
+            log_line(&upsert(3), backup.created_at + Duration::minutes(1)),
+            log_line(&upsert(5), backup.created_at + Duration::minutes(10)),
+        ]
+        .concat();
+        fs::write(&log_path, log).unwrap();
+
+        let options = RestoreOptions {
+            backup_dir: backup_dir.to_string_lossy().to_string(),
+            database_path: db_path.to_string_lossy().to_string(),
+            transaction_log_path: Some(log_path.to_string_lossy().to_string()),
+            target_time: Some(backup.created_at + Duration::minutes(5)),
+            ..Default::default()
+        };
+
+        let report = restore_database(&options).await.unwrap();
+        assert_eq!(report.replayed, 1);
+        assert!(report.swapped);
+        assert!(!Path::new(&format!("{}{}", options.database_path, STAGING_SUFFIX)).exists());
+        assert!(report.previous_database.is_some());
+
+        let restored = open_live(&db_path).await;
+        let rating: i64 = sqlx::query_scalar("SELECT rating FROM zone_ratings WHERE zone_id = 1")
+            .fetch_one(&restored)
+            .await
+            .unwrap();
+        assert_eq!(rating, 3);
+    }
+
+    #[tokio::test]
+    async fn tampered_backup_is_rejected_before_touching_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("zones.db");
+        let backup_dir = dir.path().join("backups");
+
+        let live = open_live(&db_path).await;
+        let backup = create_backup(&live, backup_dir.to_str().unwrap())
+            .await
+            .unwrap();
+        live.close().await;
+
+        let mut bytes = fs::read(&backup.path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        fs::write(&backup.path, bytes).unwrap();
+        let before = fs::read(&db_path).unwrap();
+
+        let options = RestoreOptions {
+            backup_dir: backup_dir.to_string_lossy().to_string(),
+            database_path: db_path.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let err = restore_database(&options).await.unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"));
+        assert_eq!(fs::read(&db_path).unwrap(), before);
+    }
+}
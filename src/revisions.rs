@@ -0,0 +1,404 @@
+use serde_json::Value;
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
+
+use crate::audit::{self, AuditAction, AuditChange, AuditEntity, AuditEntry};
+use crate::error::{AppError, AppResult};
+
+/// Bind a JSON value from a row image as the matching SQLite type
+fn push_value(builder: &mut QueryBuilder<'_, Sqlite>, value: &Value) {
+    match value {
+        Value::Null => builder.push_bind(None::<String>),
+        Value::Bool(b) => builder.push_bind(*b),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => builder.push_bind(i),
+            None => builder.push_bind(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => builder.push_bind(s.clone()),
+        other => builder.push_bind(other.to_string()),
+    };
+}
+
+/// Columns of the row image that still exist in the table, with their values
+async fn image_columns<'a>(
+    conn: &mut SqliteConnection,
+    entity: AuditEntity,
+    image: &'a Value,
+) -> AppResult<Vec<(String, &'a Value)>> {
+    let image = image
+        .as_object()
+        .ok_or_else(|| AppError::Validation("Stored row image is not an object".to_string()))?;
+
+    Ok(audit::table_columns(conn, entity)
+        .await?
+        .into_iter()
+        .filter_map(|column| image.get(&column).map(|value| (column, value)))
+        .collect())
+}
+
+/// Refuse to undo an entry when the row has moved on since it was recorded, naming
+/// the later entries that changed it so they can be reverted first
+async fn ensure_unchanged(
+    conn: &mut SqliteConnection,
+    entry: &AuditEntry,
+    current: &Value,
+    label: &str,
+) -> AppResult<()> {
+    let Some(after) = entry.after.as_ref().and_then(Value::as_object) else {
+        return Ok(());
+    };
+    // Columns added or dropped since the entry was written don't count as changes
+    let changed = after
+        .iter()
+        .any(|(column, value)| current.get(column).is_some_and(|now| now != value));
+    if !changed {
+        return Ok(());
+    }
+
+    let later = audit::later_entries(conn, entry).await?;
+    let listed = if later.is_empty() {
+        "changes made outside the audit log".to_string()
+    } else {
+        later
+            .iter()
+            .map(|e| format!("#{} {} by {} at {}", e.id, e.action, e.actor, e.created_at))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    Err(AppError::Validation(format!(
+        "{} has changed since entry {}; revert the later entries first: {}",
+        label, entry.id, listed
+    )))
+}
+
+/// Undo one audit entry inside an open transaction, returning the change made
+async fn undo_entry(conn: &mut SqliteConnection, entry: &AuditEntry) -> AppResult<AuditChange> {
+    let entity = AuditEntity::parse(&entry.entity_type).ok_or_else(|| {
+        AppError::Validation(format!("Unknown entity type {}", entry.entity_type))
+    })?;
+    let action = match entry.action.as_str() {
+        "create" => AuditAction::Create,
+        "update" => AuditAction::Update,
+        _ => AuditAction::Delete,
+    };
+    let current = audit::fetch_row_json_on(&mut *conn, entity, entry.entity_id).await?;
+    let label = format!("{} #{}", entity.as_str(), entry.entity_id);
+
+    match action {
+        AuditAction::Create => {
+            let Some(current) = current else {
+                return Err(AppError::Validation(format!(
+                    "{} no longer exists, so its creation cannot be undone",
+                    label
+                )));
+            };
+            ensure_unchanged(&mut *conn, entry, &current, &label).await?;
+            sqlx::query(&format!("DELETE FROM {} WHERE id = ?", entity.table()))
+                .bind(entry.entity_id)
+                .execute(&mut *conn)
+                .await?;
+
+            Ok(AuditChange {
+                action: AuditAction::Delete,
+                entity,
+                entity_id: entry.entity_id,
+                before: Some(current),
+                after: None,
+            })
+        }
+        AuditAction::Update => {
+            let Some(current) = current else {
+                return Err(AppError::Validation(format!(
+                    "{} no longer exists, so this edit cannot be undone",
+                    label
+                )));
+            };
+            ensure_unchanged(&mut *conn, entry, &current, &label).await?;
+            let before = entry.before.as_ref().ok_or_else(|| {
+                AppError::Validation(format!("No earlier revision of {} was recorded", label))
+            })?;
+
+            let columns = image_columns(&mut *conn, entity, before).await?;
+            let mut builder = QueryBuilder::new(format!("UPDATE {} SET ", entity.table()));
+            let mut separated = false;
+            for (column, value) in columns.iter().filter(|(column, _)| column != "id") {
+                if separated {
+                    builder.push(", ");
+                }
+                builder.push(format!("\"{}\" = ", column));
+                push_value(&mut builder, value);
+                separated = true;
+            }
+            builder.push(" WHERE id = ").push_bind(entry.entity_id);
+            if separated {
+                builder.build().execute(&mut *conn).await?;
+            }
+
+            let restored = audit::fetch_row_json_on(&mut *conn, entity, entry.entity_id).await?;
+            Ok(AuditChange {
+                action: AuditAction::Update,
+                entity,
+                entity_id: entry.entity_id,
+                before: Some(current),
+                after: restored,
+            })
+        }
+        AuditAction::Delete => {
+            if current.is_some() {
+                return Err(AppError::Validation(format!(
+                    "{} already exists again, so its deletion cannot be undone",
+                    label
+                )));
+            }
+            let before = entry.before.as_ref().ok_or_else(|| {
+                AppError::Validation(format!("No copy of deleted {} was recorded", label))
+            })?;
+
+            let columns = image_columns(&mut *conn, entity, before).await?;
+            let mut builder = QueryBuilder::new(format!("INSERT INTO {} (", entity.table()));
+            builder.push(
+                columns
+                    .iter()
+                    .map(|(column, _)| format!("\"{}\"", column))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+            builder.push(") VALUES (");
+            for (index, (_, value)) in columns.iter().enumerate() {
+                if index > 0 {
+                    builder.push(", ");
+                }
+                push_value(&mut builder, value);
+            }
+            builder.push(")");
+            builder.build().execute(&mut *conn).await.map_err(|e| {
+                AppError::Validation(format!("{} could not be restored: {}", label, e))
+            })?;
+
+            let restored = audit::fetch_row_json_on(&mut *conn, entity, entry.entity_id).await?;
+            Ok(AuditChange {
+                action: AuditAction::Create,
+                entity,
+                entity_id: entry.entity_id,
+                before: None,
+                after: restored,
+            })
+        }
+    }
+}
+
+/// Undo the whole operation an audit entry belongs to in one transaction, restoring
+/// every row it touched to its recorded revision. The revert is itself recorded as a
+/// new operation pointing back at the original. Returns the revert's root entry id.
+pub async fn revert_entry(pool: &SqlitePool, entry_id: i64) -> AppResult<i64> {
+    let entry = audit::get_entry(pool, entry_id)
+        .await?
+        .ok_or_else(|| AppError::Validation(format!("Audit entry {} not found", entry_id)))?;
+    let root = entry.batch_root();
+
+    let mut tx = pool.begin().await?;
+
+    if let Some(revert) = audit::find_revert(&mut tx, root).await? {
+        return Err(AppError::Validation(format!(
+            "This change was already reverted by entry {}",
+            revert
+        )));
+    }
+
+    let batch = audit::get_batch(&mut tx, root).await?;
+
+    // Remove created rows first (children were created last), then restore edits,
+    // then re-insert deleted rows parent first so foreign keys are satisfied
+    let mut order: Vec<usize> = Vec::with_capacity(batch.len());
+    order.extend(
+        (0..batch.len())
+            .rev()
+            .filter(|&i| batch[i].action == "create"),
+    );
+    order.extend(
+        (0..batch.len())
+            .rev()
+            .filter(|&i| batch[i].action == "update"),
+    );
+    order.extend((0..batch.len()).filter(|&i| batch[i].action == "delete"));
+
+    let mut undone: Vec<Option<AuditChange>> = vec![None; batch.len()];
+    for index in order {
+        undone[index] = Some(undo_entry(&mut tx, &batch[index]).await?);
+    }
+
+    // Record in the original order so the revert reads like the change it undoes
+    let revert_root =
+        audit::insert_batch(&mut tx, undone.into_iter().flatten().collect(), Some(root))
+            .await?
+            .ok_or_else(|| AppError::Validation("Nothing to revert".to_string()))?;
+
+    tx.commit().await?;
+
+    Ok(revert_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (34, 'Nagafen''s Lair', '[[40,55]]', 'Classic', 'Dungeon')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO zone_aliases (zone_id, alias) VALUES (34, 'solb')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        pool
+    }
+
+    async fn zone_name(pool: &SqlitePool) -> Option<String> {
+        sqlx::query_scalar("SELECT name FROM zones WHERE id = 34")
+            .fetch_optional(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reverts_an_edit_once() {
+        let pool = setup_pool().await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let before = audit::capture(&mut tx, AuditEntity::Zone, 34).await;
+        sqlx::query("UPDATE zones SET name = 'Sol B', rating = 4 WHERE id = 34")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        let after = audit::capture(&mut tx, AuditEntity::Zone, 34).await;
+        audit::record_change(
+            &mut tx,
+            AuditAction::Update,
+            AuditEntity::Zone,
+            34,
+            before,
+            after,
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+
+        let edit = audit::list_entries(&pool, &Default::default(), 1, 0)
+            .await
+            .unwrap()
+            .remove(0);
+        let revert = revert_entry(&pool, edit.id).await.unwrap();
+        assert_eq!(zone_name(&pool).await.as_deref(), Some("Nagafen's Lair"));
+
+        let recorded = audit::get_entry(&pool, revert).await.unwrap().unwrap();
+        assert_eq!(recorded.revert_of, Some(edit.id));
+        assert_eq!(recorded.action, "update");
+        assert!(
+            recorded
+                .changes
+                .iter()
+                .any(|c| c.field == "name" && c.after == "Nagafen's Lair")
+        );
+
+        assert!(matches!(
+            revert_entry(&pool, edit.id).await,
+            Err(AppError::Validation(_))
+        ));
+    }
+
+    async fn record_zone_edit(pool: &SqlitePool, sql: &str) -> i64 {
+        let mut tx = pool.begin().await.unwrap();
+        let before = audit::capture(&mut tx, AuditEntity::Zone, 34).await;
+        sqlx::query(sql).execute(&mut *tx).await.unwrap();
+        let after = audit::capture(&mut tx, AuditEntity::Zone, 34).await;
+        audit::record_change(
+            &mut tx,
+            AuditAction::Update,
+            AuditEntity::Zone,
+            34,
+            before,
+            after,
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+
+        audit::list_entries(pool, &Default::default(), 1, 0)
+            .await
+            .unwrap()
+            .remove(0)
+            .id
+    }
+
+    #[tokio::test]
+    async fn refuses_to_revert_over_later_changes() {
+        let pool = setup_pool().await;
+        let first = record_zone_edit(&pool, "UPDATE zones SET name = 'Sol B' WHERE id = 34").await;
+        let second = record_zone_edit(&pool, "UPDATE zones SET rating = 5 WHERE id = 34").await;
+
+        let Err(AppError::Validation(message)) = revert_entry(&pool, first).await else {
+            panic!("revert over a later edit should be refused");
+        };
+        assert!(
+            message.contains(&format!("#{} update", second)),
+            "{}",
+            message
+        );
+        assert_eq!(zone_name(&pool).await.as_deref(), Some("Sol B"));
+
+        // Newest first works, and then the older edit can be undone too
+        revert_entry(&pool, second).await.unwrap();
+        revert_entry(&pool, first).await.unwrap();
+        assert_eq!(zone_name(&pool).await.as_deref(), Some("Nagafen's Lair"));
+    }
+
+    #[tokio::test]
+    async fn reverting_a_delete_restores_cascaded_rows() {
+        let pool = setup_pool().await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let changes = audit::capture_cascade(&mut tx, AuditEntity::Zone, 34).await;
+        assert_eq!(changes.len(), 2);
+        sqlx::query("DELETE FROM zones WHERE id = 34")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        let root = audit::record_batch_change(&mut tx, changes)
+            .await
+            .unwrap()
+            .unwrap();
+        tx.commit().await.unwrap();
+        assert_eq!(zone_name(&pool).await, None);
+
+        // Any entry of the operation reverts all of it
+        let child = root + 1;
+        revert_entry(&pool, child).await.unwrap();
+        assert_eq!(zone_name(&pool).await.as_deref(), Some("Nagafen's Lair"));
+        let aliases = crate::aliases::get_aliases(&pool, crate::aliases::LocationKind::Zone, 34)
+            .await
+            .unwrap();
+        assert_eq!(aliases.len(), 1);
+
+        // Undoing the revert deletes the zone again
+        let revert = audit::list_entries(&pool, &Default::default(), 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|e| e.revert_of == Some(root) && e.batch_id.is_none())
+            .unwrap();
+        assert_eq!(revert.action, "create");
+        revert_entry(&pool, revert.id).await.unwrap();
+        assert_eq!(zone_name(&pool).await, None);
+    }
+}
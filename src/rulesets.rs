@@ -0,0 +1,237 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+
+use crate::classes::CLASSES;
+use crate::races::RACES;
+
+/// Races that only became playable with a later expansion, keyed by expansion code
+pub const RACE_EXPANSIONS: &[(&str, &str)] = &[
+    ("Iksar", "RoK"),
+    ("Vah Shir", "SoL"),
+    ("Froglok", "LoY"),
+    ("Drakkin", "TSS"),
+];
+
+/// Classes that only became playable with a later expansion, keyed by expansion code
+pub const CLASS_EXPANSIONS: &[(&str, &str)] = &[("Beastlord", "SoL"), ("Berserker", "GoD")];
+
+/// A server ruleset profile resolved against the expansion catalogue
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Ruleset {
+    pub id: Option<i64>,
+    pub name: String,
+    pub display_name: String,
+    pub max_expansion: String,
+    pub max_release_order: i64,
+    pub level_cap: u8,
+    pub allowed_races: Vec<String>,
+    pub allowed_classes: Vec<String>,
+}
+
+impl Ruleset {
+    pub fn allows_race(&self, race: &str) -> bool {
+        self.allowed_races
+            .iter()
+            .any(|r| r.eq_ignore_ascii_case(race))
+    }
+
+    pub fn allows_class(&self, class: &str) -> bool {
+        self.allowed_classes
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(class))
+    }
+
+    /// True when at least one level range starts at or below the ruleset's level cap
+    pub fn allows_levels(&self, level_ranges: &[[u8; 2]]) -> bool {
+        level_ranges.iter().any(|&[lmin, _]| lmin <= self.level_cap)
+    }
+}
+
+/// Names from `candidates` whose introducing expansion (if any) is released by `max_release_order`
+fn unlocked(
+    candidates: &[&str],
+    introduced_in: &[(&str, &str)],
+    release_orders: &HashMap<String, i64>,
+    max_release_order: i64,
+) -> Vec<String> {
+    candidates
+        .iter()
+        .filter(|name| {
+            introduced_in
+                .iter()
+                .find(|(n, _)| n == *name)
+                .and_then(|(_, code)| release_orders.get(*code))
+                .is_none_or(|order| *order <= max_release_order)
+        })
+        .map(|name| name.to_string())
+        .collect()
+}
+
+async fn build_ruleset(
+    pool: &SqlitePool,
+    row: &sqlx::sqlite::SqliteRow,
+) -> Result<Ruleset, sqlx::Error> {
+    let release_orders: HashMap<String, i64> =
+        sqlx::query("SELECT code, release_order FROM expansions")
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| (r.get::<String, _>("code"), r.get::<i64, _>("release_order")))
+            .collect();
+
+    let max_release_order: i64 = row.get("release_order");
+
+    Ok(Ruleset {
+        id: Some(row.get::<i64, _>("id")),
+        name: row.get("name"),
+        display_name: row.get("display_name"),
+        max_expansion: row.get("max_expansion"),
+        max_release_order,
+        level_cap: row.get::<i64, _>("level_cap") as u8,
+        allowed_races: unlocked(RACES, RACE_EXPANSIONS, &release_orders, max_release_order),
+        allowed_classes: unlocked(
+            CLASSES,
+            CLASS_EXPANSIONS,
+            &release_orders,
+            max_release_order,
+        ),
+    })
+}
+
+const RULESET_SELECT: &str = r#"
+    SELECT r.id, r.name, r.display_name, e.code AS max_expansion, e.release_order,
+           COALESCE(r.level_cap, e.level_cap) AS level_cap
+    FROM rulesets r
+    JOIN expansions e ON LOWER(e.code) = LOWER(r.max_expansion)
+"#;
+
+pub async fn get_rulesets(pool: &SqlitePool) -> Result<Vec<Ruleset>, sqlx::Error> {
+    let query = format!(
+        "{} ORDER BY e.release_order, r.display_name",
+        RULESET_SELECT
+    );
+    let rows = sqlx::query(&query).fetch_all(pool).await?;
+
+    let mut rulesets = Vec::new();
+    for row in &rows {
+        rulesets.push(build_ruleset(pool, row).await?);
+    }
+
+    Ok(rulesets)
+}
+
+/// Look up a ruleset by name, ignoring case
+pub async fn find_ruleset(pool: &SqlitePool, name: &str) -> Result<Option<Ruleset>, sqlx::Error> {
+    let query = format!("{} WHERE LOWER(r.name) = LOWER(?)", RULESET_SELECT);
+    let row = sqlx::query(&query)
+        .bind(name.trim())
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => Ok(Some(build_ruleset(pool, &row).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Resolve an optional `ruleset=` query parameter for a handler.
+/// Unknown rulesets are rejected with 400 so a typo never silently rolls unrestricted.
+pub async fn resolve_ruleset_param(
+    pool: &SqlitePool,
+    ruleset: Option<&str>,
+) -> Result<Option<Ruleset>, StatusCode> {
+    let Some(name) = ruleset.filter(|name| !name.trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    find_ruleset(pool, name)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Database error resolving ruleset");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map(Some)
+        .ok_or(StatusCode::BAD_REQUEST)
+}
+
+pub async fn get_rulesets_api(
+    State(state): State<crate::AppState>,
+) -> Result<Json<Vec<Ruleset>>, StatusCode> {
+    let pool = &*state.zone_state.pool;
+
+    let rulesets = get_rulesets(pool).await.map_err(|e| {
+        tracing::error!(error = %e, "Database error loading rulesets");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+
+    Ok(Json(rulesets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        crate::expansions::seed_expansions(&pool).await.unwrap();
+        pool
+    }
+
+    async fn insert_ruleset(pool: &SqlitePool, name: &str, max_expansion: &str, cap: Option<i64>) {
+        sqlx::query(
+            "INSERT INTO rulesets (name, display_name, max_expansion, level_cap) VALUES (?, ?, ?, ?)",
+        )
+        .bind(name)
+        .bind(name.to_uppercase())
+        .bind(max_expansion)
+        .bind(cap)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn velious_ruleset_locks_later_races_and_classes() {
+        let pool = setup_pool().await;
+        insert_ruleset(&pool, "mangler", "SoV", None).await;
+
+        let ruleset = find_ruleset(&pool, "Mangler").await.unwrap().unwrap();
+        assert_eq!(ruleset.level_cap, 60);
+        assert!(ruleset.allows_race("Iksar"));
+        assert!(!ruleset.allows_race("Vah Shir"));
+        assert!(!ruleset.allows_race("Froglok"));
+        assert!(!ruleset.allows_race("Drakkin"));
+        assert!(ruleset.allows_class("Warrior"));
+        assert!(!ruleset.allows_class("Beastlord"));
+        assert!(!ruleset.allows_class("Berserker"));
+        assert!(ruleset.allows_levels(&[[55, 65]]));
+        assert!(!ruleset.allows_levels(&[[61, 65]]));
+    }
+
+    #[tokio::test]
+    async fn level_cap_override_and_unknown_names() {
+        let pool = setup_pool().await;
+        insert_ruleset(&pool, "godcap", "GoD", Some(60)).await;
+
+        let ruleset = find_ruleset(&pool, "godcap").await.unwrap().unwrap();
+        assert_eq!(ruleset.level_cap, 60);
+        assert!(ruleset.allows_class("Berserker"));
+        assert!(ruleset.allows_race("Froglok"));
+        assert!(!ruleset.allows_race("Drakkin"));
+
+        assert_eq!(
+            resolve_ruleset_param(&pool, Some("nope")).await,
+            Err(StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(resolve_ruleset_param(&pool, None).await, Ok(None));
+        assert_eq!(get_rulesets(&pool).await.unwrap().len(), 1);
+    }
+}
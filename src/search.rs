@@ -0,0 +1,395 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use crate::security::{escape_html, sanitize_user_input};
+
+/// Result kinds stored in the `kind` column of `search_fts`
+pub const SEARCH_KINDS: &[&str] = &["zone", "instance", "zone_note", "instance_note", "link"];
+
+const DEFAULT_LIMIT: u32 = 20;
+const MAX_LIMIT: u32 = 50;
+const MAX_QUERY_LENGTH: usize = 200;
+
+// Snippet markers are control characters so highlighting survives HTML escaping
+const MARK_START: char = '\u{2}';
+const MARK_END: char = '\u{3}';
+
+// Each trigger body is a single statement so data.sql dumps split cleanly on ';'
+const SEARCH_TRIGGERS: &[&str] = &[
+    "CREATE TRIGGER IF NOT EXISTS search_zones_ai AFTER INSERT ON zones BEGIN INSERT INTO search_fts (kind, ref_id, name, aliases, content) VALUES ('zone', new.id, new.name, '', new.expansion || ' ' || new.zone_type || ' ' || new.continent); END",
+    "CREATE TRIGGER IF NOT EXISTS search_zones_au AFTER UPDATE OF id, name, expansion, zone_type, continent ON zones BEGIN UPDATE search_fts SET ref_id = new.id, name = new.name, content = new.expansion || ' ' || new.zone_type || ' ' || new.continent WHERE kind = 'zone' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_zones_ad AFTER DELETE ON zones BEGIN DELETE FROM search_fts WHERE kind = 'zone' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_instances_ai AFTER INSERT ON instances BEGIN INSERT INTO search_fts (kind, ref_id, name, aliases, content) VALUES ('instance', new.id, new.name, '', new.expansion || ' ' || new.zone_type || ' ' || new.continent); END",
+    "CREATE TRIGGER IF NOT EXISTS search_instances_au AFTER UPDATE OF id, name, expansion, zone_type, continent ON instances BEGIN UPDATE search_fts SET ref_id = new.id, name = new.name, content = new.expansion || ' ' || new.zone_type || ' ' || new.continent WHERE kind = 'instance' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_instances_ad AFTER DELETE ON instances BEGIN DELETE FROM search_fts WHERE kind = 'instance' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_zone_notes_ai AFTER INSERT ON zone_notes BEGIN INSERT INTO search_fts (kind, ref_id, name, aliases, content) VALUES ('zone_note', new.id, '', '', new.content); END",
+    "CREATE TRIGGER IF NOT EXISTS search_zone_notes_au AFTER UPDATE OF id, content ON zone_notes BEGIN UPDATE search_fts SET ref_id = new.id, content = new.content WHERE kind = 'zone_note' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_zone_notes_ad AFTER DELETE ON zone_notes BEGIN DELETE FROM search_fts WHERE kind = 'zone_note' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_instance_notes_ai AFTER INSERT ON instance_notes BEGIN INSERT INTO search_fts (kind, ref_id, name, aliases, content) VALUES ('instance_note', new.id, '', '', new.content); END",
+    "CREATE TRIGGER IF NOT EXISTS search_instance_notes_au AFTER UPDATE OF id, content ON instance_notes BEGIN UPDATE search_fts SET ref_id = new.id, content = new.content WHERE kind = 'instance_note' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_instance_notes_ad AFTER DELETE ON instance_notes BEGIN DELETE FROM search_fts WHERE kind = 'instance_note' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_links_ai AFTER INSERT ON links BEGIN INSERT INTO search_fts (kind, ref_id, name, aliases, content) VALUES ('link', new.id, new.name, '', COALESCE(new.description, '') || ' ' || new.category); END",
+    "CREATE TRIGGER IF NOT EXISTS search_links_au AFTER UPDATE OF id, name, description, category ON links BEGIN UPDATE search_fts SET ref_id = new.id, name = new.name, content = COALESCE(new.description, '') || ' ' || new.category WHERE kind = 'link' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_links_ad AFTER DELETE ON links BEGIN DELETE FROM search_fts WHERE kind = 'link' AND ref_id = old.id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_zone_aliases_ai AFTER INSERT ON zone_aliases BEGIN UPDATE search_fts SET aliases = (SELECT COALESCE(group_concat(alias, ' '), '') FROM zone_aliases WHERE zone_id = search_fts.ref_id) WHERE kind = 'zone' AND ref_id = new.zone_id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_zone_aliases_au AFTER UPDATE ON zone_aliases BEGIN UPDATE search_fts SET aliases = (SELECT COALESCE(group_concat(alias, ' '), '') FROM zone_aliases WHERE zone_id = search_fts.ref_id) WHERE kind = 'zone' AND ref_id IN (old.zone_id, new.zone_id); END",
+    "CREATE TRIGGER IF NOT EXISTS search_zone_aliases_ad AFTER DELETE ON zone_aliases BEGIN UPDATE search_fts SET aliases = (SELECT COALESCE(group_concat(alias, ' '), '') FROM zone_aliases WHERE zone_id = search_fts.ref_id) WHERE kind = 'zone' AND ref_id = old.zone_id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_instance_aliases_ai AFTER INSERT ON instance_aliases BEGIN UPDATE search_fts SET aliases = (SELECT COALESCE(group_concat(alias, ' '), '') FROM instance_aliases WHERE instance_id = search_fts.ref_id) WHERE kind = 'instance' AND ref_id = new.instance_id; END",
+    "CREATE TRIGGER IF NOT EXISTS search_instance_aliases_au AFTER UPDATE ON instance_aliases BEGIN UPDATE search_fts SET aliases = (SELECT COALESCE(group_concat(alias, ' '), '') FROM instance_aliases WHERE instance_id = search_fts.ref_id) WHERE kind = 'instance' AND ref_id IN (old.instance_id, new.instance_id); END",
+    "CREATE TRIGGER IF NOT EXISTS search_instance_aliases_ad AFTER DELETE ON instance_aliases BEGIN UPDATE search_fts SET aliases = (SELECT COALESCE(group_concat(alias, ' '), '') FROM instance_aliases WHERE instance_id = search_fts.ref_id) WHERE kind = 'instance' AND ref_id = old.instance_id; END",
+];
+
+/// Create the `search_fts` index and its sync triggers. Called from `create_tables`
+/// after every indexed table exists; a newly created index is filled from existing rows.
+pub(crate) async fn create_search_index(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let index_exists =
+        sqlx::query("SELECT name FROM sqlite_master WHERE type='table' AND name='search_fts'")
+            .fetch_optional(pool)
+            .await?
+            .is_some();
+
+    if !index_exists {
+        println!("Creating search index...");
+
+        sqlx::query(
+            r#"
+            CREATE VIRTUAL TABLE search_fts USING fts5(
+                kind UNINDEXED,
+                ref_id UNINDEXED,
+                name,
+                aliases,
+                content,
+                tokenize = 'unicode61 remove_diacritics 2'
+            )
+            "#,
+        )
+        .execute(pool)
+        .await?;
+
+        rebuild_search_index(pool).await?;
+        println!("Search index created successfully.");
+    } else {
+        println!("Search index already exists");
+    }
+
+    for trigger in SEARCH_TRIGGERS {
+        sqlx::query(trigger).execute(pool).await?;
+    }
+
+    Ok(())
+}
+
+/// Repopulate `search_fts` from the source tables
+pub async fn rebuild_search_index(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM search_fts")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO search_fts (kind, ref_id, name, aliases, content)
+        SELECT 'zone', id, name,
+            (SELECT COALESCE(group_concat(alias, ' '), '') FROM zone_aliases WHERE zone_id = zones.id),
+            expansion || ' ' || zone_type || ' ' || continent FROM zones
+        UNION ALL
+        SELECT 'instance', id, name,
+            (SELECT COALESCE(group_concat(alias, ' '), '') FROM instance_aliases WHERE instance_id = instances.id),
+            expansion || ' ' || zone_type || ' ' || continent FROM instances
+        UNION ALL
+        SELECT 'zone_note', id, '', '', content FROM zone_notes
+        UNION ALL
+        SELECT 'instance_note', id, '', '', content FROM instance_notes
+        UNION ALL
+        SELECT 'link', id, name, '', COALESCE(description, '') || ' ' || category FROM links
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+/// Turn free text into an FTS5 query: every word must match, each as a prefix.
+/// Words are quoted so user input can never use FTS5 operators. Returns None when
+/// nothing searchable remains.
+pub fn build_match_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+/// Escape a raw FTS5 snippet and turn its markers into `<mark>` tags
+fn highlight(snippet: &str) -> String {
+    escape_html(snippet)
+        .replace(MARK_START, "<mark>")
+        .replace(MARK_END, "</mark>")
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SearchResult {
+    pub kind: String,
+    pub id: i64,
+    pub title: String,
+    /// HTML-escaped excerpt with matches wrapped in `<mark>`
+    pub snippet: String,
+    /// Zone or instance a note belongs to
+    pub parent_id: Option<i64>,
+    pub score: f64,
+}
+
+pub async fn search(
+    pool: &SqlitePool,
+    match_query: &str,
+    kinds: &[&str],
+    limit: u32,
+) -> Result<Vec<SearchResult>, sqlx::Error> {
+    let kind_filter = if kinds.is_empty() {
+        String::new()
+    } else {
+        format!(
+            " AND s.kind IN ({})",
+            kinds.iter().map(|_| "?").collect::<Vec<_>>().join(", ")
+        )
+    };
+
+    // Names weigh ten times as much as note and description text, aliases nearly as much
+    let query = format!(
+        r#"
+        SELECT
+            s.kind,
+            s.ref_id,
+            snippet(search_fts, -1, char(2), char(3), '…', 12) AS snippet,
+            bm25(search_fts, 0.0, 0.0, 10.0, 8.0, 1.0) AS rank,
+            COALESCE(zn.zone_id, inn.instance_id) AS parent_id,
+            COALESCE(z.name, i.name, zp.name, ip.name, l.name, '') AS title
+        FROM search_fts s
+        LEFT JOIN zones z ON s.kind = 'zone' AND z.id = s.ref_id
+        LEFT JOIN instances i ON s.kind = 'instance' AND i.id = s.ref_id
+        LEFT JOIN zone_notes zn ON s.kind = 'zone_note' AND zn.id = s.ref_id
+        LEFT JOIN zones zp ON zp.id = zn.zone_id
+        LEFT JOIN instance_notes inn ON s.kind = 'instance_note' AND inn.id = s.ref_id
+        LEFT JOIN instances ip ON ip.id = inn.instance_id
+        LEFT JOIN links l ON s.kind = 'link' AND l.id = s.ref_id
+        WHERE search_fts MATCH ?{}
+          AND z.deleted_at IS NULL AND i.deleted_at IS NULL AND l.deleted_at IS NULL
+          AND zp.deleted_at IS NULL AND ip.deleted_at IS NULL
+        ORDER BY rank
+        LIMIT ?
+        "#,
+        kind_filter
+    );
+
+    let mut sql_query = sqlx::query(&query).bind(match_query);
+    for kind in kinds {
+        sql_query = sql_query.bind(*kind);
+    }
+    let rows = sql_query.bind(limit).fetch_all(pool).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| SearchResult {
+            kind: row.get("kind"),
+            id: row.get("ref_id"),
+            title: sanitize_user_input(&row.get::<String, _>("title")),
+            snippet: highlight(&row.get::<String, _>("snippet")),
+            parent_id: row.get("parent_id"),
+            score: -row.get::<f64, _>("rank"),
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    q: Option<String>,
+    kind: Option<String>, // Comma-separated result kinds
+    limit: Option<u32>,
+}
+
+pub async fn search_api(
+    Query(params): Query<SearchQuery>,
+    State(state): State<crate::AppState>,
+) -> Result<Json<Vec<SearchResult>>, StatusCode> {
+    let pool = &*state.zone_state.pool;
+
+    let text = params.q.unwrap_or_default();
+    if text.len() > MAX_QUERY_LENGTH {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let match_query = build_match_query(&text).ok_or(StatusCode::BAD_REQUEST)?;
+
+    let mut kinds = Vec::new();
+    for kind in params.kind.as_deref().unwrap_or_default().split(',') {
+        let kind = kind.trim();
+        if kind.is_empty() {
+            continue;
+        }
+        let known = SEARCH_KINDS
+            .iter()
+            .find(|k| k.eq_ignore_ascii_case(kind))
+            .ok_or(StatusCode::BAD_REQUEST)?;
+        kinds.push(*known);
+    }
+
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let results = search(pool, &match_query, &kinds, limit)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "Database error running search");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok(Json(results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (1, 'Nagafen''s Lair', '[[35,55]]', 'Classic', 'Indoor'), (2, 'Befallen', '[[7,25]]', 'Classic', 'Indoor')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO note_types (id, name, display_name, color_class) VALUES (1, 'epic', 'Epic', 'bg-yellow-500')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO zone_notes (id, zone_id, note_type_id, content) VALUES (1, 2, 1, 'Cleric epic 1.0 <b>drop</b> from the shadowed man')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO links (id, name, url, category, description) VALUES (1, 'Allakhazam', 'https://everquest.allakhazam.com', 'Database', 'Item and quest lookups')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        pool
+    }
+
+    async fn run(pool: &SqlitePool, text: &str, kinds: &[&str]) -> Vec<SearchResult> {
+        search(pool, &build_match_query(text).unwrap(), kinds, 20)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn match_query_quotes_words_as_prefixes() {
+        assert_eq!(
+            build_match_query("sol b").as_deref(),
+            Some("\"sol\"* \"b\"*")
+        );
+        assert_eq!(
+            build_match_query("say \"hi\" OR -").as_deref(),
+            Some("\"say\"* \"\"\"hi\"\"\"* \"OR\"*")
+        );
+        assert_eq!(build_match_query("  - * "), None);
+    }
+
+    #[tokio::test]
+    async fn finds_names_notes_and_links_with_escaped_snippets() {
+        let pool = setup_pool().await;
+
+        let results = run(&pool, "naga", &[]).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].kind, "zone");
+        assert_eq!(results[0].id, 1);
+        assert!(results[0].snippet.contains("<mark>Nagafen</mark>"));
+
+        let results = run(&pool, "epic 1.0 cleric", &[]).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].kind, "zone_note");
+        assert_eq!(results[0].parent_id, Some(2));
+        assert_eq!(results[0].title, "Befallen");
+        assert!(!results[0].snippet.contains("<b>"));
+        assert!(results[0].snippet.contains("<mark>Cleric</mark>"));
+
+        let results = run(&pool, "quest", &["link"]).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Allakhazam");
+        assert!(run(&pool, "quest", &["zone"]).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn triggers_keep_index_in_sync() {
+        let pool = setup_pool().await;
+
+        sqlx::query("UPDATE zones SET name = 'Nagafen''s Lair (Sol B)' WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(run(&pool, "sol b", &[]).await[0].id, 1);
+
+        sqlx::query("DELETE FROM zones WHERE id = 2")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(run(&pool, "befallen", &[]).await.is_empty());
+        // Note rows go with their zone through the cascade
+        assert!(run(&pool, "cleric", &[]).await.is_empty());
+
+        rebuild_search_index(&pool).await.unwrap();
+        assert_eq!(run(&pool, "allakhazam", &[]).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn aliases_are_searchable_and_old_indexes_upgrade() {
+        let pool = setup_pool().await;
+
+        sqlx::query("INSERT INTO zone_aliases (zone_id, alias) VALUES (1, 'solb')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let results = run(&pool, "solb", &[]).await;
+        assert_eq!((results[0].kind.as_str(), results[0].id), ("zone", 1));
+        assert!(results[0].snippet.contains("<mark>solb</mark>"));
+
+        sqlx::query("DELETE FROM zone_aliases")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(run(&pool, "solb", &[]).await.is_empty());
+
+        // A missing index is created and filled from existing rows, aliases included
+        sqlx::query("INSERT INTO zone_aliases (zone_id, alias) VALUES (2, 'bef')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DROP TABLE search_fts")
+            .execute(&pool)
+            .await
+            .unwrap();
+        create_search_index(&pool).await.unwrap();
+        assert_eq!(run(&pool, "bef", &[]).await[0].id, 2);
+    }
+}
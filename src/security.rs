@@ -1,11 +1,26 @@
-use std::collections::HashSet;
+use axum::http::{HeaderName, HeaderValue, StatusCode};
+use html5ever::tendril::StrTendril;
+use html5ever::tokenizer::states::RawKind;
+use html5ever::tokenizer::{
+    BufferQueue, Tag, TagKind, Token, TokenSink, TokenSinkResult, Tokenizer, TokenizerOpts,
+};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use tracing::Level;
 use url::Url;
 
-/// Comprehensive HTML sanitization for preventing XSS attacks
+use crate::config::SecurityHeadersConfig;
+use crate::logging::log_security_event;
+
+/// HTML sanitizer built on the html5ever tokenizer. Only allow-listed tags are
+/// kept, each with its own allow-listed attributes; everything else is dropped and
+/// text is re-escaped. Comments, CDATA sections and doctypes are removed, and the
+/// contents of elements like `<script>` and `<style>` are dropped with them.
+/// Entities are decoded by the tokenizer before anything is checked, so encoded
+/// tricks such as `&#106;avascript:` are seen for what they are.
 pub struct HtmlSanitizer {
-    allowed_tags: HashSet<&'static str>,
-    #[allow(dead_code)]
-    allowed_attributes: HashSet<&'static str>,
+    /// Tags that survive, mapped to the attributes each may carry
+    allowed_tags: HashMap<&'static str, HashSet<&'static str>>,
 }
 
 impl Default for HtmlSanitizer {
@@ -14,196 +29,177 @@ impl Default for HtmlSanitizer {
     }
 }
 
+/// Elements whose content is raw text to the tokenizer; it is dropped along with
+/// the element unless the element is allowed
+const RAW_TEXT_ELEMENTS: &[(&str, RawKind)] = &[
+    ("script", RawKind::ScriptData),
+    ("style", RawKind::Rawtext),
+    ("iframe", RawKind::Rawtext),
+    ("noscript", RawKind::Rawtext),
+    ("noembed", RawKind::Rawtext),
+    ("noframes", RawKind::Rawtext),
+    ("xmp", RawKind::Rawtext),
+    ("textarea", RawKind::Rcdata),
+    ("title", RawKind::Rcdata),
+];
+
+/// Elements that never have an end tag
+const VOID_ELEMENTS: &[&str] = &["br", "hr", "img", "wbr"];
+
 impl HtmlSanitizer {
-    /// Create a new HTML sanitizer with safe defaults
+    /// Create a new HTML sanitizer with safe defaults: basic formatting and links
     pub fn new() -> Self {
-        let mut allowed_tags = HashSet::new();
-        // Only allow very basic formatting tags
-        allowed_tags.insert("b");
-        allowed_tags.insert("i");
-        allowed_tags.insert("em");
-        allowed_tags.insert("strong");
-        allowed_tags.insert("br");
-        allowed_tags.insert("p");
-
-        let allowed_attributes = HashSet::new();
-        // No attributes allowed by default for maximum security
-
-        Self {
-            allowed_tags,
-            allowed_attributes,
-        }
+        Self::strip_all()
+            .allow_tag("b", &[])
+            .allow_tag("i", &[])
+            .allow_tag("em", &[])
+            .allow_tag("strong", &[])
+            .allow_tag("br", &[])
+            .allow_tag("p", &[])
+            .allow_tag("a", &["href", "title"])
     }
 
     /// Create a sanitizer that strips all HTML (safest option)
     pub fn strip_all() -> Self {
         Self {
-            allowed_tags: HashSet::new(),
-            allowed_attributes: HashSet::new(),
+            allowed_tags: HashMap::new(),
         }
     }
 
-    /// Sanitize HTML input by removing dangerous content
+    /// Allow `tag` with the given attributes. An `href` is only kept if
+    /// `sanitize_url` accepts it, and links always get `rel="noopener noreferrer"`.
+    pub fn allow_tag(mut self, tag: &'static str, attributes: &[&'static str]) -> Self {
+        self.allowed_tags
+            .insert(tag, attributes.iter().copied().collect());
+        self
+    }
+
+    /// Sanitize HTML input by removing dangerous content. With no tags allowed the
+    /// result is plain text: entities are decoded, except that `<` and `>` stay
+    /// escaped so the text never turns back into markup.
     pub fn sanitize(&self, input: &str) -> String {
+        let sink = SanitizingSink {
+            sanitizer: self,
+            output: RefCell::new(String::with_capacity(input.len())),
+            open_tags: RefCell::new(Vec::new()),
+            skipping: RefCell::new(None),
+        };
+        let tokenizer = Tokenizer::new(sink, TokenizerOpts::default());
+        let queue = BufferQueue::default();
+        queue.push_back(StrTendril::from_slice(input));
+        let _ = tokenizer.feed(&queue);
+        tokenizer.end();
+
+        let sink = tokenizer.sink;
+        let mut output = sink.output.into_inner();
+        for tag in sink.open_tags.into_inner().iter().rev() {
+            output.push_str(&format!("</{}>", tag));
+        }
+        output
+    }
+
+    fn push_text(&self, output: &mut String, text: &str) {
         if self.allowed_tags.is_empty() {
-            // Strip all HTML
-            self.strip_html_tags(input)
+            output.push_str(&text.replace('<', "&lt;").replace('>', "&gt;"));
         } else {
-            // Allow only specific tags
-            self.sanitize_with_allowed_tags(input)
+            output.push_str(&escape_html(text));
         }
     }
 
-    /// Strip all HTML tags and return plain text
-    fn strip_html_tags(&self, input: &str) -> String {
-        let mut result = String::with_capacity(input.len());
-        let mut in_tag = false;
-        let mut in_dangerous_tag = false;
-        let mut current_tag = String::new();
-        let mut chars = input.chars();
-
-        while let Some(ch) = chars.next() {
-            match ch {
-                '<' => {
-                    in_tag = true;
-                    current_tag.clear();
-                }
-                '>' if in_tag => {
-                    in_tag = false;
-                    // Check if this was a dangerous tag
-                    let tag_lower = current_tag.to_lowercase();
-                    if tag_lower.starts_with("script")
-                        || tag_lower.starts_with("style")
-                        || tag_lower.starts_with("iframe")
-                    {
-                        in_dangerous_tag = true;
-                    } else if tag_lower.starts_with("/script")
-                        || tag_lower.starts_with("/style")
-                        || tag_lower.starts_with("/iframe")
-                    {
-                        in_dangerous_tag = false;
-                    }
-                    current_tag.clear();
-                }
-                ch if in_tag => {
-                    current_tag.push(ch);
-                }
-                ch if !in_tag && !in_dangerous_tag => {
-                    result.push(ch);
-                }
-                _ => {} // Skip characters inside dangerous tags
+    /// The kept form of an allowed start tag
+    fn start_tag(&self, tag: &Tag, allowed: &HashSet<&'static str>) -> String {
+        let mut html = format!("<{}", tag.name);
+        for attr in &tag.attrs {
+            let name = &*attr.name.local;
+            if !allowed.contains(name) || (&*tag.name == "a" && name == "rel") {
+                continue;
             }
+            let value = if name == "href" {
+                match sanitize_url(&attr.value) {
+                    Some(url) => url,
+                    None => continue,
+                }
+            } else {
+                attr.value.to_string()
+            };
+            html.push_str(&format!(r#" {}="{}""#, name, escape_html(&value)));
         }
-
-        result
+        if &*tag.name == "a" {
+            html.push_str(r#" rel="noopener noreferrer""#);
+        }
+        html.push('>');
+        html
     }
+}
+
+/// Receives tokens from the tokenizer and writes out what the sanitizer keeps
+struct SanitizingSink<'a> {
+    sanitizer: &'a HtmlSanitizer,
+    output: RefCell<String>,
+    /// Allowed elements not yet closed, closed at the end if the input never does
+    open_tags: RefCell<Vec<String>>,
+    /// Raw-text element whose content is being dropped
+    skipping: RefCell<Option<String>>,
+}
 
-    /// Sanitize HTML while allowing specific tags
-    fn sanitize_with_allowed_tags(&self, input: &str) -> String {
-        let mut result = String::with_capacity(input.len());
-        let mut chars = input.chars().peekable();
-
-        while let Some(ch) = chars.next() {
-            if ch == '<' {
-                // Check if this is a valid tag
-                let _tag_start = result.len();
-                let mut tag_content = String::new();
-                let mut found_end = false;
-
-                // Collect the full tag
-                while let Some(&next_ch) = chars.peek() {
-                    if next_ch == '>' {
-                        chars.next(); // consume the '>'
-                        found_end = true;
-                        break;
+impl TokenSink for SanitizingSink<'_> {
+    type Handle = ();
+
+    fn process_token(&self, token: Token, _line_number: u64) -> TokenSinkResult<()> {
+        let mut skipping = self.skipping.borrow_mut();
+        match token {
+            Token::CharacterTokens(text) if skipping.is_none() => {
+                self.sanitizer
+                    .push_text(&mut self.output.borrow_mut(), &text);
+            }
+            Token::TagToken(tag) => {
+                let name = tag.name.to_string();
+                if let Some(skipped) = skipping.as_deref() {
+                    if tag.kind == TagKind::EndTag && skipped == name {
+                        *skipping = None;
                     }
-                    // Safe to unwrap since we just checked chars.peek() returned Some
-                    tag_content.push(
-                        chars
-                            .next()
-                            .expect("chars.next() should succeed after peek()"),
-                    );
+                    return TokenSinkResult::Continue;
                 }
 
-                if found_end {
-                    if let Some(sanitized_tag) = self.sanitize_tag(&tag_content) {
-                        result.push('<');
-                        result.push_str(&sanitized_tag);
-                        result.push('>');
+                let allowed = self.sanitizer.allowed_tags.get(name.as_str());
+                match (tag.kind, allowed) {
+                    (TagKind::StartTag, Some(attributes)) => {
+                        self.output
+                            .borrow_mut()
+                            .push_str(&self.sanitizer.start_tag(&tag, attributes));
+                        if !VOID_ELEMENTS.contains(&name.as_str()) {
+                            self.open_tags.borrow_mut().push(name);
+                        }
                     }
-                    // If tag is not allowed, it's simply omitted
-                } else {
-                    // Malformed tag, escape the '<'
-                    result.push_str("&lt;");
-                    // Put back the characters we consumed
-                    for ch in tag_content.chars() {
-                        result.push_str(&self.escape_html_char(ch));
+                    (TagKind::EndTag, Some(_)) => {
+                        let mut open_tags = self.open_tags.borrow_mut();
+                        if let Some(position) = open_tags.iter().rposition(|open| *open == name) {
+                            let mut output = self.output.borrow_mut();
+                            for open in open_tags.drain(position..).rev() {
+                                output.push_str(&format!("</{}>", open));
+                            }
+                        }
                     }
+                    (TagKind::StartTag, None) => {
+                        if let Some((_, kind)) =
+                            RAW_TEXT_ELEMENTS.iter().find(|(raw, _)| *raw == name)
+                        {
+                            *skipping = Some(name);
+                            return TokenSinkResult::RawData(*kind);
+                        }
+                        if name == "plaintext" {
+                            *skipping = Some(name);
+                            return TokenSinkResult::Plaintext;
+                        }
+                    }
+                    (TagKind::EndTag, None) => {}
                 }
-            } else {
-                result.push_str(&self.escape_html_char(ch));
             }
+            // Comments, CDATA (tokenized as comments), doctypes, NULs and parse
+            // errors leave nothing behind
+            _ => {}
         }
-
-        result
-    }
-
-    /// Sanitize a single tag and its attributes
-    fn sanitize_tag(&self, tag_content: &str) -> Option<String> {
-        let tag_content = tag_content.trim();
-        if tag_content.is_empty() {
-            return None;
-        }
-
-        // Check if it's a closing tag
-        let is_closing = tag_content.starts_with('/');
-        let tag_name = if is_closing {
-            tag_content[1..]
-                .split_whitespace()
-                .next()
-                .unwrap_or("")
-                .to_lowercase()
-        } else {
-            tag_content
-                .split_whitespace()
-                .next()
-                .unwrap_or("")
-                .to_lowercase()
-        };
-
-        // Check if tag is allowed
-        if !self.allowed_tags.contains(tag_name.as_str()) {
-            return None;
-        }
-
-        if is_closing {
-            // Closing tags don't have attributes
-            return Some(format!("/{}", tag_name));
-        }
-
-        // For opening tags, sanitize attributes
-        let parts: Vec<&str> = tag_content.splitn(2, ' ').collect();
-        if parts.len() == 1 {
-            // No attributes
-            Some(tag_name)
-        } else {
-            // Has attributes - for now, we'll strip them all for security
-            // In the future, this could be extended to allow specific attributes
-            Some(tag_name)
-        }
-    }
-
-    /// Escape a single character for HTML safety
-    fn escape_html_char(&self, ch: char) -> String {
-        match ch {
-            '<' => "&lt;".to_string(),
-            '>' => "&gt;".to_string(),
-            '"' => "&quot;".to_string(),
-            '\'' => "&#x27;".to_string(),
-            '&' => "&amp;".to_string(),
-            '/' => "&#x2F;".to_string(),
-            _ => ch.to_string(),
-        }
+        TokenSinkResult::Continue
     }
 }
 
@@ -234,6 +230,20 @@ pub fn escape_html_attribute(input: &str) -> String {
         .replace('\t', "&#9;")
 }
 
+/// The value of the named cookie from a request's `Cookie` headers. Empty values
+/// count as missing.
+pub fn read_cookie(headers: &axum::http::HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(axum::http::header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(cookie, _)| *cookie == name)
+        .map(|(_, value)| value.to_string())
+        .filter(|value| !value.is_empty())
+}
+
 /// Sanitize user input for safe display in HTML
 pub fn sanitize_user_input(input: &str) -> String {
     let sanitizer = HtmlSanitizer::strip_all();
@@ -258,15 +268,210 @@ pub fn sanitize_url(url: &str) -> Option<String> {
     }
 }
 
-/// Content Security Policy header value for additional XSS protection
-pub const CSP_NONCE: &str = "eqrngNonce";
+/// Nonce written into inline `<script>` and `<style>` tags of the static pages at
+/// build time. It is swapped for the request's own nonce as each page is served.
+pub const CSP_NONCE_PLACEHOLDER: &str = "eqrngNonce";
+
+tokio::task_local! {
+    static NONCE: String;
+}
+
+/// The CSP nonce for the current request, kept in request extensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CspNonce(pub String);
+
+impl CspNonce {
+    /// A fresh random nonce
+    pub fn generate() -> Self {
+        CspNonce(
+            rand::random::<[u8; 16]>()
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect(),
+        )
+    }
+}
+
+/// The nonce to stamp on inline `<script>` and `<style>` tags in the current
+/// request's HTML, or the placeholder outside a request (which is then replaced
+/// on the way out)
+pub fn csp_nonce() -> String {
+    NONCE
+        .try_with(|nonce| nonce.clone())
+        .unwrap_or_else(|_| CSP_NONCE_PLACEHOLDER.to_string())
+}
 
-pub fn get_csp_header() -> &'static str {
-    "default-src 'self'; script-src 'self' 'nonce-eqrngNonce'; style-src 'self' 'nonce-eqrngNonce' https://fonts.googleapis.com; font-src 'self' https://fonts.gstatic.com; img-src 'self' data: https:; connect-src 'self'; frame-ancestors 'none'; base-uri 'self'; form-action 'self'"
+/// Where browsers send Content Security Policy violation reports
+pub const CSP_REPORT_PATH: &str = "/csp-report";
+
+/// Content Security Policy header value from `directives`, allowing inline code
+/// only with `nonce` and reporting violations to `report_uri` if set
+pub fn csp_header(directives: &[String], nonce: &str, report_uri: &str) -> String {
+    let mut directives: Vec<String> = directives
+        .iter()
+        .map(|directive| directive.trim().replace("{nonce}", nonce))
+        .filter(|directive| !directive.is_empty())
+        .collect();
+    if !report_uri.is_empty() {
+        directives.push(format!("report-uri {}", report_uri));
+    }
+    directives.join("; ")
 }
 
-pub fn get_csp_nonce() -> &'static str {
-    CSP_NONCE
+/// The security headers for a response, with the admin policy for `/admin` pages.
+/// Fails on a header name or value that cannot be sent.
+pub fn security_headers(
+    config: &SecurityHeadersConfig,
+    admin: bool,
+    nonce: &str,
+) -> Result<Vec<(HeaderName, HeaderValue)>, String> {
+    let directives = if admin {
+        &config.admin_csp
+    } else {
+        &config.csp
+    };
+    let csp_name = if config.csp_report_only {
+        "Content-Security-Policy-Report-Only"
+    } else {
+        "Content-Security-Policy"
+    };
+    let csp = if directives.is_empty() {
+        String::new()
+    } else {
+        csp_header(directives, nonce, &config.csp_report_uri)
+    };
+
+    let named = [
+        ("X-XSS-Protection", config.xss_protection.as_str()),
+        (
+            "X-Content-Type-Options",
+            config.content_type_options.as_str(),
+        ),
+        ("X-Frame-Options", config.frame_options.as_str()),
+        (csp_name, csp.as_str()),
+        ("Referrer-Policy", config.referrer_policy.as_str()),
+        ("Permissions-Policy", config.permissions_policy.as_str()),
+        (
+            "Strict-Transport-Security",
+            config.strict_transport_security.as_str(),
+        ),
+    ];
+    let extra = config
+        .extra
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()));
+
+    let mut headers: Vec<(HeaderName, HeaderValue)> = Vec::new();
+    for (name, value) in named.into_iter().chain(extra) {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| format!("'{}' is not a valid header name", name))?;
+        headers.retain(|(existing, _)| *existing != name);
+        if value.is_empty() {
+            continue;
+        }
+        let value = HeaderValue::from_str(value)
+            .map_err(|_| format!("the value for {} is not a valid header value", name))?;
+        headers.push((name, value));
+    }
+    Ok(headers)
+}
+
+/// A field of a violation report, quoted and shortened so it is safe to log
+fn report_field(report: &serde_json::Value, names: &[&str]) -> String {
+    let value = names
+        .iter()
+        .find_map(|name| report.get(*name))
+        .map(|value| match value {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+        .unwrap_or_default();
+    format!("{:?}", value.chars().take(200).collect::<String>())
+}
+
+/// `POST /csp-report`: logs Content Security Policy violations sent by browsers,
+/// either as a `report-uri` report or a Reporting API batch
+pub async fn csp_report(body: axum::body::Bytes) -> StatusCode {
+    let Ok(report) = serde_json::from_slice::<serde_json::Value>(&body) else {
+        return StatusCode::BAD_REQUEST;
+    };
+    let violations: Vec<&serde_json::Value> = match &report {
+        serde_json::Value::Array(reports) => reports
+            .iter()
+            .filter(|entry| entry.get("type").and_then(|t| t.as_str()) == Some("csp-violation"))
+            .filter_map(|entry| entry.get("body"))
+            .collect(),
+        single => single.get("csp-report").into_iter().collect(),
+    };
+    if violations.is_empty() {
+        return StatusCode::BAD_REQUEST;
+    }
+
+    for violation in violations {
+        let details = format!(
+            "directive={} blocked={} document={} source={} line={} disposition={}",
+            report_field(
+                violation,
+                &[
+                    "effective-directive",
+                    "effectiveDirective",
+                    "violated-directive"
+                ]
+            ),
+            report_field(violation, &["blocked-uri", "blockedURL"]),
+            report_field(violation, &["document-uri", "documentURL"]),
+            report_field(violation, &["source-file", "sourceFile"]),
+            report_field(violation, &["line-number", "lineNumber"]),
+            report_field(violation, &["disposition"]),
+        );
+        log_security_event("csp_violation", &details, Level::WARN);
+    }
+    StatusCode::NO_CONTENT
+}
+
+/// Replace the build-time placeholder nonce in a page with the request's nonce
+pub fn stamp_nonce(html: &str, nonce: &str) -> String {
+    html.replace(
+        &format!(r#"nonce="{}""#, CSP_NONCE_PLACEHOLDER),
+        &format!(r#"nonce="{}""#, nonce),
+    )
+}
+
+/// Middleware that gives every request its own CSP nonce. Handlers see it through
+/// `csp_nonce()`, and HTML responses, static files included, have the placeholder
+/// nonce replaced with it.
+pub async fn apply_csp_nonce(
+    mut request: axum::http::Request<axum::body::Body>,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    use axum::http::header;
+    use axum::response::IntoResponse;
+
+    let nonce = CspNonce::generate();
+    request.extensions_mut().insert(nonce.clone());
+    let response = NONCE.scope(nonce.0.clone(), next.run(request)).await;
+
+    let is_html = response.status() == StatusCode::OK
+        && response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("text/html"));
+    if !is_html {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to read page for CSP nonce");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let page = stamp_nonce(&String::from_utf8_lossy(&bytes), &nonce.0);
+    parts.headers.remove(header::CONTENT_LENGTH);
+    axum::response::Response::from_parts(parts, axum::body::Body::from(page))
 }
 
 #[cfg(test)]
@@ -292,13 +497,137 @@ mod tests {
     fn test_sanitize_with_allowed_tags() {
         let sanitizer = HtmlSanitizer::new();
         assert_eq!(sanitizer.sanitize("<b>Bold</b>"), "<b>Bold</b>");
-        assert_eq!(sanitizer.sanitize("<script>evil</script>"), "evil");
+        assert_eq!(sanitizer.sanitize("<script>evil</script>"), "");
         assert_eq!(
             sanitizer.sanitize("<b onclick='alert()'>Bold</b>"),
             "<b>Bold</b>"
         );
     }
 
+    #[test]
+    fn test_sanitize_links() {
+        let sanitizer = HtmlSanitizer::new();
+        assert_eq!(
+            sanitizer.sanitize(
+                r#"<a href="https://eqrng.com/" title="Home" target="_blank" rel="opener">Home</a>"#
+            ),
+            r#"<a href="https:&#x2F;&#x2F;eqrng.com&#x2F;" title="Home" rel="noopener noreferrer">Home</a>"#
+        );
+        assert_eq!(
+            sanitizer.sanitize(r#"<a href="javascript:alert(1)">x</a>"#),
+            r#"<a rel="noopener noreferrer">x</a>"#
+        );
+        // Unclosed and stray tags are balanced so they cannot spill into the page
+        assert_eq!(
+            sanitizer.sanitize("<b><a href=x>one</b> two</p>"),
+            r#"<b><a rel="noopener noreferrer">one</a></b> two"#
+        );
+        assert_eq!(sanitizer.sanitize("<p><i>open"), "<p><i>open</i></p>");
+        assert_eq!(sanitizer.sanitize("line<br/>break</br>"), "line<br>break");
+    }
+
+    /// Known XSS vectors; none may leave a script-capable construct behind
+    const XSS_CORPUS: &[&str] = &[
+        "<script>alert(1)</script>",
+        "<SCRIPT SRC=//evil.example/x.js></SCRIPT>",
+        "<scr<script>ipt>alert(1)</scr</script>ipt>",
+        "<img src=x onerror=alert(1)>",
+        "<svg/onload=alert(1)>",
+        "<svg><script>alert(1)</script></svg>",
+        "<math><mtext><table><mglyph><style><img src=x onerror=alert(1)>",
+        "<b onmouseover=alert(1)>hover</b>",
+        "<b\tonclick=alert(1)>x</b>",
+        "<a href=\"javascript:alert(1)\">x</a>",
+        "<a href=\"JaVaScRiPt:alert(1)\">x</a>",
+        "<a href=\"&#106;&#97;&#118;&#97;&#115;&#99;&#114;&#105;&#112;&#116;&#58;alert(1)\">x</a>",
+        "<a href=\"&#x6A;avascript&colon;alert(1)\">x</a>",
+        "<a href=\"java\tscript:alert(1)\">x</a>",
+        "<a href=\"java&#x09;script:alert(1)\">x</a>",
+        "<a href=\" javascript:alert(1)\">x</a>",
+        "<a href=\"data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==\">x</a>",
+        "<a href=\"vbscript:msgbox(1)\">x</a>",
+        "<a href=\"https://ok.example\" onclick=\"alert(1)\" style=\"x:expression(alert(1))\">x</a>",
+        "<a href='https://ok.example' href='javascript:alert(1)'>x</a>",
+        "<!--<script>alert(1)</script>-->",
+        "<!--><script>alert(1)</script>-->",
+        "<![CDATA[<script>alert(1)</script>]]>",
+        "<!DOCTYPE html><script>alert(1)</script>",
+        "&lt;script&gt;alert(1)&lt;/script&gt;",
+        "&#60;script&#62;alert(1)&#60;/script&#62;",
+        "<style>@import 'https://evil.example/x.css';</style>",
+        "<iframe src=\"javascript:alert(1)\"></iframe>",
+        "<iframe srcdoc=\"<script>alert(1)</script>\"></iframe>",
+        "<object data=\"javascript:alert(1)\"></object>",
+        "<embed src=\"javascript:alert(1)\">",
+        "<form action=\"javascript:alert(1)\"><button>go</button></form>",
+        "<meta http-equiv=\"refresh\" content=\"0;url=javascript:alert(1)\">",
+        "<base href=\"javascript:alert(1)//\">",
+        "<textarea><script>alert(1)</script></textarea>",
+        "<title><script>alert(1)</script></title>",
+        "<noscript><p title=\"</noscript><img src=x onerror=alert(1)>\"></noscript>",
+        "<xmp><script>alert(1)</script></xmp>",
+        "<plaintext><script>alert(1)</script>",
+        "<p style=\"background:url(javascript:alert(1))\">x</p>",
+        "<<script>script>alert(1)<</script>/script>",
+        "<b>\"><script>alert(1)</script></b>",
+        "<a href=\"https://ok.example/\\\"onmouseover=alert(1)\">x</a>",
+        "<a title=\"&quot; onmouseover=alert(1) x=&quot;\">x</a>",
+        "<p\0 onclick=alert(1)>x</p>",
+        "<template><script>alert(1)</script></template>",
+        "<details open ontoggle=alert(1)>",
+        "<video><source onerror=alert(1)></video>",
+    ];
+
+    /// Whether sanitized output still holds anything a browser could run
+    fn is_dangerous(html: &str) -> bool {
+        let lower = html.to_lowercase();
+        let element = ["<script", "<iframe", "<img", "<svg", "<style", "<!--"]
+            .iter()
+            .any(|element| lower.contains(element));
+        element
+            || lower.split('<').skip(1).any(|tag| {
+                let tag = tag.split('>').next().unwrap_or("");
+                let scheme = ["javascript:", "vbscript:", "data:"]
+                    .iter()
+                    .any(|scheme| tag.contains(scheme));
+                // Attribute names only; quoted values are inert text
+                let attribute = tag
+                    .split('"')
+                    .step_by(2)
+                    .flat_map(str::split_whitespace)
+                    .any(|attr| attr.starts_with("on") || attr.starts_with("style"));
+                scheme || attribute
+            })
+    }
+
+    #[test]
+    fn test_sanitize_xss_corpus() {
+        for sanitizer in [HtmlSanitizer::new(), HtmlSanitizer::strip_all()] {
+            for vector in XSS_CORPUS {
+                let output = sanitizer.sanitize(vector);
+                assert!(!is_dangerous(&output), "{:?} became {:?}", vector, output);
+                // Sanitizing is stable, so stored notes can be sanitized again on read
+                assert_eq!(sanitizer.sanitize(&output), output, "{:?}", vector);
+            }
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_sanitize_never_leaves_markup_it_did_not_allow(
+            input in proptest::string::string_regex(
+                r#"([<>/="'&#;! a-z:()]|script|onload|href|javascript|<a |<b>|</b>|<!--|-->|<!\[CDATA\[|&#106;){0,40}"#
+            ).unwrap()
+        ) {
+            let formatted = HtmlSanitizer::new().sanitize(&input);
+            proptest::prop_assert!(!is_dangerous(&formatted), "{:?} became {:?}", input, formatted);
+            proptest::prop_assert_eq!(HtmlSanitizer::new().sanitize(&formatted), formatted.clone());
+
+            let plain = HtmlSanitizer::strip_all().sanitize(&input);
+            proptest::prop_assert!(!plain.contains('<') && !plain.contains('>'));
+        }
+    }
+
     #[test]
     fn test_escape_html_characters_outside_tags() {
         let sanitizer = HtmlSanitizer::new();
@@ -326,4 +655,99 @@ mod tests {
         assert_eq!(sanitize_url("data:text/html,<script>"), None);
         assert_eq!(sanitize_url("/relative/path"), None);
     }
+
+    #[tokio::test]
+    async fn test_csp_nonce_per_request() {
+        let first = CspNonce::generate();
+        let second = CspNonce::generate();
+        assert_ne!(first, second);
+        assert_eq!(first.0.len(), 32);
+
+        let header = csp_header(&SecurityHeadersConfig::default().csp, &first.0, "");
+        assert!(header.contains(&format!("script-src 'self' 'nonce-{}'", first.0)));
+        assert!(!header.contains(CSP_NONCE_PLACEHOLDER));
+
+        assert_eq!(csp_nonce(), CSP_NONCE_PLACEHOLDER);
+        let inside = NONCE.scope(first.0.clone(), async { csp_nonce() }).await;
+        assert_eq!(inside, first.0);
+
+        let page = r#"<style nonce="eqrngNonce">p{}</style><p>nonce="other"</p>"#;
+        assert_eq!(
+            stamp_nonce(page, "abc"),
+            r#"<style nonce="abc">p{}</style><p>nonce="other"</p>"#
+        );
+    }
+
+    #[test]
+    fn security_headers_follow_the_configuration() {
+        let header = |headers: &[(HeaderName, HeaderValue)], name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n.as_str().eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.to_str().unwrap().to_string())
+        };
+
+        let mut config = SecurityHeadersConfig::default();
+        let public = security_headers(&config, false, "abc").unwrap();
+        let csp = header(&public, "content-security-policy").unwrap();
+        assert!(csp.contains("script-src 'self' 'nonce-abc'"));
+        assert!(csp.contains("fonts.googleapis.com"));
+        assert!(csp.ends_with("; report-uri /csp-report"));
+        assert_eq!(header(&public, "x-frame-options").as_deref(), Some("DENY"));
+
+        let admin = security_headers(&config, true, "abc").unwrap();
+        let csp = header(&admin, "content-security-policy").unwrap();
+        assert!(csp.contains("style-src 'self' 'nonce-abc'"));
+        assert!(!csp.contains("fonts.googleapis.com"));
+
+        config.csp_report_only = true;
+        config.csp_report_uri = String::new();
+        config.frame_options = "SAMEORIGIN".into();
+        config.strict_transport_security = String::new();
+        config
+            .extra
+            .insert("Permissions-Policy".into(), "camera=()".into());
+        config
+            .extra
+            .insert("Cross-Origin-Opener-Policy".into(), "same-origin".into());
+        let headers = security_headers(&config, false, "abc").unwrap();
+        assert!(header(&headers, "content-security-policy").is_none());
+        assert!(
+            !header(&headers, "content-security-policy-report-only")
+                .unwrap()
+                .contains("report-uri")
+        );
+        assert_eq!(
+            header(&headers, "x-frame-options").as_deref(),
+            Some("SAMEORIGIN")
+        );
+        assert!(header(&headers, "strict-transport-security").is_none());
+        assert_eq!(
+            header(&headers, "permissions-policy").as_deref(),
+            Some("camera=()")
+        );
+        assert_eq!(
+            header(&headers, "cross-origin-opener-policy").as_deref(),
+            Some("same-origin")
+        );
+
+        config.extra.insert("Bad Header".into(), "x".into());
+        assert!(security_headers(&config, false, "abc").is_err());
+    }
+
+    #[tokio::test]
+    async fn csp_reports_are_accepted_in_both_formats() {
+        let legacy = r#"{"csp-report":{"document-uri":"https://eqrng.com/","effective-directive":"script-src-elem","blocked-uri":"inline"}}"#;
+        assert_eq!(csp_report(legacy.into()).await, StatusCode::NO_CONTENT);
+
+        let batch = r#"[{"type":"csp-violation","body":{"documentURL":"https://eqrng.com/","effectiveDirective":"style-src-elem","blockedURL":"https://evil.example/x.css","disposition":"report"}}]"#;
+        assert_eq!(csp_report(batch.into()).await, StatusCode::NO_CONTENT);
+
+        assert_eq!(csp_report("nope".into()).await, StatusCode::BAD_REQUEST);
+        assert_eq!(csp_report("{}".into()).await, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            csp_report(r#"[{"type":"deprecation","body":{}}]"#.into()).await,
+            StatusCode::BAD_REQUEST
+        );
+    }
 }
@@ -0,0 +1,842 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+use tokio::sync::Mutex;
+
+use crate::expansions::{Expansion, get_expansions};
+use crate::instances::{INSTANCE_COLUMNS, Instance, InstanceDetails};
+use crate::locations::{HOT_ZONE_FLAG, Location, load_flags, load_notes};
+use crate::rulesets::{Ruleset, get_rulesets};
+use crate::security::{sanitize_url, sanitize_user_input};
+use crate::zones::Zone;
+
+/// Filters shared by `/random_zone` and `/random_instance`, resolved against a snapshot
+#[derive(Debug, Default, Clone)]
+pub struct RollFilter {
+    pub min: Option<u8>,
+    pub max: Option<u8>,
+    pub zone_type: Option<String>,
+    /// Lowercased expansion names accepted by an `expansion=` parameter
+    pub expansions: Vec<String>,
+    pub max_release_order: Option<i64>,
+    pub ruleset: Option<Ruleset>,
+    pub continent: Option<String>,
+    /// Lowercased filterable flag names; an item matches if it has any of them
+    pub flags: Vec<String>,
+    pub hot_zone: Option<bool>,
+}
+
+/// Positions into a snapshot's zone or instance list, keyed by lowercased value
+#[derive(Debug, Default)]
+struct RollIndex {
+    by_expansion: HashMap<String, Vec<usize>>,
+    by_zone_type: HashMap<String, Vec<usize>>,
+    by_flag: HashMap<String, Vec<usize>>,
+    /// Items with a level range covering the key level
+    by_level: HashMap<u8, Vec<usize>>,
+    /// Filterable flag names per item, lowercased
+    flags: Vec<Vec<String>>,
+}
+
+impl RollIndex {
+    fn build<T: Location>(items: &[T], flags: Vec<Vec<String>>) -> Self {
+        let mut index = RollIndex {
+            flags,
+            ..Default::default()
+        };
+
+        for (position, item) in items.iter().enumerate() {
+            index
+                .by_expansion
+                .entry(item.expansion().to_lowercase())
+                .or_default()
+                .push(position);
+            index
+                .by_zone_type
+                .entry(item.zone_type().to_lowercase())
+                .or_default()
+                .push(position);
+            for flag in &index.flags[position] {
+                index
+                    .by_flag
+                    .entry(flag.clone())
+                    .or_default()
+                    .push(position);
+            }
+
+            let mut levels: Vec<u8> = item
+                .level_ranges()
+                .iter()
+                .flat_map(|&[lmin, lmax]| lmin..=lmax)
+                .collect();
+            levels.sort_unstable();
+            levels.dedup();
+            for level in levels {
+                index.by_level.entry(level).or_default().push(position);
+            }
+        }
+
+        index
+    }
+
+    /// Union of the posting lists for `keys`, sorted and deduplicated
+    fn union(map: &HashMap<String, Vec<usize>>, keys: &[String]) -> Vec<usize> {
+        let mut positions: Vec<usize> = keys
+            .iter()
+            .filter_map(|key| map.get(key))
+            .flatten()
+            .copied()
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+
+    /// Narrow to the smallest indexed candidate set, then check every filter on each candidate
+    fn matching<T: Location>(
+        &self,
+        items: &[T],
+        filter: &RollFilter,
+        release_orders: &HashMap<String, i64>,
+    ) -> Vec<usize> {
+        let mut candidate_sets: Vec<Vec<usize>> = Vec::new();
+        if let Some(ref zone_type) = filter.zone_type {
+            candidate_sets.push(Self::union(&self.by_zone_type, &[zone_type.to_lowercase()]));
+        }
+        if !filter.expansions.is_empty() {
+            candidate_sets.push(Self::union(&self.by_expansion, &filter.expansions));
+        }
+        if !filter.flags.is_empty() {
+            candidate_sets.push(Self::union(&self.by_flag, &filter.flags));
+        }
+        if let (Some(min), Some(_)) = (filter.min, filter.max) {
+            candidate_sets.push(self.by_level.get(&min).cloned().unwrap_or_default());
+        }
+
+        let candidates = candidate_sets
+            .into_iter()
+            .min_by_key(|set| set.len())
+            .unwrap_or_else(|| (0..items.len()).collect());
+
+        candidates
+            .into_iter()
+            .filter(|&position| self.matches(position, &items[position], filter, release_orders))
+            .collect()
+    }
+
+    fn matches<T: Location>(
+        &self,
+        position: usize,
+        item: &T,
+        filter: &RollFilter,
+        release_orders: &HashMap<String, i64>,
+    ) -> bool {
+        let level_ranges = item.level_ranges();
+
+        if let Some(ref zone_type) = filter.zone_type
+            && !item.zone_type().eq_ignore_ascii_case(zone_type)
+        {
+            return false;
+        }
+        if !filter.expansions.is_empty()
+            && !filter.expansions.contains(&item.expansion().to_lowercase())
+        {
+            return false;
+        }
+        if let Some(max_order) = filter.max_release_order
+            && release_orders
+                .get(&item.expansion().to_lowercase())
+                .is_none_or(|order| *order > max_order)
+        {
+            return false;
+        }
+        if let Some(ref ruleset) = filter.ruleset
+            && !ruleset.allows_levels(level_ranges)
+        {
+            return false;
+        }
+        if let Some(ref continent) = filter.continent
+            && !item.continent().eq_ignore_ascii_case(continent)
+        {
+            return false;
+        }
+        if filter.hot_zone.is_some() && item.hot_zone() != filter.hot_zone {
+            return false;
+        }
+        if !filter.flags.is_empty()
+            && !self.flags[position]
+                .iter()
+                .any(|f| filter.flags.contains(f))
+        {
+            return false;
+        }
+
+        match (filter.min, filter.max) {
+            (Some(min), Some(max)) => level_ranges
+                .iter()
+                .any(|&[lmin, lmax]| lmin <= min && lmax >= max),
+            (Some(min), None) => level_ranges.iter().any(|&[_lmin, lmax]| lmax >= min),
+            (None, Some(max)) => level_ranges.iter().any(|&[lmin, _lmax]| lmin <= max),
+            (None, None) => true,
+        }
+    }
+}
+
+/// An immutable view of everything random rolls need, built in one pass over the database
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    pub zones: Vec<Zone>,
+    pub instances: Vec<Instance>,
+    pub expansions: Vec<Expansion>,
+    pub rulesets: Vec<Ruleset>,
+    /// None until the first build
+    pub built_at: Option<DateTime<Utc>>,
+    zone_index: RollIndex,
+    instance_index: RollIndex,
+    release_orders: HashMap<String, i64>,
+}
+
+impl Snapshot {
+    pub async fn build(pool: &SqlitePool) -> Result<Self, sqlx::Error> {
+        let expansions = get_expansions(pool).await?;
+        let rulesets = get_rulesets(pool).await?;
+        let (zones, zone_flags) = load_zones(pool).await?;
+        let (instances, instance_flags) = load_instances(pool).await?;
+
+        let release_orders = expansions
+            .iter()
+            .map(|e| (e.name.to_lowercase(), e.release_order))
+            .collect();
+        let zone_index = RollIndex::build(&zones, zone_flags);
+        let instance_index = RollIndex::build(&instances, instance_flags);
+
+        Ok(Snapshot {
+            zones,
+            instances,
+            expansions,
+            rulesets,
+            built_at: Some(Utc::now()),
+            zone_index,
+            instance_index,
+            release_orders,
+        })
+    }
+
+    /// Look up an expansion by short code or display name, ignoring case
+    pub fn find_expansion(&self, code_or_name: &str) -> Option<&Expansion> {
+        let key = code_or_name.trim();
+        self.expansions
+            .iter()
+            .find(|e| e.code.eq_ignore_ascii_case(key) || e.name.eq_ignore_ascii_case(key))
+    }
+
+    /// Resolve an optional `ruleset=` parameter; unknown names are a 400 like
+    /// `rulesets::resolve_ruleset_param`
+    pub fn resolve_ruleset(&self, ruleset: Option<&str>) -> Result<Option<Ruleset>, StatusCode> {
+        let Some(name) = ruleset.map(str::trim).filter(|name| !name.is_empty()) else {
+            return Ok(None);
+        };
+
+        self.rulesets
+            .iter()
+            .find(|r| r.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .map(Some)
+            .ok_or(StatusCode::BAD_REQUEST)
+    }
+
+    /// Build a roll filter from request parameters. `up_to_expansion` and `ruleset`
+    /// must name a known expansion or ruleset, otherwise the request is a 400.
+    pub fn roll_filter(&self, params: RollParams<'_>) -> Result<RollFilter, StatusCode> {
+        let expansions = match params.expansion {
+            Some(expansion) => {
+                let mut names = vec![expansion.to_lowercase()];
+                if let Some(found) = self
+                    .expansions
+                    .iter()
+                    .find(|e| e.code.eq_ignore_ascii_case(expansion))
+                {
+                    names.push(found.name.to_lowercase());
+                }
+                names
+            }
+            None => Vec::new(),
+        };
+
+        let era_order = match params.up_to_expansion {
+            Some(era) => Some(
+                self.find_expansion(era)
+                    .ok_or(StatusCode::BAD_REQUEST)?
+                    .release_order,
+            ),
+            None => None,
+        };
+
+        let ruleset = self.resolve_ruleset(params.ruleset)?;
+        let max_release_order = [era_order, ruleset.as_ref().map(|r| r.max_release_order)]
+            .into_iter()
+            .flatten()
+            .min();
+
+        let flags = params
+            .flags
+            .map(|flags| {
+                flags
+                    .split(',')
+                    .map(|f| f.trim().to_lowercase())
+                    .filter(|f| !f.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(RollFilter {
+            min: params.min,
+            max: params.max,
+            zone_type: params.zone_type.map(str::to_string),
+            expansions,
+            max_release_order,
+            ruleset,
+            continent: params.continent.map(str::to_string),
+            flags,
+            hot_zone: params.hot_zone,
+        })
+    }
+
+    pub fn matching_zones(&self, filter: &RollFilter) -> Vec<&Zone> {
+        self.zone_index
+            .matching(&self.zones, filter, &self.release_orders)
+            .into_iter()
+            .map(|position| &self.zones[position])
+            .collect()
+    }
+
+    pub fn matching_instances(&self, filter: &RollFilter) -> Vec<&Instance> {
+        self.instance_index
+            .matching(&self.instances, filter, &self.release_orders)
+            .into_iter()
+            .map(|position| &self.instances[position])
+            .collect()
+    }
+}
+
+/// Raw roll parameters as they arrive on the query string
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RollParams<'a> {
+    pub min: Option<u8>,
+    pub max: Option<u8>,
+    pub zone_type: Option<&'a str>,
+    pub expansion: Option<&'a str>,
+    pub up_to_expansion: Option<&'a str>,
+    pub ruleset: Option<&'a str>,
+    pub continent: Option<&'a str>,
+    pub flags: Option<&'a str>,
+    pub hot_zone: Option<bool>,
+}
+
+/// Shared handle to the current snapshot. Readers clone the `Arc` and never wait on a rebuild;
+/// rebuilds are serialized so a slow rebuild can never overwrite a newer one.
+#[derive(Clone, Default)]
+pub struct SnapshotCache {
+    current: Arc<RwLock<Arc<Snapshot>>>,
+    rebuild_lock: Arc<Mutex<()>>,
+}
+
+impl SnapshotCache {
+    /// The current snapshot, which may be unbuilt
+    pub fn current(&self) -> Arc<Snapshot> {
+        self.current
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// The current snapshot, building it first if nothing has been loaded yet
+    pub async fn get(&self, pool: &SqlitePool) -> Result<Arc<Snapshot>, sqlx::Error> {
+        let snapshot = self.current();
+        if snapshot.built_at.is_some() {
+            return Ok(snapshot);
+        }
+        self.rebuild(pool).await
+    }
+
+    /// Rebuild from the database and swap the new snapshot in
+    pub async fn rebuild(&self, pool: &SqlitePool) -> Result<Arc<Snapshot>, sqlx::Error> {
+        let _guard = self.rebuild_lock.lock().await;
+        let started = Instant::now();
+
+        let snapshot = Arc::new(Snapshot::build(pool).await?);
+        *self
+            .current
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = snapshot.clone();
+
+        tracing::info!(
+            zones = snapshot.zones.len(),
+            instances = snapshot.instances.len(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "Roll snapshot rebuilt"
+        );
+
+        Ok(snapshot)
+    }
+}
+
+fn parse_level_ranges(table: &str, id: i64, raw: &str) -> Option<Vec<[u8; 2]>> {
+    match serde_json::from_str(raw) {
+        Ok(ranges) => Some(ranges),
+        Err(err) => {
+            tracing::warn!(
+                table,
+                id,
+                error = %err,
+                raw = %raw,
+                "failed to parse level_ranges; leaving out of snapshot"
+            );
+            None
+        }
+    }
+}
+
+fn parse_connections(table: &str, id: i64, raw: &str) -> Vec<String> {
+    serde_json::from_str(raw).unwrap_or_else(|err| {
+        tracing::warn!(
+            table,
+            id,
+            error = %err,
+            raw = %raw,
+            "failed to parse connections; using empty list"
+        );
+        Vec::new()
+    })
+}
+
+/// Notes and flags of every location of one kind, keyed by owner id
+struct Children<L: Location> {
+    notes: HashMap<i64, Vec<L::Note>>,
+    flags: HashMap<i64, Vec<L::Flag>>,
+    /// Filterable flag names, lowercased
+    filterable: HashMap<i64, Vec<String>>,
+}
+
+impl<L: Location> Children<L> {
+    async fn load(pool: &SqlitePool) -> Result<Self, sqlx::Error> {
+        let mut children = Children {
+            notes: HashMap::new(),
+            flags: HashMap::new(),
+            filterable: HashMap::new(),
+        };
+
+        for flag in load_flags(pool, L::KIND, None).await? {
+            if flag.filterable {
+                children
+                    .filterable
+                    .entry(flag.location_id)
+                    .or_default()
+                    .push(flag.flag_type.name.to_lowercase());
+            }
+            children
+                .flags
+                .entry(flag.location_id)
+                .or_default()
+                .push(L::Flag::from(flag));
+        }
+        for note in load_notes(pool, L::KIND, None).await? {
+            children
+                .notes
+                .entry(note.location_id)
+                .or_default()
+                .push(L::Note::from(note));
+        }
+
+        Ok(children)
+    }
+
+    /// Take one location's notes, flags and filterable flag names
+    fn take(&mut self, id: i64) -> (Vec<L::Note>, Vec<L::Flag>, Vec<String>) {
+        (
+            self.notes.remove(&id).unwrap_or_default(),
+            self.flags.remove(&id).unwrap_or_default(),
+            self.filterable.remove(&id).unwrap_or_default(),
+        )
+    }
+}
+
+/// Zones with flags and notes attached, plus each zone's filterable flag names
+async fn load_zones(pool: &SqlitePool) -> Result<(Vec<Zone>, Vec<Vec<String>>), sqlx::Error> {
+    let mut children = Children::<Zone>::load(pool).await?;
+
+    let rows = sqlx::query("SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, verified FROM zones WHERE deleted_at IS NULL ORDER BY id")
+        .fetch_all(pool)
+        .await?;
+
+    let mut zones = Vec::with_capacity(rows.len());
+    let mut zone_flags = Vec::with_capacity(rows.len());
+    for row in rows {
+        let id: i64 = row.get("id");
+        let Some(level_ranges) =
+            parse_level_ranges("zones", id, &row.get::<String, _>("level_ranges"))
+        else {
+            continue;
+        };
+        let (notes, flags, filterable) = children.take(id);
+
+        zones.push(Zone {
+            id: Some(id),
+            name: sanitize_user_input(&row.get::<String, _>("name")),
+            level_ranges,
+            expansion: sanitize_user_input(&row.get::<String, _>("expansion")),
+            continent: sanitize_user_input(&row.get::<String, _>("continent")),
+            zone_type: sanitize_user_input(&row.get::<String, _>("zone_type")),
+            connections: parse_connections("zones", id, &row.get::<String, _>("connections"))
+                .into_iter()
+                .map(|c| sanitize_user_input(&c))
+                .collect(),
+            image_url: sanitize_url(&row.get::<String, _>("image_url")).unwrap_or_default(),
+            map_url: sanitize_url(&row.get::<String, _>("map_url")).unwrap_or_default(),
+            rating: row.get::<i32, _>("rating") as u8,
+            verified: row.get("verified"),
+            notes,
+            flags,
+        });
+        zone_flags.push(filterable);
+    }
+
+    Ok((zones, zone_flags))
+}
+
+/// Instances with flags and notes attached, plus each instance's filterable flag names
+async fn load_instances(
+    pool: &SqlitePool,
+) -> Result<(Vec<Instance>, Vec<Vec<String>>), sqlx::Error> {
+    let mut children = Children::<Instance>::load(pool).await?;
+
+    let rows = sqlx::query(&format!(
+        "SELECT id, {} FROM instances WHERE deleted_at IS NULL ORDER BY id",
+        INSTANCE_COLUMNS
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    let mut instances = Vec::with_capacity(rows.len());
+    let mut instance_flags = Vec::with_capacity(rows.len());
+    for row in rows {
+        let id: i64 = row.get("id");
+        let Some(level_ranges) =
+            parse_level_ranges("instances", id, &row.get::<String, _>("level_ranges"))
+        else {
+            continue;
+        };
+        let (notes, flags, filterable) = children.take(id);
+        let details = InstanceDetails::from_row(&row);
+        // The hot_zone flag is the source of truth; the old column is only read by the migration
+        let hot_zone = flags.iter().any(|flag| {
+            flag.flag_type
+                .as_ref()
+                .is_some_and(|flag_type| flag_type.name == HOT_ZONE_FLAG)
+        });
+
+        instances.push(Instance {
+            id: Some(id),
+            name: row.get("name"),
+            level_ranges,
+            expansion: row.get("expansion"),
+            continent: row.get("continent"),
+            zone_type: row.get("zone_type"),
+            connections: parse_connections("instances", id, &row.get::<String, _>("connections")),
+            image_url: row.get("image_url"),
+            map_url: row.get("map_url"),
+            rating: row.get::<i32, _>("rating") as u8,
+            hot_zone,
+            verified: row.get("verified"),
+            notes,
+            flags,
+            min_players: details.min_players,
+            max_players: details.max_players,
+            lockout_minutes: details.lockout_minutes,
+            request_npc: details.request_npc,
+            request_zone: details.request_zone,
+            task_type: details.task_type,
+            classification: details.classification,
+        });
+        instance_flags.push(filterable);
+    }
+
+    Ok((instances, instance_flags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+        crate::expansions::seed_expansions(&pool).await.unwrap();
+
+        for (id, name, ranges, expansion, zone_type) in [
+            (1, "Befallen", "[[7,25]]", "Classic", "Indoor"),
+            (2, "Nagafen's Lair", "[[35,55]]", "Classic", "Indoor"),
+            (3, "Dreadlands", "[[35,60]]", "Kunark", "Outdoor"),
+            (
+                4,
+                "Plane of Knowledge",
+                "[[1,65]]",
+                "Planes of Power",
+                "City",
+            ),
+            (5, "Broken", "not json", "Classic", "Indoor"),
+        ] {
+            sqlx::query(
+                "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(id)
+            .bind(name)
+            .bind(ranges)
+            .bind(expansion)
+            .bind(zone_type)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        sqlx::query(
+            "INSERT INTO flag_types (id, name, display_name, color_class, filterable) VALUES (1, 'raid', 'Raid', 'bg-red-500', 1), (2, 'hidden', 'Hidden', 'bg-gray-500', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO zone_flags (zone_id, flag_type_id) VALUES (2, 1), (3, 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO rulesets (name, display_name, max_expansion) VALUES ('mangler', 'Mangler', 'SoV')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        pool
+    }
+
+    fn names(zones: Vec<&Zone>) -> Vec<&str> {
+        let mut names: Vec<&str> = zones.iter().map(|z| z.name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[tokio::test]
+    async fn filters_match_by_index_and_predicate() {
+        let pool = setup_pool().await;
+        let snapshot = Snapshot::build(&pool).await.unwrap();
+        assert_eq!(snapshot.zones.len(), 4);
+
+        let filter = snapshot
+            .roll_filter(RollParams {
+                expansion: Some("classic"),
+                zone_type: Some("indoor"),
+                min: Some(20),
+                max: Some(24),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(names(snapshot.matching_zones(&filter)), vec!["Befallen"]);
+
+        // Only filterable flags are indexed
+        let filter = snapshot
+            .roll_filter(RollParams {
+                flags: Some("RAID, hidden"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            names(snapshot.matching_zones(&filter)),
+            vec!["Nagafen's Lair"]
+        );
+
+        let filter = snapshot
+            .roll_filter(RollParams {
+                expansion: Some("RoK"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(names(snapshot.matching_zones(&filter)), vec!["Dreadlands"]);
+    }
+
+    #[tokio::test]
+    async fn instances_filter_by_flags_like_zones() {
+        let pool = setup_pool().await;
+        for (id, name) in [(1, "Miragul's Menagerie"), (2, "Deepest Guk")] {
+            sqlx::query(
+                "INSERT INTO instances (id, name, level_ranges, expansion, zone_type) VALUES (?, ?, '[[50,65]]', 'Luclin', 'Dungeon')",
+            )
+            .bind(id)
+            .bind(name)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query("INSERT INTO instance_flags (instance_id, flag_type_id) VALUES (1, 1), (2, 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let snapshot = Snapshot::build(&pool).await.unwrap();
+        let filter = snapshot
+            .roll_filter(RollParams {
+                flags: Some("raid,hidden"),
+                ..Default::default()
+            })
+            .unwrap();
+        let matching = snapshot.matching_instances(&filter);
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].name, "Miragul's Menagerie");
+        assert_eq!(
+            matching[0].flags[0].flag_type.as_ref().unwrap().name,
+            "raid"
+        );
+
+        // Unfilterable flags still show up on the instance itself
+        let guk = snapshot.instances.iter().find(|i| i.id == Some(2)).unwrap();
+        assert_eq!(guk.flags.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn hot_instances_move_to_the_hot_zone_flag() {
+        let pool = setup_pool().await;
+        sqlx::query(
+            "INSERT INTO flag_types (id, name, display_name, color_class, filterable) VALUES (3, 'hot_zone', 'Hot Zone', 'bg-red-500', 1)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        for (id, name, hot_zone) in [(1, "Miragul's Menagerie", true), (2, "Deepest Guk", false)] {
+            sqlx::query(
+                "INSERT INTO instances (id, name, level_ranges, expansion, zone_type, hot_zone) VALUES (?, ?, '[[50,65]]', 'Luclin', 'Dungeon', ?)",
+            )
+            .bind(id)
+            .bind(name)
+            .bind(hot_zone)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(
+            crate::migrate_hot_instances_to_flags(&pool).await.unwrap(),
+            1
+        );
+        assert_eq!(
+            crate::migrate_hot_instances_to_flags(&pool).await.unwrap(),
+            0
+        );
+
+        // The old column is cleared, so only the flag marks a hot instance now
+        let still_set: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM instances WHERE hot_zone = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(still_set, 0);
+        let snapshot = Snapshot::build(&pool).await.unwrap();
+        for (hot_zone, expected) in [(true, "Miragul's Menagerie"), (false, "Deepest Guk")] {
+            let filter = snapshot
+                .roll_filter(RollParams {
+                    hot_zone: Some(hot_zone),
+                    ..Default::default()
+                })
+                .unwrap();
+            let matching = snapshot.matching_instances(&filter);
+            assert_eq!(matching.len(), 1);
+            assert_eq!(matching[0].name, expected);
+            assert_eq!(matching[0].hot_zone, hot_zone);
+        }
+
+        // A flag an admin removes stays removed across restarts
+        sqlx::query("DELETE FROM instance_flags WHERE instance_id = 1 AND flag_type_id = 3")
+            .execute(&pool)
+            .await
+            .unwrap();
+        crate::run_migrations(&pool).await.unwrap();
+        let flags: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM instance_flags WHERE flag_type_id = 3")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(flags, 0);
+    }
+
+    #[tokio::test]
+    async fn era_and_ruleset_limits_and_unknown_names() {
+        let pool = setup_pool().await;
+        let snapshot = Snapshot::build(&pool).await.unwrap();
+
+        let filter = snapshot
+            .roll_filter(RollParams {
+                ruleset: Some("Mangler"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            names(snapshot.matching_zones(&filter)),
+            vec!["Befallen", "Dreadlands", "Nagafen's Lair"]
+        );
+
+        let filter = snapshot
+            .roll_filter(RollParams {
+                up_to_expansion: Some("Classic"),
+                min: Some(30),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(
+            names(snapshot.matching_zones(&filter)),
+            vec!["Nagafen's Lair"]
+        );
+
+        for params in [
+            RollParams {
+                ruleset: Some("nope"),
+                ..Default::default()
+            },
+            RollParams {
+                up_to_expansion: Some("Ykesha"),
+                ..Default::default()
+            },
+        ] {
+            assert_eq!(
+                snapshot.roll_filter(params).unwrap_err(),
+                StatusCode::BAD_REQUEST
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn cache_builds_lazily_and_swaps_on_rebuild() {
+        let pool = setup_pool().await;
+        let cache = SnapshotCache::default();
+        assert!(cache.current().built_at.is_none());
+
+        let first = cache.get(&pool).await.unwrap();
+        assert_eq!(first.zones.len(), 4);
+
+        sqlx::query("DELETE FROM zones WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(cache.get(&pool).await.unwrap().zones.len(), 4);
+
+        cache.rebuild(&pool).await.unwrap();
+        assert_eq!(cache.current().zones.len(), 3);
+        // Readers holding the old snapshot keep a consistent view
+        assert_eq!(first.zones.len(), 4);
+    }
+}
@@ -0,0 +1,278 @@
+use serde::Serialize;
+use sqlx::{Row, SqlitePool};
+use std::time::Duration;
+
+use crate::audit::{self, AuditAction, AuditEntity};
+use crate::config::TrashConfig;
+
+/// Tables whose rows go to the trash instead of being deleted outright
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TrashKind {
+    Zone,
+    Instance,
+    Link,
+}
+
+impl TrashKind {
+    pub const ALL: [TrashKind; 3] = [TrashKind::Zone, TrashKind::Instance, TrashKind::Link];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrashKind::Zone => "zone",
+            TrashKind::Instance => "instance",
+            TrashKind::Link => "link",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    fn entity(self) -> AuditEntity {
+        match self {
+            TrashKind::Zone => AuditEntity::Zone,
+            TrashKind::Instance => AuditEntity::Instance,
+            TrashKind::Link => AuditEntity::Link,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct TrashedItem {
+    pub kind: TrashKind,
+    pub id: i64,
+    pub name: String,
+    pub deleted_at: String,
+}
+
+/// Set `deleted_at` on a live row, or clear it on a trashed one. Returns false if
+/// there was no row in the expected state.
+async fn set_deleted(
+    pool: &SqlitePool,
+    kind: TrashKind,
+    id: i64,
+    deleted: bool,
+) -> Result<bool, sqlx::Error> {
+    let entity = kind.entity();
+    let mut tx = pool.begin().await?;
+    let before = audit::capture(&mut tx, entity, id).await;
+
+    let query = if deleted {
+        format!(
+            "UPDATE {} SET deleted_at = CURRENT_TIMESTAMP WHERE id = ? AND deleted_at IS NULL",
+            entity.table()
+        )
+    } else {
+        format!(
+            "UPDATE {} SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL",
+            entity.table()
+        )
+    };
+    let result = sqlx::query(&query).bind(id).execute(&mut *tx).await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    let after = audit::capture(&mut tx, entity, id).await;
+    audit::record_change(&mut tx, AuditAction::Update, entity, id, before, after).await?;
+    tx.commit().await?;
+    Ok(true)
+}
+
+/// Move a zone, instance or link to the trash. It disappears from every public
+/// query and roll but keeps its notes, flags, aliases and ratings until purged.
+pub async fn soft_delete(pool: &SqlitePool, kind: TrashKind, id: i64) -> Result<bool, sqlx::Error> {
+    set_deleted(pool, kind, id, true).await
+}
+
+/// Take an item back out of the trash
+pub async fn restore(pool: &SqlitePool, kind: TrashKind, id: i64) -> Result<bool, sqlx::Error> {
+    set_deleted(pool, kind, id, false).await
+}
+
+/// Permanently delete a trashed item and everything that cascades from it
+pub async fn purge(pool: &SqlitePool, kind: TrashKind, id: i64) -> Result<bool, sqlx::Error> {
+    let entity = kind.entity();
+    let mut tx = pool.begin().await?;
+    let changes = audit::capture_cascade(&mut tx, entity, id).await;
+
+    let query = format!(
+        "DELETE FROM {} WHERE id = ? AND deleted_at IS NOT NULL",
+        entity.table()
+    );
+    let result = sqlx::query(&query).bind(id).execute(&mut *tx).await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    audit::record_batch_change(&mut tx, changes).await?;
+    tx.commit().await?;
+    Ok(true)
+}
+
+/// Everything in the trash, most recently deleted first
+pub async fn list_trash(pool: &SqlitePool) -> Result<Vec<TrashedItem>, sqlx::Error> {
+    let mut items = Vec::new();
+
+    for kind in TrashKind::ALL {
+        let query = format!(
+            "SELECT id, name, deleted_at FROM {} WHERE deleted_at IS NOT NULL",
+            kind.entity().table()
+        );
+        for row in sqlx::query(&query).fetch_all(pool).await? {
+            items.push(TrashedItem {
+                kind,
+                id: row.get("id"),
+                name: row.get("name"),
+                deleted_at: row.get("deleted_at"),
+            });
+        }
+    }
+
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    Ok(items)
+}
+
+/// Purge every item that has been in the trash longer than `retention_days`.
+/// Returns how many were purged.
+pub async fn purge_expired(pool: &SqlitePool, retention_days: u64) -> Result<usize, sqlx::Error> {
+    let mut purged = 0;
+
+    for kind in TrashKind::ALL {
+        let query = format!(
+            "SELECT id FROM {} WHERE deleted_at IS NOT NULL AND deleted_at < datetime('now', ?)",
+            kind.entity().table()
+        );
+        let ids: Vec<i64> = sqlx::query_scalar(&query)
+            .bind(format!("-{} days", retention_days))
+            .fetch_all(pool)
+            .await?;
+
+        for id in ids {
+            if purge(pool, kind, id).await? {
+                purged += 1;
+            }
+        }
+    }
+
+    Ok(purged)
+}
+
+pub fn spawn_purge_scheduler(
+    pool: SqlitePool,
+    config: TrashConfig,
+) -> Option<tokio::task::JoinHandle<()>> {
+    if config.retention_days == 0 || config.purge_interval_minutes == 0 {
+        tracing::info!("Trash purging is disabled; trashed items are kept until purged by hand");
+        return None;
+    }
+
+    let period = Duration::from_secs(config.purge_interval_minutes * 60);
+    tracing::info!(
+        retention_days = config.retention_days,
+        interval_minutes = config.purge_interval_minutes,
+        "Trash purging enabled"
+    );
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            match purge_expired(&pool, config.retention_days).await {
+                Ok(0) => {}
+                Ok(purged) => tracing::info!(purged, "Purged expired items from the trash"),
+                Err(e) => tracing::error!(error = %e, "Failed to purge expired trash"),
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::create_tables(&pool).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO zones (id, name, level_ranges, expansion, zone_type) VALUES (2, 'Befallen', '[[7,25]]', 'Classic', 'Indoor')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO zone_aliases (zone_id, alias) VALUES (2, 'bef')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn trashed_zones_leave_public_queries_until_restored() {
+        let pool = setup_pool().await;
+
+        assert!(soft_delete(&pool, TrashKind::Zone, 2).await.unwrap());
+        assert!(!soft_delete(&pool, TrashKind::Zone, 2).await.unwrap());
+
+        let snapshot = crate::snapshot::Snapshot::build(&pool).await.unwrap();
+        assert!(snapshot.zones.is_empty());
+        assert!(
+            crate::aliases::resolve_name(&pool, "bef")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(crate::get_zones_count(&pool).await.unwrap(), 0);
+
+        let trash = list_trash(&pool).await.unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!((trash[0].kind, trash[0].id), (TrashKind::Zone, 2));
+
+        assert!(restore(&pool, TrashKind::Zone, 2).await.unwrap());
+        assert!(list_trash(&pool).await.unwrap().is_empty());
+        let resolved = crate::aliases::resolve_name(&pool, "bef")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(resolved.id, 2);
+    }
+
+    #[tokio::test]
+    async fn purges_only_expired_items() {
+        let pool = setup_pool().await;
+
+        // Live rows are never purged, however old
+        assert!(!purge(&pool, TrashKind::Zone, 2).await.unwrap());
+
+        soft_delete(&pool, TrashKind::Zone, 2).await.unwrap();
+        assert_eq!(purge_expired(&pool, 30).await.unwrap(), 0);
+
+        sqlx::query("UPDATE zones SET deleted_at = datetime('now', '-31 days') WHERE id = 2")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(purge_expired(&pool, 30).await.unwrap(), 1);
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM zones")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+        assert!(
+            crate::aliases::get_all_alias_names(&pool)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
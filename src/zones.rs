@@ -1,33 +1,17 @@
+use crate::security::{sanitize_url, sanitize_user_input};
+use crate::snapshot::RollParams;
 use axum::extract::FromRef;
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::Json,
 };
-use crate::security::{
-    sanitize_url,
-    sanitize_user_input,
-    sanitize_user_input_with_formatting,
-};
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct NoteType {
-    pub id: Option<i64>,
-    pub name: String,
-    pub display_name: String,
-    pub color_class: String,
-}
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct FlagType {
-    pub id: Option<i64>,
-    pub name: String,
-    pub display_name: String,
-    pub color_class: String,
-}
+use crate::locations::{FlagRecord, Location, LocationKind, NoteRecord};
+pub use crate::locations::{FlagType, NoteType};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ZoneFlag {
@@ -42,10 +26,48 @@ pub struct ZoneNote {
     pub id: Option<i64>,
     pub zone_id: i64,
     pub note_type_id: i64,
+    /// The note as written, in Markdown
     pub content: String,
+    /// The note rendered to sanitized HTML
+    #[serde(default)]
+    pub content_html: String,
     pub note_type: Option<NoteType>,
 }
 
+impl From<FlagRecord> for ZoneFlag {
+    fn from(record: FlagRecord) -> Self {
+        ZoneFlag {
+            id: Some(record.id),
+            zone_id: record.location_id,
+            flag_type_id: record.flag_type_id,
+            flag_type: Some(FlagType {
+                id: record.flag_type.id,
+                name: sanitize_user_input(&record.flag_type.name),
+                display_name: sanitize_user_input(&record.flag_type.display_name),
+                color_class: sanitize_user_input(&record.flag_type.color_class),
+            }),
+        }
+    }
+}
+
+impl From<NoteRecord> for ZoneNote {
+    fn from(record: NoteRecord) -> Self {
+        ZoneNote {
+            id: Some(record.id),
+            zone_id: record.location_id,
+            note_type_id: record.note_type_id,
+            content_html: crate::markdown::render(&record.content),
+            content: record.content,
+            note_type: Some(NoteType {
+                id: record.note_type.id,
+                name: sanitize_user_input(&record.note_type.name),
+                display_name: sanitize_user_input(&record.note_type.display_name),
+                color_class: sanitize_user_input(&record.note_type.color_class),
+            }),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ZoneState {
     pub pool: Arc<SqlitePool>,
@@ -74,12 +96,39 @@ pub struct Zone {
     pub flags: Vec<ZoneFlag>,
 }
 
+impl Location for Zone {
+    const KIND: LocationKind = LocationKind::Zone;
+    type Note = ZoneNote;
+    type Flag = ZoneFlag;
+
+    fn id(&self) -> Option<i64> {
+        self.id
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn level_ranges(&self) -> &[[u8; 2]] {
+        &self.level_ranges
+    }
+    fn expansion(&self) -> &str {
+        &self.expansion
+    }
+    fn zone_type(&self) -> &str {
+        &self.zone_type
+    }
+    fn continent(&self) -> &str {
+        &self.continent
+    }
+}
+
 #[derive(Deserialize)]
 pub struct RangeQuery {
     pub min: Option<u8>,
     pub max: Option<u8>,
     zone_type: Option<String>,
     expansion: Option<String>,
+    up_to_expansion: Option<String>, // Expansion code or name; includes every earlier expansion
+    ruleset: Option<String>,         // Server ruleset profile name
     continent: Option<String>,
     flags: Option<String>, // Comma-separated flag names
 }
@@ -90,178 +139,34 @@ pub async fn random_zone(
 ) -> Result<Json<Zone>, StatusCode> {
     let pool = &*state.zone_state.pool;
 
-    let mut query = String::from(
-        "SELECT DISTINCT z.id, z.name, z.level_ranges, z.expansion, z.continent, z.zone_type, z.connections, z.image_url, z.map_url, z.rating, z.verified FROM zones z",
-    );
-    let mut bindings: Vec<String> = Vec::new();
-    let mut where_conditions = Vec::new();
-
-    // Add flag filtering if specified (only for filterable flags)
-    if let Some(ref flags_param) = params.flags {
-        let flag_names: Vec<&str> = flags_param.split(',').map(|s| s.trim()).collect();
-        if !flag_names.is_empty() {
-            query.push_str(" JOIN zone_flags zf ON z.id = zf.zone_id JOIN flag_types ft ON zf.flag_type_id = ft.id");
-            let flag_placeholders = flag_names
-                .iter()
-                .map(|_| "LOWER(ft.name) = LOWER(?) AND ft.filterable = 1")
-                .collect::<Vec<_>>()
-                .join(" OR ");
-            where_conditions.push(format!("({})", flag_placeholders));
-            for flag_name in flag_names {
-                bindings.push(flag_name.to_string());
-            }
-        }
-    }
-
-    where_conditions.push("1=1".to_string());
-
-    if let Some(ref zone_type) = params.zone_type {
-        where_conditions.push("LOWER(z.zone_type) = LOWER(?)".to_string());
-        bindings.push(zone_type.clone());
-    }
-
-    if let Some(ref expansion) = params.expansion {
-        where_conditions.push("LOWER(z.expansion) = LOWER(?)".to_string());
-        bindings.push(expansion.clone());
-    }
-
-    if let Some(ref continent) = params.continent {
-        where_conditions.push("LOWER(z.continent) = LOWER(?)".to_string());
-        bindings.push(continent.clone());
-    }
-
-    query.push_str(" WHERE ");
-    query.push_str(&where_conditions.join(" AND "));
-    query.push_str(" ORDER BY RANDOM() LIMIT 100");
-
-    // Diagnostic: log the constructed query and bindings to help debug 500s
-    let mut sql_query = sqlx::query(&query);
-    tracing::debug!(sql = %query, bindings = ?bindings, "random_zone query");
-    for binding in &bindings {
-        sql_query = sql_query.bind(binding);
-    }
-
-    let rows = sql_query.fetch_all(pool).await.map_err(|e| {
-        tracing::error!(error = %e, "Database error in random_zone query");
+    let snapshot = state.snapshot.get(pool).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to load roll snapshot");
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
-    let mut matching_zones: Vec<Zone> = Vec::new();
-
-    for row in rows {
-        let level_ranges_json: String = row.get("level_ranges");
-        let connections_json: String = row.get("connections");
-
-        // Parse level_ranges with diagnostics. If parsing fails, skip this row rather than returning 500.
-        let level_ranges: Vec<[u8; 2]> = match serde_json::from_str(&level_ranges_json) {
-            Ok(v) => v,
-            Err(err) => {
-                tracing::warn!(
-                    zone_id = row.get::<i64, _>("id"),
-                    error = %err,
-                    raw = %level_ranges_json,
-                    "failed to parse level_ranges; skipping zone"
-                );
-                // Skip this row since we can't interpret its level ranges
-                continue;
-            }
-        };
-
-        // Parse connections; on failure fall back to empty list but continue processing the zone
-        let connections: Vec<String> = match serde_json::from_str(&connections_json) {
-            Ok(v) => v,
-            Err(err) => {
-                tracing::warn!(
-                    zone_id = row.get::<i64, _>("id"),
-                    error = %err,
-                    raw = %connections_json,
-                    "failed to parse connections; using empty list"
-                );
-                Vec::new()
-            }
-        };
-
-        let zone_id = row.get::<i64, _>("id");
-
-        // Load flags for this zone with error handling
-        let flags = match get_zone_flags(pool, zone_id).await {
-            Ok(f) => f,
-            Err(e) => {
-                tracing::warn!(zone_id = zone_id, error = %e, "failed to load flags for zone");
-                Vec::new()
-            }
-        };
-
-        let zone = Zone {
-            id: Some(zone_id),
-            name: sanitize_user_input(&row.get::<String, _>("name")),
-            level_ranges: level_ranges.clone(),
-            expansion: sanitize_user_input(&row.get::<String, _>("expansion")),
-            continent: sanitize_user_input(&row.get::<String, _>("continent")),
-            zone_type: sanitize_user_input(&row.get::<String, _>("zone_type")),
-            connections: connections
-                .into_iter()
-                .map(|c| sanitize_user_input(&c))
-                .collect(),
-            image_url: sanitize_url(&row.get::<String, _>("image_url")).unwrap_or_default(),
-            map_url: sanitize_url(&row.get::<String, _>("map_url")).unwrap_or_default(),
-            rating: row.get::<i32, _>("rating") as u8,
-            verified: row.get("verified"),
-            notes: Vec::new(),
-            flags,
-        };
-
-        let mut level_match = true;
-        if params.min.is_some() || params.max.is_some() {
-            level_match = false;
-            if let (Some(min), Some(max)) = (params.min, params.max) {
-                if level_ranges
-                    .iter()
-                    .any(|&[lmin, lmax]| lmin <= min && lmax >= max)
-                {
-                    level_match = true;
-                }
-            } else if let Some(min) = params.min {
-                if level_ranges.iter().any(|&[_lmin, lmax]| lmax >= min) {
-                    level_match = true;
-                }
-            } else if let Some(max) = params.max {
-                if level_ranges.iter().any(|&[lmin, _lmax]| lmin <= max) {
-                    level_match = true;
-                }
-            }
-        }
-
-        if level_match {
-            matching_zones.push(zone);
-        }
-    }
-
-    if matching_zones.is_empty() {
-        return Err(StatusCode::NOT_FOUND);
-    }
+    let filter = snapshot.roll_filter(RollParams {
+        min: params.min,
+        max: params.max,
+        zone_type: params.zone_type.as_deref(),
+        expansion: params.expansion.as_deref(),
+        up_to_expansion: params.up_to_expansion.as_deref(),
+        ruleset: params.ruleset.as_deref(),
+        continent: params.continent.as_deref(),
+        flags: params.flags.as_deref(),
+        hot_zone: None,
+    })?;
 
     use rand::SeedableRng;
     use rand::rngs::StdRng;
     use rand::seq::SliceRandom;
     let mut rng = StdRng::from_entropy();
 
-    if let Some(zone) = matching_zones.choose(&mut rng) {
-        // Clone the chosen zone and load its notes so the /random_zone response includes notes.
-        let mut chosen = zone.clone();
-        if let Some(id) = chosen.id {
-            match get_zone_notes(pool, id).await {
-                Ok(notes) => chosen.notes = notes,
-                Err(e) => {
-                    tracing::warn!(zone_id = id, error = %e, "failed to load notes for chosen zone");
-                    chosen.notes = Vec::new();
-                }
-            }
-        }
-        Ok(Json(chosen))
-    } else {
-        Err(StatusCode::NOT_FOUND)
-    }
+    // Snapshot zones already carry their flags and notes, so the pick needs no further queries
+    snapshot
+        .matching_zones(&filter)
+        .choose(&mut rng)
+        .map(|zone| Json((*zone).clone()))
+        .ok_or(StatusCode::NOT_FOUND)
 }
 
 pub async fn get_zone_notes_endpoint(
@@ -270,14 +175,13 @@ pub async fn get_zone_notes_endpoint(
 ) -> Result<Json<Vec<ZoneNote>>, StatusCode> {
     let pool = &*state.zone_state.pool;
 
-    match get_zone_notes(pool, zone_id).await {
-        Ok(notes) => Ok(Json(notes)),
-        Err(_) => Ok(Json(Vec::new())), // Return empty array on error
-    }
+    Ok(Json(
+        crate::locations::public_notes::<Zone>(pool, zone_id).await,
+    ))
 }
 
 pub async fn get_all_zones(pool: &SqlitePool) -> Result<Vec<Zone>, sqlx::Error> {
-    let rows = sqlx::query("SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, verified FROM zones ORDER BY name")
+    let rows = sqlx::query("SELECT id, name, level_ranges, expansion, continent, zone_type, connections, image_url, map_url, rating, verified FROM zones WHERE deleted_at IS NULL ORDER BY name")
         .fetch_all(pool)
         .await?;
 
@@ -315,44 +219,7 @@ pub async fn get_all_zones(pool: &SqlitePool) -> Result<Vec<Zone>, sqlx::Error>
 }
 
 pub async fn get_zone_notes(pool: &SqlitePool, zone_id: i64) -> Result<Vec<ZoneNote>, sqlx::Error> {
-    let rows = sqlx::query(
-        r#"
-        SELECT
-            zn.id,
-            zn.zone_id,
-            zn.note_type_id,
-            zn.content,
-            nt.name as note_type_name,
-            nt.display_name as note_type_display_name,
-            nt.color_class as note_type_color_class
-        FROM zone_notes zn
-        JOIN note_types nt ON zn.note_type_id = nt.id
-        WHERE zn.zone_id = ?
-        ORDER BY zn.created_at ASC
-        "#,
-    )
-    .bind(zone_id)
-    .fetch_all(pool)
-    .await?;
-
-    let mut notes = Vec::new();
-
-    for row in rows {
-        notes.push(ZoneNote {
-            id: Some(row.get::<i64, _>("id")),
-            zone_id: row.get("zone_id"),
-            note_type_id: row.get("note_type_id"),
-            content: sanitize_user_input_with_formatting(&row.get::<String, _>("content")),
-            note_type: Some(NoteType {
-                id: Some(row.get("note_type_id")),
-                name: sanitize_user_input(&row.get::<String, _>("note_type_name")),
-                display_name: sanitize_user_input(&row.get::<String, _>("note_type_display_name")),
-                color_class: sanitize_user_input(&row.get::<String, _>("note_type_color_class")),
-            }),
-        });
-    }
-
-    Ok(notes)
+    crate::locations::get_notes::<Zone>(pool, zone_id).await
 }
 
 pub async fn get_note_types(pool: &SqlitePool) -> Result<Vec<NoteType>, sqlx::Error> {
@@ -419,42 +286,7 @@ pub async fn get_all_flag_types(pool: &SqlitePool) -> Result<Vec<FlagType>, sqlx
 }
 
 pub async fn get_zone_flags(pool: &SqlitePool, zone_id: i64) -> Result<Vec<ZoneFlag>, sqlx::Error> {
-    let rows = sqlx::query(
-        r#"
-        SELECT
-            zf.id,
-            zf.zone_id,
-            zf.flag_type_id,
-            ft.name as flag_type_name,
-            ft.display_name as flag_type_display_name,
-            ft.color_class as flag_type_color_class
-        FROM zone_flags zf
-        JOIN flag_types ft ON zf.flag_type_id = ft.id
-        WHERE zf.zone_id = ?
-        ORDER BY ft.display_name ASC
-        "#,
-    )
-    .bind(zone_id)
-    .fetch_all(pool)
-    .await?;
-
-    let mut flags = Vec::new();
-
-    for row in rows {
-        flags.push(ZoneFlag {
-            id: Some(row.get::<i64, _>("id")),
-            zone_id: row.get("zone_id"),
-            flag_type_id: row.get("flag_type_id"),
-            flag_type: Some(FlagType {
-                id: Some(row.get("flag_type_id")),
-                name: sanitize_user_input(&row.get::<String, _>("flag_type_name")),
-                display_name: sanitize_user_input(&row.get::<String, _>("flag_type_display_name")),
-                color_class: sanitize_user_input(&row.get::<String, _>("flag_type_color_class")),
-            }),
-        });
-    }
-
-    Ok(flags)
+    crate::locations::get_flags::<Zone>(pool, zone_id).await
 }
 
 pub async fn get_flag_types_api(